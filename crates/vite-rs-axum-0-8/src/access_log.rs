@@ -0,0 +1,178 @@
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::extract::ConnectInfo;
+use axum::http::request::Parts;
+use axum::http::{header, HeaderMap, Version};
+use axum::response::Response;
+
+type Sink = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Logs every request served by `ViteServe` in the Combined Log Format:
+///
+/// ```text
+/// 127.0.0.1 - - [10/Oct/2024:13:55:36 +0000] "GET /index.html HTTP/1.1" 200 2326 "-" "curl/8.5.0"
+/// ```
+///
+/// Lines are printed to stdout unless a custom sink is given. Clones share their enabled
+/// state, so keep a clone around to toggle logging at runtime:
+///
+/// ```ignore
+/// let access_log = AccessLog::new();
+/// let service = ViteServe::new(Assets::boxed()).with_access_log(access_log.clone());
+///
+/// // later, e.g. from an admin endpoint:
+/// access_log.disable();
+/// ```
+///
+/// The client address is only known if the server was started with
+/// `into_make_service_with_connect_info::<SocketAddr>()`; otherwise it is logged as `-`.
+#[derive(Clone)]
+pub struct AccessLog {
+    enabled: Arc<AtomicBool>,
+    sink: Sink,
+}
+
+impl Default for AccessLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccessLog {
+    /// An enabled access log which prints to stdout.
+    pub fn new() -> Self {
+        Self::with_sink(|line| println!("{}", line))
+    }
+
+    /// An enabled access log which hands every line to `sink` (without a trailing newline).
+    pub fn with_sink<F>(sink: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            enabled: Arc::new(AtomicBool::new(true)),
+            sink: Arc::new(sink),
+        }
+    }
+
+    pub fn enable(&self) {
+        self.set_enabled(true);
+    }
+
+    pub fn disable(&self) {
+        self.set_enabled(false);
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn log(&self, req: &Parts, response: &Response) {
+        if self.is_enabled() {
+            (self.sink)(&combined_log_line(req, response, SystemTime::now()));
+        }
+    }
+}
+
+fn combined_log_line(req: &Parts, response: &Response, time: SystemTime) -> String {
+    let remote_addr = req
+        .extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip().to_string())
+        .unwrap_or_else(|| "-".to_string());
+
+    let target = req
+        .uri
+        .path_and_query()
+        .map(|target| target.as_str())
+        .unwrap_or("/");
+
+    let bytes = response
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok())
+        .filter(|length| *length != "0")
+        .unwrap_or("-");
+
+    format!(
+        "{} - - [{}] \"{} {} {}\" {} {} \"{}\" \"{}\"",
+        remote_addr,
+        format_time(time),
+        req.method,
+        escape(target),
+        format_version(req.version),
+        response.status().as_u16(),
+        bytes,
+        escape(header_or_dash(&req.headers, header::REFERER)),
+        escape(header_or_dash(&req.headers, header::USER_AGENT)),
+    )
+}
+
+fn header_or_dash(headers: &HeaderMap, name: header::HeaderName) -> &str {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("-")
+}
+
+/// Quotes and backslashes would otherwise break the quoted fields of the log line.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn format_version(version: Version) -> &'static str {
+    match version {
+        Version::HTTP_09 => "HTTP/0.9",
+        Version::HTTP_10 => "HTTP/1.0",
+        Version::HTTP_2 => "HTTP/2.0",
+        Version::HTTP_3 => "HTTP/3.0",
+        _ => "HTTP/1.1",
+    }
+}
+
+/// Formats a timestamp as `10/Oct/2024:13:55:36 +0000` (always UTC).
+fn format_time(time: SystemTime) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or(0);
+    let (year, month, day) = civil_from_days((secs / 86400) as i64);
+    let secs_of_day = secs % 86400;
+
+    format!(
+        "{:02}/{}/{}:{:02}:{:02}:{:02} +0000",
+        day,
+        MONTHS[month as usize - 1],
+        year,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Converts days since the unix epoch to a (year, month, day) date.
+/// See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
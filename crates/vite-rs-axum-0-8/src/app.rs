@@ -0,0 +1,175 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use vite_rs_interface::{GetFromVite, RequestContext, RouteManifest};
+
+use crate::vite_serve::ViteServe;
+
+type DevServerStart = Box<dyn FnOnce() -> Box<dyn Any + Send> + Send>;
+
+/// Serves an API router and the assets on one port (see `app_with_assets`).
+pub struct AppWithAssets {
+    api: Router,
+    serve: ViteServe,
+    health_path: Option<String>,
+    dev_server: Option<DevServerStart>,
+}
+
+/// Serves `api` and the assets on one port: the API's routes come first, then the health check
+/// (`GET /healthz`), and every other path is served by a `ViteServe` with client-side routes, so
+/// `index.html` answers paths like `/settings/profile`.
+///
+/// ```ignore
+/// let app = vite_rs_axum_0_8::app_with_assets(api, Assets::boxed())
+///     .with_routes(Assets::routes())
+///     .with_api_route("/api/*");
+///
+/// #[cfg(debug_assertions)]
+/// let app = app.with_dev_server(|| Assets::start_dev_server(false));
+///
+/// app.serve(listener, shutdown_signal()).await?;
+/// ```
+///
+/// The API keeps working when the assets don't: while the ViteJS dev server is unavailable, asset
+/// requests get `503 Service Unavailable` and the health check reports `"assets":"unavailable"`.
+pub fn app_with_assets(api: Router, assets: Box<dyn GetFromVite>) -> AppWithAssets {
+    AppWithAssets {
+        api,
+        serve: ViteServe::new(assets)
+            .with_routes(RouteManifest::new().with_entry("/*", "index.html")),
+        health_path: Some("/healthz".to_string()),
+        dev_server: None,
+    }
+}
+
+impl AppWithAssets {
+    /// Which HTML entry serves which client-side route, e.g. `Assets::routes()`. Defaults to
+    /// `index.html` for every path. API routes marked with `with_api_route` before are kept.
+    pub fn with_routes(mut self, routes: RouteManifest) -> Self {
+        let api_routes = self
+            .serve
+            .routes
+            .take()
+            .map(|previous| previous.api_routes().to_vec())
+            .unwrap_or_default();
+
+        let routes = api_routes
+            .into_iter()
+            .fold(routes, RouteManifest::with_api_route);
+        self.serve = self.serve.with_routes(routes);
+        self
+    }
+
+    /// Marks the paths matching `pattern` as the API's, e.g. `/api/*`, so the ones the API router
+    /// doesn't handle get `404 Not Found` rather than an HTML entry.
+    pub fn with_api_route(mut self, pattern: impl Into<String>) -> Self {
+        let routes = self.serve.routes.take().unwrap_or_default();
+        self.serve.routes = Some(routes.with_api_route(pattern));
+        self
+    }
+
+    /// Configures the `ViteServe` serving the assets, e.g. its cache strategy.
+    pub fn map_vite_serve(mut self, f: impl FnOnce(ViteServe) -> ViteServe) -> Self {
+        self.serve = f(self.serve);
+        self
+    }
+
+    /// Serves the health check at `path` instead of `/healthz`.
+    pub fn with_health_path(mut self, path: impl Into<String>) -> Self {
+        self.health_path = Some(path.into());
+        self
+    }
+
+    pub fn without_health_check(mut self) -> Self {
+        self.health_path = None;
+        self
+    }
+
+    /// Starts the dev server when `serve` is called, e.g. with `|| Assets::start_dev_server(false)`,
+    /// and drops what `start` returns (stopping the dev server) after the graceful shutdown. Only
+    /// `serve` does this; `into_router` leaves the dev server to you.
+    pub fn with_dev_server<G: Send + 'static>(
+        mut self,
+        start: impl FnOnce() -> G + Send + 'static,
+    ) -> Self {
+        self.dev_server = Some(Box::new(move || Box::new(start()) as Box<dyn Any + Send>));
+        self
+    }
+
+    pub fn into_router(self) -> Router {
+        let mut router = self.api;
+
+        if let Some(path) = self.health_path {
+            let assets: Arc<dyn GetFromVite> = Arc::from(self.serve.assets.clone_box());
+            let entry = self
+                .serve
+                .routes
+                .as_ref()
+                .and_then(|routes| routes.resolve("/"))
+                .unwrap_or("index.html")
+                .to_string();
+
+            router = router.route(&path, get(move || health(assets.clone(), entry.clone())));
+        }
+
+        router.fallback_service(self.serve)
+    }
+
+    /// Starts the dev server (see `with_dev_server`) and serves the app on `listener` until
+    /// `shutdown` completes, then waits for the open requests and stops the dev server.
+    #[cfg(feature = "serve")]
+    pub async fn serve(
+        mut self,
+        listener: tokio::net::TcpListener,
+        shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+    ) -> std::io::Result<()> {
+        // starting the dev server blocks, e.g. while a previous one shuts down
+        let dev_server = match self.dev_server.take() {
+            Some(start) => Some(
+                tokio::task::spawn_blocking(start)
+                    .await
+                    .map_err(std::io::Error::other)?,
+            ),
+            None => None,
+        };
+
+        let result = axum::serve(listener, self.into_router())
+            .with_graceful_shutdown(shutdown)
+            .await;
+
+        // so is stopping it, which gets a thread of its own: on one of the runtime's, the dev
+        // server would only be asked to shut down
+        let (stopped, dev_server_stopped) = tokio::sync::oneshot::channel();
+        std::thread::spawn(move || {
+            drop(dev_server);
+            let _ = stopped.send(());
+        });
+        let _ = dev_server_stopped.await;
+
+        result
+    }
+}
+
+/// Always `200 OK`, so the API isn't restarted while the dev server is (re)starting; the body tells
+/// whether the HTML entry can be served.
+async fn health(assets: Arc<dyn GetFromVite>, entry: String) -> Response {
+    let status = match assets
+        .try_get_async_with_context(&entry, &RequestContext::default())
+        .await
+    {
+        Ok(Some(_)) => r#"{"status":"ok","assets":"available"}"#,
+        Ok(None) => r#"{"status":"degraded","assets":"missing"}"#,
+        Err(_) => r#"{"status":"degraded","assets":"unavailable"}"#,
+    };
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        status,
+    )
+        .into_response()
+}
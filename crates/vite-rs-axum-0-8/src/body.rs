@@ -0,0 +1,84 @@
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use axum::body::{Body, Bytes, HttpBody};
+use http_body::{Frame, SizeHint};
+
+/// Decides whether an asset's body is sent as a single frame or in chunks (see
+/// `ViteServe::with_body_streaming`).
+///
+/// Small bodies are cheapest to send at once. Larger ones are split into chunks, so hyper can
+/// write them out as the connection accepts data instead of buffering a large frame. The defaults
+/// (64 KiB, sent in 16 KiB chunks, the default HTTP/2 frame size) keep typical JS and CSS bundles
+/// in a single frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BodyStreaming {
+    /// Bodies up to this many bytes are sent as a single frame.
+    pub inline_threshold: usize,
+    /// Size of the chunks larger bodies are sent in, in bytes.
+    pub chunk_size: usize,
+}
+
+impl Default for BodyStreaming {
+    fn default() -> Self {
+        Self {
+            inline_threshold: 64 * 1024,
+            chunk_size: 16 * 1024,
+        }
+    }
+}
+
+impl BodyStreaming {
+    pub fn new(inline_threshold: usize, chunk_size: usize) -> Self {
+        Self {
+            inline_threshold,
+            chunk_size,
+        }
+    }
+
+    /// The body for `bytes`: a single frame, or chunks if it's larger than `inline_threshold`.
+    pub fn body(&self, bytes: Bytes) -> Body {
+        if bytes.len() <= self.inline_threshold || self.chunk_size == 0 {
+            Body::from(bytes)
+        } else {
+            Body::new(Chunks {
+                bytes,
+                chunk_size: self.chunk_size,
+            })
+        }
+    }
+}
+
+/// Sends `bytes` in frames of (at most) `chunk_size` bytes, without copying.
+struct Chunks {
+    bytes: Bytes,
+    chunk_size: usize,
+}
+
+impl HttpBody for Chunks {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        if self.bytes.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        let len = self.chunk_size.min(self.bytes.len());
+        let chunk = self.bytes.split_to(len);
+
+        Poll::Ready(Some(Ok(Frame::data(chunk))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.bytes.len() as u64)
+    }
+}
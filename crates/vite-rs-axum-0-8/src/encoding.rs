@@ -0,0 +1,58 @@
+//! Serves the compressed copies of assets embedded with the `zstd`, `brotli` and `gzip` features
+//! of `vite-rs`.
+
+use std::borrow::Cow;
+
+use axum::http::request::Parts;
+use axum::http::{header, HeaderMap, HeaderValue};
+use vite_rs_interface::{http, ViteFile};
+
+/// Sends one of the file's compressed copies instead of its bytes if the client accepts it (the
+/// first of `ViteFile::encodings`), and adjusts the headers to match. Files without a compressed
+/// copy are returned as they are; for the others, `Accept-Encoding` is added to the `negotiated`
+/// request headers.
+pub(crate) fn encode(
+    req: &Parts,
+    mut file: ViteFile,
+    headers: &mut HeaderMap,
+    negotiated: &mut Vec<&'static str>,
+) -> ViteFile {
+    let mut copies = file.encodings().peekable();
+    if copies.peek().is_none() {
+        return file;
+    }
+
+    // caches have to keep the compressed and uncompressed responses apart
+    negotiated.push("Accept-Encoding");
+
+    let accept_encoding = req
+        .headers
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|header| header.to_str().ok());
+    let Some((coding, compressed)) = copies
+        .find(|(coding, _)| http::accepts_encoding(accept_encoding.unwrap_or_default(), coding))
+    else {
+        return file;
+    };
+
+    file.bytes = Cow::Borrowed(compressed);
+    file.content_length = compressed.len() as u64;
+
+    headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static(coding));
+    headers.insert(
+        header::CONTENT_LENGTH,
+        HeaderValue::from(file.content_length),
+    );
+
+    // the compressed representation needs an ETag of its own
+    let etag = headers
+        .get(header::ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(|etag| http::encoded_etag(etag, coding))
+        .and_then(|etag| HeaderValue::from_str(&etag).ok());
+    if let Some(etag) = etag {
+        headers.insert(header::ETAG, etag);
+    }
+
+    file
+}
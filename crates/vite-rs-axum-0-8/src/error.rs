@@ -0,0 +1,30 @@
+/// Errors which can occur while serving an asset. See `ViteServe::try_serve`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ServeError {
+    /// The response could not be built, e.g. because an asset's content type
+    /// (as reported by a Vite plugin) is not a valid header value.
+    InvalidResponse(axum::http::Error),
+}
+
+impl std::fmt::Display for ServeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServeError::InvalidResponse(e) => write!(f, "could not build response: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ServeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ServeError::InvalidResponse(e) => Some(e),
+        }
+    }
+}
+
+impl From<axum::http::Error> for ServeError {
+    fn from(e: axum::http::Error) -> Self {
+        ServeError::InvalidResponse(e)
+    }
+}
@@ -0,0 +1,201 @@
+//! Forwards WebSocket upgrades (Vite's HMR connection) to the ViteJS dev server, for when the
+//! browser loads the app from the Rust server rather than from the dev server itself.
+
+use std::io;
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::http::request::Parts;
+use axum::http::{header, HeaderName, HeaderValue, StatusCode};
+use axum::response::Response;
+use hyper::upgrade::OnUpgrade;
+use hyper_util::rt::TokioIo;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Response heads from the dev server larger than this are rejected.
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+
+/// How long the dev server gets to accept the connection and respond to the upgrade request.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Whether the request asks to switch to the WebSocket protocol.
+pub(crate) fn is_websocket_upgrade(req: &Parts) -> bool {
+    req.headers
+        .get(header::UPGRADE)
+        .and_then(|upgrade| upgrade.to_str().ok())
+        .is_some_and(|upgrade| upgrade.eq_ignore_ascii_case("websocket"))
+}
+
+/// Sends the upgrade request to the dev server at `dev_server_url` (e.g. `http://localhost:21012`)
+/// and relays its response. Once both sides switched protocols, bytes are copied between the
+/// client and the dev server until either closes the connection.
+pub(crate) async fn proxy(dev_server_url: &str, req: &mut Parts) -> Response {
+    match try_proxy(dev_server_url, req).await {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!(
+                "vite-rs: failed to proxy '{}' to the ViteJS dev server at {}: {}",
+                req.uri.path(),
+                dev_server_url,
+                e
+            );
+
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::BAD_GATEWAY;
+            response
+        }
+    }
+}
+
+async fn try_proxy(dev_server_url: &str, req: &mut Parts) -> io::Result<Response> {
+    let authority = authority(dev_server_url)?;
+
+    // inserted by hyper for connections which can be upgraded (HTTP/1.1)
+    let on_upgrade = req
+        .extensions
+        .remove::<OnUpgrade>()
+        .ok_or_else(|| io::Error::other("the connection can't be upgraded"))?;
+
+    // e.g. a dev server which hangs shouldn't keep the request (and its connection) open forever
+    let (mut dev_server, head, rest) = tokio::time::timeout(
+        HANDSHAKE_TIMEOUT,
+        handshake(authority, &request_head(req, authority)),
+    )
+    .await
+    .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "the dev server didn't respond"))??;
+    let response = parse_response_head(&head)?;
+
+    if response.status() == StatusCode::SWITCHING_PROTOCOLS {
+        tokio::spawn(async move {
+            let Ok(upgraded) = on_upgrade.await else {
+                return;
+            };
+            let mut client = TokioIo::new(upgraded);
+
+            // the dev server may have sent its first frames along with the response head
+            if client.write_all(&rest).await.is_ok() {
+                let _ = tokio::io::copy_bidirectional(&mut client, &mut dev_server).await;
+            }
+        });
+    }
+
+    Ok(response)
+}
+
+/// The `host:port` of the dev server at `dev_server_url`. Only plain HTTP (`http://`, or no
+/// scheme at all) is supported: the connection to the dev server isn't encrypted.
+fn authority(dev_server_url: &str) -> io::Result<&str> {
+    let authority = match dev_server_url.split_once("://") {
+        Some((scheme, authority)) if scheme.eq_ignore_ascii_case("http") => authority,
+        Some((scheme, _)) => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("only http:// dev servers can be proxied, not {}://", scheme),
+            ))
+        }
+        None => dev_server_url,
+    };
+
+    Ok(authority.split('/').next().unwrap_or_default())
+}
+
+/// Connects to the dev server and sends it the request head. Returns the connection, the response
+/// head and whatever was read after it.
+async fn handshake(
+    authority: &str,
+    request_head: &[u8],
+) -> io::Result<(TcpStream, Vec<u8>, Vec<u8>)> {
+    let mut dev_server = TcpStream::connect(authority).await?;
+    dev_server.write_all(request_head).await?;
+
+    let (head, rest) = read_response_head(&mut dev_server).await?;
+    Ok((dev_server, head, rest))
+}
+
+/// The request head sent to the dev server: the client's, with the dev server as `Host`.
+fn request_head(req: &Parts, authority: &str) -> Vec<u8> {
+    let path = req
+        .uri
+        .path_and_query()
+        .map(|path| path.as_str())
+        .unwrap_or("/");
+
+    let mut head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\n",
+        req.method, path, authority
+    )
+    .into_bytes();
+
+    for (name, value) in &req.headers {
+        if name != header::HOST {
+            head.extend_from_slice(name.as_str().as_bytes());
+            head.extend_from_slice(b": ");
+            head.extend_from_slice(value.as_bytes());
+            head.extend_from_slice(b"\r\n");
+        }
+    }
+    head.extend_from_slice(b"\r\n");
+
+    head
+}
+
+/// Reads up to the end of the response head. Returns the head and whatever was read after it.
+async fn read_response_head(stream: &mut TcpStream) -> io::Result<(Vec<u8>, Vec<u8>)> {
+    let mut buf = Vec::new();
+    let mut chunk = [0; 1024];
+
+    loop {
+        if let Some(end) = buf.windows(4).position(|window| window == b"\r\n\r\n") {
+            let rest = buf.split_off(end + 4);
+            return Ok((buf, rest));
+        }
+
+        if buf.len() > MAX_HEAD_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "response head too large",
+            ));
+        }
+
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
+
+/// Turns the dev server's response head into a response without a body. Other than for
+/// `101 Switching Protocols`, the body isn't forwarded, so its framing headers are left out.
+fn parse_response_head(head: &[u8]) -> io::Result<Response> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid response head");
+
+    let head = std::str::from_utf8(head).map_err(|_| invalid())?;
+    let mut lines = head.split("\r\n").filter(|line| !line.is_empty());
+
+    let status = lines
+        .next()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .and_then(|status| StatusCode::from_bytes(status.as_bytes()).ok())
+        .ok_or_else(invalid)?;
+
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    for line in lines {
+        let (name, value) = line.split_once(':').ok_or_else(invalid)?;
+        let name = HeaderName::from_bytes(name.trim().as_bytes()).map_err(|_| invalid())?;
+        let value = HeaderValue::from_str(value.trim()).map_err(|_| invalid())?;
+
+        if status != StatusCode::SWITCHING_PROTOCOLS
+            && (name == header::CONTENT_LENGTH || name == header::TRANSFER_ENCODING)
+        {
+            continue;
+        }
+
+        response.headers_mut().append(name, value);
+    }
+
+    Ok(response)
+}
@@ -1,4 +1,28 @@
+#[cfg(feature = "access-log")]
+mod access_log;
+mod app;
+mod body;
+#[cfg(all(
+    any(feature = "zstd", feature = "gzip", feature = "brotli"),
+    not(vite_rs_dev)
+))]
+mod encoding;
+mod error;
+#[cfg(feature = "hmr-proxy")]
+mod hmr_proxy;
+mod locale;
+mod pattern;
+mod responder;
+mod vary;
 mod vite_serve;
 mod vite_tower_service;
 
-pub use vite_serve::{CacheStrategy, ViteServe};
+#[cfg(feature = "access-log")]
+pub use access_log::AccessLog;
+pub use app::{app_with_assets, AppWithAssets};
+pub use body::BodyStreaming;
+pub use error::ServeError;
+pub use locale::Locales;
+pub use responder::{AssetBody, AssetRequest, AssetResponder, AssetResponse, DefaultResponder};
+pub use vary::Vary;
+pub use vite_serve::{CacheStrategy, RequestRewrite, VariantSelector, ViteServe};
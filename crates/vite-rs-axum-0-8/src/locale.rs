@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+
+/// Selects between localized HTML entries (e.g. `index.en.html`, `index.de.html`)
+/// based on the request's `Accept-Language` header.
+#[derive(Clone)]
+pub struct Locales {
+    /// Locales which have localized HTML entries, e.g. `["en", "de"]`.
+    pub supported: Vec<&'static str>,
+    /// Locale used when none of the requested languages are supported.
+    pub default_locale: &'static str,
+}
+
+impl Locales {
+    pub fn new(
+        supported: impl IntoIterator<Item = &'static str>,
+        default_locale: &'static str,
+    ) -> Self {
+        Self {
+            supported: supported.into_iter().collect(),
+            default_locale,
+        }
+    }
+
+    /// Returns the locales to try, most preferred first. The default locale is always last.
+    pub(crate) fn candidates(&self, accept_language: Option<&str>) -> Vec<&'static str> {
+        let mut candidates = Vec::new();
+
+        for tag in parse_accept_language(accept_language.unwrap_or_default()) {
+            // try the full tag first (e.g. `de-CH`), then its primary subtag (e.g. `de`)
+            let primary = tag.split('-').next().unwrap_or(&tag);
+
+            for wanted in [tag.as_str(), primary] {
+                let supported = self
+                    .supported
+                    .iter()
+                    .find(|locale| locale.eq_ignore_ascii_case(wanted));
+
+                if let Some(locale) = supported {
+                    if !candidates.contains(locale) {
+                        candidates.push(*locale);
+                    }
+                }
+            }
+        }
+
+        if !candidates.contains(&self.default_locale) {
+            candidates.push(self.default_locale);
+        }
+
+        candidates
+    }
+}
+
+/// Parses an `Accept-Language` header value into language tags ordered by their quality value.
+/// Wildcards and tags with `q=0` are dropped.
+fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut tags = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut params = entry.trim().split(';');
+            let tag = params.next()?.trim();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+
+            let quality = params
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            (quality > 0.0).then(|| (tag.to_string(), quality))
+        })
+        .collect::<Vec<_>>();
+
+    // the sort is stable, so tags with equal quality keep their header order
+    tags.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+    tags.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Inserts the locale before the `.html` extension: `index.html` => `index.de.html`.
+/// Returns `None` for non-HTML paths.
+pub(crate) fn localized_path(path: &str, locale: &str) -> Option<String> {
+    path.strip_suffix(".html")
+        .map(|stem| format!("{}.{}.html", stem, locale))
+}
@@ -0,0 +1,34 @@
+/// Matches a path against a simple glob pattern, where `*` matches any sequence of
+/// characters (including `/`) and `?` matches a single character. So `*.map` matches
+/// `assets/index.js.map`, and `assets/*` matches everything under `assets/`.
+pub(crate) fn matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches('/').as_bytes();
+    let path = path.as_bytes();
+
+    // iterative wildcard matching with backtracking to the last `*`
+    let (mut p, mut s) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while s < path.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, s));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == path[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match star {
+                Some((star_p, star_s)) => {
+                    p = star_p + 1;
+                    s = star_s + 1;
+                    star = Some((star_p, star_s + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
@@ -0,0 +1,122 @@
+use axum::body::{Body, Bytes};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use vite_rs_interface::ViteFile;
+
+use crate::body::BodyStreaming;
+
+/// Builds the response for an asset `ViteServe` found (see `ViteServe::with_responder`).
+///
+/// `ViteServe` still resolves the request to an asset (index files, locales, variants) and works
+/// out its headers and whether the client's cached copy is current; the responder only decides
+/// what is sent. Requests for missing assets get a `404 Not Found` without calling it.
+///
+/// Implementations can wrap `DefaultResponder` to adjust its response:
+///
+/// ```ignore
+/// struct SignedUrls;
+///
+/// impl AssetResponder for SignedUrls {
+///     fn respond(&self, req: AssetRequest<'_>, file: ViteFile) -> AssetResponse {
+///         let mut response = DefaultResponder.respond(req, file);
+///         response.headers.insert("x-signature", sign(&response.body));
+///         response
+///     }
+/// }
+///
+/// ViteServe::new(Assets::boxed()).with_responder(SignedUrls)
+/// ```
+pub trait AssetResponder: Send + Sync + 'static {
+    fn respond(&self, req: AssetRequest<'_>, file: ViteFile) -> AssetResponse;
+}
+
+/// A request `ViteServe` resolved to an asset.
+#[non_exhaustive]
+pub struct AssetRequest<'a> {
+    /// The request's method, URI, headers, etc.
+    pub parts: &'a Parts,
+    /// The asset the request resolved to, e.g. `index.html` for `/`.
+    pub path: &'a str,
+    /// The headers `ViteServe` sends for this asset: `Content-Type`, `Content-Length`, `ETag` and
+    /// `Cache-Control`, and `Last-Modified` and `Vary` where they apply.
+    pub headers: HeaderMap,
+    /// Whether the client's cached copy is current (its `If-None-Match` matches the `ETag`), so a
+    /// `304 Not Modified` can be sent instead of the asset.
+    pub not_modified: bool,
+}
+
+/// The response for an asset: status, headers and body.
+#[non_exhaustive]
+pub struct AssetResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: AssetBody,
+}
+
+/// What is sent as the body of an asset response.
+#[non_exhaustive]
+pub enum AssetBody {
+    /// No body, e.g. for `304 Not Modified`.
+    Empty,
+    /// The whole body. Depending on its size, `ViteServe` sends it at once or in chunks (see
+    /// `BodyStreaming`).
+    Full(Bytes),
+}
+
+impl AssetResponse {
+    pub fn new(status: StatusCode, headers: HeaderMap, body: AssetBody) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    /// Builds the response, with the body sent according to `streaming`.
+    pub(crate) fn into_response_with(self, streaming: &BodyStreaming) -> Response {
+        let body = match self.body {
+            AssetBody::Empty => Body::empty(),
+            AssetBody::Full(bytes) => streaming.body(bytes),
+        };
+
+        let mut response = Response::new(body);
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
+    }
+}
+
+impl IntoResponse for AssetResponse {
+    fn into_response(self) -> Response {
+        self.into_response_with(&BodyStreaming::default())
+    }
+}
+
+/// Sends the asset with `ViteServe`'s headers, or `304 Not Modified` (without a body) when the
+/// client's cached copy is current. Used unless `ViteServe::with_responder` sets another one.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultResponder;
+
+impl AssetResponder for DefaultResponder {
+    fn respond(&self, req: AssetRequest<'_>, file: ViteFile) -> AssetResponse {
+        if req.not_modified {
+            return AssetResponse::new(StatusCode::NOT_MODIFIED, req.headers, AssetBody::Empty);
+        }
+
+        AssetResponse::new(StatusCode::OK, req.headers, AssetBody::Full(bytes(file)))
+    }
+}
+
+#[cfg(not(vite_rs_dev))]
+fn bytes(file: ViteFile) -> Bytes {
+    match file.bytes {
+        std::borrow::Cow::Borrowed(bytes) => Bytes::from_static(bytes),
+        std::borrow::Cow::Owned(bytes) => Bytes::from(bytes),
+    }
+}
+
+#[cfg(vite_rs_dev)]
+fn bytes(file: ViteFile) -> Bytes {
+    Bytes::from(file.bytes)
+}
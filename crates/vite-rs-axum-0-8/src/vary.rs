@@ -0,0 +1,61 @@
+use axum::http::{HeaderName, HeaderValue};
+
+/// Which request headers the `Vary` header of asset responses lists, so shared caches keep the
+/// responses to different requests for the same URL apart (see `ViteServe::with_vary`).
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub enum Vary {
+    /// The request headers `ViteServe` chose the response by: `Accept-Language` for localized
+    /// HTML entries (see `ViteServe::with_locales`) and `Accept-Encoding` for assets with a
+    /// compressed copy (the `zstd`, `brotli` and `gzip` features). Responses which don't depend on either have no
+    /// `Vary` header.
+    #[default]
+    Auto,
+    /// Like `Auto`, and these headers too. Use it for what `ViteServe` can't know about, like
+    /// the cookie or `Host` header a variant selector (`ViteServe::with_variant_selector`) or a
+    /// custom `GetFromVite` reads.
+    Extend(Vec<HeaderName>),
+    /// Exactly these headers, e.g. `Accept-Encoding` on every response for a cache which needs
+    /// it. With none, no `Vary` header is sent.
+    Replace(Vec<HeaderName>),
+}
+
+impl Vary {
+    /// The `Vary` header for a response chosen by the `negotiated` request headers, if any.
+    pub(crate) fn header_value(&self, negotiated: &[&'static str]) -> Option<HeaderValue> {
+        let extra = match self {
+            Vary::Auto => &[][..],
+            Vary::Extend(names) => names,
+            Vary::Replace(names) => {
+                return join(names.iter().map(HeaderName::as_str));
+            }
+        };
+
+        join(
+            negotiated
+                .iter()
+                .copied()
+                .chain(extra.iter().map(HeaderName::as_str)),
+        )
+    }
+}
+
+/// Lists the header names once each (they're case-insensitive), in order.
+fn join<'a>(names: impl Iterator<Item = &'a str>) -> Option<HeaderValue> {
+    let mut listed: Vec<&str> = Vec::new();
+    for name in names {
+        if !listed
+            .iter()
+            .any(|listed| listed.eq_ignore_ascii_case(name))
+        {
+            listed.push(name);
+        }
+    }
+
+    if listed.is_empty() {
+        return None;
+    }
+
+    // header names are valid header values
+    HeaderValue::from_str(&listed.join(", ")).ok()
+}
@@ -1,10 +1,54 @@
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 use axum::body::Body;
+use axum::http::request::Parts;
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode};
 use axum::response::Response;
-use vite_rs_interface::GetFromVite;
+use vite_rs_interface::{http, GetFromVite, RequestContext, RouteManifest, ViteFile};
+
+#[cfg(feature = "access-log")]
+use crate::access_log::AccessLog;
+use crate::body::BodyStreaming;
+use crate::error::ServeError;
+use crate::locale::{localized_path, Locales};
+use crate::pattern;
+use crate::responder::{AssetRequest, AssetResponder, DefaultResponder};
+use crate::vary::Vary;
+
+/// Picks the name of the asset variant to serve for a request (see `ViteServe::with_variant_selector`).
+pub type VariantSelector = Arc<dyn Fn(&Parts) -> &str + Send + Sync>;
+
+/// Maps a request path to the path to serve (see `ViteServe::with_request_rewrite`).
+pub type RequestRewrite = Arc<dyn for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync>;
 
 pub struct ViteServe {
     pub cache_strategy: CacheStrategy,
     pub assets: Box<dyn GetFromVite>,
+    /// When set, HTML entries are resolved to their localized variants (see `with_locales`).
+    pub locales: Option<Locales>,
+    /// Named alternatives to `assets`, chosen per request by `variant_selector`.
+    pub variants: Vec<(&'static str, Box<dyn GetFromVite>)>,
+    pub variant_selector: Option<VariantSelector>,
+    /// Applied to request paths before they're resolved to assets (see `with_request_rewrite`).
+    pub request_rewrite: Option<RequestRewrite>,
+    /// `(pattern, content type)` pairs which replace the embedded content type of matching
+    /// paths (see `with_content_type_overrides`).
+    pub content_type_overrides: Vec<(String, String)>,
+    /// Builds the responses for found assets (see `with_responder`).
+    pub responder: Arc<dyn AssetResponder>,
+    /// When asset bodies are sent in chunks (see `with_body_streaming`).
+    pub body_streaming: BodyStreaming,
+    /// Which HTML entry serves paths without an asset (see `with_routes`).
+    pub routes: Option<RouteManifest>,
+    /// Which request headers the `Vary` header lists (see `with_vary`).
+    pub vary: Vary,
+    #[cfg(feature = "access-log")]
+    pub access_log: Option<AccessLog>,
+    /// Where WebSocket upgrades are forwarded to (see `with_hmr_proxy`).
+    #[cfg(feature = "hmr-proxy")]
+    pub hmr_proxy: Option<String>,
 }
 
 impl Clone for ViteServe {
@@ -12,13 +56,35 @@ impl Clone for ViteServe {
         Self {
             cache_strategy: self.cache_strategy.clone(),
             assets: self.assets.clone_box(),
+            locales: self.locales.clone(),
+            variants: self
+                .variants
+                .iter()
+                .map(|(name, assets)| (*name, assets.clone_box()))
+                .collect(),
+            variant_selector: self.variant_selector.clone(),
+            request_rewrite: self.request_rewrite.clone(),
+            content_type_overrides: self.content_type_overrides.clone(),
+            responder: self.responder.clone(),
+            body_streaming: self.body_streaming,
+            routes: self.routes.clone(),
+            vary: self.vary.clone(),
+            #[cfg(feature = "access-log")]
+            access_log: self.access_log.clone(),
+            #[cfg(feature = "hmr-proxy")]
+            hmr_proxy: self.hmr_proxy.clone(),
         }
     }
 }
 
 /// Caching strategies specify how the server sets the Control-Cache header.
 /// In development, we always send 'no-cache' to ensure the latest files are served.
+///
+/// With `Eager` and `Lazy`, immutable files (see `#[immutable_assets]`) requested by their own
+/// path are cached for a year without revalidating. Conditional requests for them are answered
+/// with `304 Not Modified` without comparing validators, whatever the strategy.
 #[derive(Clone)]
+#[non_exhaustive]
 pub enum CacheStrategy {
     /// Always up-to-date. Checks for new updates before serving files.
     /// Clients will always receive the latest version of served assets.
@@ -38,11 +104,24 @@ pub enum CacheStrategy {
 impl ViteServe {
     pub fn new(assets: Box<dyn GetFromVite>) -> Self {
         Self {
-            #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+            #[cfg(vite_rs_dev)]
             cache_strategy: CacheStrategy::None,
-            #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+            #[cfg(not(vite_rs_dev))]
             cache_strategy: CacheStrategy::Eager,
             assets,
+            locales: None,
+            variants: Vec::new(),
+            variant_selector: None,
+            request_rewrite: None,
+            content_type_overrides: Vec::new(),
+            responder: Arc::new(DefaultResponder),
+            body_streaming: BodyStreaming::default(),
+            routes: None,
+            vary: Vary::default(),
+            #[cfg(feature = "access-log")]
+            access_log: None,
+            #[cfg(feature = "hmr-proxy")]
+            hmr_proxy: None,
         }
     }
 
@@ -51,110 +130,510 @@ impl ViteServe {
         self
     }
 
+    /// Serve localized HTML entries based on the `Accept-Language` request header.
+    ///
+    /// For a request resolving to `index.html`, the first existing entry among
+    /// `index.<locale>.html` (for the requested locales, in order of preference, then
+    /// `default_locale`) is served. If none exist, `index.html` is served as usual.
+    ///
+    /// ```ignore
+    /// ViteServe::new(Assets::boxed()).with_locales(["en", "de"], "en")
+    /// ```
+    pub fn with_locales(
+        mut self,
+        supported: impl IntoIterator<Item = &'static str>,
+        default_locale: &'static str,
+    ) -> Self {
+        self.locales = Some(Locales::new(supported, default_locale));
+        self
+    }
+
+    /// Registers an alternative set of assets which can be served instead of the default
+    /// ones, e.g. the "experiment" bundle of an A/B test. See `with_variant_selector`.
+    pub fn with_variant(mut self, name: &'static str, assets: Box<dyn GetFromVite>) -> Self {
+        self.variants.retain(|(existing, _)| *existing != name);
+        self.variants.push((name, assets));
+        self
+    }
+
+    /// Chooses which assets to serve for each request. The selector returns the name of a
+    /// variant registered with `with_variant`; any other name serves the default assets.
+    ///
+    /// No state is kept between requests, so the selection should be derived from something
+    /// the client sends every time (a cookie or header):
+    ///
+    /// ```ignore
+    /// ViteServe::new(Control::boxed())
+    ///     .with_variant("experiment", Experiment::boxed())
+    ///     .with_variant_selector(|req| {
+    ///         let cookies = req.headers.get("cookie").and_then(|c| c.to_str().ok());
+    ///         match cookies {
+    ///             Some(cookies) if cookies.contains("ab=experiment") => "experiment",
+    ///             _ => "control",
+    ///         }
+    ///     })
+    /// ```
+    pub fn with_variant_selector<F>(mut self, selector: F) -> Self
+    where
+        F: Fn(&Parts) -> &str + Send + Sync + 'static,
+    {
+        self.variant_selector = Some(Arc::new(selector));
+        self
+    }
+
+    /// Rewrites request paths before they're resolved to assets, e.g. to map legacy URLs or to
+    /// strip a prefix the embedded assets don't have. The function gets the path with its leading
+    /// slash (and without the query string), and returns the path to serve instead:
+    ///
+    /// ```ignore
+    /// ViteServe::new(Assets::boxed()).with_request_rewrite(|path| match path.strip_prefix("/v2") {
+    ///     Some(rest) => Cow::Owned(rest.to_string()),
+    ///     None => Cow::Borrowed(path),
+    /// })
+    /// ```
+    ///
+    /// Everything else (localized entries, `with_routes`, content type overrides) applies to the
+    /// rewritten path. The access log records the original one.
+    pub fn with_request_rewrite<F>(mut self, rewrite: F) -> Self
+    where
+        F: for<'a> Fn(&'a str) -> Cow<'a, str> + Send + Sync + 'static,
+    {
+        self.request_rewrite = Some(Arc::new(rewrite));
+        self
+    }
+
+    /// Overrides the content type of files matching a pattern, for when the type inferred at
+    /// embed time is wrong for a deployment. In patterns, `*` matches any sequence of characters
+    /// (including `/`) and `?` matches a single character. The first matching pattern wins.
+    ///
+    /// ```ignore
+    /// ViteServe::new(Assets::boxed())
+    ///     .with_content_type_overrides([("*.map", "application/json")])
+    /// ```
+    pub fn with_content_type_overrides<I, P, C>(mut self, overrides: I) -> Self
+    where
+        I: IntoIterator<Item = (P, C)>,
+        P: Into<String>,
+        C: Into<String>,
+    {
+        self.content_type_overrides.extend(
+            overrides
+                .into_iter()
+                .map(|(pattern, content_type)| (pattern.into(), content_type.into())),
+        );
+        self
+    }
+
+    /// Customizes the responses for found assets, e.g. to add headers, while keeping how requests
+    /// are resolved to assets and the `ETag`/`If-None-Match` handling. See `AssetResponder`.
+    pub fn with_responder(mut self, responder: impl AssetResponder) -> Self {
+        self.responder = Arc::new(responder);
+        self
+    }
+
+    /// Sets when asset bodies are sent as a single frame and when in chunks (by default, bodies
+    /// over 64 KiB are sent in 16 KiB chunks). See `BodyStreaming`.
+    ///
+    /// ```ignore
+    /// ViteServe::new(Assets::boxed()).with_body_streaming(BodyStreaming::new(256 * 1024, 64 * 1024))
+    /// ```
+    pub fn with_body_streaming(mut self, body_streaming: BodyStreaming) -> Self {
+        self.body_streaming = body_streaming;
+        self
+    }
+
+    /// Serves client-side (SPA) routes: a path without an asset is served the HTML entry of its
+    /// route, e.g. `index.html` for `/settings/profile`. API routes respond with `404 Not Found`
+    /// instead, like other paths without a route.
+    ///
+    /// ```ignore
+    /// ViteServe::new(Assets::boxed()).with_routes(Assets::routes().with_api_route("/api/*"))
+    /// ```
+    pub fn with_routes(mut self, routes: RouteManifest) -> Self {
+        self.routes = Some(routes);
+        self
+    }
+
+    /// Sets which request headers the `Vary` header of asset responses lists. By default, these
+    /// are the headers `ViteServe` chose the response by (see `Vary::Auto`); add the ones a
+    /// variant selector reads, so shared caches don't serve one variant to everyone:
+    ///
+    /// ```ignore
+    /// ViteServe::new(Control::boxed())
+    ///     .with_variant("experiment", Experiment::boxed())
+    ///     .with_variant_selector(select_by_cookie)
+    ///     .with_vary(Vary::Extend(vec![header::COOKIE]))
+    /// ```
+    pub fn with_vary(mut self, vary: Vary) -> Self {
+        self.vary = vary;
+        self
+    }
+
+    /// Log served requests in the Combined Log Format. See `AccessLog`.
+    #[cfg(feature = "access-log")]
+    pub fn with_access_log(mut self, access_log: AccessLog) -> Self {
+        self.access_log = Some(access_log);
+        self
+    }
+
+    /// Forwards WebSocket upgrades to the ViteJS dev server at `dev_server_url`, so that Vite's
+    /// HMR client connects through this server when the app is loaded from it. Requires the
+    /// `hmr-proxy` feature.
+    ///
+    /// ```ignore
+    /// let service = ViteServe::new(Assets::boxed());
+    /// #[cfg(debug_assertions)]
+    /// let service = service.with_hmr_proxy(Assets::dev_server_url());
+    /// ```
+    ///
+    /// All WebSocket upgrades which reach this service are forwarded, so mount your own WebSocket
+    /// routes separately. Only HTTP/1.1 connections can be upgraded, and only to an `http://` dev
+    /// server: upgrades for any other (e.g. `https://`) are answered with `502 Bad Gateway`.
+    #[cfg(feature = "hmr-proxy")]
+    pub fn with_hmr_proxy(mut self, dev_server_url: impl Into<String>) -> Self {
+        self.hmr_proxy = Some(dev_server_url.into());
+        self
+    }
+
     pub async fn serve<B>(&self, req: axum::http::request::Request<B>) -> Response
     where
         B: axum::body::HttpBody<Data = axum::body::Bytes> + Send + 'static,
     {
-        // Extract the path from the request, removing the leading slash
-        let path = req.uri().path().trim_start_matches('/');
-        let query = req
-            .uri()
+        // The body is never read; we only need the request's metadata.
+        #[allow(unused_mut)]
+        let (mut req, _body) = req.into_parts();
+
+        let response = self.respond_or_proxy(&mut req).await.unwrap_or_else(|e| {
+            eprintln!("vite-rs: failed to serve '{}': {}", req.uri.path(), e);
+
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+            response
+        });
+
+        #[cfg(feature = "access-log")]
+        if let Some(access_log) = &self.access_log {
+            access_log.log(&req, &response);
+        }
+
+        response
+    }
+
+    /// Like `serve`, but returns an error instead of responding with
+    /// `500 Internal Server Error` when the response can't be built.
+    pub async fn try_serve<B>(
+        &self,
+        req: axum::http::request::Request<B>,
+    ) -> Result<Response, ServeError>
+    where
+        B: axum::body::HttpBody<Data = axum::body::Bytes> + Send + 'static,
+    {
+        #[allow(unused_mut)]
+        let (mut req, _body) = req.into_parts();
+
+        self.respond_or_proxy(&mut req).await
+    }
+
+    /// Forwards WebSocket upgrades if `with_hmr_proxy` is set; responds with an asset otherwise.
+    #[allow(clippy::needless_pass_by_ref_mut)]
+    async fn respond_or_proxy(&self, req: &mut Parts) -> Result<Response, ServeError> {
+        #[cfg(feature = "hmr-proxy")]
+        if let Some(dev_server_url) = &self.hmr_proxy {
+            if crate::hmr_proxy::is_websocket_upgrade(req) {
+                return Ok(crate::hmr_proxy::proxy(dev_server_url, req).await);
+            }
+        }
+
+        self.respond(req).await
+    }
+
+    async fn respond(&self, req: &Parts) -> Result<Response, ServeError> {
+        let path = match &self.request_rewrite {
+            Some(rewrite) => rewrite(req.uri.path()),
+            None => Cow::Borrowed(req.uri.path()),
+        };
+        // removing the leading slash
+        let path = path.trim_start_matches('/');
+        let _query = req
+            .uri
             .query()
             .map(|q| format!("?{}", q))
             .unwrap_or_default();
 
-        let index_candidate = format!("{}/index.html", path);
+        let accept_language = req
+            .headers
+            .get(axum::http::header::ACCEPT_LANGUAGE)
+            .and_then(|header| header.to_str().ok());
+
+        let assets = RequestAssets {
+            assets: self.select_assets(req),
+            context: request_context(req),
+            unavailable: AtomicBool::new(false),
+        };
+        let assets = &assets;
+
+        let index_candidate = http::index_path(path);
         let request_file_path = if path.is_empty() {
-            "index.html".to_string()
-        } else if self.has_asset(index_candidate.as_str()) {
-            index_candidate
+            self.resolve_html(assets, "index.html", accept_language)
+                .await
+                .unwrap_or_else(|| "index.html".to_string())
+        } else if let Some(index) = self
+            .resolve_html(assets, &index_candidate, accept_language)
+            .await
+        {
+            index
         } else {
-            path.to_string()
+            self.localize(assets, path, accept_language)
+                .await
+                .unwrap_or_else(|| path.to_string())
         };
 
-        match self.assets.get(&request_file_path) {
-            Some(file) => {
-                let mut response = Response::builder();
+        // in development, this fetches from the ViteJS dev server without blocking the runtime
+        let found = match assets.get(&request_file_path).await {
+            Some(file) => Some((request_file_path, file)),
+            None => self.route_entry(assets, path, accept_language).await,
+        };
 
-                response = response.header("Content-Type", file.content_type);
-                response = response.header("Content-Length", file.content_length);
+        match found {
+            Some((request_file_path, mut file)) => {
+                // e.g. an HTML entry served for a client-side route, or a localized one: what's
+                // served at the requested URL changes, even if the file itself never does
+                if request_file_path != path {
+                    file.immutable = false;
+                }
 
-                let etag = {
-                    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
-                    {
-                        file.content_hash
-                    }
+                #[allow(unused_mut)]
+                let mut headers = self.asset_headers(&request_file_path, &file)?;
 
-                    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-                    {
-                        &file.content_hash
-                    }
-                };
+                // the request headers the response was chosen by
+                #[allow(unused_mut)]
+                let mut negotiated = Vec::new();
+                if self.locales.is_some() && request_file_path.ends_with(".html") {
+                    negotiated.push("Accept-Language");
+                }
 
-                response = response.status(200).header("ETag", etag);
-
-                match self.cache_strategy {
-                    CacheStrategy::Eager => {
-                        response = response.header("Cache-Control", "max-age=0, must-revalidate");
-                    }
-                    CacheStrategy::Lazy => {
-                        response = response
-                            .header("Cache-Control", "max-age=0, stale-while-revalidate=604800");
-                    }
-                    CacheStrategy::None => {
-                        response = response.header("Cache-Control", "no-cache");
-                    }
-                    CacheStrategy::Custom(header) => {
-                        response = response.header("Cache-Control", header);
-                    }
-                };
+                #[cfg(all(
+                    any(feature = "zstd", feature = "gzip", feature = "brotli"),
+                    not(vite_rs_dev)
+                ))]
+                let file = crate::encoding::encode(req, file, &mut headers, &mut negotiated);
 
-                if let Some(last_modified) = file.last_modified {
-                    response = response.header("Last-Modified", last_modified);
+                if let Some(vary) = self.vary.header_value(&negotiated) {
+                    headers.insert(header::VARY, vary);
                 }
 
-                match req.headers().get(axum::http::header::IF_NONE_MATCH) {
-                    Some(header) => {
-                        let header_etag = header.to_str().expect(
-                            "Could not read IF_NONE_MATCH header, it contained invalid characters.",
-                        );
-
-                        if etag.eq(header_etag) {
-                            // If the ETag matches, return 304 Not Modified
-                            #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-                            return response.status(304).body(Body::from(vec![])).unwrap();
-
-                            #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
-                            return response.status(304).body(Body::from(&[][..])).unwrap();
-                        } else {
-                            // If it doesn't match, return the full response
-                            return response.body(Body::from(file.bytes)).unwrap();
-                        }
-                    }
-                    None => {
-                        // If no IF_NONE_MATCH header, return the full response
-                        return response.body(Body::from(file.bytes)).unwrap();
-                    }
-                }
+                // An If-None-Match header we can't read is treated like a mismatch
+                let etag = headers
+                    .get(axum::http::header::ETAG)
+                    .and_then(|etag| etag.to_str().ok());
+                let not_modified = if file.immutable {
+                    // whichever copy the client has cached is this one: it never changes
+                    req.headers.contains_key(header::IF_NONE_MATCH)
+                        || req.headers.contains_key(header::IF_MODIFIED_SINCE)
+                } else {
+                    req.headers
+                        .get(axum::http::header::IF_NONE_MATCH)
+                        .and_then(|header| header.to_str().ok())
+                        .zip(etag)
+                        .is_some_and(|(if_none_match, etag)| {
+                            http::if_none_match(if_none_match, etag)
+                        })
+                };
+
+                let asset_request = AssetRequest {
+                    parts: req,
+                    path: &request_file_path,
+                    headers,
+                    not_modified,
+                };
+
+                Ok(self
+                    .responder
+                    .respond(asset_request, file)
+                    .into_response_with(&self.body_streaming))
             }
+            // e.g. while the ViteJS dev server is starting: the asset may well exist
+            None if assets.unavailable.load(Ordering::Relaxed) => Ok(Response::builder()
+                .status(StatusCode::SERVICE_UNAVAILABLE)
+                .header(header::RETRY_AFTER, "1")
+                .body(Body::empty())?),
             None => {
                 // Return 404 Not Found with an empty body
-                #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-                return Response::builder()
-                    .status(404)
-                    .body(Body::from(vec![]))
-                    .unwrap();
-
-                #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
-                return Response::builder()
-                    .status(404)
-                    .body(Body::from(&[][..]))
-                    .unwrap();
+                Ok(Response::builder().status(404).body(Body::empty())?)
             }
         }
     }
 
-    fn has_asset(&self, path: &str) -> bool {
-        self.assets.get(path).is_some()
+    /// The headers sent with an asset (see `AssetRequest::headers`).
+    fn asset_headers(&self, path: &str, file: &ViteFile) -> Result<HeaderMap, ServeError> {
+        let value = |value: &str| HeaderValue::from_str(value).map_err(axum::http::Error::from);
+        let mut headers = HeaderMap::new();
+
+        // `String`s in development builds, `&'static str`s in release builds
+        #[cfg(vite_rs_dev)]
+        let (content_type, content_hash) = (&file.content_type, &file.content_hash);
+        #[cfg(not(vite_rs_dev))]
+        let (content_type, content_hash) = (file.content_type, file.content_hash);
+
+        let content_type = self.content_type_override(path).unwrap_or(content_type);
+        headers.insert(header::CONTENT_TYPE, value(content_type)?);
+        headers.insert(
+            header::CONTENT_LENGTH,
+            HeaderValue::from(file.content_length),
+        );
+        headers.insert(header::ETAG, value(content_hash)?);
+
+        let cache_control = match self.cache_strategy {
+            // e.g. `assets/index-BPvgi06w.js`, whose path changes with its contents
+            CacheStrategy::Eager | CacheStrategy::Lazy if file.immutable => {
+                "public, max-age=31536000, immutable"
+            }
+            CacheStrategy::Eager => "max-age=0, must-revalidate",
+            CacheStrategy::Lazy => "max-age=0, stale-while-revalidate=604800",
+            CacheStrategy::None => "no-cache",
+            CacheStrategy::Custom(header) => header,
+        };
+        headers.insert(header::CACHE_CONTROL, value(cache_control)?);
+
+        if let Some(last_modified) = &file.last_modified {
+            headers.insert(header::LAST_MODIFIED, value(last_modified)?);
+        }
+
+        // e.g. for a service worker registered with `#[generated_entry]`
+        if let Some(scope) = &file.service_worker_allowed {
+            headers.insert(
+                HeaderName::from_static("service-worker-allowed"),
+                value(scope)?,
+            );
+        }
+
+        Ok(headers)
     }
+
+    /// Returns the assets of the variant chosen for this request, or the default assets.
+    fn select_assets(&self, req: &Parts) -> &dyn GetFromVite {
+        let selected = self
+            .variant_selector
+            .as_ref()
+            .map(|selector| selector(req))
+            .and_then(|name| self.variants.iter().find(|(variant, _)| *variant == name));
+
+        match selected {
+            Some((_, assets)) => assets.as_ref(),
+            None => self.assets.as_ref(),
+        }
+    }
+
+    fn content_type_override(&self, path: &str) -> Option<&str> {
+        self.content_type_overrides
+            .iter()
+            .find(|(pattern, _)| pattern::matches(pattern, path))
+            .map(|(_, content_type)| content_type.as_str())
+    }
+
+    /// The HTML entry (path and file) of the route `path` belongs to, see `with_routes`.
+    async fn route_entry(
+        &self,
+        assets: &RequestAssets<'_>,
+        path: &str,
+        accept_language: Option<&str>,
+    ) -> Option<(String, ViteFile)> {
+        let entry = self.routes.as_ref()?.resolve(&format!("/{}", path))?;
+        let entry = self
+            .resolve_html(assets, entry, accept_language)
+            .await
+            .unwrap_or_else(|| entry.to_string());
+
+        let file = assets.get(&entry).await?;
+        Some((entry, file))
+    }
+
+    async fn has_asset(assets: &RequestAssets<'_>, path: &str) -> bool {
+        assets.get(path).await.is_some()
+    }
+
+    /// Returns the first existing localized variant of an HTML path, if locales are configured.
+    async fn localize(
+        &self,
+        assets: &RequestAssets<'_>,
+        path: &str,
+        accept_language: Option<&str>,
+    ) -> Option<String> {
+        let locales = self.locales.as_ref()?;
+
+        let candidates = locales
+            .candidates(accept_language)
+            .into_iter()
+            .filter_map(|locale| localized_path(path, locale));
+
+        for candidate in candidates {
+            if Self::has_asset(assets, &candidate).await {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    /// Resolves an HTML path to its localized variant, or to itself if it exists.
+    async fn resolve_html(
+        &self,
+        assets: &RequestAssets<'_>,
+        path: &str,
+        accept_language: Option<&str>,
+    ) -> Option<String> {
+        match self.localize(assets, path, accept_language).await {
+            Some(localized) => Some(localized),
+            None => Self::has_asset(assets, path)
+                .await
+                .then(|| path.to_string()),
+        }
+    }
+}
+
+/// The assets selected for a request, with the request's metadata.
+struct RequestAssets<'a> {
+    assets: &'a dyn GetFromVite,
+    context: RequestContext,
+    /// Whether an asset couldn't be retrieved (see `GetError`), so a missing one may exist.
+    unavailable: AtomicBool,
+}
+
+impl RequestAssets<'_> {
+    async fn get(&self, path: &str) -> Option<ViteFile> {
+        match self
+            .assets
+            .try_get_async_with_context(path, &self.context)
+            .await
+        {
+            Ok(file) => file,
+            Err(_) => {
+                self.unavailable.store(true, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+}
+
+/// The `RequestContext` passed to the assets. The peer address is only known with the
+/// `connect-info` feature, for servers started with `into_make_service_with_connect_info`.
+fn request_context(req: &Parts) -> RequestContext {
+    let context = req
+        .headers
+        .iter()
+        .filter_map(|(name, value)| Some((name, value.to_str().ok()?)))
+        .fold(
+            RequestContext::new(req.method.as_str()),
+            |context, (name, value)| context.with_header(name, value),
+        );
+
+    #[cfg(feature = "connect-info")]
+    if let Some(axum::extract::ConnectInfo(peer_addr)) = req
+        .extensions
+        .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+    {
+        return context.with_peer_addr(*peer_addr);
+    }
+
+    context
 }
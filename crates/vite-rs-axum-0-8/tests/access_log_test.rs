@@ -0,0 +1,76 @@
+mod util;
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::{body::Body, extract::ConnectInfo, http};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::{AccessLog, ViteServe};
+
+const ASSETS: MockAssets = MockAssets(&[("index.html", "text/html", "<h1>hi</h1>")]);
+
+fn recording_log() -> (AccessLog, Arc<Mutex<Vec<String>>>) {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let access_log = AccessLog::with_sink({
+        let lines = lines.clone();
+        move |line| lines.lock().unwrap().push(line.to_string())
+    });
+
+    (access_log, lines)
+}
+
+async fn request(service: ViteServe, uri: &str) {
+    let request = http::Request::builder()
+        .uri(uri)
+        .header("Referer", "http://example.com/")
+        .header("User-Agent", "test \"agent\"")
+        .extension(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4321))))
+        .body(Body::empty())
+        .unwrap();
+
+    axum::Router::new()
+        .fallback_service(service)
+        .oneshot(request)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn logs_in_combined_log_format() {
+    let (access_log, lines) = recording_log();
+    let service = ViteServe::new(Box::new(ASSETS)).with_access_log(access_log);
+
+    request(service.clone(), "/?lang=en").await;
+    request(service, "/missing.js").await;
+
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines.len(), 2);
+
+    let (prefix, rest) = lines[0].split_once(" [").unwrap();
+    assert_eq!(prefix, "10.0.0.1 - -");
+
+    let (time, rest) = rest.split_once("] ").unwrap();
+    assert!(time.ends_with(" +0000"));
+    assert_eq!(time.len(), "10/Oct/2024:13:55:36 +0000".len());
+
+    assert_eq!(
+        rest,
+        "\"GET /?lang=en HTTP/1.1\" 200 11 \"http://example.com/\" \"test \\\"agent\\\"\""
+    );
+    assert!(lines[1].contains("\"GET /missing.js HTTP/1.1\" 404 - "));
+}
+
+#[tokio::test]
+async fn can_be_toggled_at_runtime() {
+    let (access_log, lines) = recording_log();
+    let service = ViteServe::new(Box::new(ASSETS)).with_access_log(access_log.clone());
+
+    access_log.disable();
+    request(service.clone(), "/").await;
+    assert!(lines.lock().unwrap().is_empty());
+
+    access_log.enable();
+    request(service, "/").await;
+    assert_eq!(lines.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,193 @@
+mod util;
+
+use axum::{
+    body::{self, Body},
+    http,
+    routing::get,
+    Router,
+};
+use reqwest::StatusCode;
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs::{GetError, GetFromVite, RouteManifest, ViteFile};
+use vite_rs_axum_0_8::{app_with_assets, AppWithAssets};
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "index"),
+    ("script.js", "text/javascript", "script"),
+]);
+
+/// Assets while the ViteJS dev server is unavailable.
+#[derive(Clone)]
+struct Unavailable;
+
+impl GetFromVite for Unavailable {
+    fn get(&self, _file_path: &str) -> Option<ViteFile> {
+        None
+    }
+
+    fn clone_box(&self) -> Box<dyn GetFromVite> {
+        Box::new(self.clone())
+    }
+
+    fn try_get(&self, _file_path: &str) -> Result<Option<ViteFile>, GetError> {
+        Err(GetError::DevServerUnavailable)
+    }
+
+    fn try_get_async_with_context<'a>(
+        &'a self,
+        _file_path: &'a str,
+        _context: &'a vite_rs::RequestContext,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Option<ViteFile>, GetError>> + Send + 'a>,
+    > {
+        Box::pin(async { Err(GetError::DevServerUnavailable) })
+    }
+}
+
+fn api() -> Router {
+    Router::new().route("/api/users", get(|| async { "users" }))
+}
+
+async fn get_from(app: AppWithAssets, uri: &str) -> (StatusCode, String) {
+    let request = http::Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.into_router().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn serves_the_api_and_the_assets() {
+    let app = || app_with_assets(api(), Box::new(ASSETS));
+
+    assert_eq!(
+        get_from(app(), "/api/users").await,
+        (StatusCode::OK, "users".to_string())
+    );
+    assert_eq!(get_from(app(), "/script.js").await.1, "script");
+    assert_eq!(get_from(app(), "/").await.1, "index");
+}
+
+#[tokio::test]
+async fn serves_index_html_for_client_side_routes() {
+    let app = app_with_assets(api(), Box::new(ASSETS));
+
+    assert_eq!(
+        get_from(app, "/settings/profile").await,
+        (StatusCode::OK, "index".to_string())
+    );
+}
+
+#[tokio::test]
+async fn doesnt_serve_index_html_for_api_routes() {
+    let app = app_with_assets(api(), Box::new(ASSETS)).with_api_route("/api/*");
+
+    assert_eq!(get_from(app, "/api/posts").await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn keeps_api_routes_when_the_routes_are_set_afterwards() {
+    let app = app_with_assets(api(), Box::new(ASSETS))
+        .with_api_route("/api/*")
+        .with_routes(RouteManifest::new().with_entry("/*", "index.html"));
+
+    assert_eq!(get_from(app, "/api/posts").await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn reports_health() {
+    let app = app_with_assets(api(), Box::new(ASSETS));
+
+    assert_eq!(
+        get_from(app, "/healthz").await,
+        (
+            StatusCode::OK,
+            r#"{"status":"ok","assets":"available"}"#.to_string()
+        )
+    );
+}
+
+#[tokio::test]
+async fn keeps_serving_the_api_while_the_assets_are_unavailable() {
+    let app = || app_with_assets(api(), Box::new(Unavailable));
+
+    assert_eq!(
+        get_from(app(), "/api/users").await,
+        (StatusCode::OK, "users".to_string())
+    );
+    assert_eq!(
+        get_from(app(), "/healthz").await,
+        (
+            StatusCode::OK,
+            r#"{"status":"degraded","assets":"unavailable"}"#.to_string()
+        )
+    );
+    assert_eq!(
+        get_from(app(), "/settings").await.0,
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+#[tokio::test]
+async fn moves_or_removes_the_health_check() {
+    let app = app_with_assets(api(), Box::new(ASSETS)).with_health_path("/status");
+    assert_eq!(get_from(app, "/status").await.0, StatusCode::OK);
+
+    let app = app_with_assets(api(), Box::new(ASSETS))
+        .without_health_check()
+        .with_api_route("/healthz");
+    assert_eq!(get_from(app, "/healthz").await.0, StatusCode::NOT_FOUND);
+}
+
+#[cfg(feature = "serve")]
+#[tokio::test]
+async fn starts_the_dev_server_on_serve_and_stops_it_on_shutdown() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    struct Guard(Arc<AtomicBool>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let started = Arc::new(AtomicBool::new(false));
+    let stopped = Arc::new(AtomicBool::new(false));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (shutdown, on_shutdown) = tokio::sync::oneshot::channel::<()>();
+
+    let app = app_with_assets(api(), Box::new(ASSETS)).with_dev_server({
+        let (started, stopped) = (started.clone(), stopped.clone());
+        move || {
+            started.store(true, Ordering::SeqCst);
+            Guard(stopped)
+        }
+    });
+    let server = tokio::spawn(app.serve(listener, async {
+        let _ = on_shutdown.await;
+    }));
+
+    let body = reqwest::get(format!("{}/api/users", url))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(body, "users");
+    assert!(started.load(Ordering::SeqCst));
+    assert!(!stopped.load(Ordering::SeqCst));
+
+    shutdown.send(()).unwrap();
+    server.await.unwrap().unwrap();
+    assert!(stopped.load(Ordering::SeqCst));
+}
@@ -17,12 +17,12 @@ struct Assets;
 /// since the vite dev server can't be started multiple times.
 #[tokio::test]
 async fn test() {
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     let _guard = Assets::start_dev_server(true);
 
     let hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
-        #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+        #[cfg(vite_rs_dev)]
         {
             Assets::stop_dev_server();
         }
@@ -31,7 +31,7 @@ async fn test() {
         hook(info);
     }));
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     std::thread::sleep(std::time::Duration::from_secs(2)); // wait for dev server to start
 
     for app in [app_with_fallback_service, app_with_mounted_service] {
@@ -152,7 +152,7 @@ async fn test_default_cache_strategy() {
 
     assert_eq!(response.status(), StatusCode::OK);
     // Ensure that the default cache strategy is `CacheStrategy::None`
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     assert_eq!(
         response
             .headers()
@@ -161,7 +161,7 @@ async fn test_default_cache_strategy() {
         Some("no-cache")
     );
 
-    #[cfg(not(all(debug_assertions, not(feature = "debug-prod"))))]
+    #[cfg(not(vite_rs_dev))]
     // Ensure that the default cache strategy is `CacheStrategy::Eager`
     assert_eq!(
         response
@@ -184,14 +184,14 @@ async fn ensure_serves_index(app: axum::Router) {
 
     let body_bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     if cfg!(windows) {
         assert_eq!(body_bytes, "<!DOCTYPE html>\r\n<html lang=\"en\">\r\n  <head>\n    <script type=\"module\">import { injectIntoGlobalHook } from \"/@react-refresh\";\ninjectIntoGlobalHook(window);\nwindow.$RefreshReg$ = () => {};\nwindow.$RefreshSig$ = () => (type) => type;</script>\n\n    <script type=\"module\" src=\"/@vite/client\"></script>\n\r\n    <title>Hello World</title>\r\n    <link rel=\"stylesheet\" href=\"/test.css\" />\r\n  </head>\r\n  <body>\r\n    <h1>Loading...</h1>\r\n    <script type=\"module\" src=\"/script.tsx\"></script>\r\n  </body>\r\n</html>\r\n");
     } else {
         assert_eq!(body_bytes, "<!DOCTYPE html>\n<html lang=\"en\">\n  <head>\n    <script type=\"module\">import { injectIntoGlobalHook } from \"/@react-refresh\";\ninjectIntoGlobalHook(window);\nwindow.$RefreshReg$ = () => {};\nwindow.$RefreshSig$ = () => (type) => type;</script>\n\n    <script type=\"module\" src=\"/@vite/client\"></script>\n\n    <title>Hello World</title>\n    <link rel=\"stylesheet\" href=\"/test.css\" />\n  </head>\n  <body>\n    <h1>Loading...</h1>\n    <script type=\"module\" src=\"/script.tsx\"></script>\n  </body>\n</html>\n");
     }
 
-    #[cfg(not(all(debug_assertions, not(feature = "debug-prod"))))]
+    #[cfg(not(vite_rs_dev))]
     if cfg!(windows) {
         assert_eq!(body_bytes, "<!DOCTYPE html>\r\n<html lang=\"en\">\r\n  <head>\r\n    <title>Hello World</title>\r\n    <link rel=\"stylesheet\" href=\"/test.css\" />\r\n    <script type=\"module\" crossorigin src=\"/assets/index-CgRBhnJL.js\"></script>\n  </head>\r\n  <body>\r\n    <h1>Loading...</h1>\r\r\n  </body>\r\n</html>\r\n");
     } else {
@@ -211,14 +211,14 @@ async fn ensure_serves_public_files(app: axum::Router) {
 
     let body_bytes = body::to_bytes(response.into_body(), 2048).await.unwrap();
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     if cfg!(windows) {
         assert_eq!(body_bytes, "body {\r\n  background-color: black;\r\n  color: white;\r\n  font-family: Arial, sans-serif;\r\n  padding: 42px;\r\n}\r\n");
     } else {
         assert_eq!(body_bytes, "body {\n  background-color: black;\n  color: white;\n  font-family: Arial, sans-serif;\n  padding: 42px;\n}\n");
     }
 
-    #[cfg(not(all(debug_assertions, not(feature = "debug-prod"))))]
+    #[cfg(not(vite_rs_dev))]
     if cfg!(windows) {
         assert_eq!(body_bytes, "body {\r\n  background-color: black;\r\n  color: white;\r\n  font-family: Arial, sans-serif;\r\n  padding: 42px;\r\n}\r\n");
     } else {
@@ -227,7 +227,7 @@ async fn ensure_serves_public_files(app: axum::Router) {
 }
 
 async fn ensure_serves_imports(app: axum::Router) {
-    let uri = if cfg!(all(debug_assertions, not(feature = "debug-prod"))) {
+    let uri = if cfg!(vite_rs_dev) {
         "/script.tsx"
     } else {
         "/assets/index-CgRBhnJL.js"
@@ -244,9 +244,9 @@ async fn ensure_serves_imports(app: axum::Router) {
 
     let body_bytes = body::to_bytes(response.into_body(), 262144).await.unwrap();
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     assert!(body_bytes.starts_with(b"import __vite__cjsImport0_react_jsxDevRuntime from \"/node_modules/.vite/deps/react_jsx-dev-runtime.js?v="));
 
-    #[cfg(not(all(debug_assertions, not(feature = "debug-prod"))))]
+    #[cfg(not(vite_rs_dev))]
     assert!(body_bytes.starts_with(b"(function(){const vl=document.createElement(\"link\").relList;if(vl&&vl.supports&&vl.supports(\"modulepreload\"))return;for(const Q of document.querySelectorAll('link[rel=\"modulepreload\"]'))r(Q);new MutationObserver(Q=>{for(const L of Q)if(L.type===\"childList\")for(const tl of L.addedNodes)tl.tagName===\"LINK\"&&tl.rel===\"modulepreload\"&&r(tl)}).observe(document,{childList:!0,subtree:!0});function J(Q){const L={};return Q.integrity&&(L.integrity=Q.integrity),Q.referrerPolicy&&(L.referrerPolicy=Q.referrerPolicy),Q.crossOrigin===\"use-credentials\"?L.credentials=\"include\":Q.crossOrigin===\"anonymous\"?L.credentials=\"omit\":L.credentials=\"same-origin\",L}function r(Q){if(Q.ep)return;Q.ep=!0;const L=J(Q);fetch(Q.href,L)}})();const R1=\"modulepreload\",H1=function(_){return\"/\"+_},wv={},N1=function(vl,J,r){let Q=Promise.resolve();if(J&&J.length>0){let tl=function(T){return Promise.all(T.map(U=>Promise.resolve(U).then(k=>({status:\"fulfilled\",value:k}),k=>({status:\"rejected\",reason:k}))))};document.getElementsByTagName(\"link\""));
 }
@@ -0,0 +1,64 @@
+mod util;
+
+use std::pin::Pin;
+
+use axum::{
+    body::{Body, HttpBody},
+    http,
+};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::{BodyStreaming, ViteServe};
+
+fn assets() -> MockAssets {
+    let large: &'static str = "x".repeat(100 * 1024).leak();
+    let entries = vec![
+        ("small.js", "text/javascript", "small()"),
+        ("large.js", "text/javascript", large),
+    ];
+
+    MockAssets(entries.leak())
+}
+
+/// Sizes of the data frames the response body is sent in.
+async fn frames(service: ViteServe, uri: &str) -> Vec<usize> {
+    let app = axum::Router::new().fallback_service(service);
+    let request = http::Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    let mut body = response.into_body();
+    let mut frames = Vec::new();
+    while let Some(frame) = std::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+        frames.push(frame.unwrap().into_data().unwrap().len());
+    }
+
+    frames
+}
+
+#[tokio::test]
+async fn streams_large_bodies_in_chunks() {
+    let service = ViteServe::new(Box::new(assets()));
+
+    assert_eq!(frames(service.clone(), "/small.js").await, vec![7]);
+    assert_eq!(
+        frames(service, "/large.js").await,
+        vec![16 * 1024; 100 / 16]
+            .into_iter()
+            .chain([4 * 1024])
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn uses_the_configured_threshold_and_chunk_size() {
+    let service = ViteServe::new(Box::new(assets()))
+        .with_body_streaming(BodyStreaming::new(200 * 1024, 32 * 1024));
+    assert_eq!(frames(service, "/large.js").await, vec![100 * 1024]);
+
+    let service = ViteServe::new(Box::new(assets())).with_body_streaming(BodyStreaming::new(4, 4));
+    assert_eq!(frames(service, "/small.js").await, vec![4, 3]);
+}
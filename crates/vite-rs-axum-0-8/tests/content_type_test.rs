@@ -0,0 +1,63 @@
+mod util;
+
+use axum::{body::Body, http};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::ViteServe;
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "ok"),
+    ("assets/index.js.map", "application/octet-stream", "{}"),
+    ("assets/data.bin", "application/octet-stream", "data"),
+]);
+
+async fn content_type(service: ViteServe, uri: &str) -> String {
+    let app = axum::Router::new().fallback_service(service);
+    let request = http::Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), 200, "{}", uri);
+    response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn overrides_matching_paths() {
+    let service = ViteServe::new(Box::new(ASSETS))
+        .with_content_type_overrides([("*.map", "application/json")]);
+
+    assert_eq!(
+        content_type(service.clone(), "/assets/index.js.map").await,
+        "application/json"
+    );
+    assert_eq!(
+        content_type(service.clone(), "/assets/data.bin").await,
+        "application/octet-stream"
+    );
+    assert_eq!(content_type(service, "/").await, "text/html");
+}
+
+#[tokio::test]
+async fn first_matching_override_wins() {
+    let service = ViteServe::new(Box::new(ASSETS))
+        .with_content_type_overrides([
+            ("/assets/data.bin", "application/x-custom"),
+            ("assets/*", "text/plain"),
+        ])
+        .with_content_type_overrides([("*.html", "text/plain")]);
+
+    assert_eq!(
+        content_type(service.clone(), "/assets/data.bin").await,
+        "application/x-custom"
+    );
+    assert_eq!(
+        content_type(service.clone(), "/assets/index.js.map").await,
+        "text/plain"
+    );
+    assert_eq!(content_type(service, "/index.html").await, "text/plain");
+}
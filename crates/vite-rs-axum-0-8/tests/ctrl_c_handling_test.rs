@@ -4,7 +4,7 @@ mod util;
 /// since the vite dev server can't be started multiple times.
 #[test]
 fn test() {
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     {
         #[cfg(unix)]
         {
@@ -22,7 +22,7 @@ fn test() {
     }
 }
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 mod dev_tests {
     #[cfg(unix)]
     pub fn unix_ensure_dev_server_exits_on_signal(signal: nix::sys::signal::Signal) {
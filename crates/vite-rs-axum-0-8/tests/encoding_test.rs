@@ -0,0 +1,221 @@
+// compressed copies are only embedded in release builds
+#![cfg(all(
+    not(vite_rs_dev),
+    any(feature = "zstd", feature = "brotli", feature = "gzip")
+))]
+
+mod util;
+
+use axum::{
+    body::{self, Body},
+    http,
+};
+use tower::ServiceExt;
+use util::mock_assets::file;
+use vite_rs::{GetFromVite, ViteFile};
+use vite_rs_axum_0_8::{Vary, ViteServe};
+
+const COMPRESSED: &[u8] = b"(compressed)";
+
+/// A content coding the assets can be embedded with.
+#[derive(Clone, Copy, Debug)]
+struct Coding {
+    /// As in `Content-Encoding`.
+    name: &'static str,
+    /// Embeds a copy compressed with it.
+    with: fn(ViteFile, &'static [u8]) -> ViteFile,
+    /// Accepts the other codings, but not this one.
+    others: &'static str,
+}
+
+/// The codings of the enabled features, in the order they're preferred.
+fn codings() -> Vec<Coding> {
+    vec![
+        #[cfg(feature = "zstd")]
+        Coding {
+            name: "zstd",
+            with: ViteFile::with_zstd,
+            others: "gzip, br",
+        },
+        #[cfg(feature = "brotli")]
+        Coding {
+            name: "br",
+            with: ViteFile::with_brotli,
+            others: "gzip, zstd",
+        },
+        #[cfg(feature = "gzip")]
+        Coding {
+            name: "gzip",
+            with: ViteFile::with_gzip,
+            others: "br, zstd",
+        },
+    ]
+}
+
+/// `app.js` has a copy compressed with the `Coding`, `logo.png` doesn't.
+#[derive(Clone)]
+struct Assets(Coding);
+
+impl GetFromVite for Assets {
+    fn get(&self, file_path: &str) -> Option<ViteFile> {
+        match file_path {
+            "app.js" => Some((self.0.with)(
+                file("app.js", "text/javascript", "console.log('uncompressed')"),
+                COMPRESSED,
+            )),
+            "logo.png" => Some(file("logo.png", "image/png", "png")),
+            _ => None,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn GetFromVite> {
+        Box::new(self.clone())
+    }
+}
+
+async fn send(serve: ViteServe, uri: &str, headers: &[(&str, &str)]) -> http::Response<Body> {
+    let app = axum::Router::new().fallback_service(serve);
+
+    let mut request = http::Request::builder().uri(uri);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+fn serve(coding: Coding) -> ViteServe {
+    ViteServe::new(Box::new(Assets(coding)))
+}
+
+async fn body(response: http::Response<Body>) -> Vec<u8> {
+    body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn sends_the_compressed_copy_when_accepted() {
+    for coding in codings() {
+        let accept_encoding = format!("deflate, {}", coding.name);
+        let response = send(
+            serve(coding),
+            "/app.js",
+            &[("accept-encoding", &accept_encoding)],
+        )
+        .await;
+
+        assert_eq!(response.status(), 200, "{:?}", coding);
+        assert_eq!(response.headers()["content-encoding"], coding.name);
+        assert_eq!(response.headers()["content-length"], "12");
+        assert_eq!(
+            response.headers()["etag"],
+            format!("app.js-{}", coding.name).as_str()
+        );
+        assert_eq!(response.headers()["vary"], "Accept-Encoding");
+        assert_eq!(response.headers()["content-type"], "text/javascript");
+        assert_eq!(body(response).await, COMPRESSED);
+    }
+}
+
+#[tokio::test]
+async fn sends_the_original_otherwise() {
+    for coding in codings() {
+        let refused = format!("{};q=0, *", coding.name);
+
+        for headers in [
+            &[][..],
+            &[("accept-encoding", coding.others)],
+            &[("accept-encoding", refused.as_str())],
+        ] {
+            let response = send(serve(coding), "/app.js", headers).await;
+
+            assert_eq!(response.status(), 200, "{:?} {:?}", coding, headers);
+            assert!(response.headers().get("content-encoding").is_none());
+            assert_eq!(response.headers()["etag"], "app.js");
+            assert_eq!(response.headers()["vary"], "Accept-Encoding");
+            assert_eq!(body(response).await, b"console.log('uncompressed')");
+        }
+    }
+}
+
+#[tokio::test]
+async fn accepts_wildcards() {
+    for coding in codings() {
+        let headers = [("accept-encoding", "deflate;q=1, *;q=0.5")];
+        let response = send(serve(coding), "/app.js", &headers).await;
+
+        assert_eq!(response.headers()["content-encoding"], coding.name);
+    }
+}
+
+#[tokio::test]
+async fn leaves_files_without_a_compressed_copy_alone() {
+    for coding in codings() {
+        let headers = [("accept-encoding", coding.name)];
+        let response = send(serve(coding), "/logo.png", &headers).await;
+
+        assert!(response.headers().get("content-encoding").is_none());
+        assert!(response.headers().get("vary").is_none());
+        assert_eq!(body(response).await, b"png");
+    }
+}
+
+#[tokio::test]
+async fn lists_accept_encoding_with_other_vary_headers() {
+    for coding in codings() {
+        let serve = serve(coding).with_vary(Vary::Extend(vec![http::header::COOKIE]));
+        let response = send(serve, "/app.js", &[]).await;
+
+        assert_eq!(response.headers()["vary"], "Accept-Encoding, cookie");
+    }
+}
+
+#[tokio::test]
+async fn revalidates_the_compressed_copy() {
+    for coding in codings() {
+        let etag = format!("app.js-{}", coding.name);
+        let headers = [("accept-encoding", coding.name), ("if-none-match", &etag)];
+        let response = send(serve(coding), "/app.js", &headers).await;
+
+        assert_eq!(response.status(), 304, "{:?}", coding);
+    }
+}
+
+#[tokio::test]
+async fn prefers_the_better_coding_when_both_are_accepted() {
+    /// `app.js` has a copy compressed with both codings.
+    #[derive(Clone)]
+    struct BothAssets(Coding, Coding);
+
+    impl GetFromVite for BothAssets {
+        fn get(&self, _file_path: &str) -> Option<ViteFile> {
+            let app = file("app.js", "text/javascript", "console.log('uncompressed')");
+            Some((self.1.with)((self.0.with)(app, COMPRESSED), b"(other)"))
+        }
+
+        fn clone_box(&self) -> Box<dyn GetFromVite> {
+            Box::new(self.clone())
+        }
+    }
+
+    let codings = codings();
+    for (index, &preferred) in codings.iter().enumerate() {
+        for &other in &codings[index + 1..] {
+            let both = || ViteServe::new(Box::new(BothAssets(preferred, other)));
+
+            for (accept_encoding, expected) in [
+                (format!("{}, {}", other.name, preferred.name), preferred),
+                (format!("{}, {};q=0", other.name, preferred.name), other),
+            ] {
+                let headers = [("accept-encoding", accept_encoding.as_str())];
+                let response = send(both(), "/app.js", &headers).await;
+
+                assert_eq!(response.headers()["content-encoding"], expected.name);
+            }
+        }
+    }
+}
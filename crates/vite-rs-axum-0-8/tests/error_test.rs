@@ -0,0 +1,171 @@
+mod util;
+
+use std::future::Future;
+use std::pin::Pin;
+
+use axum::{
+    body::{self, Body},
+    http::{self, HeaderValue},
+};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs::{GetError, GetFromVite, RequestContext, ViteFile};
+use vite_rs_axum_0_8::{ServeError, ViteServe};
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "ok"),
+    // e.g. a misbehaving Vite plugin
+    (
+        "split.js",
+        "text/javascript\r\nSet-Cookie: session=evil",
+        "split",
+    ),
+    ("nul.css", "text/css\0", "nul"),
+]);
+
+fn request(uri: &str) -> http::request::Builder {
+    http::Request::builder().uri(uri)
+}
+
+async fn send(request: http::Request<Body>) -> http::Response<Body> {
+    let app = axum::Router::new().fallback_service(ViteServe::new(Box::new(ASSETS)));
+
+    app.oneshot(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn invalid_content_type_is_a_server_error() {
+    for uri in ["/split.js", "/nul.css"] {
+        let response = send(request(uri).body(Body::empty()).unwrap()).await;
+
+        assert_eq!(response.status(), 500, "{}", uri);
+        assert!(response.headers().get("set-cookie").is_none());
+        assert!(response.headers().get("content-type").is_none());
+
+        let body = body::to_bytes(response.into_body(), 1024).await.unwrap();
+        assert!(body.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn invalid_etag_is_a_server_error() {
+    #[derive(Clone)]
+    struct HostileEtag;
+
+    impl GetFromVite for HostileEtag {
+        fn get(&self, file_path: &str) -> Option<ViteFile> {
+            let file = util::mock_assets::file("index.html", "text/html", "ok")
+                .with_content_hash("W/\"1\"\r\nX-Injected: 1");
+
+            (file_path == "index.html").then_some(file)
+        }
+
+        fn clone_box(&self) -> Box<dyn GetFromVite> {
+            Box::new(self.clone())
+        }
+    }
+
+    let service = ViteServe::new(Box::new(HostileEtag));
+    let response = service
+        .serve(request("/").body(Body::empty()).unwrap())
+        .await;
+
+    assert_eq!(response.status(), 500);
+    assert!(response.headers().get("x-injected").is_none());
+}
+
+#[tokio::test]
+async fn unavailable_dev_server_is_retriable() {
+    /// Stands in for assets whose ViteJS dev server is still starting.
+    #[derive(Clone)]
+    struct Starting;
+
+    impl GetFromVite for Starting {
+        fn get(&self, _file_path: &str) -> Option<ViteFile> {
+            None
+        }
+
+        fn try_get_async_with_context<'a>(
+            &'a self,
+            _file_path: &'a str,
+            _context: &'a RequestContext,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<ViteFile>, GetError>> + Send + 'a>> {
+            Box::pin(async { Err(GetError::DevServerUnavailable) })
+        }
+
+        fn clone_box(&self) -> Box<dyn GetFromVite> {
+            Box::new(self.clone())
+        }
+    }
+
+    let service = ViteServe::new(Box::new(Starting));
+    let response = service
+        .serve(request("/assets/app.js").body(Body::empty()).unwrap())
+        .await;
+
+    assert_eq!(response.status(), 503);
+    assert_eq!(response.headers().get("retry-after").unwrap(), "1");
+
+    // missing assets are still missing
+    let response = send(request("/missing.js").body(Body::empty()).unwrap()).await;
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn sync_try_get_isnt_called_on_the_runtime() {
+    /// Only tells why an asset is missing through the sync `try_get`, which may block.
+    #[derive(Clone)]
+    struct Starting;
+
+    impl GetFromVite for Starting {
+        fn get(&self, _file_path: &str) -> Option<ViteFile> {
+            None
+        }
+
+        fn try_get(&self, _file_path: &str) -> Result<Option<ViteFile>, GetError> {
+            Err(GetError::DevServerUnavailable)
+        }
+
+        fn clone_box(&self) -> Box<dyn GetFromVite> {
+            Box::new(self.clone())
+        }
+    }
+
+    let service = ViteServe::new(Box::new(Starting));
+    let response = service
+        .serve(request("/assets/app.js").body(Body::empty()).unwrap())
+        .await;
+
+    // it has to override `try_get_async_with_context` to tell
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn unreadable_if_none_match_is_ignored() {
+    let mut request = request("/index.html").body(Body::empty()).unwrap();
+    request.headers_mut().insert(
+        http::header::IF_NONE_MATCH,
+        HeaderValue::from_bytes(b"W/\"\xff\"").unwrap(),
+    );
+
+    let response = send(request).await;
+
+    assert_eq!(response.status(), 200);
+    let body = body::to_bytes(response.into_body(), 1024).await.unwrap();
+    assert_eq!(&body[..], b"ok");
+}
+
+#[tokio::test]
+async fn try_serve_returns_the_error() {
+    let service = ViteServe::new(Box::new(ASSETS));
+
+    let result = service
+        .try_serve(request("/split.js").body(Body::empty()).unwrap())
+        .await;
+    assert!(matches!(result, Err(ServeError::InvalidResponse(_))));
+
+    let result = service
+        .try_serve(request("/index.html").body(Body::empty()).unwrap())
+        .await;
+    assert_eq!(result.unwrap().status(), 200);
+}
@@ -0,0 +1,193 @@
+mod util;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::ViteServe;
+
+const ASSETS: MockAssets = MockAssets(&[("index.html", "text/html", "<html></html>")]);
+
+/// Reads until the end of an HTTP head, returning it.
+async fn read_head(stream: &mut TcpStream) -> String {
+    let mut head = Vec::new();
+    let mut byte = [0; 1];
+
+    while !head.ends_with(b"\r\n\r\n") {
+        assert_eq!(
+            stream.read(&mut byte).await.unwrap(),
+            1,
+            "connection closed"
+        );
+        head.push(byte[0]);
+    }
+
+    String::from_utf8(head).unwrap()
+}
+
+/// A stand-in for the ViteJS dev server: accepts one WebSocket upgrade, then echoes whatever it
+/// receives.
+async fn start_dev_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let head = read_head(&mut stream).await;
+        assert!(head.starts_with("GET /?token=abc HTTP/1.1\r\n"), "{head}");
+        assert!(head.contains(&format!("Host: {addr}\r\n")), "{head}");
+        assert!(
+            head.contains("sec-websocket-protocol: vite-hmr\r\n"),
+            "{head}"
+        );
+
+        stream
+            .write_all(
+                b"HTTP/1.1 101 Switching Protocols\r\n\
+                  Upgrade: websocket\r\n\
+                  Connection: Upgrade\r\n\
+                  Sec-WebSocket-Protocol: vite-hmr\r\n\r\n",
+            )
+            .await
+            .unwrap();
+
+        let mut buf = [0; 64];
+        loop {
+            let n = stream.read(&mut buf).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            stream.write_all(&buf[..n]).await.unwrap();
+        }
+    });
+
+    format!("http://{addr}")
+}
+
+async fn start_server(dev_server_url: String) -> String {
+    let app = axum::Router::new()
+        .fallback_service(ViteServe::new(Box::new(ASSETS)).with_hmr_proxy(dev_server_url));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    addr.to_string()
+}
+
+#[tokio::test]
+async fn forwards_websocket_upgrades() {
+    let dev_server_url = start_dev_server().await;
+    let addr = start_server(dev_server_url).await;
+
+    let mut client = TcpStream::connect(&addr).await.unwrap();
+    client
+        .write_all(
+            format!(
+                "GET /?token=abc HTTP/1.1\r\n\
+                 Host: {addr}\r\n\
+                 Upgrade: websocket\r\n\
+                 Connection: Upgrade\r\n\
+                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                 Sec-WebSocket-Version: 13\r\n\
+                 Sec-WebSocket-Protocol: vite-hmr\r\n\r\n"
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    let head = read_head(&mut client).await;
+    assert!(head.starts_with("HTTP/1.1 101"), "{head}");
+    assert!(
+        head.contains("sec-websocket-protocol: vite-hmr\r\n"),
+        "{head}"
+    );
+
+    client.write_all(b"ping").await.unwrap();
+    let mut echo = [0; 4];
+    client.read_exact(&mut echo).await.unwrap();
+    assert_eq!(&echo, b"ping");
+}
+
+#[tokio::test]
+async fn serves_assets_for_other_requests() {
+    let addr = start_server("http://127.0.0.1:1".to_string()).await;
+
+    let response = reqwest::get(format!("http://{addr}/")).await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text().await.unwrap(), "<html></html>");
+}
+
+#[tokio::test]
+async fn responds_with_bad_gateway_when_the_dev_server_is_unreachable() {
+    let addr = start_server("http://127.0.0.1:1".to_string()).await;
+
+    let mut client = TcpStream::connect(&addr).await.unwrap();
+    client
+        .write_all(
+            format!(
+                "GET / HTTP/1.1\r\n\
+                 Host: {addr}\r\n\
+                 Upgrade: websocket\r\n\
+                 Connection: Upgrade\r\n\r\n"
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    let head = read_head(&mut client).await;
+    assert!(head.starts_with("HTTP/1.1 502"), "{head}");
+}
+
+/// The dev server accepts the connection (its listener does), but never responds.
+#[tokio::test(start_paused = true)]
+async fn responds_with_bad_gateway_when_the_dev_server_hangs() {
+    let dev_server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = start_server(format!("http://{}", dev_server.local_addr().unwrap())).await;
+
+    let mut client = TcpStream::connect(&addr).await.unwrap();
+    client
+        .write_all(
+            format!(
+                "GET / HTTP/1.1\r\n\
+                 Host: {addr}\r\n\
+                 Upgrade: websocket\r\n\
+                 Connection: Upgrade\r\n\r\n"
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    let head = read_head(&mut client).await;
+    assert!(head.starts_with("HTTP/1.1 502"), "{head}");
+    drop(dev_server);
+}
+
+/// The dev server would need TLS, which isn't supported, so it's not connected to at all.
+#[tokio::test]
+async fn responds_with_bad_gateway_for_an_https_dev_server() {
+    let dev_server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = start_server(format!("https://{}", dev_server.local_addr().unwrap())).await;
+
+    let mut client = TcpStream::connect(&addr).await.unwrap();
+    client
+        .write_all(
+            format!(
+                "GET / HTTP/1.1\r\n\
+                 Host: {addr}\r\n\
+                 Upgrade: websocket\r\n\
+                 Connection: Upgrade\r\n\r\n"
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    let head = read_head(&mut client).await;
+    assert!(head.starts_with("HTTP/1.1 502"), "{head}");
+    let accepted = tokio::time::timeout(std::time::Duration::from_millis(100), dev_server.accept());
+    assert!(accepted.await.is_err());
+}
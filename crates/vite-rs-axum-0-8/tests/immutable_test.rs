@@ -0,0 +1,143 @@
+mod util;
+
+use axum::{body::Body, http};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs::{GetFromVite, RouteManifest, ViteFile};
+use vite_rs_axum_0_8::{CacheStrategy, ViteServe};
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "<html></html>"),
+    ("assets/index-BPvgi06w.js", "text/javascript", "app()"),
+]);
+
+/// Flags the hashed bundle like `#[immutable_assets = "auto"]`.
+#[derive(Clone)]
+struct HashedAssets;
+
+impl GetFromVite for HashedAssets {
+    fn get(&self, file_path: &str) -> Option<ViteFile> {
+        let file = ASSETS.get(file_path)?;
+        let immutable = file_path.starts_with("assets/");
+
+        Some(file.with_immutable(immutable))
+    }
+
+    fn clone_box(&self) -> Box<dyn GetFromVite> {
+        Box::new(self.clone())
+    }
+}
+
+async fn send(
+    cache_strategy: CacheStrategy,
+    uri: &str,
+    header: Option<(&str, &str)>,
+) -> http::Response<Body> {
+    send_to(
+        ViteServe::new(Box::new(HashedAssets)).with_cache_strategy(cache_strategy),
+        uri,
+        header,
+    )
+    .await
+}
+
+async fn send_to(
+    service: ViteServe,
+    uri: &str,
+    header: Option<(&str, &str)>,
+) -> http::Response<Body> {
+    let app = axum::Router::new().fallback_service(service);
+
+    let mut request = http::Request::builder().uri(uri);
+    if let Some((name, value)) = header {
+        request = request.header(name, value);
+    }
+
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn caches_immutable_files_for_good() {
+    for cache_strategy in [CacheStrategy::Eager, CacheStrategy::Lazy] {
+        let response = send(cache_strategy, "/assets/index-BPvgi06w.js", None).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.headers()["cache-control"],
+            "public, max-age=31536000, immutable"
+        );
+    }
+
+    let response = send(CacheStrategy::Eager, "/", None).await;
+    assert_eq!(
+        response.headers()["cache-control"],
+        "max-age=0, must-revalidate"
+    );
+}
+
+#[tokio::test]
+async fn keeps_other_cache_strategies() {
+    let response = send(CacheStrategy::None, "/assets/index-BPvgi06w.js", None).await;
+    assert_eq!(response.headers()["cache-control"], "no-cache");
+
+    let response = send(
+        CacheStrategy::Custom("max-age=60"),
+        "/assets/index-BPvgi06w.js",
+        None,
+    )
+    .await;
+    assert_eq!(response.headers()["cache-control"], "max-age=60");
+}
+
+#[tokio::test]
+async fn answers_conditional_requests_without_comparing() {
+    // any validator: the file can't have changed
+    for header in [
+        ("if-none-match", "\"something-else\""),
+        ("if-modified-since", "Thu, 01 Jan 1970 00:00:00 GMT"),
+    ] {
+        let response = send(
+            CacheStrategy::Eager,
+            "/assets/index-BPvgi06w.js",
+            Some(header),
+        )
+        .await;
+        assert_eq!(response.status(), 304);
+    }
+
+    // other files are still compared
+    let response = send(
+        CacheStrategy::Eager,
+        "/",
+        Some(("if-none-match", "\"something-else\"")),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn only_caches_immutable_files_at_their_own_path() {
+    // the route's URL isn't hashed: it may serve another file after the next deployment
+    let service = ViteServe::new(Box::new(HashedAssets))
+        .with_cache_strategy(CacheStrategy::Eager)
+        .with_routes(RouteManifest::new().with_entry("/app/*", "assets/index-BPvgi06w.js"));
+
+    let response = send_to(
+        service.clone(),
+        "/app/settings",
+        Some(("if-none-match", "\"something-else\"")),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers()["cache-control"],
+        "max-age=0, must-revalidate"
+    );
+
+    let response = send_to(service, "/assets/index-BPvgi06w.js", None).await;
+    assert_eq!(
+        response.headers()["cache-control"],
+        "public, max-age=31536000, immutable"
+    );
+}
@@ -0,0 +1,100 @@
+mod util;
+
+use axum::{
+    body::{self, Body},
+    http,
+};
+use reqwest::StatusCode;
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::ViteServe;
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "index"),
+    ("index.en.html", "text/html", "index en"),
+    ("index.de.html", "text/html", "index de"),
+    ("docs/index.de.html", "text/html", "docs de"),
+    ("about.html", "text/html", "about"),
+    ("about.de.html", "text/html", "about de"),
+    ("script.js", "text/javascript", "script"),
+]);
+
+async fn get(service: ViteServe, uri: &str, accept_language: Option<&str>) -> (StatusCode, String) {
+    let mut request = http::Request::builder().uri(uri);
+    if let Some(accept_language) = accept_language {
+        request = request.header("Accept-Language", accept_language);
+    }
+
+    let app = axum::Router::new().fallback_service(service);
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn localized() -> ViteServe {
+    ViteServe::new(Box::new(ASSETS)).with_locales(["en", "de"], "en")
+}
+
+#[tokio::test]
+async fn serves_unlocalized_entries_without_locales() {
+    let service = ViteServe::new(Box::new(ASSETS));
+
+    assert_eq!(get(service, "/", Some("de")).await.1, "index");
+}
+
+#[tokio::test]
+async fn selects_requested_locale() {
+    assert_eq!(get(localized(), "/", Some("de")).await.1, "index de");
+    assert_eq!(
+        get(localized(), "/about.html", Some("de")).await.1,
+        "about de"
+    );
+    assert_eq!(get(localized(), "/docs", Some("de")).await.1, "docs de");
+}
+
+#[tokio::test]
+async fn respects_quality_values_and_subtags() {
+    let header = Some("fr;q=1.0, de-CH;q=0.9, en;q=0.5");
+    assert_eq!(get(localized(), "/", header).await.1, "index de");
+
+    let header = Some("de;q=0, en;q=0.1");
+    assert_eq!(get(localized(), "/", header).await.1, "index en");
+}
+
+#[tokio::test]
+async fn falls_back_to_default_locale_then_unlocalized_entry() {
+    assert_eq!(get(localized(), "/", Some("fr")).await.1, "index en");
+    assert_eq!(get(localized(), "/", None).await.1, "index en");
+
+    // there is no `about.en.html`, so the unlocalized entry is served
+    assert_eq!(get(localized(), "/about.html", Some("fr")).await.1, "about");
+}
+
+#[tokio::test]
+async fn leaves_non_html_assets_alone() {
+    let (status, body) = get(localized(), "/script.js", Some("de")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "script");
+}
+
+#[tokio::test]
+async fn sets_vary_header_for_localized_entries() {
+    let app = axum::Router::new().fallback_service(localized());
+    let request = http::Request::builder()
+        .uri("/")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(
+        response.headers().get("Vary").map(|h| h.to_str().unwrap()),
+        Some("Accept-Language")
+    );
+}
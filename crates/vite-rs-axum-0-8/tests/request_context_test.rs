@@ -0,0 +1,105 @@
+mod util;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    body::{self, Body},
+    http,
+};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs::{GetFromVite, RequestContext, ViteFile};
+use vite_rs_axum_0_8::ViteServe;
+
+const PUBLIC: MockAssets = MockAssets(&[("index.html", "text/html", "public")]);
+const PRIVATE: MockAssets = MockAssets(&[("index.html", "text/html", "private")]);
+
+/// Serves `PRIVATE` to authorized requests, and records the contexts it's given.
+#[derive(Clone, Default)]
+struct Authorized(Arc<Mutex<Vec<RequestContext>>>);
+
+impl GetFromVite for Authorized {
+    fn get(&self, file_path: &str) -> Option<ViteFile> {
+        PUBLIC.get(file_path)
+    }
+
+    fn get_with_context(&self, file_path: &str, context: &RequestContext) -> Option<ViteFile> {
+        self.0.lock().unwrap().push(context.clone());
+
+        match context.header("Authorization") {
+            Some("Bearer secret") => PRIVATE.get(file_path),
+            _ => self.get(file_path),
+        }
+    }
+
+    fn get_async_with_context<'a>(
+        &'a self,
+        file_path: &'a str,
+        context: &'a RequestContext,
+    ) -> Pin<Box<dyn Future<Output = Option<ViteFile>> + Send + 'a>> {
+        Box::pin(async move { self.get_with_context(file_path, context) })
+    }
+
+    fn clone_box(&self) -> Box<dyn GetFromVite> {
+        Box::new(self.clone())
+    }
+}
+
+async fn get(assets: &Authorized, authorization: Option<&str>) -> String {
+    let mut request = http::Request::builder().uri("/index.html");
+    if let Some(authorization) = authorization {
+        request = request.header("Authorization", authorization);
+    }
+    #[allow(unused_mut)]
+    let mut request = request.body(Body::empty()).unwrap();
+    #[cfg(feature = "connect-info")]
+    request
+        .extensions_mut()
+        .insert(axum::extract::ConnectInfo(std::net::SocketAddr::from((
+            [192, 0, 2, 1],
+            4321,
+        ))));
+
+    let app = axum::Router::new().fallback_service(ViteServe::new(Box::new(assets.clone())));
+    let response = app.oneshot(request).await.unwrap();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn assets_get_the_request_context() {
+    let assets = Authorized::default();
+
+    assert_eq!(get(&assets, None).await, "public");
+    assert_eq!(get(&assets, Some("Bearer wrong")).await, "public");
+    assert_eq!(get(&assets, Some("Bearer secret")).await, "private");
+
+    let contexts = assets.0.lock().unwrap();
+    let context = contexts.last().unwrap();
+    assert_eq!(context.method, "GET");
+    assert!(context
+        .headers
+        .contains(&("authorization".to_string(), "Bearer secret".to_string())));
+
+    #[cfg(feature = "connect-info")]
+    assert_eq!(context.peer_addr, Some(([192, 0, 2, 1], 4321).into()));
+    #[cfg(not(feature = "connect-info"))]
+    assert_eq!(context.peer_addr, None);
+}
+
+#[test]
+fn other_assets_ignore_the_request_context() {
+    let context = RequestContext::new("GET").with_header("Authorization", "Bearer secret");
+
+    assert_eq!(
+        PUBLIC
+            .get_with_context("index.html", &context)
+            .unwrap()
+            .bytes,
+        PUBLIC.get("index.html").unwrap().bytes
+    );
+    assert_eq!(context.header("authorization"), Some("Bearer secret"));
+}
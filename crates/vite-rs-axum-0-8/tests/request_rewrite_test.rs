@@ -0,0 +1,82 @@
+mod util;
+
+use std::borrow::Cow;
+
+use axum::{
+    body::{self, Body},
+    http,
+};
+use reqwest::StatusCode;
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::ViteServe;
+use vite_rs_interface::RouteManifest;
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "index"),
+    ("index.de.html", "text/html", "index de"),
+    ("assets/app.js", "text/javascript", "app"),
+]);
+
+async fn get(service: ViteServe, uri: &str) -> (StatusCode, String) {
+    let request = http::Request::builder()
+        .uri(uri)
+        .header("Accept-Language", "de")
+        .body(Body::empty())
+        .unwrap();
+
+    let app = axum::Router::new().fallback_service(service);
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn strip_v2() -> ViteServe {
+    ViteServe::new(Box::new(ASSETS)).with_request_rewrite(|path| match path.strip_prefix("/v2") {
+        Some(rest) => Cow::Owned(rest.to_string()),
+        None => Cow::Borrowed(path),
+    })
+}
+
+#[tokio::test]
+async fn serves_the_rewritten_path() {
+    assert_eq!(
+        get(strip_v2(), "/v2/assets/app.js?v=1").await,
+        (StatusCode::OK, "app".to_string())
+    );
+    assert_eq!(get(strip_v2(), "/v2").await.1, "index");
+    assert_eq!(get(strip_v2(), "/assets/app.js").await.1, "app");
+}
+
+#[tokio::test]
+async fn maps_legacy_urls() {
+    let service = ViteServe::new(Box::new(ASSETS)).with_request_rewrite(|path| {
+        if path == "/legacy/app.js" {
+            Cow::Borrowed("/assets/app.js")
+        } else {
+            Cow::Borrowed(path)
+        }
+    });
+
+    assert_eq!(get(service.clone(), "/legacy/app.js").await.1, "app");
+    assert_eq!(
+        get(service, "/legacy/other.js").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn resolves_entries_and_routes_for_the_rewritten_path() {
+    let service = strip_v2()
+        .with_locales(["en", "de"], "en")
+        .with_routes(RouteManifest::new().with_entry("/settings/*", "index.html"));
+
+    assert_eq!(get(service.clone(), "/v2/").await.1, "index de");
+    assert_eq!(
+        get(service.clone(), "/v2/settings/profile").await.1,
+        "index de"
+    );
+    assert_eq!(get(service, "/v2/other").await.0, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,93 @@
+mod util;
+
+use axum::{
+    body::{self, Body},
+    http::{self, HeaderValue},
+};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs::ViteFile;
+use vite_rs_axum_0_8::{
+    AssetBody, AssetRequest, AssetResponder, AssetResponse, DefaultResponder, ViteServe,
+};
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "<html></html>"),
+    ("assets/app.js", "text/javascript", "app()"),
+]);
+
+/// Adds a header to every asset response, and replaces the body of HTML entries.
+struct Signed;
+
+impl AssetResponder for Signed {
+    fn respond(&self, req: AssetRequest<'_>, file: ViteFile) -> AssetResponse {
+        let is_html = req.path.ends_with(".html");
+        let mut response = DefaultResponder.respond(req, file);
+
+        response
+            .headers
+            .insert("x-signature", HeaderValue::from_static("signed"));
+        if is_html && matches!(response.body, AssetBody::Full(_)) {
+            response.body = AssetBody::Full("<html>signed</html>".into());
+        }
+
+        response
+    }
+}
+
+async fn send(uri: &str, if_none_match: Option<&str>) -> http::Response<Body> {
+    let app = axum::Router::new()
+        .fallback_service(ViteServe::new(Box::new(ASSETS)).with_responder(Signed));
+
+    let mut request = http::Request::builder().uri(uri);
+    if let Some(etag) = if_none_match {
+        request = request.header("if-none-match", etag);
+    }
+
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn customizes_found_assets() {
+    let response = send("/", None).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-signature"], "signed");
+    assert_eq!(response.headers()["content-type"], "text/html");
+
+    let body = body::to_bytes(response.into_body(), 1024).await.unwrap();
+    assert_eq!(body, "<html>signed</html>");
+
+    let response = send("/assets/app.js", None).await;
+    assert_eq!(response.headers()["x-signature"], "signed");
+    let body = body::to_bytes(response.into_body(), 1024).await.unwrap();
+    assert_eq!(body, "app()");
+}
+
+#[tokio::test]
+async fn keeps_conditional_requests_and_not_found() {
+    let etag = send("/assets/app.js", None).await.headers()["etag"].clone();
+
+    let response = send("/assets/app.js", Some(etag.to_str().unwrap())).await;
+    assert_eq!(response.status(), 304);
+    assert_eq!(response.headers()["x-signature"], "signed");
+    let body = body::to_bytes(response.into_body(), 1024).await.unwrap();
+    assert!(body.is_empty());
+
+    // any of the listed ETags, compared weakly
+    let etags = format!(
+        "\"other\", W/{}",
+        etag.to_str().unwrap().trim_start_matches("W/")
+    );
+    assert_eq!(send("/assets/app.js", Some(&etags)).await.status(), 304);
+    assert_eq!(
+        send("/assets/app.js", Some("\"other\"")).await.status(),
+        200
+    );
+
+    // the responder isn't asked about missing assets
+    let response = send("/missing.js", None).await;
+    assert_eq!(response.status(), 404);
+    assert!(response.headers().get("x-signature").is_none());
+}
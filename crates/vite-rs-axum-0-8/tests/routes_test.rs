@@ -0,0 +1,81 @@
+mod util;
+
+use axum::{
+    body::{self, Body},
+    http,
+};
+use reqwest::StatusCode;
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::ViteServe;
+use vite_rs_interface::RouteManifest;
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "index"),
+    ("index.de.html", "text/html", "index de"),
+    ("admin/index.html", "text/html", "admin"),
+    ("script.js", "text/javascript", "script"),
+]);
+
+async fn get(service: ViteServe, uri: &str) -> (StatusCode, String) {
+    let request = http::Request::builder()
+        .uri(uri)
+        .header("Accept-Language", "de")
+        .body(Body::empty())
+        .unwrap();
+
+    let app = axum::Router::new().fallback_service(service);
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn service() -> ViteServe {
+    ViteServe::new(Box::new(ASSETS)).with_routes(
+        RouteManifest::new()
+            .with_entry("/*", "index.html")
+            .with_entry("/admin/*", "admin/index.html")
+            .with_api_route("/api/*"),
+    )
+}
+
+#[tokio::test]
+async fn serves_the_entry_of_client_side_routes() {
+    assert_eq!(
+        get(service(), "/settings/profile").await,
+        (StatusCode::OK, "index".to_string())
+    );
+    assert_eq!(
+        get(service(), "/admin/users/42").await,
+        (StatusCode::OK, "admin".to_string())
+    );
+}
+
+#[tokio::test]
+async fn serves_assets_before_routes() {
+    assert_eq!(get(service(), "/script.js").await.1, "script");
+}
+
+#[tokio::test]
+async fn doesnt_serve_entries_for_api_routes() {
+    assert_eq!(get(service(), "/api/users").await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn responds_with_not_found_without_routes() {
+    let service = ViteServe::new(Box::new(ASSETS));
+
+    assert_eq!(
+        get(service, "/settings/profile").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn serves_localized_entries_for_routes() {
+    let service = service().with_locales(["en", "de"], "en");
+
+    assert_eq!(get(service, "/settings").await.1, "index de");
+}
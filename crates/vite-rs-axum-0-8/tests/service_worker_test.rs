@@ -0,0 +1,58 @@
+mod util;
+
+use axum::{body::Body, http};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs::{GetFromVite, ViteFile};
+use vite_rs_axum_0_8::ViteServe;
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "<html></html>"),
+    ("sw.js", "text/javascript", "self.skipWaiting()"),
+]);
+
+/// Serves `sw.js` like a file registered with
+/// `#[generated_entry("sw.js", service_worker_allowed = "/")]`.
+#[derive(Clone)]
+struct ServiceWorkerAssets;
+
+impl GetFromVite for ServiceWorkerAssets {
+    fn get(&self, file_path: &str) -> Option<ViteFile> {
+        let file = ASSETS.get(file_path)?;
+
+        Some(match file_path {
+            "sw.js" => file.with_service_worker_allowed("/"),
+            _ => file,
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn GetFromVite> {
+        Box::new(self.clone())
+    }
+}
+
+async fn service_worker_allowed(uri: &str) -> Option<String> {
+    let app = axum::Router::new().fallback_service(ViteServe::new(Box::new(ServiceWorkerAssets)));
+    let request = http::Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    response
+        .headers()
+        .get("service-worker-allowed")
+        .map(|value| value.to_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn sends_service_worker_allowed() {
+    assert_eq!(service_worker_allowed("/sw.js").await.as_deref(), Some("/"));
+}
+
+#[tokio::test]
+async fn omits_service_worker_allowed_for_other_files() {
+    assert_eq!(service_worker_allowed("/").await, None);
+}
@@ -0,0 +1,30 @@
+use vite_rs::{GetFromVite, ViteFile};
+
+/// In-memory asset table for tests which don't need a ViteJS project.
+///
+/// Entries are `(path, content_type, content)`.
+#[derive(Clone)]
+pub struct MockAssets(pub &'static [(&'static str, &'static str, &'static str)]);
+
+impl GetFromVite for MockAssets {
+    fn get(&self, file_path: &str) -> Option<ViteFile> {
+        self.0
+            .iter()
+            .find(|(path, _, _)| *path == file_path)
+            .map(|(path, content_type, content)| file(path, content_type, content))
+    }
+
+    fn clone_box(&self) -> Box<dyn GetFromVite> {
+        Box::new(self.clone())
+    }
+}
+
+/// Note: the path doubles as the content hash so it can be `'static` in release builds.
+pub fn file(path: &'static str, content_type: &'static str, content: &'static str) -> ViteFile {
+    #[cfg(vite_rs_dev)]
+    let content_hash = format!("W/\"{}\"", path);
+    #[cfg(not(vite_rs_dev))]
+    let content_hash = path;
+
+    ViteFile::new(content.as_bytes(), content_type).with_content_hash(content_hash)
+}
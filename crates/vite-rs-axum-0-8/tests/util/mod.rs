@@ -1 +1,4 @@
+#![allow(dead_code)] // not every test binary uses every helper
+
+pub mod mock_assets;
 pub mod run_project;
@@ -8,10 +8,7 @@ fn test_project_path(test_project_name: &str) -> std::path::PathBuf {
     // let's make sure this comment is correct by doing this assertion:
     assert!(workspace_dir.ends_with("crates/vite-rs-axum-0-8"));
 
-    let test_project_path =
-        std::path::PathBuf::from_iter(&[&workspace_dir, "test_projects", test_project_name]);
-
-    test_project_path
+    std::path::PathBuf::from_iter(&[&workspace_dir, "test_projects", test_project_name])
 }
 
 pub fn run(
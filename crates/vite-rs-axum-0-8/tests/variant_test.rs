@@ -0,0 +1,93 @@
+mod util;
+
+use axum::{
+    body::{self, Body},
+    http,
+};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::ViteServe;
+
+const CONTROL: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "control"),
+    ("control.js", "text/javascript", "control js"),
+]);
+
+const EXPERIMENT: MockAssets = MockAssets(&[("index.html", "text/html", "experiment")]);
+
+async fn get(service: ViteServe, uri: &str, cookie: Option<&str>) -> (u16, String) {
+    let mut request = http::Request::builder().uri(uri);
+    if let Some(cookie) = cookie {
+        request = request.header("Cookie", cookie);
+    }
+
+    let app = axum::Router::new().fallback_service(service);
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn ab_test() -> ViteServe {
+    ViteServe::new(Box::new(CONTROL))
+        .with_variant("experiment", Box::new(EXPERIMENT))
+        .with_variant_selector(|req| {
+            let cookies = req
+                .headers
+                .get(http::header::COOKIE)
+                .and_then(|cookie| cookie.to_str().ok())
+                .unwrap_or_default();
+
+            if cookies.contains("ab=experiment") {
+                "experiment"
+            } else {
+                "control"
+            }
+        })
+}
+
+#[tokio::test]
+async fn serves_selected_variant() {
+    assert_eq!(get(ab_test(), "/", None).await.1, "control");
+    assert_eq!(get(ab_test(), "/", Some("ab=control")).await.1, "control");
+    assert_eq!(
+        get(ab_test(), "/", Some("x=1; ab=experiment")).await.1,
+        "experiment"
+    );
+}
+
+#[tokio::test]
+async fn variants_do_not_share_assets() {
+    assert_eq!(get(ab_test(), "/control.js", None).await.0, 200);
+    assert_eq!(
+        get(ab_test(), "/control.js", Some("ab=experiment")).await.0,
+        404
+    );
+}
+
+#[tokio::test]
+async fn selector_can_borrow_from_the_request() {
+    let service = ViteServe::new(Box::new(CONTROL))
+        .with_variant("experiment", Box::new(EXPERIMENT))
+        .with_variant_selector(|req| {
+            req.headers
+                .get("x-variant")
+                .and_then(|variant| variant.to_str().ok())
+                .unwrap_or("control")
+        });
+
+    let app = axum::Router::new().fallback_service(service);
+    let request = http::Request::builder()
+        .uri("/")
+        .header("X-Variant", "experiment")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let body = body::to_bytes(response.into_body(), 2048).await.unwrap();
+
+    assert_eq!(body, "experiment");
+}
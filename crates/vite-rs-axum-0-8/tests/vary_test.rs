@@ -0,0 +1,74 @@
+mod util;
+
+use axum::{body::Body, http};
+use tower::ServiceExt;
+use util::mock_assets::MockAssets;
+use vite_rs_axum_0_8::{Vary, ViteServe};
+
+const ASSETS: MockAssets = MockAssets(&[
+    ("index.html", "text/html", "index"),
+    ("index.en.html", "text/html", "index en"),
+    ("script.js", "text/javascript", "script"),
+]);
+
+async fn vary(service: ViteServe, uri: &str) -> Option<String> {
+    let app = axum::Router::new().fallback_service(service);
+    let request = http::Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    let mut values = response.headers().get_all("vary").iter();
+    let value = values
+        .next()
+        .map(|value| value.to_str().unwrap().to_string());
+    assert!(values.next().is_none(), "more than one Vary header");
+
+    value
+}
+
+fn localized() -> ViteServe {
+    ViteServe::new(Box::new(ASSETS)).with_locales(["en"], "en")
+}
+
+#[tokio::test]
+async fn lists_nothing_without_negotiation() {
+    assert_eq!(vary(ViteServe::new(Box::new(ASSETS)), "/").await, None);
+    assert_eq!(vary(localized(), "/script.js").await, None);
+}
+
+#[tokio::test]
+async fn extends_the_negotiated_headers() {
+    let extended = || localized().with_vary(Vary::Extend(vec![http::header::COOKIE]));
+
+    assert_eq!(
+        vary(extended(), "/").await.as_deref(),
+        Some("Accept-Language, cookie")
+    );
+    assert_eq!(
+        vary(extended(), "/script.js").await.as_deref(),
+        Some("cookie")
+    );
+
+    // listed once, whatever the case
+    let service = localized().with_vary(Vary::Extend(vec![http::header::ACCEPT_LANGUAGE]));
+    assert_eq!(vary(service, "/").await.as_deref(), Some("Accept-Language"));
+}
+
+#[tokio::test]
+async fn replaces_the_negotiated_headers() {
+    let service = localized().with_vary(Vary::Replace(vec![
+        http::header::ACCEPT_ENCODING,
+        http::header::HOST,
+    ]));
+    assert_eq!(
+        vary(service, "/").await.as_deref(),
+        Some("accept-encoding, host")
+    );
+
+    let service = localized().with_vary(Vary::Replace(Vec::new()));
+    assert_eq!(vary(service, "/").await, None);
+}
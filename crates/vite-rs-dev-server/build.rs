@@ -0,0 +1,11 @@
+//! Sets `cfg(vite_rs_dev)` when `vite-rs-interface` does: for builds which get the assets from a
+//! Vite server instead of embedding them (see its build script).
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(vite_rs_dev)");
+
+    if std::env::var("DEP_VITE_RS_INTERFACE_DEV").as_deref() == Ok("true") {
+        println!("cargo:rustc-cfg=vite_rs_dev");
+    }
+}
@@ -0,0 +1,479 @@
+//! Builds the commands used to run Vite. Shared by the dev server and the embed macro
+//! (which runs `vite build` at compile time), so it's available in all builds.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The JavaScript runtime used to run Vite.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Runtime {
+    /// Runs Vite through a Node.js package manager (see `PackageManager`). (default)
+    #[default]
+    Node,
+    /// `deno run -A npm:vite`
+    Deno,
+}
+
+impl Runtime {
+    /// Parses a runtime name: `node` or `deno`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "node" => Some(Runtime::Node),
+            "deno" => Some(Runtime::Deno),
+            _ => None,
+        }
+    }
+
+    /// The executable which is spawned. For Node.js, this depends on the package manager.
+    pub fn program(&self, package_manager: PackageManager) -> &'static str {
+        match self {
+            Runtime::Node => package_manager.program(),
+            Runtime::Deno => "deno", // deno.exe on Windows; resolved without the extension
+        }
+    }
+
+    /// The executable which is spawned by `vite_command_with_npx_path`.
+    pub fn program_with_npx_path<'a>(
+        &self,
+        package_manager: PackageManager,
+        npx_path: Option<&'a str>,
+    ) -> &'a str {
+        match (self, npx_path) {
+            (Runtime::Node, Some(npx_path)) => npx_path,
+            _ => self.program(package_manager),
+        }
+    }
+
+    /// Returns a command which runs `vite`; add the vite arguments to it.
+    /// The package manager is ignored for runtimes other than Node.js.
+    pub fn vite_command(&self, package_manager: PackageManager) -> Command {
+        self.vite_command_with_args(package_manager, &[])
+    }
+
+    /// Like `vite_command`, with arguments for the program running `vite` (the package manager,
+    /// or `deno run`), e.g. `--registry=https://registry.example.com`.
+    pub fn vite_command_with_args(
+        &self,
+        package_manager: PackageManager,
+        program_args: &[String],
+    ) -> Command {
+        self.vite_command_with_npx_path(package_manager, None, program_args)
+    }
+
+    /// Like `vite_command_with_args`, but with Node.js, `npx_path` (if given) is run instead of
+    /// the package manager's executable, e.g. `/nix/store/...-nodejs/bin/npx`. Ignored for Deno.
+    pub fn vite_command_with_npx_path(
+        &self,
+        package_manager: PackageManager,
+        npx_path: Option<&str>,
+        program_args: &[String],
+    ) -> Command {
+        match self {
+            Runtime::Node => package_manager
+                .vite_command_for(npx_path.unwrap_or(package_manager.program()), program_args),
+            Runtime::Deno => {
+                let mut command = Command::new(self.program(package_manager));
+                command
+                    .args(["run", "-A"])
+                    .args(program_args)
+                    .arg("npm:vite");
+                command
+            }
+        }
+    }
+
+    /// Like `vite_command_with_npx_path`, but with Node.js, the project's own `vite` executable
+    /// (see `local_vite_bin`) is run directly if it's installed and no `npx_path` is given. That
+    /// saves the package manager's startup time (around half a second for `npx`), and never
+    /// picks up another (e.g. global) Vite. Used for both the dev server and `vite build`, run in
+    /// `absolute_dir` (the root directory, or the working directory if there's one).
+    pub fn vite_command_in(
+        &self,
+        absolute_dir: &str,
+        package_manager: PackageManager,
+        npx_path: Option<&str>,
+        program_args: &[String],
+    ) -> Command {
+        match self
+            .local_vite_dir(absolute_dir, npx_path)
+            .and_then(local_vite_bin)
+        {
+            Some(vite) => Command::new(vite),
+            None => self.vite_command_with_npx_path(package_manager, npx_path, program_args),
+        }
+    }
+
+    /// Where `vite_command_in` looks for the project's own `vite` executable: `absolute_dir` with
+    /// Node.js if no `npx_path` is given, and nowhere otherwise.
+    pub fn local_vite_dir<'a>(
+        &self,
+        absolute_dir: &'a str,
+        npx_path: Option<&str>,
+    ) -> Option<&'a str> {
+        match (self, npx_path) {
+            (Runtime::Node, None) => Some(absolute_dir),
+            _ => None,
+        }
+    }
+}
+
+/// The project's own `vite` executable, `node_modules/.bin/vite` (`vite.cmd` on Windows), in
+/// `absolute_root_dir` or the closest parent directory which has one (like Node.js resolves
+/// packages, e.g. in a monorepo with hoisted dependencies). `None` if Vite isn't installed
+/// there, e.g. with Yarn Plug'n'Play.
+pub fn local_vite_bin(absolute_root_dir: &str) -> Option<PathBuf> {
+    let bin = if cfg!(windows) { "vite.cmd" } else { "vite" };
+
+    Path::new(absolute_root_dir)
+        .ancestors()
+        .map(|dir| dir.join("node_modules").join(".bin").join(bin))
+        .find(|vite| vite.is_file())
+}
+
+/// The package manager used to run the project's local `vite` binary.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PackageManager {
+    /// `npx vite` (default)
+    #[default]
+    Npm,
+    /// `pnpm exec vite`
+    Pnpm,
+    /// `yarn vite`
+    Yarn,
+    /// `bunx vite`
+    Bun,
+}
+
+impl PackageManager {
+    /// Parses a package manager name: `npm`, `pnpm`, `yarn` or `bun`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "npm" => Some(PackageManager::Npm),
+            "pnpm" => Some(PackageManager::Pnpm),
+            "yarn" => Some(PackageManager::Yarn),
+            "bun" => Some(PackageManager::Bun),
+            _ => None,
+        }
+    }
+
+    /// The executable which is spawned, e.g. `npx` (or `npx.cmd` on Windows).
+    pub fn program(&self) -> &'static str {
+        #[cfg(windows)]
+        match self {
+            PackageManager::Npm => "npx.cmd",
+            PackageManager::Pnpm => "pnpm.cmd",
+            PackageManager::Yarn => "yarn.cmd",
+            PackageManager::Bun => "bunx", // bunx.exe; resolved without the extension
+        }
+
+        #[cfg(not(windows))]
+        match self {
+            PackageManager::Npm => "npx",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Yarn => "yarn",
+            PackageManager::Bun => "bunx",
+        }
+    }
+
+    /// Returns a command which runs `vite`; add the vite arguments to it.
+    pub fn vite_command(&self) -> Command {
+        self.vite_command_with_args(&[])
+    }
+
+    /// Like `vite_command`, with arguments for the package manager itself, which are placed
+    /// before `vite` (e.g. `npx --registry=https://registry.example.com vite`).
+    pub fn vite_command_with_args(&self, package_manager_args: &[String]) -> Command {
+        self.vite_command_for(self.program(), package_manager_args)
+    }
+
+    /// Runs `vite` with `program`, which is this package manager's executable or a replacement
+    /// for it (see `Runtime::vite_command_with_npx_path`).
+    fn vite_command_for(&self, program: &str, package_manager_args: &[String]) -> Command {
+        let mut command = Command::new(program);
+        command.args(package_manager_args);
+
+        if *self == PackageManager::Pnpm {
+            command.arg("exec");
+        }
+        command.arg("vite");
+
+        command
+    }
+}
+
+/// A Node.js version manager which Vite is run through (with the Node.js runtime), so it gets
+/// the Node.js version the project pins rather than the first `node` on `PATH`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionManager {
+    /// Whichever the project pins the Node.js version with (see `detect`). Vite is run as usual
+    /// if it doesn't.
+    Auto,
+    /// `volta run vite ...`, with the `volta` pins in `package.json`.
+    Volta,
+    /// `nvm use`, with `.nvmrc`, in a `bash` shell which then runs Vite (nvm is a shell function,
+    /// loaded from `$NVM_DIR/nvm.sh`). Unix only: nvm-windows switches versions globally, so Vite
+    /// is run as usual.
+    Nvm,
+    /// `mise exec -- vite ...`, with `mise.toml`, `.mise.toml` or `.tool-versions`.
+    Mise,
+}
+
+impl VersionManager {
+    /// Parses a version manager name: `auto`, `volta`, `nvm` or `mise`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "auto" => Some(VersionManager::Auto),
+            "volta" => Some(VersionManager::Volta),
+            "nvm" => Some(VersionManager::Nvm),
+            "mise" => Some(VersionManager::Mise),
+            _ => None,
+        }
+    }
+
+    /// The version manager the project in `absolute_dir` pins the Node.js version with: the one
+    /// configured in `absolute_dir` or the closest parent directory (like they look up their
+    /// configuration). Checked in each directory in this order: a `volta` key in `package.json`,
+    /// mise's configuration files, then `.nvmrc`.
+    pub fn detect(absolute_dir: &str) -> Option<Self> {
+        Path::new(absolute_dir).ancestors().find_map(|dir| {
+            let package_json =
+                std::fs::read_to_string(dir.join("package.json")).unwrap_or_default();
+
+            if has_key(&package_json, "volta") {
+                Some(VersionManager::Volta)
+            } else if ["mise.toml", ".mise.toml", ".tool-versions"]
+                .iter()
+                .any(|file| dir.join(file).is_file())
+            {
+                Some(VersionManager::Mise)
+            } else if dir.join(".nvmrc").is_file() {
+                Some(VersionManager::Nvm)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// `Auto` resolved for the project in `absolute_dir`; the others as they are.
+    pub fn resolve(self, absolute_dir: &str) -> Option<Self> {
+        match self {
+            VersionManager::Auto => Self::detect(absolute_dir),
+            version_manager => Some(version_manager),
+        }
+    }
+
+    /// The executable which is spawned instead of the package manager's (which it runs), e.g.
+    /// `volta`. `None` for `Auto` (see `resolve`), and for `Nvm` on Windows.
+    pub fn program(&self) -> Option<&'static str> {
+        match self {
+            VersionManager::Auto => None,
+            VersionManager::Volta => Some("volta"),
+            VersionManager::Nvm if cfg!(windows) => None,
+            VersionManager::Nvm => Some("bash"),
+            VersionManager::Mise => Some("mise"),
+        }
+    }
+
+    /// Runs `command` (a program and its arguments, like the ones `Runtime::vite_command_in`
+    /// returns) through this version manager, resolved for the project in `absolute_dir`.
+    /// Returns it as-is if there's nothing to run it through.
+    pub fn command_in(&self, absolute_dir: &str, command: Command) -> Command {
+        let Some(version_manager) = self.resolve(absolute_dir) else {
+            return command;
+        };
+        let Some(program) = version_manager.program() else {
+            return command;
+        };
+
+        let mut wrapped = Command::new(program);
+        match version_manager {
+            VersionManager::Volta => wrapped.arg("run"),
+            VersionManager::Mise => wrapped.args(["exec", "--"]),
+            // `$0` is the name the shell reports errors with; the command follows as `$@`
+            _ => wrapped.args([
+                "-c",
+                r#". "${NVM_DIR:-$HOME/.nvm}/nvm.sh" && nvm use --silent && exec "$@""#,
+                "vite-rs",
+            ]),
+        };
+
+        wrapped.arg(command.get_program()).args(command.get_args());
+        wrapped
+    }
+}
+
+/// Whether the JSON object `json` has a `key` key, e.g. `"volta": { ... }` in a `package.json`.
+fn has_key(json: &str, key: &str) -> bool {
+    let quoted = format!("\"{}\"", key);
+
+    json.match_indices(&quoted)
+        .any(|(i, _)| json[i + quoted.len()..].trim_start().starts_with(':'))
+}
+
+/// Extra package manager arguments from the `VITE_RS_PACKAGE_MANAGER_ARGS` environment variable
+/// (whitespace-separated, see `split_args`), e.g. `--registry=https://npm.example.com` behind a
+/// corporate proxy. They're added after the ones configured in code, both for `vite build` and the
+/// dev server.
+pub fn package_manager_args_from_env() -> Vec<String> {
+    std::env::var("VITE_RS_PACKAGE_MANAGER_ARGS")
+        .map(|args| split_args(&args))
+        .unwrap_or_default()
+}
+
+/// Splits `args` at whitespace, except inside single or double quotes, which are removed: e.g.
+/// `--yes "--cache=/tmp/npm cache"` is two arguments. Backslashes are kept as they are, so Windows
+/// paths don't need escaping.
+fn split_args(args: &str) -> Vec<String> {
+    let mut split = Vec::new();
+    let mut arg = None::<String>;
+    let mut quote = None;
+
+    for c in args.chars() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => arg.get_or_insert_with(String::new).push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                // e.g. `""` is an empty argument
+                arg.get_or_insert_with(String::new);
+            }
+            None if c.is_whitespace() => split.extend(arg.take()),
+            None => arg.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    split.extend(arg);
+    split
+}
+
+/// Path of the executable run instead of `npx` (or the configured package manager's executable)
+/// from the `VITE_RS_NPX_PATH` environment variable, e.g. for nvm, Nix or Volta installs that
+/// aren't on the `PATH` vite-rs is run with. It takes precedence over `#[npx_path]` and
+/// `DevServerOptions::npx_path`, both for `vite build` and the dev server.
+pub fn npx_path_from_env() -> Option<String> {
+    std::env::var("VITE_RS_NPX_PATH")
+        .ok()
+        .filter(|path| !path.is_empty())
+}
+
+/// The `NODE_OPTIONS` for the Vite process: the ones inherited from the environment, followed by
+/// `node_options` (e.g. `--max-old-space-size=8192`), so later (configured) flags win. `None` if
+/// there are no configured options, so the inherited variable is left alone.
+pub fn node_options_with(node_options: &[String]) -> Option<String> {
+    if node_options.is_empty() {
+        return None;
+    }
+
+    let inherited = std::env::var("NODE_OPTIONS").unwrap_or_default();
+
+    Some(
+        inherited
+            .split_whitespace()
+            .chain(node_options.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// What was tried when the program running Vite (e.g. `npx`) couldn't be found, so the error can
+/// say how to fix it. Shown by `DevServerError::CommandNotFound` and when `vite build` can't be
+/// run at compile time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NotFoundDiagnostics {
+    /// The program which was run, e.g. `npx`, or the path it was given as (`VITE_RS_NPX_PATH`).
+    pub program: String,
+    /// The directories the program was looked for in (`PATH`). Empty if it was given as a path.
+    pub searched: Vec<PathBuf>,
+    /// Whether the program exists, if it was given as a path: e.g. `node_modules/.bin/vite`,
+    /// which can't be run without Node.js.
+    pub exists: bool,
+    /// The directory whose `node_modules/.bin/vite` (or its parents') would have been run
+    /// instead, but Vite isn't installed there. `None` if it wasn't looked for (see
+    /// `Runtime::local_vite_dir`), or it's installed.
+    pub project_dir: Option<PathBuf>,
+    /// The version of the `node` on `PATH`, e.g. `v20.11.0`. `None` if there's none.
+    pub node_version: Option<String>,
+}
+
+impl NotFoundDiagnostics {
+    /// Collects what was tried to run `program`, where Vite was looked for in `local_vite_dir`
+    /// first (see `Runtime::local_vite_dir`). Runs `node --version`.
+    pub fn collect(program: &str, local_vite_dir: Option<&str>) -> Self {
+        let is_path = Path::new(program).components().count() > 1;
+
+        let searched = if is_path {
+            Vec::new()
+        } else {
+            std::env::var_os("PATH")
+                .map(|path| std::env::split_paths(&path).collect())
+                .unwrap_or_default()
+        };
+
+        let node_version = Command::new(if cfg!(windows) { "node.exe" } else { "node" })
+            .arg("--version")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+
+        Self {
+            program: program.to_string(),
+            searched,
+            exists: is_path && Path::new(program).is_file(),
+            project_dir: local_vite_dir
+                .filter(|dir| local_vite_bin(dir).is_none())
+                .map(PathBuf::from),
+            node_version,
+        }
+    }
+}
+
+impl std::fmt::Display for NotFoundDiagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.searched.is_empty() {
+            let searched = self
+                .searched
+                .iter()
+                .map(|dir| dir.display().to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "  - Searched PATH: {}", searched.join(", "))?;
+        } else if self.exists {
+            writeln!(
+                f,
+                "  - `{}` exists, but the interpreter it's run with (e.g. `node`) wasn't found.",
+                self.program
+            )?;
+        } else {
+            writeln!(f, "  - `{}` doesn't exist.", self.program)?;
+        }
+
+        if let Some(project_dir) = &self.project_dir {
+            writeln!(
+                f,
+                "  - Vite isn't installed in {} or its parents (no node_modules/.bin/vite), which would be used instead.",
+                project_dir.display()
+            )?;
+        }
+
+        match &self.node_version {
+            Some(version) if self.searched.is_empty() => {
+                writeln!(f, "  - Node.js {} is on PATH.", version)?
+            }
+            Some(version) => writeln!(
+                f,
+                "  - Node.js {} is on PATH, but `{}` isn't.",
+                version, self.program
+            )?,
+            None => writeln!(f, "  - Node.js isn't on PATH either.")?,
+        }
+
+        write!(
+            f,
+            "Install Node.js (https://nodejs.org) and run `npm install` in your project, or set VITE_RS_NPX_PATH to the `{}` executable (e.g. of an nvm, Volta or Nix install).",
+            Path::new(&self.program)
+                .file_name()
+                .map_or(self.program.as_str(), |name| name.to_str().unwrap_or_default())
+        )
+    }
+}
@@ -0,0 +1,193 @@
+//! Reads assets straight from disk in development builds, bypassing the ViteJS dev server.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A file read from disk.
+#[derive(Clone)]
+pub struct DiskFile {
+    pub bytes: Vec<u8>,
+    pub content_type: String,
+    pub content_length: u64,
+    /// A weak ETag in the same format as the one ViteJS sends: `W/"<size>-<mtime>"` (in hex).
+    pub etag: String,
+}
+
+/// Files larger than this aren't cached, e.g. videos or source maps of large bundles.
+const MAX_CACHED_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Once the cached files add up to more than this, the least recently used ones are dropped.
+const MAX_CACHE_SIZE: u64 = 32 * 1024 * 1024;
+
+struct CacheEntry {
+    modified: Option<SystemTime>,
+    file: DiskFile,
+    /// The value of `Cache::uses` when this entry was last read or inserted.
+    last_used: u64,
+}
+
+/// Files read so far, keyed by absolute path. An entry is reused as long as the file's
+/// modification time and size are unchanged.
+#[derive(Default)]
+struct Cache {
+    files: HashMap<PathBuf, CacheEntry>,
+    /// The size of the cached files, in bytes.
+    size: u64,
+    /// Counts reads and inserts, to tell which entry was used least recently.
+    uses: u64,
+}
+
+impl Cache {
+    fn get(&mut self, path: &Path, modified: Option<SystemTime>, len: u64) -> Option<DiskFile> {
+        self.uses += 1;
+        let entry = self.files.get_mut(path)?;
+
+        // without a modification time, we can't tell whether the file changed
+        if modified.is_none() || entry.modified != modified || entry.file.content_length != len {
+            return None;
+        }
+
+        entry.last_used = self.uses;
+        Some(entry.file.clone())
+    }
+
+    fn insert(&mut self, path: &Path, modified: Option<SystemTime>, file: &DiskFile) {
+        if let Some(stale) = self.files.remove(path) {
+            self.size -= stale.file.content_length;
+        }
+        if file.content_length > MAX_CACHED_FILE_SIZE {
+            return;
+        }
+
+        while self.size + file.content_length > MAX_CACHE_SIZE {
+            let Some(least_recently_used) = self
+                .files
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(path, _)| path.clone())
+            else {
+                break;
+            };
+            let evicted = self.files.remove(&least_recently_used).unwrap();
+            self.size -= evicted.file.content_length;
+        }
+
+        self.uses += 1;
+        self.size += file.content_length;
+        self.files.insert(
+            path.to_path_buf(),
+            CacheEntry {
+                modified,
+                file: file.clone(),
+                last_used: self.uses,
+            },
+        );
+    }
+}
+
+/// Caches the files read from disk, so they're only read again once they change. Files up to
+/// 1 MiB are cached; the cache holds up to 32 MiB, dropping the least recently used files.
+///
+/// `read`, `read_from` and `read_file` share a global one. A separate cache (e.g. one per test)
+/// doesn't see or evict the files read through another.
+#[derive(Default)]
+pub struct FileCache {
+    cache: Mutex<Cache>,
+}
+
+static GLOBAL_CACHE: OnceLock<FileCache> = OnceLock::new();
+
+impl FileCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `path` relative to `absolute_dir`, like `read_from`, through this cache.
+    pub fn read_from(&self, absolute_dir: impl AsRef<Path>, path: &str) -> Option<DiskFile> {
+        self.read_file(&absolute_dir.as_ref().join(sanitize(path)?))
+    }
+
+    /// Reads a file at an absolute path, like `read_file`, through this cache.
+    pub fn read_file(&self, absolute_path: &Path) -> Option<DiskFile> {
+        let metadata = std::fs::metadata(absolute_path).ok()?;
+        if !metadata.is_file() {
+            return None;
+        }
+
+        let modified = metadata.modified().ok();
+
+        if let Some(file) = self
+            .cache
+            .lock()
+            .unwrap()
+            .get(absolute_path, modified, metadata.len())
+        {
+            return Some(file);
+        }
+
+        let bytes = std::fs::read(absolute_path).ok()?;
+        let file = DiskFile {
+            content_type: mime_guess::from_path(absolute_path)
+                .first_or_octet_stream()
+                .to_string(),
+            content_length: bytes.len() as u64,
+            etag: weak_etag(bytes.len() as u64, modified),
+            bytes,
+        };
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(absolute_path, modified, &file);
+
+        Some(file)
+    }
+}
+
+/// Reads `path` the way the ViteJS dev server would resolve it: from the `public/` directory
+/// first, then relative to the project root.
+///
+/// Returns `None` if the file doesn't exist or `path` tries to escape the project root.
+pub fn read(absolute_root_dir: &str, path: &str) -> Option<DiskFile> {
+    let public_dir = Path::new(absolute_root_dir).join("public");
+
+    read_from(&public_dir, path).or_else(|| read_from(Path::new(absolute_root_dir), path))
+}
+
+/// Reads `path` relative to `absolute_dir`.
+///
+/// Returns `None` if the file doesn't exist or `path` tries to escape `absolute_dir`.
+pub fn read_from(absolute_dir: impl AsRef<Path>, path: &str) -> Option<DiskFile> {
+    read_file(&absolute_dir.as_ref().join(sanitize(path)?))
+}
+
+/// Reads a file at an absolute path. Returns `None` if it doesn't exist or isn't a file.
+///
+/// Files up to 1 MiB are cached, so they're only read again once they change. Larger files are
+/// read on every call. The cache holds up to 32 MiB, dropping the least recently used files.
+pub fn read_file(absolute_path: &Path) -> Option<DiskFile> {
+    GLOBAL_CACHE
+        .get_or_init(FileCache::new)
+        .read_file(absolute_path)
+}
+
+fn weak_etag(len: u64, modified: Option<SystemTime>) -> String {
+    let mtime_millis = modified
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|since_epoch| since_epoch.as_millis())
+        .unwrap_or(0);
+
+    format!("W/\"{:x}-{:x}\"", len, mtime_millis)
+}
+
+/// Turns a request path into a relative path, rejecting anything that isn't a plain
+/// file path (`..`, absolute paths, drive prefixes).
+fn sanitize(path: &str) -> Option<PathBuf> {
+    let path = Path::new(path.trim_start_matches('/'));
+
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        .then(|| path.to_path_buf())
+}
@@ -0,0 +1,82 @@
+/// Errors returned by `try_start_dev_server` and `try_start_dev_server_with`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DevServerError {
+    /// Another process is already listening on the dev server port.
+    PortUnavailable { port: u16 },
+    /// The command used to launch Vite (e.g. `npx`) could not be found. `diagnostics` tells
+    /// where it was looked for, and whether Node.js is installed.
+    CommandNotFound {
+        command: String,
+        diagnostics: Box<crate::command::NotFoundDiagnostics>,
+    },
+    /// The dev server process could not be started.
+    Spawn(std::io::Error),
+    /// The dev server process exited before it was ready (see `DevServerOptions::with_ready_timeout`).
+    Exited(std::process::ExitStatus),
+    /// The dev server didn't respond within the timeout (see `DevServerOptions::with_ready_timeout`).
+    NotReady { timeout: std::time::Duration },
+    /// The dev server process could not be stopped (e.g. before restarting it).
+    Stop(std::io::Error),
+    /// The Ctrl-C handler could not be registered (e.g. because another one is already set).
+    #[cfg(feature = "ctrlc")]
+    CtrlCHandler(ctrlc::Error),
+    /// The SIGTERM/SIGHUP handler could not be registered (see `DevServerOptions::with_signal_handler`).
+    #[cfg(all(unix, feature = "signal-hook"))]
+    SignalHandler(std::io::Error),
+}
+
+impl std::fmt::Display for DevServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DevServerError::PortUnavailable { port } => write!(
+                f,
+                "Selected vite-rs dev server port '{}' is not available.\na) If self-selecting a port via #[dev_server_port = XXX], ensure it is free.\nb) Otherwise, remove the #[dev_server_port] attribute and let vite-rs select a free port for you at compile time.",
+                port
+            ),
+            DevServerError::CommandNotFound {
+                command,
+                diagnostics,
+            } => write!(
+                f,
+                "failed to start ViteJS dev server: `{}` was not found.\n{}",
+                command, diagnostics
+            ),
+            DevServerError::Spawn(e) => write!(f, "failed to start ViteJS dev server: {}", e),
+            DevServerError::Exited(status) => write!(
+                f,
+                "ViteJS dev server exited before it was ready ({}); see its output above",
+                status
+            ),
+            DevServerError::NotReady { timeout } => write!(
+                f,
+                "ViteJS dev server did not respond within {:?}",
+                timeout
+            ),
+            DevServerError::Stop(e) => write!(f, "failed to stop ViteJS dev server: {}", e),
+            #[cfg(feature = "ctrlc")]
+            DevServerError::CtrlCHandler(e) => write!(
+                f,
+                "vite-rs: Error setting Ctrl-C handler ({}); if you are using a custom one, disable the ctrlc feature for the vite-rs crate, and follow the documentation here to integrate it: https://github.com/Wulf/vite-rs#ctrl-c-handler",
+                e
+            ),
+            #[cfg(all(unix, feature = "signal-hook"))]
+            DevServerError::SignalHandler(e) => {
+                write!(f, "vite-rs: Error setting SIGTERM/SIGHUP handler ({})", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DevServerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DevServerError::Spawn(e) | DevServerError::Stop(e) => Some(e),
+            #[cfg(feature = "ctrlc")]
+            DevServerError::CtrlCHandler(e) => Some(e),
+            #[cfg(all(unix, feature = "signal-hook"))]
+            DevServerError::SignalHandler(e) => Some(e),
+            _ => None,
+        }
+    }
+}
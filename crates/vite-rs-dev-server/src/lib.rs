@@ -1,61 +1,170 @@
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 use command_group::GroupChild;
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 #[cfg(feature = "ctrlc")]
 pub use ctrlc;
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
+#[cfg(feature = "reqwest")]
 pub use reqwest; // exported for use in derived code
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
+pub use registry::{DevServerHandle, DevServerRegistry};
+
+pub use command::{PackageManager, Runtime, VersionManager};
+pub use error::DevServerError;
+pub use options::{
+    DevServerEvent, DevServerOptions, DevServerRestart, DevServerStdio, LogLevel, RestartPolicy,
+};
+pub use remote::RemoteEnvironment;
+
+#[cfg(vite_rs_dev)]
 use std::sync::{Arc, Mutex};
 
+pub mod command;
+#[cfg(vite_rs_dev)]
+pub mod disk;
+mod error;
+#[cfg(vite_rs_dev)]
+mod lock;
+mod options;
+#[cfg(vite_rs_dev)]
+mod output;
+#[cfg(vite_rs_dev)]
+#[cfg(unix)]
+mod pty;
+#[cfg(vite_rs_dev)]
+mod registry;
+pub mod remote;
+#[cfg(vite_rs_dev)]
+#[cfg(all(unix, feature = "signal-hook"))]
+mod signals;
+#[cfg(vite_rs_dev)]
+mod supervisor;
+#[cfg(vite_rs_dev)]
+#[cfg(unix)]
+mod terminal;
+#[cfg(vite_rs_dev)]
+#[cfg(feature = "tokio-process")]
+pub mod tokio_process;
+#[cfg(vite_rs_dev)]
+pub mod transport;
 pub mod util;
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+/// Owns the dev server started by `start_dev_server`, and stops it when dropped. Cloning the guard
+/// shares ownership: the dev server keeps running until the last clone is dropped. A guard whose
+/// dev server was replaced (e.g. by starting it again) no longer owns it, so dropping it stops
+/// nothing.
+///
+/// Dropping the last guard asks the dev server to shut down without waiting for it: it's killed
+/// after `DevServerOptions::shutdown_grace_period` on another thread. To stop the dev server at a
+/// specific point (and wait for it), call `shutdown`. To keep it running without holding
+/// on to the guard (e.g. when it's started in a setup function), call `leak`: it then runs until
+/// `stop_dev_server` is called or this process exits.
+#[cfg(vite_rs_dev)]
 pub struct ViteProcess(pub Arc<Mutex<GroupChild>>);
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 pub struct ViteProcess;
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-lazy_static::lazy_static! {
-    static ref VITE_PROCESS: Arc<Mutex<Option<ViteProcess>>> = Arc::new(Mutex::new(None));
+#[cfg(vite_rs_dev)]
+fn unset_dev_server() {
+    if let Err(e) = DevServerRegistry::global().stop_all() {
+        eprintln!("vite-rs: could not stop the ViteJS dev server: {}", e);
+    }
 }
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-fn set_dev_server(process: ViteProcess) {
-    let original = VITE_PROCESS.lock().unwrap().replace(process);
-    if original.is_some() {
-        original
-            .unwrap()
-            .0
-            .lock()
-            .expect("(!) Could not shutdown ViteJS dev server: Mutex poisoned")
-            .kill()
-            .expect("(!) Could not shutdown ViteJS dev server.");
+#[cfg(vite_rs_dev)]
+impl ViteProcess {
+    /// A guard owning the (registered) dev server running `child`.
+    fn owning(child: Arc<Mutex<GroupChild>>) -> Self {
+        DevServerRegistry::global().add_guard(&child);
+        Self(child)
+    }
+
+    /// Whether the dev server process is still running. It may not be ready to serve requests yet.
+    pub fn is_running(&self) -> bool {
+        is_running(&self.0)
+    }
+
+    /// The process ID of the dev server (the leader of its process group), e.g. for metrics. It
+    /// changes when the dev server is restarted (see `DevServerOptions::with_restart`).
+    pub fn pid(&self) -> u32 {
+        self.0.lock().unwrap().id()
+    }
+
+    /// The dev server's exit status if it exited, without waiting for it otherwise.
+    pub fn try_wait(&self) -> std::io::Result<Option<std::process::ExitStatus>> {
+        self.0.lock().unwrap().try_wait()
+    }
+
+    /// Gives up ownership without stopping the dev server: it keeps running (and other clones of
+    /// the guard can't stop it anymore) until `stop_dev_server`/`stop_dev_server_on` is called, or
+    /// this process exits.
+    pub fn leak(self) {
+        // the guard stays counted as an owner, so the count never drops to zero
+        std::mem::forget(self);
+    }
+
+    /// Stops the dev server now, like dropping the last guard would, but waits for it to exit: it's
+    /// asked to shut down first (see `DevServerOptions::shutdown_grace_period`). Other clones of the guard no longer
+    /// own it. Does nothing if it was stopped or replaced already.
+    pub fn shutdown(self) -> std::io::Result<()> {
+        DevServerRegistry::global().stop_child(&self.0).map(|_| ())
+    }
+
+    /// Like `shutdown`, but waits for the dev server to shut down without blocking the async
+    /// runtime's thread. Use it to stop the dev server from async code, e.g. at the end of
+    /// `#[tokio::main]`: a guard dropped there only asks the dev server to shut down, and waits for
+    /// it (see `DevServerOptions::shutdown_grace_period`) on a separate thread, which may not get
+    /// to kill it before this process exits. Needs a Tokio runtime.
+    #[cfg(feature = "async")]
+    pub async fn shutdown_async(self) -> std::io::Result<()> {
+        match DevServerRegistry::global().remove_child(&self.0) {
+            Some(handle) => handle.kill_async().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Kills the dev server (and the processes it started) right away, without the shutdown grace
+    /// period, and waits for it to exit. It isn't restarted. Dropping the guard afterwards does
+    /// nothing.
+    pub fn kill(&self) -> std::io::Result<()> {
+        // unregistered first, so a supervisor doesn't restart it
+        let handle = DevServerRegistry::global().remove_child(&self.0);
+
+        let mut child = self.0.lock().unwrap();
+        if child.try_wait()?.is_none() {
+            child.kill()?;
+            child.wait()?;
+        }
+        drop(child);
+
+        if let Some(handle) = handle {
+            handle.emit(DevServerEvent::Stopped);
+        }
+        Ok(())
     }
 }
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-fn unset_dev_server() {
-    let process = VITE_PROCESS.lock().unwrap().take();
-    if process.is_some() {
-        process
-            .unwrap()
-            .0
-            .lock()
-            .expect("(!) Could not shutdown ViteJS dev server: Mutex poisoned")
-            .kill()
-            .expect("(!) Could not shutdown ViteJS dev server.");
+#[cfg(vite_rs_dev)]
+fn is_running(child: &Mutex<GroupChild>) -> bool {
+    matches!(child.lock().unwrap().try_wait(), Ok(None))
+}
+
+#[cfg(vite_rs_dev)]
+impl Clone for ViteProcess {
+    fn clone(&self) -> Self {
+        Self::owning(self.0.clone())
     }
 }
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 impl Drop for ViteProcess {
     fn drop(&mut self) {
-        unset_dev_server();
+        // other dev servers (e.g. for another `Assets` struct) keep running
+        DevServerRegistry::global().release_guard(&self.0);
     }
 }
 
@@ -87,83 +196,553 @@ impl Drop for ViteProcess {
 /// }
 ///
 /// ```
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 pub fn start_dev_server(
     absolute_root_dir: &str,
     host: &str,
     port: u16,
     #[cfg(feature = "ctrlc")] register_ctrl_c_handler: bool,
 ) -> Option<ViteProcess> {
-    use command_group::CommandGroup;
+    try_start_dev_server(
+        absolute_root_dir,
+        host,
+        port,
+        #[cfg(feature = "ctrlc")]
+        register_ctrl_c_handler,
+    )
+    .unwrap_or_else(|e| panic!("{}", e))
+}
 
-    if !util::is_port_free(port as u16) {
-        panic!(
-            "Selected vite-rs dev server port '{}' is not available.\na) If self-selecting a port via #[dev_server_port = XXX], ensure it is free.\nb) Otherwise, remove the #[dev_server_port] attribute and let vite-rs select a free port for you at compile time.",
-            port
-        )
+/// Starts the ViteJS dev server with the given options. See `DevServerOptions`.
+#[cfg(vite_rs_dev)]
+pub fn start_dev_server_with(options: DevServerOptions) -> Option<ViteProcess> {
+    try_start_dev_server_with(options).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Like `start_dev_server`, but returns an error instead of panicking when the
+/// dev server can't be started (e.g. the port is taken or `npx` is missing).
+#[cfg(vite_rs_dev)]
+pub fn try_start_dev_server(
+    absolute_root_dir: &str,
+    host: &str,
+    port: u16,
+    #[cfg(feature = "ctrlc")] register_ctrl_c_handler: bool,
+) -> Result<Option<ViteProcess>, DevServerError> {
+    let options = DevServerOptions::new(absolute_root_dir, port).with_host(host);
+    #[cfg(feature = "ctrlc")]
+    let options = options.with_ctrl_c_handler(register_ctrl_c_handler);
+
+    try_start_dev_server_with(options)
+}
+
+/// Like `start_dev_server_with`, but returns an error instead of panicking when the
+/// dev server can't be started (e.g. the port is taken or `npx` is missing).
+#[cfg(vite_rs_dev)]
+pub fn try_start_dev_server_with(
+    options: DevServerOptions,
+) -> Result<Option<ViteProcess>, DevServerError> {
+    if dev_server_disabled() {
+        note_dev_server_disabled();
+        return Ok(None);
     }
 
-    // println!("Starting dev server!");
-    // start ViteJS dev server
-    #[cfg(windows)]
-    pub const NPX: &'static str = "npx.cmd";
-    #[cfg(not(windows))]
-    pub const NPX: &'static str = "npx";
-    let child = Arc::new(Mutex::new(
-        std::process::Command::new(NPX)
-            .arg("vite")
-            .arg("--host")
-            .arg(host)
-            .arg("--port")
-            .arg(port.to_string())
-            .arg("--strictPort")
-            .arg("--clearScreen")
-            .arg("false")
-            // we don't want to send stdin to the dev server; this also
-            // hides the "press h + enter to show help" message that the dev server prints
-            .stdin(std::process::Stdio::null())
-            .current_dir(
-                absolute_root_dir, /*format!(
-                                       "{}/examples/basic_usage",
-                                       std::env::var("CARGO_MANIFEST_DIR").unwrap()
-                                   )*/
-            )
-            .group_spawn()
-            .expect("failed to start ViteJS dev server"),
-    ));
-    set_dev_server(ViteProcess(child.clone()));
+    let mut options = options;
 
-    #[cfg(feature = "ctrlc")]
-    {
-        if register_ctrl_c_handler {
-            // We handle Ctrl-C because the node process does not exit properly otherwise
-            ctrlc::try_set_handler({
-                move || {
-                    unset_dev_server();
-                    std::process::exit(0);
+    // e.g. `cargo run` in a second terminal uses the dev server the first one started
+    let root_lock = if options.process_lock {
+        match lock::acquire(&options.absolute_root_dir) {
+            Ok(lock::Acquired::Owned(root_lock)) => Some(root_lock),
+            Ok(lock::Acquired::HeldElsewhere) => {
+                if use_dev_server_of_another_process(&mut options)? {
+                    return Ok(None);
                 }
-            })
-            .expect("vite-rs: Error setting Ctrl-C handler; if you are using a custom one, disable the ctrlc feature for the vite-rs crate, and follow the documentation here to integrate it: https://github.com/Wulf/vite-rs#ctrl-c-handler");
+                None
+            }
+            // e.g. the temp directory isn't writable; we just don't coordinate with other processes
+            Err(_) => None,
+        }
+    } else {
+        None
+    };
+
+    // Another process can take the port between our check and Vite binding it (Vite can't be
+    // handed a bound socket), so on a conflict we start over, a few times.
+    let mut attempt = 0;
+    let child = loop {
+        if let Some(root_lock) = &root_lock {
+            let _ = root_lock.write_port(options.port);
         }
+
+        // only the first time: later attempts already waited, or have another port
+        let port_wait = match options.runtime_port {
+            None if attempt == 0 => options.port_wait,
+            _ => std::time::Duration::ZERO,
+        };
+
+        match start_vite(&options, root_lock.clone(), port_wait) {
+            Err(DevServerError::PortUnavailable { .. }) if attempt < options.port_retries => {
+                attempt += 1;
+
+                match options.runtime_port {
+                    // nothing depends on this particular port, so we don't have to wait for it
+                    Some(runtime_port) => options.port = runtime_port.repick(),
+                    None => {
+                        std::thread::sleep(std::time::Duration::from_millis(100 << attempt.min(5)))
+                    }
+                }
+            }
+            result => break result?,
+        }
+    };
+
+    // only once it's running, so a failed start doesn't leave them registered (and a later
+    // `with_ctrl_c_handler(true)` failing with `CtrlCHandler`)
+    if let Err(e) = install_handlers(&options) {
+        let _ = DevServerRegistry::global().stop(options.port);
+        return Err(e);
+    }
+
+    if let Some(remote) = options.remote_environment() {
+        eprintln!(
+            "vite-rs: running in {}; the ViteJS dev server is forwarded to {}",
+            remote.name(),
+            options.public_origin()
+        );
+    }
+
+    if options.restart.is_some() || options.watches_ready() {
+        let ready = options.ready_timeout.is_some();
+        supervisor::supervise(options.for_restart(), ready, child.clone());
     }
 
     // We build an RAII guard around the child process so that the dev server is killed when it's dropped
-    Some(ViteProcess(child.clone()))
+    Ok(Some(ViteProcess::owning(child)))
 }
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+/// Installs the Ctrl-C and signal handlers `options` asks for, which stop the dev server.
+#[cfg(vite_rs_dev)]
+#[allow(unused_variables)] // without `ctrlc` and `signal-hook`
+fn install_handlers(options: &DevServerOptions) -> Result<(), DevServerError> {
+    #[cfg(feature = "ctrlc")]
+    if options.register_ctrl_c_handler {
+        // We handle Ctrl-C because the node process does not exit properly otherwise
+        ctrlc::try_set_handler({
+            move || {
+                unset_dev_server();
+                std::process::exit(0);
+            }
+        })
+        .map_err(DevServerError::CtrlCHandler)?;
+    }
+
+    #[cfg(all(unix, feature = "signal-hook"))]
+    if options.register_signal_handler {
+        signals::install().map_err(DevServerError::SignalHandler)?;
+    }
+
+    Ok(())
+}
+
+/// Uses the dev server another process runs for the same root (see `lock`), if it can be reached
+/// where `options` says. Returns `false` if it can't, e.g. it runs on another fixed port.
+#[cfg(vite_rs_dev)]
+fn use_dev_server_of_another_process(
+    options: &mut DevServerOptions,
+) -> Result<bool, DevServerError> {
+    match (
+        lock::wait_for_port(&options.absolute_root_dir),
+        options.runtime_port,
+    ) {
+        (Some(port), Some(runtime_port)) => {
+            runtime_port.set(port);
+            options.port = port;
+        }
+        (Some(port), None) if port == options.port => {}
+        _ => return Ok(false),
+    }
+
+    eprintln!(
+        "vite-rs: using the ViteJS dev server started by another process at {}",
+        options.origin()
+    );
+
+    if let Some(timeout) = options.ready_timeout {
+        let url = format!("{}/", options.origin());
+        let deadline = std::time::Instant::now() + timeout;
+
+        while !is_responding(&url) {
+            if std::time::Instant::now() >= deadline {
+                return Err(DevServerError::NotReady { timeout });
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+    }
+
+    Ok(true)
+}
+
+/// Checks that the port is free (waiting up to `port_wait` for it), spawns the dev server and (if
+/// configured) waits until it's ready.
+#[cfg(vite_rs_dev)]
+fn start_vite(
+    options: &DevServerOptions,
+    root_lock: Option<lock::RootLock>,
+    port_wait: std::time::Duration,
+) -> Result<Arc<Mutex<GroupChild>>, DevServerError> {
+    let port = options.port;
+
+    if !util::wait_for_free_port(port, port_wait) {
+        return Err(DevServerError::PortUnavailable { port });
+    }
+
+    let child = Arc::new(Mutex::new(spawn(options)?));
+    DevServerRegistry::global().register(options, child.clone(), root_lock);
+
+    if let Some(timeout) = options.ready_timeout {
+        if let Err(e) = wait_until_ready(options.fetch_host(), port, &child, timeout) {
+            let _ = DevServerRegistry::global().stop(port);
+
+            return match e {
+                // with `--strictPort`, Vite exits if the port was taken after our check
+                DevServerError::Exited(_) if !util::is_port_free(port) => {
+                    Err(DevServerError::PortUnavailable { port })
+                }
+                e => Err(e),
+            };
+        }
+
+        options.emit(DevServerEvent::Ready {
+            url: options.origin(),
+        });
+    }
+
+    Ok(child)
+}
+
+/// Spawns the ViteJS dev server process (`vite --port ...`) for the given options.
+#[cfg(vite_rs_dev)]
+pub(crate) fn spawn(options: &DevServerOptions) -> Result<GroupChild, DevServerError> {
+    use command_group::CommandGroup;
+
+    options.emit(DevServerEvent::Starting { port: options.port });
+
+    let npx_path = command::npx_path_from_env().or_else(|| options.npx_path.clone());
+    let mut command = vite_command(options, npx_path.as_deref());
+
+    #[cfg(unix)]
+    let pty = match options.pty {
+        true => Some(pty::attach(&mut command).map_err(DevServerError::Spawn)?),
+        false => None,
+    };
+
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut group = command.group();
+
+    // so it can be sent CTRL_BREAK on its own to shut down gracefully (see `DevServerHandle::stop`)
+    #[cfg(windows)]
+    group.creation_flags(windows_sys::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP);
+
+    #[allow(unused_mut)]
+    let mut child = group
+        .spawn()
+        .map_err(|e| spawn_error(options, &program, npx_path.as_deref(), e))?;
+
+    output::forward(&mut child, &options.stdio);
+    #[cfg(unix)]
+    if let Some(pty) = pty {
+        pty::forward(pty, &options.stdio);
+    }
+
+    Ok(child)
+}
+
+/// The command running the dev server for the given options, with its stdin and (if captured,
+/// see `output`) stdout and stderr set up.
+#[cfg(vite_rs_dev)]
+pub(crate) fn vite_command(
+    options: &DevServerOptions,
+    npx_path: Option<&str>,
+) -> std::process::Command {
+    let mut package_manager_args = options.package_manager_args.clone();
+    package_manager_args.extend(command::package_manager_args_from_env());
+
+    let mut command = options.runtime.vite_command_in(
+        options.current_dir(),
+        options.package_manager,
+        npx_path,
+        &package_manager_args,
+    );
+
+    if let Some(version_manager) = options.resolved_version_manager() {
+        command = version_manager.command_in(options.current_dir(), command);
+    }
+
+    if options.preview_dir.is_some() {
+        command.arg("preview");
+    }
+
+    // `vite [root]`, when it isn't the working directory
+    if options.working_dir.is_some() {
+        command.arg(&options.absolute_root_dir);
+    }
+
+    if let Some(preview_dir) = &options.preview_dir {
+        command.arg("--outDir").arg(preview_dir);
+    }
+
+    command
+        .arg("--host")
+        .arg(util::unbracket(&options.host))
+        .arg("--port")
+        .arg(options.port.to_string())
+        .arg("--strictPort")
+        .arg("--clearScreen")
+        .arg(options.clear_screen.to_string());
+
+    if let Some(log_level) = options.log_level {
+        command.arg("--logLevel").arg(log_level.name());
+    }
+
+    if let Some(config_file) = &options.config_file {
+        command.arg("--config").arg(config_file);
+    }
+
+    if let Some(base) = &options.base {
+        command.arg("--base").arg(base);
+    }
+
+    if options.open {
+        command.arg("--open");
+        if let Some(open_url) = &options.open_url {
+            command.arg(open_url);
+        }
+    }
+
+    let public_origin = options.public_origin();
+
+    command
+        .args(&options.args)
+        // e.g. for `server.origin` and `server.hmr` in the Vite config; `options.envs` may override these
+        .env("VITE_RS_DEV_SERVER_URL", options.origin())
+        .env("VITE_RS_PUBLIC_URL", &public_origin)
+        .envs(
+            remote::hmr_envs(&public_origin, options.allow_public_host)
+                .into_iter()
+                // e.g. the user's own `__VITE_ADDITIONAL_SERVER_ALLOWED_HOSTS`
+                .filter(|(key, _)| std::env::var_os(key).is_none()),
+        )
+        .envs(
+            command::node_options_with(&options.node_options)
+                .map(|node_options| ("NODE_OPTIONS", node_options)),
+        )
+        .envs(options.envs.iter().map(|(key, value)| (key, value)))
+        .current_dir(options.current_dir());
+
+    if options.inherit_stdin {
+        command.stdin(std::process::Stdio::inherit());
+
+        #[cfg(unix)]
+        terminal::hand_over(&mut command);
+    } else {
+        // we don't want to send stdin to the dev server by default; this also
+        // hides the "press h + enter to show help" message that the dev server prints
+        command.stdin(std::process::Stdio::null());
+    }
+
+    if options.stdio == DevServerStdio::Null {
+        command
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null());
+    }
+
+    if output::is_captured(&options.stdio) {
+        command
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+    }
+
+    command
+}
+
+/// The error for a dev server whose `program` couldn't be spawned, with `npx_path`.
+#[cfg(vite_rs_dev)]
+pub(crate) fn spawn_error(
+    options: &DevServerOptions,
+    program: &str,
+    npx_path: Option<&str>,
+    e: std::io::Error,
+) -> DevServerError {
+    let local_vite_dir = options
+        .runtime
+        .local_vite_dir(options.current_dir(), npx_path);
+
+    match e.kind() {
+        std::io::ErrorKind::NotFound => DevServerError::CommandNotFound {
+            command: program.to_string(),
+            diagnostics: Box::new(command::NotFoundDiagnostics::collect(
+                program,
+                local_vite_dir,
+            )),
+        },
+        _ => DevServerError::Spawn(e),
+    }
+}
+
+/// Whether a dev server started by this process is running on `port`. It may not be ready to
+/// serve requests yet; see `is_responding`.
+#[cfg(vite_rs_dev)]
+pub fn is_dev_server_running(port: u16) -> bool {
+    DevServerRegistry::global()
+        .get(port)
+        .is_some_and(|server| server.is_running())
+}
+
+/// Whether something answers HTTP requests (with any status) at `url`, e.g. a dev server that
+/// wasn't started by this process. Useful for health checks.
+#[cfg(vite_rs_dev)]
+pub fn is_responding(url: &str) -> bool {
+    // may be called inside an async runtime
+    transport::get_blocking(url, &transport::FetchOptions::default()).is_ok()
+}
+
+/// Whether the `VITE_RS_NO_DEV_SERVER` environment variable is set (to anything but `0` or an
+/// empty string), e.g. for a debug binary run in a container or on CI without Node.js. Then no dev
+/// server is started (`start_dev_server` returns `None`), and derived structs fetch their assets
+/// from `VITE_RS_DEV_SERVER_URL` if it's set (see `fetch_origin`).
+#[cfg(vite_rs_dev)]
+pub fn dev_server_disabled() -> bool {
+    std::env::var("VITE_RS_NO_DEV_SERVER").is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Tells (on stderr) why `start_dev_server` didn't start anything.
+#[cfg(vite_rs_dev)]
+pub(crate) fn note_dev_server_disabled() {
+    eprintln!("vite-rs: not starting a ViteJS dev server (VITE_RS_NO_DEV_SERVER is set)");
+}
+
+/// Where derived structs fetch their assets from: `origin`, unless the dev server is disabled
+/// (see `dev_server_disabled`). Then it's the `VITE_RS_DEV_SERVER_URL` environment variable, e.g.
+/// a dev server running in another container, or `None` (noted once) if that isn't set.
+#[cfg(vite_rs_dev)]
+pub fn fetch_origin(origin: String) -> Option<String> {
+    if !dev_server_disabled() {
+        return Some(origin);
+    }
+
+    let url = std::env::var("VITE_RS_DEV_SERVER_URL")
+        .ok()
+        .filter(|url| !url.is_empty());
+
+    match url {
+        Some(url) => Some(url.trim_end_matches('/').to_string()),
+        None => {
+            static NOTED: std::sync::Once = std::sync::Once::new();
+            NOTED.call_once(|| {
+                eprintln!("vite-rs: VITE_RS_NO_DEV_SERVER is set, so assets aren't fetched from a ViteJS dev server (set VITE_RS_DEV_SERVER_URL to fetch them from one)");
+            });
+            None
+        }
+    }
+}
+
+/// Polls the dev server until it responds to an HTTP request (with any status).
+#[cfg(vite_rs_dev)]
+pub(crate) fn wait_until_ready(
+    host: &str,
+    port: u16,
+    child: &Mutex<GroupChild>,
+    timeout: std::time::Duration,
+) -> Result<(), DevServerError> {
+    let url = format!("{}/", util::http_origin(host, port));
+    let deadline = std::time::Instant::now() + timeout;
+
+    loop {
+        if let Ok(Some(status)) = child.lock().unwrap().try_wait() {
+            return Err(DevServerError::Exited(status));
+        }
+
+        let ready = is_responding(&url);
+
+        // whatever answered, it wasn't our dev server if that already exited
+        if ready {
+            return match child.lock().unwrap().try_wait() {
+                Ok(Some(status)) => Err(DevServerError::Exited(status)),
+                _ => Ok(()),
+            };
+        }
+
+        if std::time::Instant::now() >= deadline {
+            return Err(DevServerError::NotReady { timeout });
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+}
+
+#[cfg(not(vite_rs_dev))]
 pub fn start_dev_server(
     #[cfg(feature = "ctrlc")] _register_ctrl_c_handler: bool,
 ) -> Option<ViteProcess> {
     None
 }
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(not(vite_rs_dev))]
+pub fn start_dev_server_with(_options: DevServerOptions) -> Option<ViteProcess> {
+    None
+}
+
+#[cfg(not(vite_rs_dev))]
+pub fn try_start_dev_server(
+    #[cfg(feature = "ctrlc")] _register_ctrl_c_handler: bool,
+) -> Result<Option<ViteProcess>, DevServerError> {
+    Ok(None)
+}
+
+#[cfg(not(vite_rs_dev))]
+pub fn try_start_dev_server_with(
+    _options: DevServerOptions,
+) -> Result<Option<ViteProcess>, DevServerError> {
+    Ok(None)
+}
+
+/// Stops all dev servers started by this process.
+#[cfg(vite_rs_dev)]
 pub fn stop_dev_server() {
     unset_dev_server();
 }
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 pub fn stop_dev_server() {
     // do nothing
 }
+
+/// Stops the dev server started by this process on `port`, leaving others running.
+#[cfg(vite_rs_dev)]
+pub fn stop_dev_server_on(port: u16) {
+    if let Err(e) = DevServerRegistry::global().stop(port) {
+        eprintln!("vite-rs: could not stop the ViteJS dev server: {}", e);
+    }
+}
+
+#[cfg(not(vite_rs_dev))]
+pub fn stop_dev_server_on(_port: u16) {
+    // do nothing
+}
+
+/// Restarts the dev server started by this process on `port`, e.g. after the Vite config changed
+/// in a way Vite doesn't reload by itself. Returns `Ok(false)` if there's none. See
+/// `DevServerHandle::restart`.
+#[cfg(vite_rs_dev)]
+pub fn restart_dev_server_on(port: u16) -> Result<bool, DevServerError> {
+    DevServerRegistry::global().restart(port)
+}
+
+#[cfg(not(vite_rs_dev))]
+pub fn restart_dev_server_on(_port: u16) -> Result<bool, DevServerError> {
+    Ok(false)
+}
+
+#[cfg(not(vite_rs_dev))]
+pub fn is_dev_server_running(_port: u16) -> bool {
+    false
+}
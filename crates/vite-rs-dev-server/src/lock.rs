@@ -0,0 +1,96 @@
+//! Makes sure only one process runs the dev server for a root directory (see
+//! `DevServerOptions::with_process_lock`).
+
+use std::collections::HashMap;
+use std::fs::{File, TryLockError};
+use std::io::{Read, Seek, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::{Duration, Instant};
+
+/// An exclusive lock on a root directory's lock file, held while this process runs its dev server.
+/// The lock file records the dev server's port, for the other processes. Released by the OS when
+/// the last clone is dropped, or this process exits.
+#[derive(Clone, Debug)]
+pub(crate) struct RootLock(Arc<File>);
+
+pub(crate) enum Acquired {
+    Owned(RootLock),
+    /// Another process runs the dev server (see `wait_for_port`).
+    HeldElsewhere,
+}
+
+/// Locks are per open file, so a dev server started again in this process (replacing the one
+/// for the same root) reuses the lock instead of waiting for itself.
+fn held() -> &'static Mutex<HashMap<PathBuf, Weak<File>>> {
+    static HELD: OnceLock<Mutex<HashMap<PathBuf, Weak<File>>>> = OnceLock::new();
+    HELD.get_or_init(Default::default)
+}
+
+/// e.g. `/tmp/vite-rs-3f1a9c0d2b4e5f60.lock`, shared by all processes (and builds) using the root.
+pub(crate) fn lock_file(absolute_root_dir: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "vite-rs-{:016x}.lock",
+        crate::util::stable_hash(absolute_root_dir)
+    ))
+}
+
+pub(crate) fn acquire(absolute_root_dir: &str) -> std::io::Result<Acquired> {
+    let path = lock_file(absolute_root_dir);
+    let mut held = held().lock().unwrap();
+
+    if let Some(file) = held.get(&path).and_then(Weak::upgrade) {
+        return Ok(Acquired::Owned(RootLock(file)));
+    }
+
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&path)?;
+
+    match file.try_lock() {
+        Ok(()) => {
+            let file = Arc::new(file);
+            held.insert(path, Arc::downgrade(&file));
+            Ok(Acquired::Owned(RootLock(file)))
+        }
+        Err(TryLockError::WouldBlock) => Ok(Acquired::HeldElsewhere),
+        Err(TryLockError::Error(e)) => Err(e),
+    }
+}
+
+impl RootLock {
+    /// Records the port the dev server is (about to be) started on.
+    pub(crate) fn write_port(&self, port: u16) -> std::io::Result<()> {
+        let mut file = &*self.0;
+        file.set_len(0)?;
+        file.rewind()?;
+        write!(file, "{}", port)
+    }
+}
+
+/// The port of the dev server run by the process holding the lock. Waits a little for it to be
+/// recorded, which happens right after the lock is taken.
+pub(crate) fn wait_for_port(absolute_root_dir: &str) -> Option<u16> {
+    let mut file = File::open(lock_file(absolute_root_dir)).ok()?;
+    let deadline = Instant::now() + Duration::from_secs(2);
+
+    loop {
+        if let Some(port) = read_port(&mut file) {
+            return Some(port);
+        }
+        if Instant::now() >= deadline {
+            return None;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn read_port(file: &mut File) -> Option<u16> {
+    let mut contents = String::new();
+    file.rewind().ok()?;
+    file.read_to_string(&mut contents).ok()?;
+    contents.trim().parse().ok()
+}
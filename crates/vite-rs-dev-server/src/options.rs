@@ -0,0 +1,791 @@
+use std::process::ExitStatus;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::command::{PackageManager, Runtime, VersionManager};
+use crate::remote::RemoteEnvironment;
+use crate::util::RuntimePort;
+
+/// Configuration for the ViteJS dev server process started by `start_dev_server_with`.
+///
+/// ```ignore
+/// let options = DevServerOptions::new("/path/to/vite/project", 21012)
+///     .with_arg("--mode")
+///     .with_arg("staging")
+///     .with_env("VITE_API_URL", "http://localhost:3000")
+///     .with_stdio(DevServerStdio::Null)
+///     .with_ready_timeout(Duration::from_secs(10));
+///
+/// let _guard = vite_rs_dev_server::start_dev_server_with(options);
+/// ```
+///
+/// Derived structs expose their defaults through `Assets::dev_server_options()`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DevServerOptions {
+    /// Directory the dev server runs in (where the vite config lives).
+    pub absolute_root_dir: String,
+    /// If set, the dev server runs in this directory instead (e.g. the root of a pnpm or yarn
+    /// workspace), and is given `absolute_root_dir` as Vite's root.
+    pub working_dir: Option<String>,
+    /// If set, `vite preview` serves the build in this directory (relative to the root, like
+    /// Vite's `build.outDir`) instead of running the dev server. See `with_preview`.
+    pub preview_dir: Option<String>,
+    /// Host the dev server listens on, e.g. `localhost`, `0.0.0.0` or `::1` (IPv6 addresses may
+    /// also be given in brackets).
+    pub host: String,
+    /// Host vite-rs connects to, if it isn't the one the dev server listens on, e.g. a name only
+    /// this machine resolves to it. See `DevServerOptions::fetch_host`.
+    pub fetch_host: Option<String>,
+    pub port: u16,
+    /// If set, `port` was picked at runtime (see `RuntimePort`), so another one is picked when
+    /// it's taken, instead of waiting for it to be released.
+    pub runtime_port: Option<&'static RuntimePort>,
+    /// Where browsers reach the dev server, if that's not where vite-rs does (e.g. behind port
+    /// forwarding like GitHub Codespaces). See `DevServerOptions::public_origin`.
+    pub public_url: Option<String>,
+    /// Whether the forwarded URL of a remote environment like GitHub Codespaces is used as the
+    /// public URL (see `RemoteEnvironment`). Off by default.
+    pub detect_remote_environment: bool,
+    /// Whether the host of the public URL is added to Vite's `server.allowedHosts`, through its
+    /// internal `__VITE_ADDITIONAL_SERVER_ALLOWED_HOSTS` environment variable. Off by default.
+    pub allow_public_host: bool,
+    /// Runtime used to run `vite` (Node.js or Deno).
+    pub runtime: Runtime,
+    /// Used to run `vite` with Node.js (e.g. `npx vite` or `pnpm exec vite`).
+    pub package_manager: PackageManager,
+    /// Arguments for the package manager (or `deno run`), placed before `vite`, e.g.
+    /// `--registry=https://npm.example.com`. See also `command::package_manager_args_from_env`.
+    pub package_manager_args: Vec<String>,
+    /// The Node.js version manager Vite is run through, if any (see `with_version_manager`).
+    pub version_manager: Option<VersionManager>,
+    /// Executable run instead of the package manager's (e.g. `npx`), such as
+    /// `/home/ci/.nvm/versions/node/v20.11.0/bin/npx`. Only used with Node.js. The
+    /// `VITE_RS_NPX_PATH` environment variable takes precedence (see `command::npx_path_from_env`).
+    pub npx_path: Option<String>,
+    /// Vite config file, passed as `--config` (otherwise Vite looks for `vite.config.*` in the root).
+    pub config_file: Option<String>,
+    /// Public base path, passed as `--base` (e.g. `/app/`), overriding the Vite config's `base`.
+    pub base: Option<String>,
+    /// Vite's `--logLevel`, if set (otherwise Vite's default, `info`, or the Vite config's).
+    pub log_level: Option<LogLevel>,
+    /// Let Vite clear the terminal when logging (`--clearScreen`). Off by default, so the output
+    /// of this process isn't cleared away.
+    pub clear_screen: bool,
+    /// Additional arguments passed to `vite`, after the ones vite-rs sets.
+    pub args: Vec<String>,
+    /// Additional environment variables for the dev server process.
+    pub envs: Vec<(String, String)>,
+    /// Node.js flags added to `NODE_OPTIONS` (after the inherited ones), e.g.
+    /// `--max-old-space-size=8192` for large projects. A `NODE_OPTIONS` in `envs` replaces them.
+    pub node_options: Vec<String>,
+    /// What to do with the dev server's stdout and stderr.
+    pub stdio: DevServerStdio,
+    /// Pass this process' stdin to the dev server, for Vite's keyboard shortcuts (see
+    /// `with_inherit_stdin`).
+    pub inherit_stdin: bool,
+    /// Run the dev server with a pseudo-terminal as its stdout and stderr (see `with_pty`).
+    #[cfg(unix)]
+    pub pty: bool,
+    /// If set, starting the dev server blocks until it responds to HTTP requests (or fails after
+    /// this long). Otherwise, it returns right after spawning the process.
+    pub ready_timeout: Option<Duration>,
+    /// Open the browser when the dev server starts (Vite's `--open`), at `open_url` if it's set
+    /// (see `with_open`).
+    pub open: bool,
+    pub open_url: Option<String>,
+    /// Print where assets are fetched from once the dev server is ready (see
+    /// `with_ready_message`).
+    pub ready_message: bool,
+    /// Request paths fetched from the dev server each time it becomes ready, so Vite has
+    /// pre-bundled their dependencies before a browser asks for them (see `with_warmup`).
+    pub warmup: Vec<String>,
+    /// How long to wait for the port to free up when it's taken, e.g. by the dev server of a
+    /// previous run which is still exiting, before failing with `DevServerError::PortUnavailable`.
+    /// With a `runtime_port`, another port is picked right away instead.
+    pub port_wait: Duration,
+    /// How many more times to try starting the dev server when its port is taken (still after
+    /// `port_wait`, or by another process while Vite starts), with a delay doubling from 200 ms in
+    /// between. With a `runtime_port`, another port is picked right away instead.
+    ///
+    /// Note: a port taken after it was checked (while Vite starts) is only noticed with a
+    /// `ready_timeout`; without one, the dev server just exits.
+    pub port_retries: u32,
+    /// If set, the dev server is started again whenever it exits on its own (e.g. when Node.js
+    /// runs out of memory or a plugin crashes). See `RestartPolicy`.
+    pub restart: Option<RestartPolicy>,
+    /// Called with the dev server's lifecycle events (see `on_event`).
+    on_event: Option<OnEvent>,
+    /// Coordinate with other processes starting a dev server for the same root directory (e.g.
+    /// `cargo run` in two terminals): the first one starts it, the others use it (see
+    /// `with_process_lock`).
+    pub process_lock: bool,
+    /// How long the dev server gets to shut down after being asked to (SIGTERM, or CTRL_BREAK on
+    /// Windows) before it's killed, e.g. so plugins can flush their caches. `Duration::ZERO` kills
+    /// it right away. Dropping the `ViteProcess` guard doesn't wait for it; `ViteProcess::shutdown`
+    /// and the Ctrl-C handler do, until the dev server exited.
+    pub shutdown_grace_period: Duration,
+    /// Stop the dev server on SIGINT/SIGTERM/SIGHUP (see `start_dev_server`).
+    #[cfg(feature = "ctrlc")]
+    pub register_ctrl_c_handler: bool,
+    /// Stop the dev servers on SIGTERM/SIGHUP, then terminate (see `with_signal_handler`).
+    #[cfg(all(unix, feature = "signal-hook"))]
+    pub register_signal_handler: bool,
+}
+
+/// How much Vite logs (its `--logLevel`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+    Silent,
+}
+
+impl LogLevel {
+    /// Parses a log level name, like Vite's: `info`, `warn`, `error` or `silent`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "info" => Some(LogLevel::Info),
+            "warn" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            "silent" => Some(LogLevel::Silent),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+            LogLevel::Silent => "silent",
+        }
+    }
+}
+
+/// How the dev server's stdout and stderr are handled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DevServerStdio {
+    /// Print to this process' stdout and stderr (default).
+    #[default]
+    Inherit,
+    /// Discard all output.
+    Null,
+    /// Forward each line through the `log` facade, with the `vite` target: stdout at the
+    /// `info` level and stderr at the `warn` level.
+    #[cfg(feature = "log")]
+    Log,
+    /// Forward each line as a `tracing` event, with the `vite` target: stdout at the
+    /// `INFO` level and stderr at the `WARN` level.
+    #[cfg(feature = "tracing")]
+    Tracing,
+    /// Print each line with a tag in front of it, e.g. `[assets:frontend]`, so the output of
+    /// several dev servers can be told apart: stdout to this process' stdout and stderr to its
+    /// stderr.
+    Prefixed(String),
+}
+
+impl DevServerOptions {
+    pub fn new(absolute_root_dir: impl Into<String>, port: u16) -> Self {
+        Self {
+            absolute_root_dir: absolute_root_dir.into(),
+            working_dir: None,
+            preview_dir: None,
+            host: "localhost".to_string(),
+            fetch_host: None,
+            port,
+            runtime_port: None,
+            public_url: None,
+            detect_remote_environment: false,
+            allow_public_host: false,
+            runtime: Runtime::default(),
+            package_manager: PackageManager::default(),
+            package_manager_args: Vec::new(),
+            version_manager: None,
+            npx_path: None,
+            config_file: None,
+            base: None,
+            log_level: None,
+            clear_screen: false,
+            args: Vec::new(),
+            envs: Vec::new(),
+            node_options: Vec::new(),
+            stdio: DevServerStdio::default(),
+            inherit_stdin: false,
+            #[cfg(unix)]
+            pty: false,
+            ready_timeout: None,
+            open: false,
+            open_url: None,
+            ready_message: false,
+            warmup: Vec::new(),
+            port_wait: Duration::from_secs(5),
+            port_retries: 3,
+            restart: None,
+            on_event: None,
+            process_lock: true,
+            shutdown_grace_period: Duration::from_secs(3),
+            #[cfg(feature = "ctrlc")]
+            register_ctrl_c_handler: false,
+            #[cfg(all(unix, feature = "signal-hook"))]
+            register_signal_handler: false,
+        }
+    }
+
+    /// The host the dev server listens on (Vite's `--host`), e.g. `0.0.0.0` to make it reachable
+    /// from other devices. vite-rs still connects to it through the loopback address then (see
+    /// `fetch_host`).
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into();
+        self
+    }
+
+    /// The host vite-rs connects to (e.g. to check that the dev server is ready), when it's not
+    /// the one the dev server listens on.
+    pub fn with_fetch_host(mut self, fetch_host: impl Into<String>) -> Self {
+        self.fetch_host = Some(fetch_host.into());
+        self
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Uses the port picked by `runtime_port` (and picks another one when it's taken).
+    pub fn with_runtime_port(mut self, runtime_port: &'static RuntimePort) -> Self {
+        self.port = runtime_port.get();
+        self.runtime_port = Some(runtime_port);
+        self
+    }
+
+    pub fn with_public_url(mut self, public_url: impl Into<String>) -> Self {
+        self.public_url = Some(public_url.into());
+        self
+    }
+
+    /// Uses the URL the port is forwarded to when running in a remote environment like GitHub
+    /// Codespaces or Gitpod (see `RemoteEnvironment`), unless a public URL is set.
+    pub fn with_remote_environment_detection(mut self, detect_remote_environment: bool) -> Self {
+        self.detect_remote_environment = detect_remote_environment;
+        self
+    }
+
+    /// Lets Vite answer requests for the host of the public URL (see `public_origin`), which it
+    /// rejects by default, without adding it to `server.allowedHosts` in the Vite config.
+    pub fn with_public_host_allowed(mut self, allow_public_host: bool) -> Self {
+        self.allow_public_host = allow_public_host;
+        self
+    }
+
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    pub fn with_package_manager(mut self, package_manager: PackageManager) -> Self {
+        self.package_manager = package_manager;
+        self
+    }
+
+    pub fn with_package_manager_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.package_manager_args
+            .extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Runs Vite through a Node.js version manager, so it gets the Node.js version the project
+    /// pins (e.g. in `.nvmrc`) rather than the first `node` on `PATH`. `VersionManager::Auto`
+    /// uses whichever the project is set up for. Only applies to the Node.js runtime.
+    pub fn with_version_manager(mut self, version_manager: VersionManager) -> Self {
+        self.version_manager = Some(version_manager);
+        self
+    }
+
+    pub fn with_npx_path(mut self, npx_path: impl Into<String>) -> Self {
+        self.npx_path = Some(npx_path.into());
+        self
+    }
+
+    /// Runs the dev server in `working_dir` (an absolute path) rather than the root directory,
+    /// e.g. the root of a pnpm or yarn workspace. The root directory is passed to Vite as its
+    /// `root`, so the Vite config is still looked up there.
+    pub fn with_working_dir(mut self, working_dir: impl Into<String>) -> Self {
+        self.working_dir = Some(working_dir.into());
+        self
+    }
+
+    /// Runs `vite preview` instead of the dev server: it serves the build in `out_dir` (e.g.
+    /// `./dist`, relative to the root), which has to exist already, e.g. built by `vite build` in
+    /// CI. Assets are fetched from it like from the dev server, but there's no HMR and nothing is
+    /// rebuilt when sources change. Used by the `preview` feature of `vite-rs`.
+    pub fn with_preview(mut self, out_dir: impl Into<String>) -> Self {
+        self.preview_dir = Some(out_dir.into());
+        self
+    }
+
+    pub fn with_config_file(mut self, config_file: impl Into<String>) -> Self {
+        self.config_file = Some(config_file.into());
+        self
+    }
+
+    /// Serves the assets under `base`, e.g. `/app/`. Requests to the dev server (e.g. by `get()`)
+    /// have to include it.
+    pub fn with_base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    /// How much the dev server logs, e.g. `LogLevel::Warn` to hide its banner and the HMR update
+    /// messages.
+    pub fn with_log_level(mut self, log_level: LogLevel) -> Self {
+        self.log_level = Some(log_level);
+        self
+    }
+
+    /// Lets Vite clear the terminal when it logs, e.g. when the dev server starts.
+    pub fn with_clear_screen(mut self, clear_screen: bool) -> Self {
+        self.clear_screen = clear_screen;
+        self
+    }
+
+    pub fn with_arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn with_node_options<I, S>(mut self, node_options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.node_options
+            .extend(node_options.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_stdio(mut self, stdio: DevServerStdio) -> Self {
+        self.stdio = stdio;
+        self
+    }
+
+    /// Lets the dev server read this process' stdin, so Vite's shortcuts (e.g. `r + enter` to
+    /// restart it, `o + enter` to open the browser) work. Otherwise, its stdin is empty.
+    ///
+    /// On Unix, the dev server is made the terminal's foreground process group while it runs: it
+    /// receives Ctrl+C instead of this process, and this process shouldn't read stdin itself.
+    pub fn with_inherit_stdin(mut self, inherit_stdin: bool) -> Self {
+        self.inherit_stdin = inherit_stdin;
+        self
+    }
+
+    /// Runs the dev server with a pseudo-terminal as its stdout and stderr, for Vite plugins which
+    /// behave differently without a terminal (e.g. no progress output, or different logging).
+    /// Its output is still handled as `stdio` says: printed as-is with `DevServerStdio::Inherit`,
+    /// and forwarded line by line otherwise, with stderr's lines counting as stdout's. It's still
+    /// stopped with the processes it starts.
+    #[cfg(unix)]
+    pub fn with_pty(mut self, pty: bool) -> Self {
+        self.pty = pty;
+        self
+    }
+
+    pub fn with_ready_timeout(mut self, ready_timeout: Duration) -> Self {
+        self.ready_timeout = Some(ready_timeout);
+        self
+    }
+
+    /// Opens the browser at the dev server's URL when it starts, like `vite --open`. It's only
+    /// opened the first time: not again when the dev server is restarted.
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Opens the browser at `url` instead of the dev server's URL when the dev server starts, e.g.
+    /// the URL of your Rust server, which serves the assets. A path like `/docs/` is relative to
+    /// the dev server's URL (Vite resolves it). Implies `with_open(true)`.
+    pub fn with_open_url(mut self, url: impl Into<String>) -> Self {
+        self.open = true;
+        self.open_url = Some(url.into());
+        self
+    }
+
+    /// Prints a line like `vite-rs: assets served from http://localhost:5199 (proxied)` each time
+    /// the dev server becomes ready, as Vite's own banner may be hidden (see `with_stdio`) or
+    /// name a URL which isn't the one assets are fetched from. It's printed once the dev server
+    /// responds to HTTP requests, so it's polled until then (see `on_event`).
+    pub fn with_ready_message(mut self, ready_message: bool) -> Self {
+        self.ready_message = ready_message;
+        self
+    }
+
+    /// Fetches these request paths (e.g. `/`) from the dev server each time it becomes ready, and
+    /// the module scripts of the HTML pages among them, in the background. Vite pre-bundles
+    /// dependencies when it first sees them imported, and reloads the page if it finds new ones
+    /// then; warming up gets this done before the first browser request. It's polled until it's
+    /// ready (see `on_event`).
+    pub fn with_warmup<I, S>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.warmup.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// `Duration::ZERO` fails right away when the port is taken.
+    pub fn with_port_wait(mut self, port_wait: Duration) -> Self {
+        self.port_wait = port_wait;
+        self
+    }
+
+    /// `0` fails right away when the port is still taken after `port_wait` (see `port_retries`).
+    pub fn with_port_retries(mut self, port_retries: u32) -> Self {
+        self.port_retries = port_retries;
+        self
+    }
+
+    pub fn with_restart(mut self, restart: RestartPolicy) -> Self {
+        self.restart = Some(restart);
+        self
+    }
+
+    /// Called (on this thread or a background one) when the dev server starts, becomes ready,
+    /// crashes, restarts or stops, e.g. to show its state in a TUI or a Tauri dev shell without
+    /// parsing its output. See `DevServerEvent`.
+    ///
+    /// `Ready` is only sent once the dev server responds to HTTP requests, so it's polled until
+    /// then. Events of a dev server used from another process (see `with_process_lock`) aren't
+    /// sent.
+    ///
+    /// Can be called several times (also through `on_ready`, `on_exit` and `with_event_sender`):
+    /// the callbacks are called in the order they were added.
+    pub fn on_event(mut self, on_event: impl Fn(&DevServerEvent) + Send + Sync + 'static) -> Self {
+        self.on_event = Some(match self.on_event.take() {
+            Some(OnEvent(previous)) => OnEvent(Arc::new(move |event: &DevServerEvent| {
+                previous(event);
+                on_event(event);
+            })),
+            None => OnEvent(Arc::new(on_event)),
+        });
+        self
+    }
+
+    /// Called with the dev server's URL each time it becomes ready, e.g. to print a banner once
+    /// Vite is up. Shorthand for `on_event` with `DevServerEvent::Ready`.
+    pub fn on_ready(self, on_ready: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.on_event(move |event| {
+            if let DevServerEvent::Ready { url } = event {
+                on_ready(url);
+            }
+        })
+    }
+
+    /// Called with the exit status when the dev server exits on its own (not when it's stopped),
+    /// e.g. to alert that the frontend is gone. Shorthand for `on_event` with
+    /// `DevServerEvent::Crashed`. To also restart it, see `with_restart`.
+    pub fn on_exit(self, on_exit: impl Fn(ExitStatus) + Send + Sync + 'static) -> Self {
+        self.on_event(move |event| {
+            if let DevServerEvent::Crashed { status } = event {
+                on_exit(*status);
+            }
+        })
+    }
+
+    /// Sends the dev server's lifecycle events (see `on_event`) to a channel, e.g. for a TUI's
+    /// event loop to pick them up. Events are dropped once the receiver is gone.
+    ///
+    /// ```ignore
+    /// let (events, receiver) = std::sync::mpsc::channel();
+    /// let options = Assets::dev_server_options().with_event_sender(events);
+    /// ```
+    pub fn with_event_sender(self, sender: std::sync::mpsc::Sender<DevServerEvent>) -> Self {
+        self.on_event(move |event| {
+            let _ = sender.send(event.clone());
+        })
+    }
+
+    /// Whether to coordinate with other processes (enabled by default). While this process runs
+    /// the dev server, it holds a lock file for the root directory (in the temp directory), which
+    /// records the port. Another process starting a dev server for the same root then uses this
+    /// one (`start_dev_server` returns `None`, like with an external dev server), provided it can
+    /// reach it: on the same port, or on a `runtime_port`. It stops working when this process
+    /// stops the dev server.
+    pub fn with_process_lock(mut self, process_lock: bool) -> Self {
+        self.process_lock = process_lock;
+        self
+    }
+
+    pub fn with_shutdown_grace_period(mut self, shutdown_grace_period: Duration) -> Self {
+        self.shutdown_grace_period = shutdown_grace_period;
+        self
+    }
+
+    #[cfg(feature = "ctrlc")]
+    pub fn with_ctrl_c_handler(mut self, register_ctrl_c_handler: bool) -> Self {
+        self.register_ctrl_c_handler = register_ctrl_c_handler;
+        self
+    }
+
+    /// Stops the dev servers when this process receives SIGTERM or SIGHUP (e.g. from
+    /// `docker stop` or systemd), then terminates it the way the signal would have. Unlike the
+    /// `ctrlc` handler, this works alongside signal handlers registered elsewhere, and leaves
+    /// SIGINT alone. Unix only, with the `signal-hook` feature.
+    ///
+    /// If your app shuts down gracefully on SIGTERM itself, don't use this: call
+    /// `stop_dev_server` from your own handler instead.
+    #[cfg(all(unix, feature = "signal-hook"))]
+    pub fn with_signal_handler(mut self, register_signal_handler: bool) -> Self {
+        self.register_signal_handler = register_signal_handler;
+        self
+    }
+
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn on_event_handler(&self) -> Option<OnEvent> {
+        self.on_event.clone()
+    }
+
+    /// The options for restarting the dev server: the browser isn't opened again.
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn for_restart(&self) -> Self {
+        Self {
+            open: false,
+            ..self.clone()
+        }
+    }
+
+    /// The version manager Vite is run through, with `Auto` resolved (see `with_version_manager`).
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn resolved_version_manager(&self) -> Option<VersionManager> {
+        match self.runtime {
+            Runtime::Node => self.version_manager?.resolve(self.current_dir()),
+            _ => None,
+        }
+    }
+
+    /// Whether the dev server's readiness is reported, so it has to be polled.
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn watches_ready(&self) -> bool {
+        self.ready_message || !self.warmup.is_empty() || self.on_event.is_some()
+    }
+
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn emit(&self, event: DevServerEvent) {
+        if let (true, DevServerEvent::Ready { url }) = (self.ready_message, &event) {
+            println!("vite-rs: assets served from {} (proxied)", url);
+        }
+
+        if let (false, DevServerEvent::Ready { url }) = (self.warmup.is_empty(), &event) {
+            crate::transport::warm_up(url, &self.warmup);
+        }
+
+        if let Some(on_event) = &self.on_event {
+            on_event.emit(event);
+        }
+    }
+
+    /// The directory the dev server runs in: `working_dir`, or else the root directory.
+    pub fn current_dir(&self) -> &str {
+        self.working_dir
+            .as_deref()
+            .unwrap_or(&self.absolute_root_dir)
+    }
+
+    /// The host vite-rs connects to: `fetch_host`, or else the `host` the dev server listens on,
+    /// with wildcard addresses (`0.0.0.0`, `::`) replaced by loopback (see `util::fetch_host`).
+    pub fn fetch_host(&self) -> &str {
+        match &self.fetch_host {
+            Some(fetch_host) => fetch_host,
+            None => crate::util::fetch_host(&self.host),
+        }
+    }
+
+    /// Where vite-rs reaches the dev server (e.g. to fetch assets), e.g. `http://localhost:21012`
+    /// or `http://[::1]:21012`.
+    pub fn origin(&self) -> String {
+        crate::util::http_origin(self.fetch_host(), self.port)
+    }
+
+    /// Where browsers reach the dev server, e.g. for HMR or the `@vite/client` script. The first of:
+    ///
+    /// 1. the `VITE_RS_PUBLIC_URL` environment variable (e.g. set by a devcontainer),
+    /// 2. `public_url`,
+    /// 3. the forwarded URL of the port in a remote environment like GitHub Codespaces or Gitpod,
+    ///    with `detect_remote_environment` (see `RemoteEnvironment`),
+    /// 4. `origin()`.
+    ///
+    /// It's passed to the dev server as the `VITE_RS_PUBLIC_URL` environment variable (along
+    /// with `VITE_RS_DEV_SERVER_URL`, the `origin()`), and split up for `server.hmr` as
+    /// `VITE_RS_HMR_PROTOCOL`, `VITE_RS_HMR_HOST` and `VITE_RS_HMR_CLIENT_PORT`.
+    pub fn public_origin(&self) -> String {
+        let public_url = std::env::var("VITE_RS_PUBLIC_URL")
+            .ok()
+            .filter(|url| !url.is_empty())
+            .or_else(|| self.public_url.clone())
+            .or_else(|| self.remote_environment()?.forwarded_url(self.port));
+
+        match public_url {
+            Some(public_url) => public_url.trim_end_matches('/').to_string(),
+            None => self.origin(),
+        }
+    }
+
+    /// The remote environment this process runs in, with `detect_remote_environment`.
+    pub(crate) fn remote_environment(&self) -> Option<RemoteEnvironment> {
+        if !self.detect_remote_environment {
+            return None;
+        }
+
+        RemoteEnvironment::detect()
+    }
+}
+
+/// Something that happened to a dev server, passed to the `DevServerOptions::on_event` callback.
+///
+/// A dev server that crashes and is restarted (see `DevServerOptions::with_restart`) goes through
+/// `Starting`, `Ready`, `Crashed`, `Restarting`, `Starting`, `Ready`, ... until it's `Stopped`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DevServerEvent {
+    /// The dev server process is about to be spawned on `port`.
+    Starting { port: u16 },
+    /// The dev server responds to HTTP requests at `url` (see `DevServerOptions::origin`).
+    Ready { url: String },
+    /// The dev server exited on its own (e.g. Node.js ran out of memory).
+    Crashed { status: ExitStatus },
+    /// The dev server is started again after `backoff` (see `DevServerRestart`).
+    Restarting { attempt: u32, backoff: Duration },
+    /// The dev server was stopped (or replaced) by this process.
+    Stopped,
+}
+
+/// The callbacks set with `DevServerOptions::on_event`, chained.
+#[derive(Clone)]
+pub(crate) struct OnEvent(Arc<dyn Fn(&DevServerEvent) + Send + Sync>);
+
+impl OnEvent {
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn emit(&self, event: DevServerEvent) {
+        (self.0)(&event)
+    }
+}
+
+impl std::fmt::Debug for OnEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("..")
+    }
+}
+
+/// How a crashed dev server is restarted (see `DevServerOptions::with_restart`).
+///
+/// Restarts are delayed with exponential backoff: `initial_backoff` for the first one, doubling
+/// for each consecutive restart up to `max_backoff`. Once the dev server stays up for longer than
+/// `max_backoff`, the delay starts over at `initial_backoff`.
+///
+/// ```ignore
+/// let restart = RestartPolicy::default().on_restart(|restart| {
+///     log::warn!("vite exited ({}), restarting in {:?}", restart.status, restart.backoff);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct RestartPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    on_restart: Option<OnRestart>,
+}
+
+type OnRestart = Arc<dyn Fn(&DevServerRestart) + Send + Sync>;
+
+/// Passed to the `RestartPolicy::on_restart` callback before the dev server is restarted.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DevServerRestart {
+    pub port: u16,
+    /// How the dev server process exited.
+    pub status: ExitStatus,
+    /// Number of consecutive restarts, starting at 1.
+    pub attempt: u32,
+    /// How long until the dev server is started again.
+    pub backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            on_restart: None,
+        }
+    }
+}
+
+impl RestartPolicy {
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Called (on a background thread) before each restart, e.g. to log it. Without a callback,
+    /// restarts are printed to stderr.
+    pub fn on_restart(
+        mut self,
+        on_restart: impl Fn(&DevServerRestart) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_restart = Some(Arc::new(on_restart));
+        self
+    }
+
+    /// The delay before the given restart attempt (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    #[cfg(vite_rs_dev)]
+    pub(crate) fn notify(&self, restart: &DevServerRestart) {
+        match &self.on_restart {
+            Some(on_restart) => on_restart(restart),
+            None => eprintln!(
+                "vite-rs: ViteJS dev server exited ({}); restarting in {:?}",
+                restart.status, restart.backoff
+            ),
+        }
+    }
+}
+
+impl std::fmt::Debug for RestartPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RestartPolicy")
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("on_restart", &self.on_restart.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
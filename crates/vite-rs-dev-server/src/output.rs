@@ -0,0 +1,119 @@
+//! Forwards the dev server's output to the `log` or `tracing` facade, or reprints it with a
+//! prefix (see `DevServerStdio`).
+
+use std::io::{BufRead, BufReader, Read};
+
+use command_group::GroupChild;
+
+use crate::DevServerStdio;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Whether the dev server's output should be captured (piped) rather than inherited or discarded.
+pub(crate) fn is_captured(stdio: &DevServerStdio) -> bool {
+    !matches!(stdio, DevServerStdio::Inherit | DevServerStdio::Null)
+}
+
+/// Spawns a thread per captured stream, which forwards lines until the dev server exits.
+pub(crate) fn forward(child: &mut GroupChild, stdio: &DevServerStdio) {
+    if let Some(stdout) = child.inner().stdout.take() {
+        spawn_reader(stdout, stdio.clone(), Stream::Stdout);
+    }
+    if let Some(stderr) = child.inner().stderr.take() {
+        spawn_reader(stderr, stdio.clone(), Stream::Stderr);
+    }
+}
+
+pub(crate) fn spawn_reader(
+    output: impl Read + Send + 'static,
+    stdio: DevServerStdio,
+    stream: Stream,
+) {
+    let spawned = std::thread::Builder::new()
+        .name("vite-rs-output".to_string())
+        .spawn(move || {
+            let mut reader = BufReader::new(output);
+            let mut line = Vec::new();
+
+            // vite doesn't guarantee UTF-8 output (e.g. from plugins), so lines are read as bytes
+            while let Ok(read) = reader.read_until(b'\n', &mut line) {
+                if read == 0 {
+                    break;
+                }
+
+                emit_line(&stdio, stream, &line);
+                line.clear();
+            }
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("vite-rs: could not forward dev server output: {}", e);
+    }
+}
+
+/// Like `forward`, for a dev server spawned with Tokio (see `crate::tokio_process`): spawns a task
+/// per captured stream.
+#[cfg(feature = "tokio-process")]
+pub(crate) fn forward_async(child: &mut tokio::process::Child, stdio: &DevServerStdio) {
+    if let Some(stdout) = child.stdout.take() {
+        tokio::spawn(read_async(stdout, stdio.clone(), Stream::Stdout));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        tokio::spawn(read_async(stderr, stdio.clone(), Stream::Stderr));
+    }
+}
+
+#[cfg(feature = "tokio-process")]
+async fn read_async(
+    output: impl tokio::io::AsyncRead + Unpin,
+    stdio: DevServerStdio,
+    stream: Stream,
+) {
+    use tokio::io::AsyncBufReadExt;
+
+    let mut reader = tokio::io::BufReader::new(output);
+    let mut line = Vec::new();
+
+    while let Ok(read) = reader.read_until(b'\n', &mut line).await {
+        if read == 0 {
+            break;
+        }
+
+        emit_line(&stdio, stream, &line);
+        line.clear();
+    }
+}
+
+/// Emits a line read from the dev server, including its line break.
+fn emit_line(stdio: &DevServerStdio, stream: Stream, line: &[u8]) {
+    let text = String::from_utf8_lossy(line);
+    emit(stdio, stream, text.trim_end_matches(['\r', '\n']));
+}
+
+fn emit(stdio: &DevServerStdio, stream: Stream, line: &str) {
+    if line.is_empty() {
+        return;
+    }
+
+    match stdio {
+        #[cfg(feature = "log")]
+        DevServerStdio::Log => match stream {
+            Stream::Stdout => log::info!(target: "vite", "{}", line),
+            Stream::Stderr => log::warn!(target: "vite", "{}", line),
+        },
+        #[cfg(feature = "tracing")]
+        DevServerStdio::Tracing => match stream {
+            Stream::Stdout => tracing::info!(target: "vite", "{}", line),
+            Stream::Stderr => tracing::warn!(target: "vite", "{}", line),
+        },
+        DevServerStdio::Prefixed(prefix) => match stream {
+            Stream::Stdout => println!("{} {}", prefix, line),
+            Stream::Stderr => eprintln!("{} {}", prefix, line),
+        },
+        _ => {}
+    }
+}
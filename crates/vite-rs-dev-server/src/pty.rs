@@ -0,0 +1,103 @@
+//! Runs the dev server with a pseudo-terminal as its stdout and stderr (see
+//! `DevServerOptions::with_pty`), so Vite and its plugins behave like they do in a terminal, e.g.
+//! with progress output and colors.
+//!
+//! The dev server is still spawned as the leader of its own process group (only its output goes
+//! to the pseudo-terminal), so it's stopped with the processes it starts, like without one.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::process::{Command, Stdio};
+
+use crate::DevServerStdio;
+
+/// Opens a pseudo-terminal and makes it the stdout and stderr of `command`. Returns its other
+/// end, which the output is read from (see `forward`).
+pub(crate) fn attach(command: &mut Command) -> std::io::Result<File> {
+    let (mut controller, mut terminal) = (-1, -1);
+    let mut size = window_size();
+
+    // SAFETY: `openpty` writes the two file descriptors, which we own from here on
+    let (controller, terminal) = unsafe {
+        if libc::openpty(
+            &mut controller,
+            &mut terminal,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::addr_of_mut!(size),
+        ) != 0
+        {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        (
+            OwnedFd::from_raw_fd(controller),
+            OwnedFd::from_raw_fd(terminal),
+        )
+    };
+
+    // only stdout and stderr are passed on: other dev server processes (e.g. after a restart)
+    // mustn't keep either end open
+    for fd in [&controller, &terminal] {
+        // SAFETY: a plain syscall on a descriptor we own
+        if unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+
+    command
+        .stdout(Stdio::from(terminal.try_clone()?))
+        .stderr(Stdio::from(terminal));
+
+    Ok(File::from(controller))
+}
+
+/// The size of this process' terminal, if it runs in one, so the dev server's output fits it.
+fn window_size() -> libc::winsize {
+    let mut size = libc::winsize {
+        ws_row: 24,
+        ws_col: 80,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+
+    // SAFETY: `TIOCGWINSZ` only writes the `winsize` it's given, and fails on non-terminals
+    let mut current = size;
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut current) } == 0
+        && current.ws_col > 0
+    {
+        size = current;
+    }
+
+    size
+}
+
+/// Spawns a thread which forwards the output written to the pseudo-terminal until the dev server
+/// exits: as-is with `DevServerStdio::Inherit` (so progress output and colors work), and line by
+/// line otherwise. Its stdout and stderr can't be told apart, so all lines count as stdout.
+pub(crate) fn forward(controller: File, stdio: &DevServerStdio) {
+    if *stdio != DevServerStdio::Inherit {
+        // discarded for `Null`, but still read, so the dev server doesn't block on a full buffer
+        crate::output::spawn_reader(controller, stdio.clone(), crate::output::Stream::Stdout);
+        return;
+    }
+
+    let spawned = std::thread::Builder::new()
+        .name("vite-rs-output".to_string())
+        .spawn(move || {
+            let mut controller = controller;
+            let mut buf = [0; 4096];
+
+            // reading fails (`EIO` on Linux) once the dev server and its processes exited
+            while let Ok(read @ 1..) = controller.read(&mut buf) {
+                let mut stdout = std::io::stdout().lock();
+                let _ = stdout.write_all(&buf[..read]);
+                let _ = stdout.flush();
+            }
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("vite-rs: could not forward dev server output: {}", e);
+    }
+}
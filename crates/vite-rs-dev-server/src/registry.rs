@@ -0,0 +1,496 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
+
+use command_group::GroupChild;
+
+use crate::options::OnEvent;
+use crate::{DevServerError, DevServerEvent, DevServerOptions};
+
+/// Keeps track of the ViteJS dev servers started by this process, keyed by port.
+///
+/// Several dev servers can run at once, e.g. for two `Assets` structs with different Vite roots.
+/// Starting a dev server replaces the one running on the same port or for the same root
+/// directory. Use [`DevServerRegistry::global`] to get the registry used by `start_dev_server`.
+#[derive(Default)]
+pub struct DevServerRegistry {
+    servers: Mutex<HashMap<u16, DevServerHandle>>,
+}
+
+/// A handle to a running ViteJS dev server. Unlike [`crate::ViteProcess`], dropping a
+/// handle does not stop the dev server.
+#[derive(Clone)]
+pub struct DevServerHandle {
+    port: u16,
+    url: String,
+    absolute_root_dir: String,
+    shutdown_grace_period: Duration,
+    inherit_stdin: bool,
+    on_event: Option<OnEvent>,
+    child: Arc<Mutex<GroupChild>>,
+    /// How many `ViteProcess` guards own the dev server (see `DevServerRegistry::release_guard`).
+    guards: Arc<AtomicUsize>,
+    /// Set while `restart` replaces the process, so the supervisor doesn't restart it as well.
+    restarting: Arc<AtomicBool>,
+    /// Held while the dev server runs, so other processes use it instead of starting their own.
+    _root_lock: Option<crate::lock::RootLock>,
+    /// What it was started with, to start it again (see `restart`).
+    options: DevServerOptions,
+}
+
+impl DevServerHandle {
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Where the dev server is reached, e.g. `http://localhost:21012` (see `DevServerOptions::origin`).
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The directory the dev server was started in (see `DevServerOptions::new`).
+    pub fn root_dir(&self) -> &str {
+        &self.absolute_root_dir
+    }
+
+    /// Whether the dev server process is still running. It may not be ready to serve requests yet.
+    pub fn is_running(&self) -> bool {
+        crate::is_running(&self.child)
+    }
+
+    /// Stops the dev server and removes it from the global registry. It's asked to shut down first,
+    /// and killed if it's still running after `DevServerOptions::shutdown_grace_period`.
+    pub fn stop(&self) -> std::io::Result<()> {
+        DevServerRegistry::global().stop(self.port).map(|_| ())
+    }
+
+    /// Stops the dev server like `stop` does, and starts it again with the same options, e.g. so
+    /// it picks up changes to the Vite config which Vite doesn't reload by itself. Its guards and
+    /// handles keep working, and it's still supervised (see `DevServerOptions::with_restart`).
+    ///
+    /// Waits until it's ready if `DevServerOptions::with_ready_timeout` is set. If it can't be
+    /// started again (or doesn't get ready), the error is returned and it stays stopped: it's
+    /// removed from the registry like `stop` does.
+    pub fn restart(&self) -> Result<(), DevServerError> {
+        self.restarting.store(true, Ordering::SeqCst);
+        let restarted = self.replace_process();
+        self.restarting.store(false, Ordering::SeqCst);
+
+        if restarted.is_err() {
+            DevServerRegistry::global().remove_child(&self.child);
+        }
+        restarted
+    }
+
+    /// Stops the process and starts a new one in its place (see `restart`). The lock isn't held
+    /// while events are emitted: their handlers may use the dev server.
+    fn replace_process(&self) -> Result<(), DevServerError> {
+        {
+            let mut child = self.child.lock().unwrap();
+            shut_down(&mut child, self.shutdown_grace_period).map_err(DevServerError::Stop)?;
+
+            #[cfg(unix)]
+            if self.inherit_stdin {
+                crate::terminal::take_back(child.id());
+            }
+        }
+        self.emit(DevServerEvent::Stopped);
+
+        let mut restarted = crate::spawn(&self.options)?;
+
+        // holding the lock, so a concurrent `stop` waits until it can stop the new process
+        let mut child = self.child.lock().unwrap();
+        if !DevServerRegistry::global().is_current(self.port, &self.child) {
+            // stopped while it was starting
+            drop(child);
+            let _ = restarted.kill();
+            let _ = restarted.wait();
+            return Ok(());
+        }
+        *child = restarted;
+        drop(child);
+
+        if let Some(timeout) = self.options.ready_timeout {
+            let ready =
+                crate::wait_until_ready(self.options.fetch_host(), self.port, &self.child, timeout);
+
+            if let Err(e) = ready {
+                let _ = shut_down(&mut self.child.lock().unwrap(), self.shutdown_grace_period);
+                self.stopped();
+                return Err(e);
+            }
+
+            self.options.emit(DevServerEvent::Ready {
+                url: self.url.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asks the dev server to shut down, waits for it to exit, and kills it if it's still running
+    /// after the grace period. The process is only locked to check on it, so e.g. `is_running`
+    /// doesn't wait for the grace period.
+    fn kill(&self) -> std::io::Result<()> {
+        let deadline = terminate_within(&mut self.lock_child(), self.shutdown_grace_period);
+        self.wait_or_kill(deadline)
+    }
+
+    /// Like `kill`, but only asks the dev server to shut down on the calling thread: waiting for it
+    /// to exit (and killing it after the grace period) happens on a new thread.
+    fn kill_in_background(self) {
+        let deadline = terminate_within(&mut self.lock_child(), self.shutdown_grace_period);
+
+        std::thread::spawn(move || {
+            if let Err(e) = self.wait_or_kill(deadline) {
+                eprintln!("vite-rs: could not stop the ViteJS dev server: {}", e);
+            }
+        });
+    }
+
+    /// Waits for the dev server to exit until `deadline`, and kills it if it's still running then.
+    fn wait_or_kill(&self, deadline: Instant) -> std::io::Result<()> {
+        while !self.exited() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        self.kill_now()?;
+        self.stopped();
+        Ok(())
+    }
+
+    /// Like `kill`, but sleeps asynchronously during the grace period instead of blocking the
+    /// thread.
+    #[cfg(feature = "async")]
+    pub(crate) async fn kill_async(&self) -> std::io::Result<()> {
+        let deadline = terminate_within(&mut self.lock_child(), self.shutdown_grace_period);
+
+        while !self.exited() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        self.kill_now()?;
+        self.stopped();
+        Ok(())
+    }
+
+    fn exited(&self) -> bool {
+        matches!(self.lock_child().try_wait(), Ok(Some(_)))
+    }
+
+    /// Kills the dev server if it's still running. It's reaped right away, so this doesn't block.
+    fn kill_now(&self) -> std::io::Result<()> {
+        let mut child = self.lock_child();
+        if let Ok(None) = child.try_wait() {
+            child.kill()?;
+            let _ = child.wait();
+        }
+        Ok(())
+    }
+
+    /// The dev server process, even if a thread panicked while holding it: it can still be stopped.
+    fn lock_child(&self) -> MutexGuard<'_, GroupChild> {
+        self.child.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn stopped(&self) {
+        // it may have had the terminal (see `DevServerOptions::with_inherit_stdin`)
+        #[cfg(unix)]
+        if self.inherit_stdin {
+            crate::terminal::take_back(self.lock_child().id());
+        }
+
+        self.emit(DevServerEvent::Stopped);
+    }
+
+    pub(crate) fn emit(&self, event: DevServerEvent) {
+        if let Some(on_event) = &self.on_event {
+            on_event.emit(event);
+        }
+    }
+}
+
+/// Asks the dev server to shut down, and kills it if it's still running after `grace_period`.
+fn shut_down(child: &mut GroupChild, grace_period: Duration) -> std::io::Result<()> {
+    let deadline = terminate_within(child, grace_period);
+    wait_or_kill(child, deadline)
+}
+
+/// Asks the dev server to shut down, and returns when to kill it: after `grace_period`, or right
+/// away if it can't be asked to.
+fn terminate_within(child: &mut GroupChild, grace_period: Duration) -> Instant {
+    let now = Instant::now();
+
+    // it may have exited on its own already (e.g. when Vite fails to start)
+    if let Ok(Some(_)) = child.try_wait() {
+        return now;
+    }
+
+    if !grace_period.is_zero() && terminate(child).is_ok() {
+        now + grace_period
+    } else {
+        now
+    }
+}
+
+/// Waits for the dev server to exit until `deadline`, and kills it if it's still running then.
+fn wait_or_kill(child: &mut GroupChild, deadline: Instant) -> std::io::Result<()> {
+    loop {
+        if let Ok(Some(_)) = child.try_wait() {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    child.kill()?;
+
+    // reap it, so it doesn't linger as a zombie (and `is_running` is accurate right away)
+    let _ = child.wait();
+    Ok(())
+}
+
+/// Asks the dev server to shut down: sends SIGTERM to its process group.
+#[cfg(unix)]
+fn terminate(child: &GroupChild) -> std::io::Result<()> {
+    use command_group::{Signal, UnixChildExt};
+
+    child.signal(Signal::SIGTERM)
+}
+
+/// Asks the dev server to shut down: sends CTRL_BREAK to its process group (it's spawned with
+/// `CREATE_NEW_PROCESS_GROUP`, see `crate::spawn`).
+#[cfg(windows)]
+fn terminate(child: &GroupChild) -> std::io::Result<()> {
+    ctrl_break(child.id())
+}
+
+/// Sends CTRL_BREAK to the process group `process_group_id`.
+#[cfg(windows)]
+pub(crate) fn ctrl_break(process_group_id: u32) -> std::io::Result<()> {
+    use windows_sys::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT};
+
+    match unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, process_group_id) } {
+        0 => Err(std::io::Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn terminate(_child: &GroupChild) -> std::io::Result<()> {
+    Err(std::io::ErrorKind::Unsupported.into())
+}
+
+impl DevServerRegistry {
+    pub fn global() -> &'static DevServerRegistry {
+        static REGISTRY: OnceLock<DevServerRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(DevServerRegistry::default)
+    }
+
+    /// Returns the dev server running on `port`, if it was started by this process.
+    pub fn get(&self, port: u16) -> Option<DevServerHandle> {
+        self.servers.lock().unwrap().get(&port).cloned()
+    }
+
+    /// Ports of all dev servers started by this process.
+    pub fn ports(&self) -> Vec<u16> {
+        self.servers.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Stops the dev server running on `port`. Returns `Ok(false)` if there was none.
+    pub fn stop(&self, port: u16) -> std::io::Result<bool> {
+        // release the lock before killing the process
+        let handle = self.servers.lock().unwrap().remove(&port);
+
+        match handle {
+            Some(handle) => handle.kill().map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Restarts the dev server running on `port` (see `DevServerHandle::restart`). Returns
+    /// `Ok(false)` if there was none.
+    pub fn restart(&self, port: u16) -> Result<bool, DevServerError> {
+        // release the lock before restarting the process
+        let handle = self.get(port);
+
+        match handle {
+            Some(handle) => handle.restart().map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Stops the dev server running `child`, if it's still registered. Returns `Ok(false)`
+    /// otherwise (e.g. it was stopped or replaced already).
+    pub(crate) fn stop_child(&self, child: &Arc<Mutex<GroupChild>>) -> std::io::Result<bool> {
+        match self.remove_child(child) {
+            Some(handle) => handle.kill().map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Counts another `ViteProcess` guard owning the dev server running `child`, if it's registered.
+    pub(crate) fn add_guard(&self, child: &Arc<Mutex<GroupChild>>) {
+        let servers = self.servers.lock().unwrap();
+
+        if let Some(handle) = servers
+            .values()
+            .find(|handle| Arc::ptr_eq(&handle.child, child))
+        {
+            handle.guards.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Stops the dev server running `child` when the last guard owning it is released. Returns
+    /// `false` if other guards still own it, or it isn't registered anymore (e.g. it was stopped
+    /// or replaced already), so a guard can't stop a dev server it doesn't own.
+    pub(crate) fn release_guard(&self, child: &Arc<Mutex<GroupChild>>) -> bool {
+        let handle = {
+            let mut servers = self.servers.lock().unwrap();
+            let Some((&port, handle)) = servers
+                .iter()
+                .find(|(_, handle)| Arc::ptr_eq(&handle.child, child))
+            else {
+                return false;
+            };
+
+            let remaining = handle
+                .guards
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |guards| {
+                    Some(guards.saturating_sub(1))
+                })
+                .unwrap()
+                .saturating_sub(1);
+            if remaining > 0 {
+                return false;
+            }
+
+            servers.remove(&port)
+        };
+
+        // the lock is released before killing the process
+        let Some(handle) = handle else {
+            return false;
+        };
+
+        // e.g. dropped by a task, which can't wait for it without blocking the runtime's thread
+        #[cfg(feature = "async")]
+        if tokio::runtime::Handle::try_current().is_ok() {
+            eprintln!("vite-rs: the ViteJS dev server's guard was dropped on an async runtime, so it's stopped in the background; use `ViteProcess::shutdown_async` to wait for it");
+        }
+
+        // dropping a guard doesn't wait for the grace period (see `ViteProcess::shutdown`)
+        handle.kill_in_background();
+        true
+    }
+
+    /// Removes the dev server running `child` from the registry, without stopping it. Its
+    /// supervisor (if any) stops restarting it.
+    pub(crate) fn remove_child(&self, child: &Arc<Mutex<GroupChild>>) -> Option<DevServerHandle> {
+        let mut servers = self.servers.lock().unwrap();
+        let port = servers
+            .iter()
+            .find(|(_, handle)| Arc::ptr_eq(&handle.child, child))
+            .map(|(port, _)| *port);
+
+        port.and_then(|port| servers.remove(&port))
+    }
+
+    /// Stops all dev servers started by this process. They're all asked to shut down first, so
+    /// this waits for the longest grace period at most (e.g. on Ctrl-C), not for their sum. If
+    /// some can't be stopped, the others still are, and the first error is returned.
+    pub fn stop_all(&self) -> std::io::Result<()> {
+        let handles = std::mem::take(&mut *self.servers.lock().unwrap());
+
+        let stopping = handles
+            .into_values()
+            .map(|handle| {
+                let deadline =
+                    terminate_within(&mut handle.lock_child(), handle.shutdown_grace_period);
+                (handle, deadline)
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Ok(());
+        for (handle, deadline) in stopping {
+            let stopped = handle.wait_or_kill(deadline);
+            result = result.and(stopped);
+        }
+        result
+    }
+
+    /// Whether the dev server running `child` on `port` is being restarted (see
+    /// `DevServerHandle::restart`), so it's expected to exit.
+    pub(crate) fn is_restarting(&self, port: u16, child: &Arc<Mutex<GroupChild>>) -> bool {
+        self.servers
+            .lock()
+            .unwrap()
+            .get(&port)
+            .is_some_and(|handle| {
+                Arc::ptr_eq(&handle.child, child) && handle.restarting.load(Ordering::SeqCst)
+            })
+    }
+
+    /// Whether `child` is still the dev server registered on `port` (i.e. it wasn't stopped or replaced).
+    pub(crate) fn is_current(&self, port: u16, child: &Arc<Mutex<GroupChild>>) -> bool {
+        self.servers
+            .lock()
+            .unwrap()
+            .get(&port)
+            .is_some_and(|handle| Arc::ptr_eq(&handle.child, child))
+    }
+
+    pub(crate) fn register(
+        &self,
+        options: &DevServerOptions,
+        child: Arc<Mutex<GroupChild>>,
+        root_lock: Option<crate::lock::RootLock>,
+    ) -> DevServerHandle {
+        let port = options.port;
+        let handle = DevServerHandle {
+            port,
+            url: options.origin(),
+            absolute_root_dir: options.absolute_root_dir.clone(),
+            shutdown_grace_period: options.shutdown_grace_period,
+            inherit_stdin: options.inherit_stdin,
+            on_event: options.on_event_handler(),
+            child,
+            guards: Arc::default(),
+            restarting: Arc::default(),
+            _root_lock: root_lock,
+            options: options.for_restart(),
+        };
+
+        // a dev server for the same root (e.g. started again by the same `Assets` struct) is
+        // replaced; dev servers for other roots keep running
+        let previous = {
+            let mut servers = self.servers.lock().unwrap();
+            let replaced = servers
+                .iter()
+                .filter(|(_, previous)| previous.absolute_root_dir == handle.absolute_root_dir)
+                .map(|(port, _)| *port)
+                .collect::<Vec<_>>();
+
+            let mut previous = replaced
+                .into_iter()
+                .filter_map(|port| servers.remove(&port))
+                .collect::<Vec<_>>();
+            previous.extend(servers.insert(port, handle.clone()));
+            previous
+        };
+
+        for previous in previous {
+            if let Err(e) = previous.kill() {
+                eprintln!(
+                    "vite-rs: could not stop the replaced ViteJS dev server: {}",
+                    e
+                );
+            }
+        }
+
+        handle
+    }
+}
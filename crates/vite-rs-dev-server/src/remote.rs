@@ -0,0 +1,87 @@
+//! Remote development environments (e.g. GitHub Codespaces), which forward the dev server's port
+//! to a public URL. The browser reaches the dev server (and its HMR websocket) through that URL,
+//! rather than `localhost`.
+
+/// A remote development environment, detected from its environment variables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RemoteEnvironment {
+    /// GitHub Codespaces: `https://<codespace>-<port>.<forwarding domain>`
+    Codespaces,
+    /// Gitpod: `https://<port>-<workspace host>`
+    Gitpod,
+}
+
+impl RemoteEnvironment {
+    /// The environment this process runs in, if any.
+    pub fn detect() -> Option<Self> {
+        if std::env::var("CODESPACES").is_ok_and(|codespaces| codespaces == "true") {
+            Some(RemoteEnvironment::Codespaces)
+        } else if std::env::var("GITPOD_WORKSPACE_URL").is_ok() {
+            Some(RemoteEnvironment::Gitpod)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RemoteEnvironment::Codespaces => "GitHub Codespaces",
+            RemoteEnvironment::Gitpod => "Gitpod",
+        }
+    }
+
+    /// The public URL `port` is forwarded to, e.g. `https://fuzzy-space-guide-21012.app.github.dev`.
+    pub fn forwarded_url(&self, port: u16) -> Option<String> {
+        match self {
+            RemoteEnvironment::Codespaces => {
+                let name = std::env::var("CODESPACE_NAME").ok()?;
+                let domain = std::env::var("GITHUB_CODESPACES_PORT_FORWARDING_DOMAIN").ok()?;
+
+                Some(format!("https://{}-{}.{}", name, port, domain))
+            }
+            RemoteEnvironment::Gitpod => {
+                let workspace_url = std::env::var("GITPOD_WORKSPACE_URL").ok()?;
+                let workspace_host = workspace_url
+                    .strip_prefix("https://")?
+                    .trim_end_matches('/');
+
+                Some(format!("https://{}-{}", port, workspace_host))
+            }
+        }
+    }
+}
+
+/// Environment variables for the dev server derived from its public origin (see
+/// `DevServerOptions::public_origin`), e.g. for `server.hmr` in the Vite config. With
+/// `allow_host`, its host is also added to Vite's `server.allowedHosts`.
+#[cfg(vite_rs_dev)]
+pub(crate) fn hmr_envs(public_origin: &str, allow_host: bool) -> Vec<(&'static str, String)> {
+    let (https, authority) = match public_origin.split_once("://") {
+        Some((scheme, authority)) => (scheme == "https", authority),
+        None => (false, public_origin),
+    };
+    let authority = authority.split('/').next().unwrap_or_default();
+
+    // `]` is part of an IPv6 address without a port, e.g. `[::1]`
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) if !port.contains(']') => (host, port.to_string()),
+        _ => (authority, if https { "443" } else { "80" }.to_string()),
+    };
+    let host = crate::util::unbracket(host);
+
+    let mut envs = vec![
+        (
+            "VITE_RS_HMR_PROTOCOL",
+            if https { "wss" } else { "ws" }.to_string(),
+        ),
+        ("VITE_RS_HMR_HOST", host.to_string()),
+        ("VITE_RS_HMR_CLIENT_PORT", port),
+    ];
+    if allow_host {
+        // Vite (since 6.0.9) rejects requests for hosts other than `localhost` by default
+        envs.push(("__VITE_ADDITIONAL_SERVER_ALLOWED_HOSTS", host.to_string()));
+    }
+
+    envs
+}
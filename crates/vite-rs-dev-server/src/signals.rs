@@ -0,0 +1,44 @@
+//! Stops the dev servers when this process is asked to terminate (see
+//! `DevServerOptions::with_signal_handler`).
+
+use std::sync::Mutex;
+
+use signal_hook::consts::{SIGHUP, SIGTERM};
+use signal_hook::iterator::Signals;
+
+use crate::DevServerRegistry;
+
+/// Installs the handler once per process. Unlike the `ctrlc` one, it doesn't replace handlers
+/// registered elsewhere (signal-hook chains them).
+pub(crate) fn install() -> std::io::Result<()> {
+    static INSTALLED: Mutex<bool> = Mutex::new(false);
+
+    let mut installed = INSTALLED.lock().unwrap();
+    if *installed {
+        return Ok(());
+    }
+
+    let mut signals = Signals::new([SIGTERM, SIGHUP])?;
+
+    std::thread::Builder::new()
+        .name("vite-rs-signals".to_string())
+        .spawn(move || {
+            if let Some(signal) = signals.forever().next() {
+                if let Err(e) = DevServerRegistry::global().stop_all() {
+                    eprintln!("vite-rs: could not stop the ViteJS dev servers: {}", e);
+                }
+
+                // exit like the signal's default action would, e.g. so `docker stop` sees SIGTERM
+                if let Err(e) = signal_hook::low_level::emulate_default_handler(signal) {
+                    eprintln!(
+                        "vite-rs: could not terminate after signal {}: {}",
+                        signal, e
+                    );
+                }
+                std::process::exit(128 + signal);
+            }
+        })?;
+
+    *installed = true;
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Restarts the dev server when it exits on its own (see `DevServerOptions::with_restart`), and
+//! reports its lifecycle events (see `DevServerOptions::on_event`).
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use command_group::GroupChild;
+
+use crate::{DevServerEvent, DevServerOptions, DevServerRegistry, DevServerRestart};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Watches `child` on a background thread until it is stopped (or replaced) through the registry,
+/// or it exits and isn't restarted. `ready` tells whether it responds already.
+///
+/// A restarted process is put into the same `Mutex`, so existing `ViteProcess` guards and
+/// `DevServerHandle`s keep working.
+pub(crate) fn supervise(options: DevServerOptions, ready: bool, child: Arc<Mutex<GroupChild>>) {
+    let spawned = std::thread::Builder::new()
+        .name("vite-rs-supervisor".to_string())
+        .spawn(move || run(&options, ready, &child));
+
+    if let Err(e) = spawned {
+        eprintln!("vite-rs: could not start the dev server supervisor: {}", e);
+    }
+}
+
+fn run(options: &DevServerOptions, mut ready: bool, child: &Arc<Mutex<GroupChild>>) {
+    let registry = DevServerRegistry::global();
+    let origin = options.origin();
+    let mut started = Instant::now();
+    let mut attempt = 0;
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        if !registry.is_current(options.port, child) {
+            return;
+        }
+
+        // not holding the lock while emitting events: their handlers may use the dev server
+        let exited = child.lock().unwrap().try_wait();
+        let status = match exited {
+            Ok(Some(status)) => status,
+            Ok(None) => {
+                // only polled when someone is told about it
+                if !ready && options.watches_ready() {
+                    ready = crate::is_responding(&format!("{}/", origin));
+                    if ready {
+                        options.emit(DevServerEvent::Ready {
+                            url: origin.clone(),
+                        });
+                    }
+                }
+                continue;
+            }
+            Err(_) => return,
+        };
+
+        // it was stopped by this process in the meantime
+        if !registry.is_current(options.port, child) {
+            return;
+        }
+        // `DevServerHandle::restart` starts it again
+        if registry.is_restarting(options.port, child) {
+            continue;
+        }
+        options.emit(DevServerEvent::Crashed { status });
+
+        let Some(restart) = &options.restart else {
+            return;
+        };
+
+        // it ran fine for a while, so this isn't a crash loop
+        if started.elapsed() > restart.max_backoff {
+            attempt = 0;
+        }
+        attempt += 1;
+
+        let backoff = restart.backoff(attempt);
+        restart.notify(&DevServerRestart {
+            port: options.port,
+            status,
+            attempt,
+            backoff,
+        });
+        options.emit(DevServerEvent::Restarting { attempt, backoff });
+        std::thread::sleep(backoff);
+
+        if !registry.is_current(options.port, child) {
+            return;
+        }
+
+        // not holding the lock: `spawn` emits `Starting`, whose handlers may use the dev server
+        let mut restarted = match crate::spawn(options) {
+            Ok(restarted) => restarted,
+            // try again after the next backoff; the exited process is still in place
+            Err(e) => {
+                eprintln!("vite-rs: could not restart the ViteJS dev server: {}", e);
+                started = Instant::now();
+                continue;
+            }
+        };
+
+        // holding the lock, so a concurrent `stop` waits until it can kill the new process
+        let mut current = child.lock().unwrap();
+        if !registry.is_current(options.port, child) {
+            // stopped while it was starting
+            drop(current);
+            let _ = restarted.kill();
+            let _ = restarted.wait();
+            return;
+        }
+
+        *current = restarted;
+        ready = false;
+        started = Instant::now();
+    }
+}
@@ -0,0 +1,62 @@
+//! Hands the terminal to a dev server that reads stdin (see `DevServerOptions::with_inherit_stdin`).
+//!
+//! The dev server runs in its own process group, so it can be stopped with its child processes.
+//! A background process group reading from the terminal is stopped (SIGTTIN), so the dev server's
+//! group is made the terminal's foreground group while it runs, and this process' group gets it
+//! back when it's stopped.
+
+use std::io::IsTerminal;
+use std::os::unix::process::CommandExt;
+
+/// Makes the process spawned by `command` (the leader of a new process group) take over the
+/// terminal, if stdin is one.
+pub(crate) fn hand_over(command: &mut std::process::Command) {
+    if !std::io::stdin().is_terminal() {
+        return;
+    }
+
+    // SAFETY: only async-signal-safe functions are called between fork and exec
+    unsafe {
+        command.pre_exec(|| {
+            // runs after the child joined its new process group
+            set_foreground(libc::getpgrp())
+        });
+    }
+}
+
+/// Gives the terminal back to this process' group, if the dev server with process group `pgid`
+/// had it (or it was left to a process group that's gone).
+pub(crate) fn take_back(pgid: u32) {
+    if !std::io::stdin().is_terminal() {
+        return;
+    }
+
+    // SAFETY: plain syscalls on stdin and process groups
+    unsafe {
+        let foreground = libc::tcgetpgrp(libc::STDIN_FILENO);
+        let own = libc::getpgrp();
+
+        let abandoned = foreground <= 0 || libc::kill(-foreground, 0) != 0;
+        if foreground != own && (foreground as u32 == pgid || abandoned) {
+            let _ = set_foreground(own);
+        }
+    }
+}
+
+/// Makes `pgid` the terminal's foreground process group. Calling `tcsetpgrp` from a background
+/// process group raises SIGTTOU (stopping the process), unless it's blocked.
+unsafe fn set_foreground(pgid: libc::pid_t) -> std::io::Result<()> {
+    let mut ttou = std::mem::zeroed::<libc::sigset_t>();
+    let mut previous = std::mem::zeroed::<libc::sigset_t>();
+    libc::sigemptyset(&mut ttou);
+    libc::sigaddset(&mut ttou, libc::SIGTTOU);
+    libc::pthread_sigmask(libc::SIG_BLOCK, &ttou, &mut previous);
+
+    let result = match libc::tcsetpgrp(libc::STDIN_FILENO, pgid) {
+        0 => Ok(()),
+        _ => Err(std::io::Error::last_os_error()),
+    };
+
+    libc::pthread_sigmask(libc::SIG_SETMASK, &previous, std::ptr::null_mut());
+    result
+}
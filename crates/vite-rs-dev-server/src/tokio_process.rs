@@ -0,0 +1,309 @@
+//! Runs the dev server as a `tokio::process::Child` (the `tokio-process` feature), for async
+//! servers: it's watched by a task rather than a thread, nothing blocks the runtime while it
+//! starts or stops, and it's killed when the runtime shuts down. See `start`.
+
+use std::process::ExitStatus;
+use std::time::{Duration, Instant};
+
+use tokio::process::Child;
+use tokio::sync::{oneshot, watch};
+
+use crate::{util, DevServerError, DevServerEvent, DevServerOptions};
+
+/// Owns the dev server started by `start`, and stops it when dropped: it's asked to shut down
+/// first (see `DevServerOptions::shutdown_grace_period`), by a task of the runtime it was started
+/// on. When that runtime shuts down, the dev server is killed right away.
+///
+/// Unlike `ViteProcess`, it isn't registered in the `DevServerRegistry`, so `stop_dev_server` and
+/// `is_dev_server_running` don't know about it.
+pub struct TokioViteProcess {
+    pid: u32,
+    url: String,
+    shutdown: Option<oneshot::Sender<()>>,
+    /// The exit status, once the dev server exited.
+    exited: watch::Receiver<Option<ExitStatus>>,
+}
+
+impl TokioViteProcess {
+    /// The process ID of the dev server (the leader of its process group).
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Where the dev server is reached, e.g. `http://localhost:21012`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Whether the dev server process is still running. It may not be ready to serve requests yet.
+    pub fn is_running(&self) -> bool {
+        self.exited.borrow().is_none()
+    }
+
+    /// Waits until the dev server exits (e.g. it crashed) and returns its exit status. `None` if
+    /// it wasn't waited for, e.g. because the runtime is shutting down.
+    pub async fn wait(&mut self) -> Option<ExitStatus> {
+        match self.exited.wait_for(Option::is_some).await {
+            Ok(status) => *status,
+            Err(_) => None,
+        }
+    }
+
+    /// Stops the dev server, like dropping the guard would, and waits until it exited.
+    pub async fn shutdown(mut self) -> Option<ExitStatus> {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+
+        self.wait().await
+    }
+}
+
+impl Drop for TokioViteProcess {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// Like `try_start_dev_server_with`, but spawns the dev server with `tokio::process` and waits
+/// until it's ready (see `DevServerOptions::with_ready_timeout`) without blocking the runtime.
+/// Must be called from within a Tokio runtime.
+///
+/// ```ignore
+/// #[tokio::main]
+/// async fn main() {
+///     #[cfg(debug_assertions)]
+///     let _dev_server = vite_rs_dev_server::tokio_process::start(Assets::dev_server_options())
+///         .await
+///         .unwrap();
+///
+///     // ...
+/// }
+/// ```
+///
+/// The process-wide parts of `DevServerOptions` are left to the async server: the Ctrl-C and
+/// signal handlers aren't registered, and there's no process lock (`with_process_lock`) or
+/// restart policy (`with_restart`). Returns `None` if `VITE_RS_NO_DEV_SERVER` is set.
+pub async fn start(options: DevServerOptions) -> Result<Option<TokioViteProcess>, DevServerError> {
+    if crate::dev_server_disabled() {
+        crate::note_dev_server_disabled();
+        return Ok(None);
+    }
+
+    let port = options.port;
+    let deadline = Instant::now() + options.port_wait;
+    while !util::is_port_free(port) {
+        // e.g. the dev server of a previous run is still exiting (see `with_port_wait`)
+        if Instant::now() >= deadline {
+            return Err(DevServerError::PortUnavailable { port });
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let child = spawn(&options)?;
+    let pid = child.id().unwrap_or_default();
+
+    let (shutdown, shutdown_requested) = oneshot::channel();
+    let (exit_status, exited) = watch::channel(None);
+    tokio::spawn(watch_child(
+        options.clone(),
+        child,
+        pid,
+        shutdown_requested,
+        exit_status,
+    ));
+
+    let mut process = TokioViteProcess {
+        pid,
+        url: options.origin(),
+        shutdown: Some(shutdown),
+        exited,
+    };
+
+    if let Some(timeout) = options.ready_timeout {
+        if let Err(e) = wait_until_ready(&mut process, timeout).await {
+            process.shutdown().await;
+
+            return match e {
+                // with `--strictPort`, Vite exits if the port was taken after our check
+                DevServerError::Exited(_) if !util::is_port_free(port) => {
+                    Err(DevServerError::PortUnavailable { port })
+                }
+                e => Err(e),
+            };
+        }
+
+        options.emit(DevServerEvent::Ready {
+            url: options.origin(),
+        });
+    }
+
+    Ok(Some(process))
+}
+
+fn spawn(options: &DevServerOptions) -> Result<Child, DevServerError> {
+    options.emit(DevServerEvent::Starting { port: options.port });
+
+    let npx_path = crate::command::npx_path_from_env().or_else(|| options.npx_path.clone());
+    let mut command = crate::vite_command(options, npx_path.as_deref());
+
+    #[cfg(unix)]
+    let pty = match options.pty {
+        true => Some(crate::pty::attach(&mut command).map_err(DevServerError::Spawn)?),
+        false => None,
+    };
+
+    // in its own process group, so it can be stopped with the processes it starts
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+    #[cfg(windows)]
+    std::os::windows::process::CommandExt::creation_flags(
+        &mut command,
+        windows_sys::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP,
+    );
+
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = tokio::process::Command::from(command)
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| crate::spawn_error(options, &program, npx_path.as_deref(), e))?;
+
+    crate::output::forward_async(&mut child, &options.stdio);
+    #[cfg(unix)]
+    if let Some(pty) = pty {
+        crate::pty::forward(pty, &options.stdio);
+    }
+
+    Ok(child)
+}
+
+/// Waits until the dev server exits or is asked to shut down, and reports its exit status. If the
+/// task is dropped instead (when the runtime shuts down), the dev server is killed.
+async fn watch_child(
+    options: DevServerOptions,
+    mut child: Child,
+    pid: u32,
+    shutdown_requested: oneshot::Receiver<()>,
+    exit_status: watch::Sender<Option<ExitStatus>>,
+) {
+    let mut group = ProcessGroup { pid, alive: true };
+
+    let (status, stopped) = tokio::select! {
+        status = child.wait() => (status, false),
+        _ = shutdown_requested => (stop(&mut child, &mut group, options.shutdown_grace_period).await, true),
+    };
+    // whatever Vite started and left behind
+    group.kill();
+
+    // it may have had the terminal (see `DevServerOptions::with_inherit_stdin`)
+    #[cfg(unix)]
+    if options.inherit_stdin {
+        crate::terminal::take_back(pid);
+    }
+
+    // reported before the exit status, so `TokioViteProcess::shutdown` returns after it
+    if stopped {
+        options.emit(DevServerEvent::Stopped);
+    } else if let Ok(status) = status {
+        options.emit(DevServerEvent::Crashed { status });
+    }
+    if let Ok(status) = status {
+        exit_status.send_replace(Some(status));
+    }
+}
+
+/// Asks the dev server to shut down, and kills it if it's still running after `grace_period`.
+async fn stop(
+    child: &mut Child,
+    group: &mut ProcessGroup,
+    grace_period: Duration,
+) -> std::io::Result<ExitStatus> {
+    if !grace_period.is_zero() && group.terminate().is_ok() {
+        if let Ok(status) = tokio::time::timeout(grace_period, child.wait()).await {
+            return status;
+        }
+    }
+
+    group.kill();
+    let _ = child.start_kill();
+    child.wait().await
+}
+
+/// Polls the dev server until it responds to an HTTP request (with any status).
+async fn wait_until_ready(
+    process: &mut TokioViteProcess,
+    timeout: Duration,
+) -> Result<(), DevServerError> {
+    let url = format!("{}/", process.url);
+    let deadline = Instant::now() + timeout;
+    let exited = |process: &TokioViteProcess| *process.exited.borrow();
+
+    loop {
+        if let Some(status) = exited(process) {
+            return Err(DevServerError::Exited(status));
+        }
+
+        let ready = crate::transport::get_async(&url, &Default::default())
+            .await
+            .is_ok();
+
+        // whatever answered, it wasn't our dev server if that already exited
+        if ready {
+            return match exited(process) {
+                Some(status) => Err(DevServerError::Exited(status)),
+                None => Ok(()),
+            };
+        }
+
+        if Instant::now() >= deadline {
+            return Err(DevServerError::NotReady { timeout });
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+/// The dev server's process group, killed when dropped (e.g. with the task watching it). On
+/// Windows, only the dev server process itself is killed (by `kill_on_drop`).
+struct ProcessGroup {
+    pid: u32,
+    alive: bool,
+}
+
+impl ProcessGroup {
+    /// Asks the processes to shut down: SIGTERM, or CTRL_BREAK on Windows.
+    fn terminate(&self) -> std::io::Result<()> {
+        #[cfg(unix)]
+        return signal_group(self.pid, libc::SIGTERM);
+
+        #[cfg(windows)]
+        return crate::registry::ctrl_break(self.pid);
+
+        #[cfg(not(any(unix, windows)))]
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+
+    fn kill(&mut self) {
+        if std::mem::take(&mut self.alive) {
+            #[cfg(unix)]
+            let _ = signal_group(self.pid, libc::SIGKILL);
+        }
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+#[cfg(unix)]
+fn signal_group(pgid: u32, signal: libc::c_int) -> std::io::Result<()> {
+    // SAFETY: a plain syscall
+    match unsafe { libc::killpg(pgid as libc::pid_t, signal) } {
+        0 => Ok(()),
+        _ => Err(std::io::Error::last_os_error()),
+    }
+}
@@ -0,0 +1,183 @@
+//! Remembers dev server responses by path and ETag (see `ResponseCache`).
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use super::DevResponse;
+
+/// Remembers the dev server's responses (by path, with their ETag), so an unchanged file is sent
+/// with `If-None-Match` and not transferred again when the dev server answers `304 Not Modified`.
+/// Share a cache between requests by putting it in their `FetchOptions` (see
+/// `FetchOptions::with_cache`).
+///
+/// A persistent cache also keeps the responses in a directory (one file each), so they survive
+/// restarting the Rust binary during development, e.g. with `#[dev_response_cache]`:
+///
+/// ```ignore
+/// let cache = ResponseCache::persistent("target/vite-rs-cache");
+/// let options = FetchOptions::default().with_cache(Arc::new(cache));
+/// ```
+///
+/// The cache is best-effort: files which can't be read or written are skipped. Only responses
+/// with an ETag are cached.
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    dir: Option<PathBuf>,
+    entries: Mutex<HashMap<String, DevResponse>>,
+}
+
+impl ResponseCache {
+    /// Keeps the responses in memory only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the responses in `dir` too (created when needed), and reads the ones cached there
+    /// by previous runs.
+    pub fn persistent(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Where the responses are kept, if the cache is persistent.
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Forgets all responses, and removes the cache directory (if any).
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+
+        if let Some(dir) = &self.dir {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+
+    /// The response cached for `path` (the part of the URL after the origin), if any.
+    pub(crate) fn get(&self, path: &str) -> Option<DevResponse> {
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some(res) = entries.get(path) {
+            return Some(res.clone());
+        }
+
+        let res = read(&self.file(path)?, path)?;
+        entries.insert(path.to_string(), res.clone());
+        Some(res)
+    }
+
+    /// Caches `res` for `path` if it's a successful response with an ETag.
+    pub(crate) fn insert(&self, path: &str, res: &DevResponse) {
+        if res.status != 200 || res.etag.is_none() {
+            return;
+        }
+
+        if let Some(file) = self.file(path) {
+            let _ = write(&file, path, res);
+        }
+
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), res.clone());
+    }
+
+    fn file(&self, path: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        Some(dir.join(format!("{:016x}", crate::util::stable_hash(path))))
+    }
+}
+
+impl PartialEq for ResponseCache {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for ResponseCache {}
+
+/// The part of `url` after the origin, e.g. `/src/main.ts?v=1` for
+/// `http://localhost:21012/src/main.ts?v=1`, so responses are found again when the dev server
+/// runs on another port.
+pub(crate) fn cache_key(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+
+    match rest.find('/') {
+        Some(index) => &rest[index..],
+        None => "/",
+    }
+}
+
+/// Writes the response: the path, the ETag and the headers on a line each, an empty line, then the body.
+fn write(file: &Path, path: &str, res: &DevResponse) -> std::io::Result<()> {
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut contents = Vec::with_capacity(res.bytes.len() + 256);
+    writeln!(contents, "{}", path)?;
+    writeln!(contents, "{}", res.etag.as_deref().unwrap_or_default())?;
+    for (name, value) in &res.headers {
+        writeln!(contents, "{}: {}", name, value)?;
+    }
+    writeln!(contents)?;
+    contents.extend_from_slice(&res.bytes);
+
+    // written in one go under another name, so a concurrent reader never sees half a file; the
+    // name is unique to this write, as other threads and processes may write the same file
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+    let partial = file.with_extension(format!(
+        "{}-{}.tmp",
+        std::process::id(),
+        WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&partial, contents)?;
+    std::fs::rename(&partial, file)
+}
+
+fn read(file: &Path, path: &str) -> Option<DevResponse> {
+    let mut reader = BufReader::new(std::fs::File::open(file).ok()?);
+    let mut read_line = || {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        line.strip_suffix('\n').map(str::to_string)
+    };
+
+    // another path with the same hash
+    if read_line()? != path {
+        return None;
+    }
+
+    let etag = read_line()?;
+    let mut headers = Vec::new();
+    loop {
+        let line = read_line()?;
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(": ")?;
+        headers.push((name.to_string(), value.to_string()));
+    }
+
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).ok()?;
+
+    let content_type = headers
+        .iter()
+        .find(|(name, _)| name == "content-type")
+        .map(|(_, value)| value.clone());
+
+    Some(DevResponse {
+        status: 200,
+        content_type,
+        content_length: Some(bytes.len() as u64),
+        etag: Some(etag),
+        headers,
+        bytes,
+    })
+}
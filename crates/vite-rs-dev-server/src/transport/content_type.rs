@@ -0,0 +1,95 @@
+//! Compares the content types the dev server sends with the ones expected from the paths (see
+//! `check_content_type`), e.g. for `#[dev_content_type_check = "warn"]`.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+const JAVASCRIPT: &str = "text/javascript";
+
+/// Served as JavaScript modules by Vite, whatever their source language.
+const MODULE_EXTENSIONS: &[&str] = &[
+    "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx", "vue", "svelte",
+];
+
+/// Query parameters Vite answers with a JavaScript module, whatever the file, e.g.
+/// `logo.svg?url` or `style.css?import`.
+const MODULE_QUERIES: &[&str] = &["import", "url", "raw", "inline", "worker", "sharedworker"];
+
+/// The content type the dev server should send for `path` (e.g. `src/main.ts`), or `None` if
+/// it's unknown or anything goes, such as Vite's own modules (`@vite/client`, `@fs/...`).
+/// JavaScript is `text/javascript`, though any JavaScript content type is accepted.
+pub fn expected_content_type(path: &str) -> Option<&'static str> {
+    let path = path.trim_start_matches('/');
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+
+    if path.starts_with('@') {
+        return None;
+    }
+
+    if query
+        .split('&')
+        .any(|param| MODULE_QUERIES.contains(&param.split('=').next().unwrap_or_default()))
+    {
+        return Some(JAVASCRIPT);
+    }
+
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    let extension = match file_name.rsplit_once('.') {
+        Some((_, extension)) => extension.to_ascii_lowercase(),
+        // e.g. a client-side route, which the dev server answers with `index.html`
+        None => return Some("text/html"),
+    };
+
+    if MODULE_EXTENSIONS.contains(&extension.as_str()) {
+        return Some(JAVASCRIPT);
+    }
+
+    mime_guess::from_ext(&extension).first_raw()
+}
+
+/// Whether `content_type` (as sent by the dev server for `path`) is the expected one, ignoring
+/// parameters like `charset`. Otherwise, this prints a warning (once per path and content type):
+/// a mismatch is usually the first sign of a misconfigured plugin, e.g. one answering a missing
+/// module with `index.html`.
+pub fn check_content_type(path: &str, content_type: &str) -> bool {
+    let Some(expected) = expected_content_type(path) else {
+        return true;
+    };
+
+    let actual = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    if actual == expected || (expected == JAVASCRIPT && is_javascript(&actual)) {
+        return true;
+    }
+
+    static WARNED: OnceLock<Mutex<HashSet<(String, String)>>> = OnceLock::new();
+    let first_time = WARNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap()
+        .insert((path.to_string(), actual));
+
+    if first_time {
+        eprintln!(
+            "vite-rs: the ViteJS dev server sent '{}' as {}, expected {} (is a Vite plugin misconfigured?)",
+            path, content_type, expected
+        );
+    }
+
+    false
+}
+
+fn is_javascript(content_type: &str) -> bool {
+    matches!(
+        content_type,
+        "text/javascript"
+            | "application/javascript"
+            | "application/x-javascript"
+            | "text/ecmascript"
+            | "application/ecmascript"
+    )
+}
@@ -0,0 +1,66 @@
+//! Filters dev server response headers down to the ones which are safe to forward to clients.
+
+/// Headers which only apply to a single connection and must never be forwarded (RFC 9110, section 7.6.1).
+const HOP_BY_HOP: &[&str] = &[
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Returns the headers which can be forwarded as-is, with lowercase names.
+///
+/// Hop-by-hop headers (including any listed in the `Connection` header) are removed, as are
+/// headers with an invalid name or a value containing control characters, which could
+/// otherwise be used to split or smuggle responses.
+pub(crate) fn forwardable<I>(headers: I) -> Vec<(String, String)>
+where
+    I: IntoIterator<Item = (String, Vec<u8>)>,
+{
+    let headers = headers
+        .into_iter()
+        .filter_map(|(name, value)| {
+            if !is_valid_name(&name) || !is_valid_value(&value) {
+                return None;
+            }
+
+            let value = String::from_utf8(value).ok()?;
+
+            Some((name.to_ascii_lowercase(), value.trim().to_string()))
+        })
+        .collect::<Vec<_>>();
+
+    let connection_options = headers
+        .iter()
+        .filter(|(name, _)| name == "connection")
+        .flat_map(|(_, value)| value.split(','))
+        .map(|option| option.trim().to_ascii_lowercase())
+        .collect::<Vec<_>>();
+
+    headers
+        .into_iter()
+        .filter(|(name, _)| {
+            !HOP_BY_HOP.contains(&name.as_str()) && !connection_options.contains(name)
+        })
+        .collect()
+}
+
+/// A header name must be a non-empty token (RFC 9110, section 5.6.2).
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+/// A header value may contain visible characters, spaces and tabs, but no other control characters.
+fn is_valid_value(value: &[u8]) -> bool {
+    value
+        .iter()
+        .all(|&b| b == b'\t' || (b >= 0x20 && b != 0x7f))
+}
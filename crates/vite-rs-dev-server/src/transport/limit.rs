@@ -0,0 +1,156 @@
+//! Limits how many requests are sent to the dev server at once (see `FetchLimit`).
+
+use std::sync::{Condvar, Mutex};
+
+/// Limits how many requests are sent to the dev server at once; further requests wait for one to
+/// finish. A large page can otherwise open hundreds of requests at once on its first load, which
+/// slows down (or overwhelms) the Node.js process. Share a limit between requests by putting it
+/// in their `FetchOptions` (see `FetchOptions::with_concurrency_limit`).
+///
+/// The counters can be used for metrics, e.g. to tune the limit:
+///
+/// ```ignore
+/// if let Some(limit) = Assets::dev_fetch_options().concurrency_limit {
+///     println!("{} in flight, {} queued (at most {})", limit.in_flight(), limit.queued(), limit.max_queued());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FetchLimit {
+    max_concurrent: usize,
+    state: Mutex<State>,
+    released: Condvar,
+    #[cfg(feature = "async")]
+    released_async: tokio::sync::Notify,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    in_flight: usize,
+    queued: usize,
+    max_queued: usize,
+}
+
+/// Held while a request is sent; lets the next queued one go when dropped.
+pub(crate) struct FetchPermit<'a> {
+    limit: &'a FetchLimit,
+}
+
+impl FetchLimit {
+    /// Allows `max_concurrent` requests at once (at least one).
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            state: Mutex::new(State::default()),
+            released: Condvar::new(),
+            #[cfg(feature = "async")]
+            released_async: tokio::sync::Notify::new(),
+        }
+    }
+
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Requests being sent right now.
+    pub fn in_flight(&self) -> usize {
+        self.state.lock().unwrap().in_flight
+    }
+
+    /// Requests waiting for one of the `in_flight` ones to finish.
+    pub fn queued(&self) -> usize {
+        self.state.lock().unwrap().queued
+    }
+
+    /// The most requests that were ever `queued` at once.
+    pub fn max_queued(&self) -> usize {
+        self.state.lock().unwrap().max_queued
+    }
+
+    /// Blocks until a request may be sent.
+    pub(crate) fn acquire(&self) -> FetchPermit<'_> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.in_flight >= self.max_concurrent {
+            state.enqueue();
+            while state.in_flight >= self.max_concurrent {
+                state = self.released.wait(state).unwrap();
+            }
+            state.queued -= 1;
+        }
+
+        state.in_flight += 1;
+        FetchPermit { limit: self }
+    }
+
+    /// Waits (without blocking the runtime) until a request may be sent. A request cancelled
+    /// while waiting (i.e. the future is dropped) is no longer counted as queued.
+    #[cfg(feature = "async")]
+    pub(crate) async fn acquire_async(&self) -> FetchPermit<'_> {
+        let mut queued = None;
+
+        loop {
+            // registered before checking, so a release in between isn't missed
+            let released = self.released_async.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.in_flight < self.max_concurrent {
+                    state.in_flight += 1;
+                    drop(state);
+
+                    drop(queued);
+                    return FetchPermit { limit: self };
+                }
+                if queued.is_none() {
+                    state.enqueue();
+                    queued = Some(Queued { limit: self });
+                }
+            }
+
+            released.await;
+        }
+    }
+}
+
+/// Counts a request waiting in `acquire_async` as queued until it's dropped: when it may be sent,
+/// or when it's cancelled.
+#[cfg(feature = "async")]
+struct Queued<'a> {
+    limit: &'a FetchLimit,
+}
+
+#[cfg(feature = "async")]
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        self.limit.state.lock().unwrap().queued -= 1;
+    }
+}
+
+impl State {
+    fn enqueue(&mut self) {
+        self.queued += 1;
+        self.max_queued = self.max_queued.max(self.queued);
+    }
+}
+
+impl Drop for FetchPermit<'_> {
+    fn drop(&mut self) {
+        self.limit.state.lock().unwrap().in_flight -= 1;
+
+        // either kind of waiter may be next; the one that doesn't get to go waits again
+        self.limit.released.notify_one();
+        #[cfg(feature = "async")]
+        self.limit.released_async.notify_one();
+    }
+}
+
+/// Limits are compared by identity: two options are equal if they share the same limit.
+impl PartialEq for FetchLimit {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for FetchLimit {}
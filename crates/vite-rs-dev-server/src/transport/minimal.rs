@@ -0,0 +1,162 @@
+//! A deliberately small HTTP/1.1 client. It only needs to talk to the local ViteJS dev
+//! server, so it supports plain `http://` GET requests and nothing more.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use super::{DevResponse, FetchError};
+
+pub fn get(
+    url: &str,
+    timeout: Option<Duration>,
+    if_none_match: Option<&str>,
+) -> Result<DevResponse, FetchError> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| FetchError::new(format!("unsupported URL (expected http://): {}", url)))?;
+
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+
+    let io_err = |e: std::io::Error| FetchError::new(format!("{} ({})", e, url));
+
+    let mut stream =
+        connect(authority, timeout).map_err(|e| FetchError::connect(format!("{} ({})", e, url)))?;
+    stream.set_read_timeout(timeout).map_err(io_err)?;
+    stream.set_write_timeout(timeout).map_err(io_err)?;
+    let if_none_match = if_none_match
+        .map(|etag| format!("If-None-Match: {}\r\n", etag))
+        .unwrap_or_default();
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: {}\r\nAccept: */*\r\n{}Connection: close\r\n\r\n",
+        path, authority, if_none_match
+    )
+    .map_err(io_err)?;
+
+    let mut reader = BufReader::new(stream);
+
+    let status_line =
+        String::from_utf8_lossy(&read_line(&mut reader).map_err(io_err)?).into_owned();
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| FetchError::new(format!("invalid status line: {:?}", status_line)))?;
+
+    let mut content_type = None;
+    let mut content_length = None;
+    let mut etag = None;
+    let mut chunked = false;
+    let mut headers = Vec::new();
+
+    loop {
+        // header values aren't necessarily UTF-8, so we read raw bytes
+        let line = read_line(&mut reader).map_err(io_err)?;
+        if line.is_empty() {
+            break;
+        }
+
+        let Some(colon) = line.iter().position(|&b| b == b':') else {
+            return Err(FetchError::new(format!(
+                "invalid header line: {:?}",
+                String::from_utf8_lossy(&line)
+            )));
+        };
+        let name = String::from_utf8_lossy(&line[..colon]).into_owned();
+        let raw_value = line[colon + 1..].to_vec();
+        let value = String::from_utf8_lossy(&raw_value);
+        let value = value.trim();
+
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-type" => content_type = Some(value.to_string()),
+            "content-length" => content_length = value.parse::<u64>().ok(),
+            "etag" => etag = Some(value.to_string()),
+            "transfer-encoding" => chunked = value.eq_ignore_ascii_case("chunked"),
+            _ => {}
+        }
+
+        headers.push((name, raw_value));
+    }
+
+    let bytes = if status == 204 || status == 304 {
+        // never have a body, even with a `Content-Length` (of the unchanged file, for a 304)
+        Vec::new()
+    } else if chunked {
+        read_chunked(&mut reader).map_err(io_err)?
+    } else if let Some(length) = content_length {
+        let mut bytes = vec![0; length as usize];
+        reader.read_exact(&mut bytes).map_err(io_err)?;
+        bytes
+    } else {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(io_err)?;
+        bytes
+    };
+
+    Ok(DevResponse {
+        status,
+        content_type,
+        content_length,
+        etag,
+        headers: super::headers::forwardable(headers),
+        bytes,
+    })
+}
+
+/// Connects to the first address `authority` resolves to that accepts the connection.
+fn connect(authority: &str, timeout: Option<Duration>) -> std::io::Result<TcpStream> {
+    let Some(timeout) = timeout else {
+        return TcpStream::connect(authority);
+    };
+
+    let mut last_err = None;
+    for addr in authority.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_err = Some(e),
+        }
+    }
+
+    Err(last_err.unwrap_or_else(|| std::io::ErrorKind::AddrNotAvailable.into()))
+}
+
+/// Reads a CRLF-terminated line, without the line ending.
+fn read_line(reader: &mut impl BufRead) -> std::io::Result<Vec<u8>> {
+    let mut line = Vec::new();
+    if reader.read_until(b'\n', &mut line)? == 0 {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+
+    while line.last().is_some_and(|&b| b == b'\n' || b == b'\r') {
+        line.pop();
+    }
+
+    Ok(line)
+}
+
+fn read_chunked(reader: &mut impl BufRead) -> std::io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    loop {
+        let size_line = String::from_utf8_lossy(&read_line(reader)?).into_owned();
+        // chunk extensions (`1a;name=value`) are allowed but meaningless to us
+        let size = size_line.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "bad chunk size"))?;
+
+        if size == 0 {
+            // skip trailers
+            while !read_line(reader)?.is_empty() {}
+            return Ok(bytes);
+        }
+
+        let start = bytes.len();
+        bytes.resize(start + size, 0);
+        reader.read_exact(&mut bytes[start..])?;
+        read_line(reader)?; // CRLF after the chunk data
+    }
+}
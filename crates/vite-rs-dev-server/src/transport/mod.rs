@@ -0,0 +1,308 @@
+//! HTTP client used by derived code to fetch assets from the ViteJS dev server.
+//!
+//! By default, this uses `reqwest`. With the `minimal` feature (or when the `reqwest`
+//! feature is disabled), a small built-in HTTP/1.1 client is used instead, which keeps
+//! `reqwest` and its dependency tree out of development builds.
+//!
+//! With the `async` feature, `get_async` fetches without blocking (it needs a Tokio runtime):
+//! with `reqwest`'s async client, or with the built-in client on Tokio's blocking threads, so the
+//! `minimal` feature still keeps `reqwest` out.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+mod cache;
+mod content_type;
+mod headers;
+mod limit;
+#[cfg(not(all(feature = "reqwest", not(feature = "minimal"))))]
+mod minimal;
+mod prewarm;
+#[cfg(all(feature = "async", feature = "reqwest", not(feature = "minimal")))]
+mod reqwest_async;
+#[cfg(all(feature = "reqwest", not(feature = "minimal")))]
+mod reqwest_client;
+#[cfg(all(feature = "reqwest", not(feature = "minimal")))]
+mod worker;
+
+#[cfg(not(all(feature = "reqwest", not(feature = "minimal"))))]
+use minimal as client;
+#[cfg(all(feature = "async", feature = "reqwest", not(feature = "minimal")))]
+use reqwest_async::get as client_get_async;
+#[cfg(all(feature = "reqwest", not(feature = "minimal")))]
+use reqwest_client as client;
+
+pub use cache::ResponseCache;
+pub use content_type::{check_content_type, expected_content_type};
+pub use limit::FetchLimit;
+pub use prewarm::prewarm;
+pub(crate) use prewarm::warm_up;
+
+/// Timeout, retries, concurrency limit and cache for requests to the dev server (see `get_with`).
+///
+/// ```ignore
+/// let options = FetchOptions::default()
+///     .with_timeout(Duration::from_secs(5))
+///     .with_retries(2);
+///
+/// let res = vite_rs_dev_server::transport::get_with("http://localhost:21012/index.html", &options);
+/// ```
+///
+/// Derived structs use the ones set with `#[dev_fetch_timeout]`, `#[dev_fetch_retries]`,
+/// `#[dev_fetch_concurrency]` and `#[dev_response_cache]` (see `Assets::dev_fetch_options()`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FetchOptions {
+    /// How long a request may take before it fails, e.g. when a Vite plugin hangs. `None` waits
+    /// indefinitely. With the `minimal` client, this applies to connecting and to each read
+    /// separately, rather than to the whole request.
+    pub timeout: Option<Duration>,
+    /// How many more times a failed request is sent (e.g. it timed out, or the dev server is
+    /// restarting). Responses are never retried, whatever their status.
+    pub retries: u32,
+    /// Delay before the first retry, doubling for each further one.
+    pub retry_delay: Duration,
+    /// If set, requests sharing this limit wait while too many of them are in flight. Unlimited
+    /// by default. See `FetchLimit`.
+    pub concurrency_limit: Option<Arc<FetchLimit>>,
+    /// If set, responses are remembered, and fetched again only if they changed. None by default.
+    /// See `ResponseCache`.
+    pub cache: Option<Arc<ResponseCache>>,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(30)),
+            retries: 0,
+            retry_delay: Duration::from_millis(100),
+            concurrency_limit: None,
+            cache: None,
+        }
+    }
+}
+
+impl FetchOptions {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn without_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Shares `limit` between the requests sent with these options (and clones of them).
+    pub fn with_concurrency_limit(mut self, limit: Arc<FetchLimit>) -> Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
+    /// Shares `cache` between the requests sent with these options (and clones of them).
+    pub fn with_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+}
+
+/// A response from the ViteJS dev server.
+#[derive(Clone, Debug)]
+pub struct DevResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub content_length: Option<u64>,
+    pub etag: Option<String>,
+    /// All response headers which are safe to forward to clients, with lowercase names.
+    /// Hop-by-hop headers and headers with invalid names or values are left out.
+    pub headers: Vec<(String, String)>,
+    pub bytes: Vec<u8>,
+}
+
+/// Error returned when the dev server could not be reached or sent an invalid response.
+#[derive(Debug)]
+pub struct FetchError {
+    message: String,
+    connect: bool,
+}
+
+impl FetchError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            connect: false,
+        }
+    }
+
+    /// An error connecting to the dev server.
+    pub(crate) fn connect(message: impl Into<String>) -> Self {
+        Self {
+            connect: true,
+            ..Self::new(message)
+        }
+    }
+
+    /// Tells connection errors apart.
+    #[cfg(all(feature = "reqwest", not(feature = "minimal")))]
+    pub(crate) fn from_reqwest(e: reqwest::Error) -> Self {
+        if e.is_connect() {
+            Self::connect(e.to_string())
+        } else {
+            Self::new(e.to_string())
+        }
+    }
+
+    /// Whether the dev server couldn't be connected to, e.g. because it's still starting (as
+    /// opposed to sending an error or an invalid response). Retrying shortly may succeed.
+    pub fn is_connect(&self) -> bool {
+        self.connect
+    }
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to fetch from ViteJS dev server: {}",
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Sends a GET request to the dev server, with the default `FetchOptions`. `url` must be an
+/// absolute `http://` URL.
+pub fn get(url: &str) -> Result<DevResponse, FetchError> {
+    get_with(url, &FetchOptions::default())
+}
+
+/// Like `get`, with the given timeout, retries, concurrency limit and cache.
+pub fn get_with(url: &str, options: &FetchOptions) -> Result<DevResponse, FetchError> {
+    let cached = cached(url, options);
+    let if_none_match = cached.as_ref().and_then(|res| res.etag.as_deref());
+    let mut attempt = 0;
+
+    loop {
+        let permit = options
+            .concurrency_limit
+            .as_ref()
+            .map(|limit| limit.acquire());
+        let result = client::get(url, options.timeout, if_none_match);
+        // not held while waiting to retry
+        drop(permit);
+
+        match result {
+            Err(_) if attempt < options.retries => {
+                std::thread::sleep(
+                    options
+                        .retry_delay
+                        .saturating_mul(2u32.saturating_pow(attempt)),
+                );
+                attempt += 1;
+            }
+            result => return cache_response(url, options, cached, result),
+        }
+    }
+}
+
+/// Like `get_with`, and can be called from within an async runtime too (blocking it). The reqwest
+/// client can't be, so it sends the request from another thread, one of a few kept for the next
+/// requests. Derived structs' `get()` uses this.
+pub fn get_blocking(url: &str, options: &FetchOptions) -> Result<DevResponse, FetchError> {
+    #[cfg(all(feature = "reqwest", not(feature = "minimal")))]
+    {
+        let url = url.to_string();
+        let options = options.clone();
+        worker::run(move || get_with(&url, &options))
+    }
+
+    #[cfg(not(all(feature = "reqwest", not(feature = "minimal"))))]
+    get_with(url, options)
+}
+
+/// Like `get_with`, but doesn't block: waiting for the dev server (and between retries) yields
+/// to the Tokio runtime instead.
+#[cfg(feature = "async")]
+pub async fn get_async(url: &str, options: &FetchOptions) -> Result<DevResponse, FetchError> {
+    let cached = cached(url, options);
+    let if_none_match = cached.as_ref().and_then(|res| res.etag.as_deref());
+    let mut attempt = 0;
+
+    loop {
+        let permit = match &options.concurrency_limit {
+            Some(limit) => Some(limit.acquire_async().await),
+            None => None,
+        };
+        let result = client_get_async(url, options.timeout, if_none_match).await;
+        // not held while waiting to retry
+        drop(permit);
+
+        match result {
+            Err(_) if attempt < options.retries => {
+                tokio::time::sleep(
+                    options
+                        .retry_delay
+                        .saturating_mul(2u32.saturating_pow(attempt)),
+                )
+                .await;
+                attempt += 1;
+            }
+            result => return cache_response(url, options, cached, result),
+        }
+    }
+}
+
+/// Sends the request with the built-in client on one of Tokio's blocking threads.
+#[cfg(all(
+    feature = "async",
+    not(all(feature = "reqwest", not(feature = "minimal")))
+))]
+async fn client_get_async(
+    url: &str,
+    timeout: Option<Duration>,
+    if_none_match: Option<&str>,
+) -> Result<DevResponse, FetchError> {
+    let url = url.to_string();
+    let if_none_match = if_none_match.map(String::from);
+
+    tokio::task::spawn_blocking(move || client::get(&url, timeout, if_none_match.as_deref()))
+        .await
+        .map_err(|e| FetchError::new(e.to_string()))?
+}
+
+/// The response cached for `url`, if there's a cache.
+fn cached(url: &str, options: &FetchOptions) -> Option<DevResponse> {
+    options.cache.as_ref()?.get(cache::cache_key(url))
+}
+
+/// Answers a `304 Not Modified` with the `cached` response, and caches a new one.
+fn cache_response(
+    url: &str,
+    options: &FetchOptions,
+    cached: Option<DevResponse>,
+    result: Result<DevResponse, FetchError>,
+) -> Result<DevResponse, FetchError> {
+    let Some(cache) = &options.cache else {
+        return result;
+    };
+
+    match (result, cached) {
+        (Ok(res), Some(cached)) if res.status == 304 => Ok(cached),
+        (Ok(res), _) => {
+            cache.insert(cache::cache_key(url), &res);
+            Ok(res)
+        }
+        (Err(e), _) => Err(e),
+    }
+}
@@ -0,0 +1,119 @@
+//! Fetches assets ahead of time, so the dev server has them ready (see `prewarm` and `warm_up`).
+
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use super::FetchOptions;
+
+/// How long `prewarm` waits for the dev server to respond.
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches `urls` from the dev server in the background and discards the responses, so it has
+/// transformed them by the time a browser asks for them (e.g. after restarting the backend, which
+/// restarts the dev server). The URLs are fetched at once (within `options`' concurrency limit),
+/// each retried until the dev server responds, for up to 30 seconds.
+///
+/// With a `ResponseCache` in `options`, this fills the cache too. Derived structs call this with
+/// their `dev_fetch_options()` in `Assets::prewarm`.
+pub fn prewarm(urls: Vec<String>, options: FetchOptions) -> JoinHandle<()> {
+    let deadline = Instant::now() + READY_TIMEOUT;
+
+    std::thread::Builder::new()
+        .name("vite-rs-prewarm".to_string())
+        .spawn(move || {
+            std::thread::scope(|scope| {
+                for url in &urls {
+                    let options = &options;
+                    scope.spawn(move || fetch_when_ready(url, options, deadline));
+                }
+            });
+        })
+        .expect("Failed to spawn thread to prewarm ViteJS dev server resources.")
+}
+
+fn fetch_when_ready(url: &str, options: &FetchOptions, deadline: Instant) {
+    // the dev server may still be starting
+    while super::get_with(url, options).is_err() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Fetches `paths` from the dev server at `origin` in the background, then the module scripts
+/// (`<script type="module" src="...">`) of the HTML pages among them, so Vite has scanned and
+/// pre-bundled their dependencies (see `DevServerOptions::with_warmup`). The dev server is ready,
+/// so nothing is retried.
+pub(crate) fn warm_up(origin: &str, paths: &[String]) -> JoinHandle<()> {
+    let origin = origin.trim_end_matches('/').to_string();
+    let paths = paths.to_vec();
+
+    std::thread::Builder::new()
+        .name("vite-rs-warmup".to_string())
+        .spawn(move || {
+            let options = FetchOptions::default();
+
+            std::thread::scope(|scope| {
+                for path in &paths {
+                    let (origin, options) = (&origin, &options);
+
+                    scope.spawn(move || {
+                        let Ok(page) = super::get_with(&join(origin, "/", path), options) else {
+                            return;
+                        };
+                        let is_html = page
+                            .content_type
+                            .as_deref()
+                            .is_some_and(|content_type| content_type.starts_with("text/html"));
+
+                        if is_html {
+                            for script in module_scripts(&String::from_utf8_lossy(&page.bytes)) {
+                                let _ = super::get_with(&join(origin, path, &script), options);
+                            }
+                        }
+                    });
+                }
+            });
+        })
+        .expect("Failed to spawn thread to warm up the ViteJS dev server.")
+}
+
+/// The URL of `src`, as referenced from the page at `page_path`: `/src/main.ts` is the same
+/// wherever it's referenced, and `main.ts` is relative to the page's directory.
+fn join(origin: &str, page_path: &str, src: &str) -> String {
+    if src.starts_with('/') {
+        return format!("{}{}", origin, src);
+    }
+
+    let page_path = page_path.trim_start_matches('/');
+    let dir = page_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+    if dir.is_empty() {
+        format!("{}/{}", origin, src)
+    } else {
+        format!("{}/{}/{}", origin, dir, src)
+    }
+}
+
+/// The `src` of each `<script type="module">` in `html` served by the dev server, leaving out
+/// scripts from other origins.
+fn module_scripts(html: &str) -> Vec<String> {
+    html.split("<script")
+        .skip(1)
+        .filter_map(|tag| tag.split_once('>').map(|(attributes, _)| attributes))
+        .filter(|attributes| {
+            attributes.contains("type=\"module\"") || attributes.contains("type='module'")
+        })
+        .filter_map(|attributes| attribute(attributes, "src"))
+        .filter(|src| !src.starts_with("//") && !src.contains("://"))
+        .collect()
+}
+
+/// The value of the quoted `name` attribute among a tag's `attributes`.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    attributes.split_whitespace().find_map(|attribute| {
+        let value = attribute.strip_prefix(name)?.strip_prefix('=')?;
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+
+        value[1..]
+            .split_once(quote)
+            .map(|(value, _)| value.to_string())
+    })
+}
@@ -0,0 +1,57 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use super::{DevResponse, FetchError};
+
+/// Shared by all requests, so connections to the dev server are reused.
+fn client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
+pub async fn get(
+    url: &str,
+    timeout: Option<Duration>,
+    if_none_match: Option<&str>,
+) -> Result<DevResponse, FetchError> {
+    let mut req = client().get(url);
+    if let Some(timeout) = timeout {
+        req = req.timeout(timeout);
+    }
+    if let Some(etag) = if_none_match {
+        req = req.header(reqwest::header::IF_NONE_MATCH, etag);
+    }
+
+    let res = req.send().await.map_err(FetchError::from_reqwest)?;
+
+    let header = |name: reqwest::header::HeaderName| {
+        res.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    };
+
+    let status = res.status().as_u16();
+    let content_type = header(reqwest::header::CONTENT_TYPE);
+    let etag = header(reqwest::header::ETAG);
+    let content_length = res.content_length();
+    let headers = super::headers::forwardable(
+        res.headers()
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec())),
+    );
+    let bytes = res
+        .bytes()
+        .await
+        .map_err(|e| FetchError::new(e.to_string()))?
+        .to_vec();
+
+    Ok(DevResponse {
+        status,
+        content_type,
+        content_length,
+        etag,
+        headers,
+        bytes,
+    })
+}
@@ -0,0 +1,81 @@
+//! Threads sending blocking requests for callers which may be inside an async runtime (see
+//! `get_blocking`).
+//!
+//! The blocking reqwest client can't be used from within a Tokio runtime, so requests are handed
+//! to these threads. They are kept for the next requests (for a while), so a thread isn't spawned
+//! for each one. There are as many as there are requests in flight at once.
+
+use std::collections::VecDeque;
+use std::sync::{mpsc, Condvar, Mutex, OnceLock};
+use std::time::Duration;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// How long an idle thread waits for another request before it exits.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct Workers {
+    state: Mutex<State>,
+    available: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    jobs: VecDeque<Job>,
+    /// Threads waiting for a job.
+    idle: usize,
+}
+
+fn workers() -> &'static Workers {
+    static WORKERS: OnceLock<Workers> = OnceLock::new();
+    WORKERS.get_or_init(Workers::default)
+}
+
+/// Runs `f` on one of the threads, and waits for its result.
+pub(crate) fn run<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let job: Job = Box::new(move || {
+        let _ = sender.send(f());
+    });
+
+    let workers = workers();
+    let mut state = workers.state.lock().unwrap();
+    state.jobs.push_back(job);
+
+    if state.idle >= state.jobs.len() {
+        workers.available.notify_one();
+    } else {
+        std::thread::Builder::new()
+            .name("vite-rs-fetch".into())
+            .spawn(move || work(workers))
+            .expect("Failed to spawn thread to fetch ViteJS dev server resource.");
+    }
+    drop(state);
+
+    receiver
+        .recv()
+        .expect("vite-rs: the thread fetching from the ViteJS dev server panicked")
+}
+
+fn work(workers: &Workers) {
+    let mut state = workers.state.lock().unwrap();
+
+    loop {
+        if let Some(job) = state.jobs.pop_front() {
+            drop(state);
+            job();
+            state = workers.state.lock().unwrap();
+            continue;
+        }
+
+        state.idle += 1;
+        let (next, timeout) = workers.available.wait_timeout(state, IDLE_TIMEOUT).unwrap();
+        state = next;
+        state.idle -= 1;
+
+        if timeout.timed_out() && state.jobs.is_empty() {
+            return;
+        }
+    }
+}
@@ -1,21 +1,236 @@
+//! Helpers to check and pick ports, e.g. for the dev server or in tests. Available in all builds.
+//!
+//! ```
+//! use vite_rs_dev_server::util;
+//!
+//! let port = util::find_free_port(21012..22022).expect("no free port");
+//! assert!(util::is_port_free(port));
+//! assert!(util::is_port_free_on("127.0.0.1", port));
+//! ```
+//!
+//! Note: these only tell whether a port is free at the time of the check. Another process can
+//! take it right after.
+
 use std::{
-    net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, TcpListener, ToSocketAddrs},
+    io::ErrorKind,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, ToSocketAddrs},
     ops::Range,
+    sync::atomic::{AtomicU16, Ordering},
+    time::{Duration, Instant},
 };
 
-fn test_bind<A: ToSocketAddrs>(addr: A) -> bool {
-    TcpListener::bind(addr)
-        .map(|t| t.local_addr().is_ok())
-        .unwrap_or(false)
+/// Ports the dev server is started on when none is specified.
+pub const DEFAULT_PORT_RANGE: Range<u16> = 21012..22022;
+
+/// Binds `addr` and releases it right away. Like Node.js, Rust's std sets `SO_REUSEADDR` on Unix,
+/// so a port whose previous connections are still in `TIME_WAIT` counts as free, just as it would
+/// for Vite.
+fn test_bind(addr: SocketAddr) -> bool {
+    match TcpListener::bind(addr) {
+        Ok(listener) => listener.local_addr().is_ok(),
+        // without IPv6 support (e.g. disabled in a container), nothing can listen there either
+        Err(e) if addr.is_ipv6() => {
+            !matches!(e.kind(), ErrorKind::AddrInUse | ErrorKind::PermissionDenied)
+        }
+        Err(_) => false,
+    }
+}
+
+/// A hash which stays the same between builds and processes (unlike `DefaultHasher`), e.g. for
+/// file names (FNV-1a).
+#[cfg(vite_rs_dev)]
+pub(crate) fn stable_hash(string: &str) -> u64 {
+    string.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
 }
 
+/// Whether `port` is free on all interfaces, for both IPv4 (`0.0.0.0`) and IPv6 (`::`).
+///
+/// This is stricter than [`is_port_free_on`]: a port that another process bound on `127.0.0.1`
+/// only is taken here, since the dev server may listen on any interface (see `--host`).
 pub fn is_port_free(port: u16) -> bool {
-    let ipv4 = SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, port);
-    let ipv6 = SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, port, 0, 0);
+    let ipv4 = SocketAddr::from((Ipv4Addr::UNSPECIFIED, port));
+    let ipv6 = SocketAddr::from((Ipv6Addr::UNSPECIFIED, port));
 
     test_bind(ipv6) && test_bind(ipv4)
 }
 
+/// Whether `port` is free on `host` (e.g. `localhost`, `127.0.0.1` or `::1`), i.e. on every
+/// address it resolves to. Returns `false` if `host` can't be resolved.
+///
+/// Note: binding `0.0.0.0` and a specific address (e.g. `127.0.0.1`) can differ: depending on the
+/// platform, a port taken on one may still be free on the other. Use [`is_port_free`] to check
+/// all interfaces.
+pub fn is_port_free_on(host: &str, port: u16) -> bool {
+    let addrs = match (unbracket(host), port).to_socket_addrs() {
+        Ok(addrs) => addrs.collect::<Vec<_>>(),
+        Err(_) => return false,
+    };
+
+    !addrs.is_empty() && addrs.into_iter().all(test_bind)
+}
+
+/// Waits up to `timeout` for `port` to be free on all interfaces (see [`is_port_free`]), e.g.
+/// while the process which had it is still exiting. Returns whether it's free.
+pub fn wait_for_free_port(port: u16, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        if is_port_free(port) {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// The first port in `range` that is free on all interfaces (see [`is_port_free`]).
 pub fn find_free_port(mut range: Range<u16>) -> Option<u16> {
     range.find(|port| is_port_free(*port))
 }
+
+/// The first port in `range` that is free on `host` (see [`is_port_free_on`]).
+pub fn find_free_port_on(host: &str, mut range: Range<u16>) -> Option<u16> {
+    range.find(|port| is_port_free_on(host, *port))
+}
+
+/// The origin of a dev server, e.g. `http://localhost:21012`. IPv6 addresses are enclosed in
+/// brackets, so both `::1` and `[::1]` give `http://[::1]:21012`.
+pub fn http_origin(host: &str, port: u16) -> String {
+    let host = unbracket(host);
+
+    if host.contains(':') {
+        format!("http://[{}]:{}", host, port)
+    } else {
+        format!("http://{}:{}", host, port)
+    }
+}
+
+/// Where a dev server listening on `bind_host` is reached from this machine: the loopback address
+/// for a wildcard address (`0.0.0.0` gives `127.0.0.1`, and `::` gives `::1`), since those can't
+/// be connected to everywhere. Other hosts are returned as-is.
+pub fn fetch_host(bind_host: &str) -> &str {
+    match unbracket(bind_host) {
+        "0.0.0.0" => "127.0.0.1",
+        "::" => "::1",
+        _ => bind_host,
+    }
+}
+
+/// Removes the brackets around an IPv6 address (`[::1]` to `::1`), as Vite's `--host` and
+/// address parsing expect. Other hosts are returned as-is.
+pub fn unbracket(host: &str) -> &str {
+    host.strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host)
+}
+
+/// A dev server port picked at runtime, when it's first needed (see `#[dev_server_port = "auto"]`).
+/// Starting the dev server and fetching assets from it share it, so a port that's taken doesn't
+/// require recompiling, unlike one picked at compile time.
+///
+/// ```
+/// use vite_rs_dev_server::util::RuntimePort;
+///
+/// static PORT: RuntimePort = RuntimePort::new();
+///
+/// let port = PORT.get();
+/// assert_eq!(PORT.get(), port);
+///
+/// // e.g. once another process took it
+/// let port = PORT.repick();
+/// assert_eq!(PORT.get(), port);
+/// ```
+///
+/// `RuntimePort::preferring(5173)` is `5173` unless it's taken, and the next free port after it
+/// otherwise, like Vite picks one without `--strictPort` (see `#[dev_server_port_fallback]`).
+#[derive(Debug, Default)]
+pub struct RuntimePort {
+    port: AtomicU16,
+    /// The first port tried; `0` for any free one in [`DEFAULT_PORT_RANGE`].
+    preferred: u16,
+}
+
+impl RuntimePort {
+    pub const fn new() -> Self {
+        Self::preferring(0)
+    }
+
+    /// Starts at `port`, or the next free port after it if it's taken when it's first needed.
+    pub const fn preferring(port: u16) -> Self {
+        Self {
+            port: AtomicU16::new(0),
+            preferred: port,
+        }
+    }
+
+    /// The current port. On first use, picks a free one in [`DEFAULT_PORT_RANGE`], or the
+    /// preferred one (if it's free) and the ones after it. If none is free, it's the preferred
+    /// port (or the first in the range), and starting the dev server on it fails with
+    /// `DevServerError::PortUnavailable`.
+    pub fn get(&self) -> u16 {
+        match self.port.load(Ordering::SeqCst) {
+            0 => {
+                let port = self
+                    .pick(self.preferred)
+                    .unwrap_or_else(|| self.range().start);
+
+                // another thread may have picked one in the meantime
+                match self
+                    .port
+                    .compare_exchange(0, port, Ordering::SeqCst, Ordering::SeqCst)
+                {
+                    Ok(_) if self.preferred != 0 && port != self.preferred => {
+                        eprintln!(
+                            "vite-rs: port {} is in use, using {} for the ViteJS dev server instead",
+                            self.preferred, port
+                        );
+                        port
+                    }
+                    Ok(_) => port,
+                    Err(current) => current,
+                }
+            }
+            port => port,
+        }
+    }
+
+    /// Uses `port` from now on, e.g. one picked by another process.
+    pub fn set(&self, port: u16) {
+        self.port.store(port, Ordering::SeqCst);
+    }
+
+    /// Picks another free port, e.g. because the current one was taken in the meantime. Keeps the
+    /// current one if none is free.
+    pub fn repick(&self) -> u16 {
+        let current = self.port.load(Ordering::SeqCst);
+        let port = match self.pick(current.saturating_add(1)) {
+            Some(port) => port,
+            None if current != 0 => current,
+            None => self.range().start,
+        };
+        self.port.store(port, Ordering::SeqCst);
+
+        port
+    }
+
+    /// The first free port in [`DEFAULT_PORT_RANGE`] (or from the preferred port on), starting at
+    /// `from` and wrapping around.
+    fn pick(&self, from: u16) -> Option<u16> {
+        let range = self.range();
+        let from = from.clamp(range.start, range.end);
+
+        find_free_port(from..range.end).or_else(|| find_free_port(range.start..from))
+    }
+
+    /// The ports picked from.
+    fn range(&self) -> Range<u16> {
+        match self.preferred {
+            0 => DEFAULT_PORT_RANGE,
+            preferred => preferred..u16::MAX,
+        }
+    }
+}
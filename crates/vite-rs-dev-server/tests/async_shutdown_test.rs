@@ -0,0 +1,119 @@
+#![cfg(all(unix, feature = "async", vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio};
+
+/// Installs a fake Vite in the `root`, which runs `trap` on SIGTERM (once it's created
+/// `trapped`). The tests run in parallel, so each picks its port from its own `ports`.
+fn options(
+    root: &Path,
+    trap: &str,
+    ports: std::ops::Range<u16>,
+    grace_period: Duration,
+) -> DevServerOptions {
+    fake_vite::vite(
+        root,
+        &format!("#!/bin/sh\ntrap {trap} TERM\ntouch trapped\nwhile true; do sleep 0.1; done\n"),
+    );
+
+    let port = vite_rs_dev_server::util::find_free_port(ports).unwrap();
+    DevServerOptions::new(root.to_str().unwrap(), port)
+        .with_stdio(DevServerStdio::Null)
+        .with_shutdown_grace_period(grace_period)
+}
+
+#[tokio::test]
+async fn shuts_down_without_blocking_the_runtime() {
+    let root = TempDir::new("async-shutdown-test-graceful");
+    let options = options(&root, "''", 36000..36500, Duration::from_millis(500));
+    let port = options.port;
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options)
+        .unwrap()
+        .unwrap();
+    let handle = DevServerRegistry::global().get(port).unwrap();
+    wait_until_trapped(&handle).await;
+
+    // runs on the same (single) thread while the dev server gets its grace period
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let ticker = tokio::spawn({
+        let ticks = ticks.clone();
+        async move {
+            loop {
+                ticks.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        }
+    });
+
+    let stopping = Instant::now();
+    guard.shutdown_async().await.unwrap();
+    assert!(stopping.elapsed() >= Duration::from_millis(500));
+    assert!(ticks.load(Ordering::SeqCst) >= 5);
+    assert!(!handle.is_running());
+    assert!(DevServerRegistry::global().get(port).is_none());
+
+    ticker.abort();
+}
+
+#[tokio::test]
+async fn dropping_the_guard_on_a_runtime_waits_on_another_thread() {
+    let root = TempDir::new("async-shutdown-test-dropped");
+    let options = options(&root, "''", 36500..37000, Duration::from_secs(1));
+    let port = options.port;
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    let handle = DevServerRegistry::global().get(port).unwrap();
+    wait_until_trapped(&handle).await;
+
+    let stopping = Instant::now();
+    drop(guard);
+    assert!(stopping.elapsed() < Duration::from_millis(500));
+    assert!(handle.is_running());
+
+    // killed once the grace period is over
+    while handle.is_running() && stopping.elapsed() < Duration::from_secs(10) {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(!handle.is_running());
+    assert!(stopping.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn dropping_the_guard_at_the_end_of_tokio_main_shuts_it_down_gracefully() {
+    let root = TempDir::new("async-shutdown-test-main");
+    drop_the_guard_at_the_end(options(
+        &root,
+        "'touch terminated; exit'",
+        37000..37500,
+        Duration::from_secs(10),
+    ));
+
+    // asked to shut down when the guard was dropped, and waited for on another thread
+    let terminated = root.join("terminated");
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !terminated.exists() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(terminated.exists());
+}
+
+#[tokio::main]
+async fn drop_the_guard_at_the_end(options: DevServerOptions) {
+    let port = options.port;
+    let _guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    wait_until_trapped(&DevServerRegistry::global().get(port).unwrap()).await;
+}
+
+async fn wait_until_trapped(handle: &vite_rs_dev_server::DevServerHandle) {
+    let trapped = Path::new(handle.root_dir()).join("trapped");
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !trapped.exists() && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(vite_rs_dev)]
+
+use vite_rs_dev_server::transport::{check_content_type, expected_content_type};
+
+#[test]
+fn expects_javascript_for_modules() {
+    assert_eq!(
+        expected_content_type("src/main.ts"),
+        Some("text/javascript")
+    );
+    assert_eq!(
+        expected_content_type("/src/App.vue"),
+        Some("text/javascript")
+    );
+    assert_eq!(
+        expected_content_type("src/style.css?import"),
+        Some("text/javascript")
+    );
+    assert_eq!(
+        expected_content_type("src/logo.svg?url&v=1"),
+        Some("text/javascript")
+    );
+}
+
+#[test]
+fn expects_content_types_by_extension() {
+    assert_eq!(expected_content_type("index.html"), Some("text/html"));
+    assert_eq!(expected_content_type("src/style.css"), Some("text/css"));
+    assert_eq!(expected_content_type("logo.svg"), Some("image/svg+xml"));
+    assert_eq!(expected_content_type("about"), Some("text/html"));
+    assert_eq!(expected_content_type("@vite/client"), None);
+    assert_eq!(expected_content_type("data.unknownext"), None);
+}
+
+#[test]
+fn checks_content_types() {
+    assert!(check_content_type("src/main.ts", "text/javascript"));
+    assert!(check_content_type(
+        "src/main.ts",
+        "application/javascript; charset=utf-8"
+    ));
+    assert!(check_content_type("index.html", "text/html; charset=UTF-8"));
+    assert!(check_content_type("@vite/client", "text/plain"));
+
+    // e.g. a plugin answering a missing module with `index.html`
+    assert!(!check_content_type("src/missing.ts", "text/html"));
+    assert!(!check_content_type("src/style.css", "text/javascript"));
+}
@@ -0,0 +1,44 @@
+#![cfg(all(unix, feature = "ctrlc", vite_rs_dev))]
+
+mod util;
+
+use std::net::TcpListener;
+use std::time::Duration;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerError, DevServerOptions, DevServerStdio};
+
+/// Puts a fake `npx` on `PATH`, which keeps running.
+/// Note: a single #[test] because the Ctrl-C handler can only be set once per process.
+#[test]
+fn a_failed_start_does_not_keep_the_ctrl_c_handler() {
+    let dir = TempDir::new("ctrl-c-handler-test");
+
+    fake_vite::npx(&dir, "#!/bin/sh\nexec sleep 30\n");
+    fake_vite::put_on_path(&dir);
+
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let options = || {
+        DevServerOptions::new(dir.to_str().unwrap(), port)
+            .with_host("127.0.0.1")
+            .with_stdio(DevServerStdio::Null)
+            .with_ctrl_c_handler(true)
+            .with_port_wait(Duration::ZERO)
+            .with_port_retries(0)
+    };
+
+    let result = vite_rs_dev_server::try_start_dev_server_with(options());
+    assert!(matches!(
+        result,
+        Err(DevServerError::PortUnavailable { port: p }) if p == port
+    ));
+
+    // the handler is set once the dev server runs
+    drop(listener);
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options()).unwrap();
+    assert!(guard.is_some());
+    assert!(ctrlc::try_set_handler(|| {}).is_err());
+
+    drop(guard);
+}
@@ -0,0 +1,92 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::path::Path;
+
+use util::temp_dir::TempDir;
+use vite_rs_dev_server::disk::{self, FileCache};
+
+/// Changes a file without changing its size or modification time, so only a read that skips
+/// the cache sees the change.
+fn overwrite_unnoticed(path: &Path, contents: &[u8]) {
+    let modified = std::fs::metadata(path).unwrap().modified().unwrap();
+    std::fs::write(path, contents).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+}
+
+#[test]
+fn rereads_files_when_they_change() {
+    let cache = FileCache::new();
+    let dir = TempDir::new("disk-test");
+    std::fs::write(dir.join("data.json"), "[1]").unwrap();
+
+    let file = cache.read_from(&dir, "/data.json").unwrap();
+    assert_eq!(file.bytes, b"[1]");
+    assert_eq!(file.content_type, "application/json");
+    assert!(file.etag.starts_with("W/\"3-"));
+
+    // served from the cache
+    overwrite_unnoticed(&dir.join("data.json"), b"[2]");
+    let cached = cache.read_from(&dir, "data.json").unwrap();
+    assert_eq!(cached.bytes, b"[1]");
+    assert_eq!(cached.etag, file.etag);
+
+    std::fs::write(dir.join("data.json"), "[1, 2]").unwrap();
+
+    let changed = cache.read_from(&dir, "data.json").unwrap();
+    assert_eq!(changed.bytes, b"[1, 2]");
+    assert_eq!(changed.content_length, 6);
+    assert_ne!(changed.etag, file.etag);
+}
+
+#[test]
+fn does_not_cache_large_files() {
+    let cache = FileCache::new();
+    let dir = TempDir::new("disk-large-file-test");
+    std::fs::write(dir.join("video.mp4"), vec![0; 2 * 1024 * 1024]).unwrap();
+
+    let file = cache.read_from(&dir, "video.mp4").unwrap();
+    assert_eq!(file.content_length, 2 * 1024 * 1024);
+
+    overwrite_unnoticed(&dir.join("video.mp4"), &[1; 2 * 1024 * 1024]);
+    let again = cache.read_from(&dir, "video.mp4").unwrap();
+    assert!(again.bytes.iter().all(|byte| *byte == 1));
+}
+
+#[test]
+fn drops_the_least_recently_used_files_when_full() {
+    let cache = FileCache::new();
+    let dir = TempDir::new("disk-full-cache-test");
+    let megabyte = vec![0; 1024 * 1024];
+    for i in 0..33 {
+        std::fs::write(dir.join(format!("{i}.bin")), &megabyte).unwrap();
+    }
+
+    // fills the 32 MiB cache, then reads `0.bin` again so `1.bin` is the least recently used
+    for i in 0..32 {
+        cache.read_from(&dir, &format!("{i}.bin")).unwrap();
+    }
+    cache.read_from(&dir, "0.bin").unwrap();
+    cache.read_from(&dir, "32.bin").unwrap();
+
+    overwrite_unnoticed(&dir.join("0.bin"), &[1; 1024 * 1024]);
+    overwrite_unnoticed(&dir.join("1.bin"), &[1; 1024 * 1024]);
+    assert_eq!(cache.read_from(&dir, "0.bin").unwrap().bytes[0], 0);
+    assert_eq!(cache.read_from(&dir, "1.bin").unwrap().bytes[0], 1);
+}
+
+#[test]
+fn rejects_paths_outside_the_dir() {
+    let dir = std::env::current_dir().unwrap().join("src");
+
+    assert!(disk::read_from(&dir, "lib.rs").is_some());
+    assert!(disk::read_from(&dir, "../Cargo.toml").is_none());
+    assert!(disk::read_from(&dir, "./../Cargo.toml").is_none());
+    assert!(disk::read_from(&dir, "").is_none());
+}
@@ -0,0 +1,85 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::net::TcpListener;
+
+use util::temp_dir::TempDir;
+use vite_rs_dev_server::{DevServerError, DevServerOptions};
+
+/// Note: a single #[test] because it modifies `PATH` for the whole process.
+#[test]
+fn returns_errors_instead_of_panicking() {
+    // port is taken
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let result = vite_rs_dev_server::try_start_dev_server_with(DevServerOptions::new(".", port));
+    assert!(matches!(
+        result,
+        Err(DevServerError::PortUnavailable { port: p }) if p == port
+    ));
+
+    drop(listener);
+
+    // npx is missing
+    let empty_dir = TempDir::new("error-test");
+    std::env::set_var("PATH", empty_dir.as_os_str());
+
+    let port = vite_rs_dev_server::util::find_free_port(32000..33000).unwrap();
+    let result = vite_rs_dev_server::try_start_dev_server_with(DevServerOptions::new(".", port));
+    match result {
+        Err(e @ DevServerError::CommandNotFound { .. }) => {
+            let message = e.to_string();
+            assert!(message.contains("`npx` was not found"));
+            assert!(message.contains(&format!("Searched PATH: {}", empty_dir.display())));
+            assert!(message.contains("Vite isn't installed in"));
+            assert!(message.contains("Node.js isn't on PATH either"));
+            assert!(message.contains("set VITE_RS_NPX_PATH to the `npx` executable"));
+        }
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected an error"),
+    }
+
+    // the configured npx doesn't exist
+    let npx_path = empty_dir.join("bin/npx");
+    let result = vite_rs_dev_server::try_start_dev_server_with(
+        DevServerOptions::new(".", port).with_npx_path(npx_path.to_str().unwrap()),
+    );
+    match result {
+        Err(DevServerError::CommandNotFound { diagnostics, .. }) => {
+            assert!(diagnostics.searched.is_empty());
+            let message = diagnostics.to_string();
+            assert!(message.contains(&format!("`{}` doesn't exist", npx_path.display())));
+            // it's run instead of a local Vite
+            assert!(!message.contains("Vite isn't installed"));
+        }
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected an error"),
+    }
+
+    // the local Vite is run, but its interpreter is missing
+    let root = TempDir::new("error-test-local-vite");
+    let vite = util::fake_vite::vite(&root, "#!/nonexistent/node\n");
+    let result = vite_rs_dev_server::try_start_dev_server_with(DevServerOptions::new(
+        root.to_str().unwrap(),
+        port,
+    ));
+    match result {
+        Err(DevServerError::CommandNotFound {
+            command,
+            diagnostics,
+        }) => {
+            assert_eq!(command, vite.to_str().unwrap());
+            let message = diagnostics.to_string();
+            assert!(
+                message.contains("exists, but the interpreter"),
+                "{}",
+                message
+            );
+            assert!(!message.contains("Vite isn't installed"));
+        }
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("expected an error"),
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerEvent, DevServerOptions, DevServerStdio, RestartPolicy};
+
+/// Puts a fake `npx` on `PATH`, which crashes a while after it's started the first time and keeps
+/// running after that. Something else answers HTTP requests on its port, standing in for Vite.
+#[test]
+fn reports_the_lifecycle_of_the_dev_server() {
+    let dir = TempDir::new("events-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\nif [ ! -e started ]; then touch started; sleep 2; exit 1; fi\nexec sleep 30\n",
+    );
+
+    fake_vite::put_on_path(&dir);
+
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let ready = Arc::new(Mutex::new(Vec::new()));
+    let exits = Arc::new(Mutex::new(Vec::new()));
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_restart(
+            RestartPolicy::default()
+                .with_initial_backoff(Duration::from_millis(50))
+                .on_restart(|_| {}),
+        )
+        .on_event({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event.clone())
+        })
+        .on_ready({
+            let ready = ready.clone();
+            move |url| ready.lock().unwrap().push(url.to_string())
+        })
+        .on_exit({
+            let exits = exits.clone();
+            move |status| exits.lock().unwrap().push(status.code())
+        })
+        .with_event_sender(sender);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+    answer_on(port);
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while events.lock().unwrap().len() < 6 && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    guard.unwrap().shutdown().unwrap();
+
+    let url = format!("http://127.0.0.1:{}", port);
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 7, "{:?}", events);
+    assert_eq!(events[0], DevServerEvent::Starting { port });
+    assert_eq!(events[1], DevServerEvent::Ready { url: url.clone() });
+    assert!(matches!(&events[2], DevServerEvent::Crashed { status } if status.code() == Some(1)));
+    assert_eq!(
+        events[3],
+        DevServerEvent::Restarting {
+            attempt: 1,
+            backoff: Duration::from_millis(50)
+        }
+    );
+    assert_eq!(events[4], DevServerEvent::Starting { port });
+    assert_eq!(events[5], DevServerEvent::Ready { url: url.clone() });
+    assert_eq!(events[6], DevServerEvent::Stopped);
+
+    // all the callbacks are called, and the channel gets the same events
+    assert_eq!(*ready.lock().unwrap(), [url.clone(), url]);
+    assert_eq!(*exits.lock().unwrap(), [Some(1)]);
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), *events);
+}
+
+/// Answers every request on `port` with a 404.
+fn answer_on(port: u16) {
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+
+            // read the whole request, so closing the connection doesn't reset it
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(n) if n > 0 => request.extend_from_slice(&buf[..n]),
+                    _ => break,
+                }
+            }
+
+            let _ = stream.write_all(
+                b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            );
+        }
+    });
+}
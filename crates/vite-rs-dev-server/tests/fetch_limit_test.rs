@@ -0,0 +1,143 @@
+#![cfg(vite_rs_dev)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use vite_rs_dev_server::transport::{self, FetchLimit, FetchOptions};
+
+/// Answers every request after a short delay, and records how many were handled at once.
+fn serve_slowly(max_concurrent: Arc<AtomicUsize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let concurrent = Arc::new(AtomicUsize::new(0));
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = stream.unwrap();
+            let concurrent = concurrent.clone();
+            let max_concurrent = max_concurrent.clone();
+
+            std::thread::spawn(move || {
+                let mut reader = BufReader::new(stream);
+
+                // drain the request head
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                    line.clear();
+                }
+
+                let now = concurrent.fetch_add(1, Ordering::SeqCst) + 1;
+                max_concurrent.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(100));
+                concurrent.fetch_sub(1, Ordering::SeqCst);
+
+                reader
+                    .into_inner()
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                    )
+                    .unwrap();
+            });
+        }
+    });
+
+    vite_rs_dev_server::util::http_origin(&addr.ip().to_string(), addr.port())
+}
+
+#[test]
+fn limits_concurrent_requests() {
+    let max_concurrent = Arc::new(AtomicUsize::new(0));
+    let url = serve_slowly(max_concurrent.clone());
+
+    let limit = Arc::new(FetchLimit::new(2));
+    let options = FetchOptions::default().with_concurrency_limit(limit.clone());
+
+    let requests = (0..8)
+        .map(|i| {
+            let url = format!("{}/{}.js", url, i);
+            let options = options.clone();
+            std::thread::spawn(move || transport::get_with(&url, &options).unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    for request in requests {
+        assert_eq!(request.join().unwrap().bytes, b"ok");
+    }
+
+    assert_eq!(max_concurrent.load(Ordering::SeqCst), 2);
+    assert!(limit.max_queued() >= 1, "{:?}", limit);
+    assert_eq!(limit.in_flight(), 0);
+    assert_eq!(limit.queued(), 0);
+}
+
+#[test]
+fn limits_are_compared_by_identity() {
+    let limit = Arc::new(FetchLimit::new(4));
+    let options = FetchOptions::default().with_concurrency_limit(limit.clone());
+
+    assert_eq!(options, options.clone());
+    assert_ne!(
+        options,
+        FetchOptions::default().with_concurrency_limit(Arc::new(FetchLimit::new(4)))
+    );
+}
+
+#[cfg(feature = "async")]
+#[tokio::test(flavor = "multi_thread")]
+async fn limits_concurrent_async_requests() {
+    let max_concurrent = Arc::new(AtomicUsize::new(0));
+    let url = serve_slowly(max_concurrent.clone());
+
+    let limit = Arc::new(FetchLimit::new(3));
+    let options = FetchOptions::default().with_concurrency_limit(limit.clone());
+
+    let requests = (0..9)
+        .map(|i| {
+            let url = format!("{}/{}.js", url, i);
+            let options = options.clone();
+            tokio::spawn(async move { transport::get_async(&url, &options).await.unwrap() })
+        })
+        .collect::<Vec<_>>();
+
+    for request in requests {
+        assert_eq!(request.await.unwrap().bytes, b"ok");
+    }
+
+    assert_eq!(max_concurrent.load(Ordering::SeqCst), 3);
+    assert!(limit.max_queued() >= 1, "{:?}", limit);
+    assert_eq!(limit.queued(), 0);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test(flavor = "multi_thread")]
+async fn cancelled_async_requests_are_not_queued() {
+    let url = serve_slowly(Arc::new(AtomicUsize::new(0)));
+
+    let limit = Arc::new(FetchLimit::new(1));
+    let options = FetchOptions::default().with_concurrency_limit(limit.clone());
+
+    let first = tokio::spawn({
+        let (url, options) = (format!("{}/first.js", url), options.clone());
+        async move { transport::get_async(&url, &options).await.unwrap() }
+    });
+    while limit.in_flight() == 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    // given up on while they wait for the first one
+    for i in 0..3 {
+        let url = format!("{}/{}.js", url, i);
+        let waiting = transport::get_async(&url, &options);
+        assert!(tokio::time::timeout(Duration::from_millis(10), waiting)
+            .await
+            .is_err());
+    }
+    assert_eq!(limit.queued(), 0);
+    assert!(limit.max_queued() >= 1, "{:?}", limit);
+
+    assert_eq!(first.await.unwrap().bytes, b"ok");
+    assert_eq!(limit.in_flight(), 0);
+}
@@ -0,0 +1,83 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio};
+
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// Puts a fake `npx` on `PATH`, then drops guards in various orders.
+#[test]
+fn only_the_last_owning_guard_stops_the_dev_server() {
+    let dir = TempDir::new("guard-test");
+
+    fake_vite::npx(&dir, "#!/bin/sh\nwhile true; do sleep 0.1; done\n");
+
+    fake_vite::put_on_path(&dir);
+
+    let options = DevServerOptions::new(dir.to_str().unwrap(), free_port())
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null);
+    let registry = DevServerRegistry::global();
+
+    // clones share ownership
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options.clone())
+        .unwrap()
+        .unwrap();
+    let clone = guard.clone();
+    drop(guard);
+    assert!(clone.is_running());
+    assert!(registry.get(options.port).is_some());
+
+    drop(clone);
+    assert!(registry.get(options.port).is_none());
+
+    // a guard of a replaced dev server can't stop the one replacing it
+    let replaced = vite_rs_dev_server::try_start_dev_server_with(options.clone())
+        .unwrap()
+        .unwrap();
+    let replaced_clone = replaced.clone();
+    let restarted_options = options.clone().with_port(free_port());
+    let restarted = vite_rs_dev_server::try_start_dev_server_with(restarted_options.clone())
+        .unwrap()
+        .unwrap();
+    assert!(!replaced.is_running());
+
+    drop(replaced);
+    drop(replaced_clone);
+    assert!(restarted.is_running());
+    assert!(registry.get(restarted_options.port).is_some());
+
+    drop(restarted);
+    assert!(registry.get(restarted_options.port).is_none());
+
+    // a leaked guard keeps the dev server running, even when its clones are dropped
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options.clone())
+        .unwrap()
+        .unwrap();
+    let clone = guard.clone();
+    guard.leak();
+    drop(clone);
+    let leaked = registry.get(options.port).unwrap();
+    assert!(leaked.is_running());
+
+    vite_rs_dev_server::stop_dev_server_on(options.port);
+    assert!(!leaked.is_running());
+
+    // shutting down stops it, whatever other clones exist
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options.clone())
+        .unwrap()
+        .unwrap();
+    let clone = guard.clone();
+    guard.shutdown().unwrap();
+    assert!(!clone.is_running());
+    assert!(registry.get(options.port).is_none());
+    drop(clone);
+}
@@ -0,0 +1,89 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Writes a fake executable to `path` which records its arguments in `<path>.txt`.
+fn fake_executable(path: &Path) {
+    fake_vite::script(
+        path,
+        &format!(
+            "#!/bin/sh\necho \"$@\" > {}.txt\nexec sleep 30\n",
+            path.display()
+        ),
+    );
+}
+
+/// Starts the dev server and waits until `executable` recorded its arguments.
+fn start(options: DevServerOptions, executable: &Path) -> String {
+    let _guard =
+        vite_rs_dev_server::start_dev_server_with(options.with_stdio(DevServerStdio::Null));
+
+    let output = executable.with_extension("txt");
+    let start = Instant::now();
+    loop {
+        match std::fs::read_to_string(&output) {
+            Ok(contents) if contents.ends_with('\n') => return contents,
+            _ if start.elapsed() > Duration::from_secs(5) => {
+                panic!("timed out waiting for {}", output.display())
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    }
+}
+
+#[test]
+fn finds_the_closest_node_modules() {
+    let dir = TempDir::new("local-vite-bin-lookup-test");
+    let root = dir.join("packages/app");
+    std::fs::create_dir_all(&root).unwrap();
+    assert_eq!(
+        vite_rs_dev_server::command::local_vite_bin(root.to_str().unwrap()),
+        None
+    );
+
+    // hoisted to the workspace root
+    let hoisted = dir.join("node_modules/.bin/vite");
+    fake_executable(&hoisted);
+    assert_eq!(
+        vite_rs_dev_server::command::local_vite_bin(root.to_str().unwrap()),
+        Some(hoisted)
+    );
+
+    let own = root.join("node_modules/.bin/vite");
+    fake_executable(&own);
+    assert_eq!(
+        vite_rs_dev_server::command::local_vite_bin(root.to_str().unwrap()),
+        Some(own)
+    );
+}
+
+#[test]
+fn runs_the_installed_vite_directly() {
+    let dir = TempDir::new("local-vite-bin-test");
+    let vite = dir.join("node_modules/.bin/vite");
+    fake_executable(&vite);
+    // not on `PATH`
+    let npx = dir.join("bin/npx");
+    fake_executable(&npx);
+
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_package_manager_args(["--registry=https://npm.example.com"]);
+
+    let args = start(options.clone(), &vite);
+    assert!(args.starts_with("--host localhost"), "{}", args);
+
+    // an explicit `npx` takes precedence
+    let args = start(options.with_npx_path(npx.to_str().unwrap()), &npx);
+    assert!(
+        args.starts_with("--registry=https://npm.example.com vite --host localhost"),
+        "{}",
+        args
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerEvent, DevServerOptions, DevServerStdio};
+
+/// Installs a fake Vite in the root, which counts how often it was started and keeps running.
+#[test]
+fn restarts_the_dev_server_on_request() {
+    let root = TempDir::new("manual-restart-test");
+    fake_vite::vite(
+        &root,
+        "#!/bin/sh\nn=$(cat starts 2>/dev/null || echo 0)\necho $((n + 1)) > starts\nexec sleep 30\n",
+    );
+
+    let starts = || {
+        std::fs::read_to_string(root.join("starts"))
+            .ok()
+            .and_then(|starts| starts.trim().parse::<u32>().ok())
+            .unwrap_or(0)
+    };
+    let wait_for_starts = |n| {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while starts() < n && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert_eq!(starts(), n);
+    };
+
+    let (events, received) = std::sync::mpsc::channel();
+    let port = vite_rs_dev_server::util::find_free_port(35000..36000).unwrap();
+    let options = DevServerOptions::new(root.to_str().unwrap(), port)
+        .with_stdio(DevServerStdio::Null)
+        .with_event_sender(events);
+
+    let guard = vite_rs_dev_server::start_dev_server_with(options).unwrap();
+    wait_for_starts(1);
+    let pid = guard.pid();
+
+    assert!(vite_rs_dev_server::restart_dev_server_on(port).unwrap());
+    wait_for_starts(2);
+
+    // the guard owns the new process
+    assert_ne!(guard.pid(), pid);
+    assert!(guard.is_running());
+    assert!(vite_rs_dev_server::is_dev_server_running(port));
+
+    let events = received.try_iter().collect::<Vec<_>>();
+    assert!(
+        matches!(
+            events[..],
+            [
+                DevServerEvent::Starting { .. },
+                DevServerEvent::Stopped,
+                DevServerEvent::Starting { port: restarted }
+            ] if restarted == port
+        ),
+        "{:?}",
+        events
+    );
+
+    // nothing to restart
+    assert!(!vite_rs_dev_server::restart_dev_server_on(port + 1).unwrap());
+
+    drop(guard);
+    assert!(!vite_rs_dev_server::is_dev_server_running(port));
+}
+
+#[test]
+fn event_handlers_can_use_the_dev_server_during_a_restart() {
+    let root = TempDir::new("manual-restart-handler-test");
+    fake_vite::vite(&root, "#!/bin/sh\nexec sleep 30\n");
+
+    let port = vite_rs_dev_server::util::find_free_port(36000..37000).unwrap();
+    let options = DevServerOptions::new(root.to_str().unwrap(), port)
+        .with_stdio(DevServerStdio::Null)
+        .on_event(move |_| {
+            vite_rs_dev_server::is_dev_server_running(port);
+        });
+
+    let guard = vite_rs_dev_server::start_dev_server_with(options).unwrap();
+
+    // would deadlock if the events were emitted while the process was locked
+    let (restarted, done) = std::sync::mpsc::channel();
+    std::thread::spawn(move || restarted.send(vite_rs_dev_server::restart_dev_server_on(port)));
+    let restarted = done.recv_timeout(Duration::from_secs(10)).unwrap();
+
+    assert!(restarted.unwrap());
+    assert!(guard.is_running());
+}
@@ -0,0 +1,66 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio};
+
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// Puts a fake `npx` on `PATH` and starts dev servers for two roots, like two `Assets` structs would.
+#[test]
+fn dev_servers_for_different_roots_run_side_by_side() {
+    let dir = TempDir::new("multiple-servers-test");
+    let (admin, app) = (dir.join("admin"), dir.join("app"));
+    std::fs::create_dir_all(&admin).unwrap();
+    std::fs::create_dir_all(&app).unwrap();
+
+    fake_vite::npx(&dir, "#!/bin/sh\nwhile true; do sleep 0.1; done\n");
+
+    fake_vite::put_on_path(&dir);
+
+    let options = |root: &std::path::Path| {
+        DevServerOptions::new(root.to_str().unwrap(), free_port())
+            .with_host("127.0.0.1")
+            .with_stdio(DevServerStdio::Null)
+    };
+    let (admin_options, app_options) = (options(&admin), options(&app));
+
+    let admin_guard = vite_rs_dev_server::try_start_dev_server_with(admin_options.clone()).unwrap();
+    let app_guard = vite_rs_dev_server::try_start_dev_server_with(app_options.clone()).unwrap();
+
+    let registry = DevServerRegistry::global();
+    let admin_server = registry.get(admin_options.port).unwrap();
+    let app_server = registry.get(app_options.port).unwrap();
+    assert_eq!(admin_server.root_dir(), admin.to_str().unwrap());
+    assert!(admin_server.is_running());
+    assert!(app_server.is_running());
+
+    // stopping one dev server leaves the other alone
+    admin_guard.unwrap().shutdown().unwrap();
+    assert!(!admin_server.is_running());
+    assert!(app_server.is_running());
+    assert!(registry.get(admin_options.port).is_none());
+
+    // starting the app's dev server again (here on another port) replaces it
+    let restarted_options = options(&app);
+    let restarted_guard =
+        vite_rs_dev_server::try_start_dev_server_with(restarted_options.clone()).unwrap();
+    assert!(!app_server.is_running());
+    assert!(registry.get(app_options.port).is_none());
+    assert!(registry.get(restarted_options.port).unwrap().is_running());
+
+    // the replaced guard no longer owns a dev server, so dropping it doesn't stop the new one
+    drop(app_guard);
+    assert!(registry.get(restarted_options.port).unwrap().is_running());
+
+    vite_rs_dev_server::stop_dev_server_on(restarted_options.port);
+    assert!(registry.get(restarted_options.port).is_none());
+    drop(restarted_guard);
+}
@@ -0,0 +1,62 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Writes a fake `npx` to `path` which records its arguments in `<path>.txt`.
+fn fake_npx(path: &Path) {
+    fake_vite::script(
+        path,
+        &format!(
+            "#!/bin/sh\necho \"$@\" > {}.txt\nexec sleep 30\n",
+            path.display()
+        ),
+    );
+}
+
+/// Starts the dev server and waits until `npx` recorded its arguments.
+fn start(options: DevServerOptions, npx: &Path) -> String {
+    let _guard =
+        vite_rs_dev_server::start_dev_server_with(options.with_stdio(DevServerStdio::Null));
+
+    let output = npx.with_extension("txt");
+    let start = Instant::now();
+    loop {
+        match std::fs::read_to_string(&output) {
+            Ok(contents) if contents.ends_with('\n') => return contents,
+            _ if start.elapsed() > Duration::from_secs(5) => {
+                panic!("timed out waiting for {}", output.display())
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    }
+}
+
+/// Note: a single #[test] because it sets `VITE_RS_NPX_PATH` for the whole process.
+#[test]
+fn runs_the_configured_npx() {
+    let dir = TempDir::new("npx-path-test");
+    // neither is on `PATH`
+    let configured = dir.join("configured/npx");
+    let from_env = dir.join("from-env/npx");
+    fake_npx(&configured);
+    fake_npx(&from_env);
+
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_npx_path(configured.to_str().unwrap());
+
+    let args = start(options.clone(), &configured);
+    assert!(args.starts_with("vite --host localhost"), "{}", args);
+
+    // the environment variable takes precedence
+    std::env::set_var("VITE_RS_NPX_PATH", &from_env);
+    let args = start(options, &from_env);
+    assert!(args.starts_with("vite --host localhost"), "{}", args);
+    std::env::remove_var("VITE_RS_NPX_PATH");
+}
@@ -0,0 +1,51 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio, RestartPolicy};
+
+/// Uses a fake Vite which records its arguments each time it's started, and crashes the first
+/// time.
+#[test]
+fn opens_the_browser_when_the_dev_server_first_starts() {
+    let dir = TempDir::new("open-test");
+    fake_vite::vite(
+        &dir,
+        "#!/bin/sh\necho \"$@\" >> args.txt\nif [ ! -e started ]; then touch started; exit 1; fi\nexec sleep 30\n",
+    );
+
+    let port = vite_rs_dev_server::util::find_free_port(39000..40000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_restart(RestartPolicy::default().with_initial_backoff(Duration::from_millis(50)))
+        .with_open_url("http://127.0.0.1:3000/");
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+
+    let starts = || {
+        std::fs::read_to_string(dir.join("args.txt"))
+            .map(|args| args.lines().map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default()
+    };
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while starts().len() < 2 && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    let starts = starts();
+    assert_eq!(starts.len(), 2, "{:?}", starts);
+    assert!(
+        starts[0].ends_with("--open http://127.0.0.1:3000/"),
+        "{}",
+        starts[0]
+    );
+    assert!(!starts[1].contains("--open"), "{}", starts[1]);
+
+    drop(guard);
+}
@@ -0,0 +1,97 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use util::{env_var::EnvVar, fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio, LogLevel};
+
+/// Note: a single #[test] because it modifies `PATH` for the whole process.
+#[test]
+fn passes_options_to_the_dev_server_process() {
+    let dir = TempDir::new("options-test");
+
+    // a fake `npx` which records how it was started
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\necho \"$VITE_RS_TEST_ENV\" > env.txt\necho \"$VITE_RS_DEV_SERVER_URL $VITE_RS_PUBLIC_URL\" > urls.txt\necho \"$VITE_RS_HMR_PROTOCOL $VITE_RS_HMR_HOST $VITE_RS_HMR_CLIENT_PORT $__VITE_ADDITIONAL_SERVER_ALLOWED_HOSTS\" > hmr.txt\necho \"$@\" > args.txt\nprintf '%s\\n' \"$@\" > argv.txt\necho \"$NODE_OPTIONS\" > node_options.txt\necho 'this should not be printed'\nexec sleep 30\n",
+    );
+    fake_vite::put_on_path(&dir);
+    let _package_manager_args = EnvVar::set(
+        "VITE_RS_PACKAGE_MANAGER_ARGS",
+        " --yes  '--cache=/tmp/npm cache' ",
+    );
+    let _node_options = EnvVar::set("NODE_OPTIONS", "--enable-source-maps");
+
+    let port = vite_rs_dev_server::util::find_free_port(31000..32000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_public_url("https://vite.example.com/")
+        .with_public_host_allowed(true)
+        .with_config_file("vite.custom.config.ts")
+        .with_base("/app/")
+        .with_log_level(LogLevel::Warn)
+        .with_clear_screen(true)
+        .with_package_manager_args(["--registry=https://npm.example.com"])
+        .with_args(["--mode", "staging"])
+        .with_env("VITE_RS_TEST_ENV", "it works")
+        .with_node_options(["--max-old-space-size=8192"])
+        .with_stdio(DevServerStdio::Null);
+
+    assert!(!vite_rs_dev_server::is_dev_server_running(port));
+
+    let guard = vite_rs_dev_server::start_dev_server_with(options);
+    assert!(guard.as_ref().unwrap().is_running());
+    assert!(vite_rs_dev_server::is_dev_server_running(port));
+
+    let handle = DevServerRegistry::global().get(port).unwrap();
+    assert!(handle.is_running());
+    assert_eq!(handle.url(), format!("http://127.0.0.1:{}", port));
+
+    let args = wait_for_file(&dir.join("args.txt"));
+    assert_eq!(
+        args,
+        format!(
+            "--registry=https://npm.example.com --yes --cache=/tmp/npm cache vite --host 127.0.0.1 --port {} --strictPort --clearScreen true --logLevel warn --config vite.custom.config.ts --base /app/ --mode staging\n",
+            port
+        )
+    );
+    // quoted arguments from the environment variable aren't split
+    assert!(wait_for_file(&dir.join("argv.txt"))
+        .starts_with("--registry=https://npm.example.com\n--yes\n--cache=/tmp/npm cache\nvite\n"));
+    assert_eq!(wait_for_file(&dir.join("env.txt")), "it works\n");
+    // added to the inherited `NODE_OPTIONS`
+    assert_eq!(
+        wait_for_file(&dir.join("node_options.txt")),
+        "--enable-source-maps --max-old-space-size=8192\n"
+    );
+    assert_eq!(
+        wait_for_file(&dir.join("urls.txt")),
+        format!("http://127.0.0.1:{} https://vite.example.com\n", port)
+    );
+    assert_eq!(
+        wait_for_file(&dir.join("hmr.txt")),
+        "wss vite.example.com 443 vite.example.com\n"
+    );
+
+    guard.unwrap().shutdown().unwrap();
+    assert!(DevServerRegistry::global().get(port).is_none());
+    assert!(!handle.is_running());
+    assert!(!vite_rs_dev_server::is_dev_server_running(port));
+}
+
+fn wait_for_file(path: &Path) -> String {
+    let start = Instant::now();
+
+    loop {
+        match std::fs::read_to_string(path) {
+            Ok(contents) if contents.ends_with('\n') => return contents,
+            _ if start.elapsed() > Duration::from_secs(5) => {
+                panic!("timed out waiting for {}", path.display())
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    }
+}
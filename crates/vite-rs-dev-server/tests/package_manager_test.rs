@@ -0,0 +1,104 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio, PackageManager, Runtime};
+
+/// Note: a single #[test] because it modifies `PATH` for the whole process.
+#[test]
+fn runs_vite_through_the_selected_runtime_and_package_manager() {
+    let dir = TempDir::new("pm-test");
+
+    // fake package managers which record how they were started
+    for program in ["pnpm", "yarn", "bunx", "deno"] {
+        fake_vite::script(
+            &dir.join(program),
+            &format!(
+                "#!/bin/sh\necho \"{} $@\" > {}.txt\nexec sleep 30\n",
+                program, program
+            ),
+        );
+    }
+
+    fake_vite::put_on_path(&dir);
+
+    for (runtime, package_manager, program, expected, expected_with_args) in [
+        (
+            Runtime::Node,
+            PackageManager::Pnpm,
+            "pnpm",
+            "pnpm exec vite",
+            "pnpm --reporter=silent exec vite",
+        ),
+        (
+            Runtime::Node,
+            PackageManager::Yarn,
+            "yarn",
+            "yarn vite",
+            "yarn --reporter=silent vite",
+        ),
+        (
+            Runtime::Node,
+            PackageManager::Bun,
+            "bunx",
+            "bunx vite",
+            "bunx --reporter=silent vite",
+        ),
+        // the package manager doesn't matter for deno
+        (
+            Runtime::Deno,
+            PackageManager::Pnpm,
+            "deno",
+            "deno run -A npm:vite",
+            "deno run -A --reporter=silent npm:vite",
+        ),
+    ] {
+        let options = DevServerOptions::new(dir.to_str().unwrap(), 0)
+            .with_runtime(runtime)
+            .with_package_manager(package_manager)
+            .with_stdio(DevServerStdio::Null);
+
+        expect_started_with(&dir, program, options.clone(), expected);
+        // placed before `vite` (or `npm:vite`)
+        expect_started_with(
+            &dir,
+            program,
+            options.with_package_manager_args(["--reporter=silent"]),
+            expected_with_args,
+        );
+    }
+}
+
+/// Starts the dev server with `options` (on a free port), and checks how the fake `program` was
+/// run.
+fn expect_started_with(dir: &Path, program: &str, mut options: DevServerOptions, expected: &str) {
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    options.port = port;
+
+    // left behind by the previous run
+    let output = dir.join(format!("{}.txt", program));
+    std::fs::remove_file(&output).ok();
+
+    let _guard = vite_rs_dev_server::start_dev_server_with(options);
+
+    let start = Instant::now();
+    let args = loop {
+        match std::fs::read_to_string(&output) {
+            Ok(contents) if contents.ends_with('\n') => break contents,
+            _ if start.elapsed() > Duration::from_secs(5) => {
+                panic!("timed out waiting for {}", output.display())
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    };
+
+    assert!(
+        args.starts_with(&format!("{} --host localhost --port {} ", expected, port)),
+        "{}",
+        args
+    );
+}
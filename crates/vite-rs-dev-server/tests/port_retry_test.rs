@@ -0,0 +1,57 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerError, DevServerOptions, DevServerStdio};
+
+/// Puts a fake `npx` on `PATH`, which keeps running.
+/// Note: a single #[test] because it modifies `PATH` for the whole process.
+#[test]
+fn retries_while_the_port_is_taken() {
+    let dir = TempDir::new("port-retry-test");
+
+    fake_vite::npx(&dir, "#!/bin/sh\nexec sleep 30\n");
+    fake_vite::put_on_path(&dir);
+
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let options = || {
+        DevServerOptions::new(dir.to_str().unwrap(), port)
+            .with_host("127.0.0.1")
+            .with_stdio(DevServerStdio::Null)
+    };
+
+    // without waiting or retries, a taken port fails right away
+    let start = Instant::now();
+    let result = vite_rs_dev_server::try_start_dev_server_with(
+        options()
+            .with_port_wait(Duration::ZERO)
+            .with_port_retries(0),
+    );
+    assert!(matches!(
+        result,
+        Err(DevServerError::PortUnavailable { port: p }) if p == port
+    ));
+    assert!(start.elapsed() < Duration::from_millis(100));
+
+    // the port is released while retrying (e.g. a previous dev server shutting down)
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        drop(listener);
+    });
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(
+        options()
+            .with_port_wait(Duration::ZERO)
+            .with_port_retries(5),
+    )
+    .unwrap();
+    assert!(guard.is_some());
+    release.join().unwrap();
+
+    drop(guard);
+}
@@ -0,0 +1,64 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerError, DevServerOptions, DevServerStdio};
+
+/// Installs a fake Vite in a temporary root, which keeps running.
+fn root(name: &str) -> TempDir {
+    let root = TempDir::new(&format!("port-wait-test-{}", name));
+    fake_vite::vite(&root, "#!/bin/sh\nexec sleep 30\n");
+
+    root
+}
+
+fn options(root: &std::path::Path, port: u16) -> DevServerOptions {
+    DevServerOptions::new(root.to_str().unwrap(), port)
+        .with_stdio(DevServerStdio::Null)
+        .with_port_retries(0)
+}
+
+#[test]
+fn waits_for_the_port_to_free_up() {
+    let root = root("free");
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    // e.g. the dev server of a previous run, still exiting
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(500));
+        drop(listener);
+    });
+
+    let start = Instant::now();
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options(&root, port)).unwrap();
+    assert!(guard.is_some());
+    assert!(start.elapsed() >= Duration::from_millis(400));
+    release.join().unwrap();
+
+    drop(guard);
+}
+
+#[test]
+fn fails_when_the_port_stays_taken() {
+    let root = root("taken");
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let start = Instant::now();
+    let result = vite_rs_dev_server::try_start_dev_server_with(
+        options(&root, port).with_port_wait(Duration::from_millis(300)),
+    );
+    assert!(matches!(
+        result,
+        Err(DevServerError::PortUnavailable { port: p }) if p == port
+    ));
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert!(!vite_rs_dev_server::is_dev_server_running(port));
+
+    drop(listener);
+}
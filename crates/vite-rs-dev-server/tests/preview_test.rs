@@ -0,0 +1,44 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+#[test]
+fn runs_vite_preview_on_the_output_dir() {
+    let root = TempDir::new("preview-test");
+
+    // records how it was started
+    fake_vite::vite(&root, "#!/bin/sh\necho \"$@\" > vite.txt\nexec sleep 30\n");
+
+    let port = vite_rs_dev_server::util::find_free_port(34000..35000).unwrap();
+    let options = DevServerOptions::new(root.to_str().unwrap(), port)
+        .with_preview("./build")
+        .with_stdio(DevServerStdio::Null);
+
+    let _guard = vite_rs_dev_server::start_dev_server_with(options);
+
+    let output = root.join("vite.txt");
+    let start = Instant::now();
+    let invocation = loop {
+        match std::fs::read_to_string(&output) {
+            Ok(contents) if contents.ends_with('\n') => break contents,
+            _ if start.elapsed() > Duration::from_secs(5) => {
+                panic!("timed out waiting for {}", output.display())
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    };
+
+    assert!(
+        invocation.starts_with(&format!(
+            "preview --outDir ./build --host localhost --port {} --strictPort ",
+            port
+        )),
+        "{}",
+        invocation
+    );
+}
@@ -0,0 +1,53 @@
+#![cfg(vite_rs_dev)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use vite_rs_dev_server::transport::{self, FetchOptions};
+
+#[test]
+fn fetches_the_urls_once_the_dev_server_responds() {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let origin = vite_rs_dev_server::util::http_origin("127.0.0.1", port);
+
+    let prewarm = transport::prewarm(
+        vec![
+            format!("{}/index.html", origin),
+            format!("{}/src/main.ts", origin),
+        ],
+        FetchOptions::default(),
+    );
+
+    // the dev server starts a little later
+    std::thread::sleep(Duration::from_millis(300));
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+
+    let mut requested = Vec::new();
+    for stream in listener.incoming().take(2) {
+        let mut reader = BufReader::new(stream.unwrap());
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        requested.push(request_line.split_whitespace().nth(1).unwrap().to_string());
+
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+            line.clear();
+        }
+
+        reader
+            .into_inner()
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+            .unwrap();
+    }
+
+    prewarm.join().unwrap();
+
+    requested.sort();
+    assert_eq!(requested, ["/index.html", "/src/main.ts"]);
+}
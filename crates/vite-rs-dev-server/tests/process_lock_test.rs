@@ -0,0 +1,91 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::util::RuntimePort;
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Set in the child process, which tries to start a dev server for the same root.
+const CHILD_PORT: &str = "VITE_RS_PROCESS_LOCK_TEST_PORT";
+const CHILD_DIR: &str = "VITE_RS_PROCESS_LOCK_TEST_DIR";
+
+/// Starts a dev server (with a fake `npx`), then runs this test again in a child process, which
+/// uses that dev server instead of starting another one.
+#[test]
+fn a_second_process_uses_the_running_dev_server() {
+    if let (Ok(port), Some(dir)) = (std::env::var(CHILD_PORT), std::env::var_os(CHILD_DIR)) {
+        return run_child(Path::new(&dir), port.parse().unwrap());
+    }
+
+    let dir = TempDir::new("process-lock-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\necho started >> starts.txt\nexec sleep 30\n",
+    );
+    fake_vite::put_on_path(&dir);
+
+    let port = vite_rs_dev_server::util::find_free_port(31000..32000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null);
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+    while !dir.join("starts.txt").exists() {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "a_second_process_uses_the_running_dev_server",
+            "--exact",
+            "--nocapture",
+        ])
+        .env(CHILD_PORT, port.to_string())
+        .env(CHILD_DIR, dir.as_os_str())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains(&format!(
+            "vite-rs: using the ViteJS dev server started by another process at http://127.0.0.1:{}",
+            port
+        )),
+        "{:?}",
+        output
+    );
+
+    drop(guard);
+}
+
+fn run_child(dir: &Path, port: u16) {
+    static RUNTIME_PORT: RuntimePort = RuntimePort::new();
+
+    // picked at runtime, like with `#[dev_server_port = "auto"]`, so it can follow the other process
+    let options = DevServerOptions::new(dir.to_str().unwrap(), RUNTIME_PORT.get())
+        .with_host("127.0.0.1")
+        .with_runtime_port(&RUNTIME_PORT)
+        .with_stdio(DevServerStdio::Null);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_none());
+    assert_eq!(RUNTIME_PORT.get(), port);
+    // only the other process started one
+    assert_eq!(
+        std::fs::read_to_string(dir.join("starts.txt")).unwrap(),
+        "started\n"
+    );
+
+    // without the lock, it starts its own
+    let options = DevServerOptions::new(dir.to_str().unwrap(), RUNTIME_PORT.repick())
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_process_lock(false);
+    fake_vite::put_on_path(dir);
+    assert!(vite_rs_dev_server::try_start_dev_server_with(options)
+        .unwrap()
+        .is_some());
+}
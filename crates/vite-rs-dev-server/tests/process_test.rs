@@ -0,0 +1,53 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::time::Duration;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio, RestartPolicy};
+
+/// Puts a fake `npx` on `PATH` which ignores SIGTERM, so only `kill` can stop it right away.
+#[test]
+fn exposes_and_kills_the_dev_server_process() {
+    let dir = TempDir::new("process-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\ntrap '' TERM\necho $$ > pid\nwhile true; do sleep 0.1; done\n",
+    );
+
+    fake_vite::put_on_path(&dir);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_shutdown_grace_period(Duration::from_secs(30))
+        .with_restart(RestartPolicy::default().with_initial_backoff(Duration::from_millis(10)));
+
+    let process = vite_rs_dev_server::try_start_dev_server_with(options)
+        .unwrap()
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+
+    assert_eq!(
+        std::fs::read_to_string(dir.join("pid")).unwrap().trim(),
+        process.pid().to_string()
+    );
+    assert!(process.try_wait().unwrap().is_none());
+
+    // no grace period, and the supervisor doesn't restart it
+    process.kill().unwrap();
+    assert!(process.try_wait().unwrap().is_some());
+    assert!(DevServerRegistry::global().get(port).is_none());
+
+    std::thread::sleep(Duration::from_millis(500));
+    assert!(!process.is_running());
+
+    drop(process);
+}
@@ -0,0 +1,78 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::time::Duration;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Set in the child process, which starts the dev server (with a pseudo-terminal if it's `pty`).
+const CHILD_MODE: &str = "VITE_RS_PTY_TEST_MODE";
+const CHILD_DIR: &str = "VITE_RS_PTY_TEST_DIR";
+
+/// Runs this test again in child processes, whose output is checked, with a fake Vite which tells
+/// whether its stdout and stderr are terminals, and starts a background process (recording its
+/// PID).
+#[test]
+fn runs_the_dev_server_in_a_pty() {
+    if let (Some(mode), Some(dir)) = (std::env::var_os(CHILD_MODE), std::env::var_os(CHILD_DIR)) {
+        return run_child(Path::new(&dir), mode == "pty");
+    }
+
+    let dir = TempDir::new("pty-test");
+    fake_vite::vite(
+        &dir,
+        "#!/bin/sh\nsleep 30 &\necho $! > background.pid\nif [ -t 1 ]; then echo 'stdout: tty'; else echo 'stdout: no tty'; fi\nif [ -t 2 ]; then echo 'stderr: tty' >&2; else echo 'stderr: no tty' >&2; fi\nexec sleep 30\n",
+    );
+
+    let run = |mode: &str| {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["runs_the_dev_server_in_a_pty", "--exact", "--nocapture"])
+            .env(CHILD_MODE, mode)
+            .env(CHILD_DIR, dir.as_os_str())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{:?}", output);
+
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+
+    // stderr is written to the terminal too, so its lines are forwarded as stdout
+    let stdout = run("pty");
+    assert!(stdout.contains("[frontend] stdout: tty\n"), "{}", stdout);
+    assert!(stdout.contains("[frontend] stderr: tty\n"), "{}", stdout);
+
+    // the background process was stopped with the dev server (once it's reaped, as it's orphaned)
+    let pid = std::fs::read_to_string(dir.join("background.pid")).unwrap();
+    let running = || {
+        std::process::Command::new("kill")
+            .args(["-0", pid.trim()])
+            .status()
+            .unwrap()
+            .success()
+    };
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while running() && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(
+        !running(),
+        "the dev server's background process is still running"
+    );
+
+    let stdout = run("pipe");
+    assert!(stdout.contains("[frontend] stdout: no tty\n"), "{}", stdout);
+}
+
+fn run_child(dir: &Path, pty: bool) {
+    let port = vite_rs_dev_server::util::find_free_port(37000..38000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_stdio(DevServerStdio::Prefixed("[frontend]".to_string()))
+        .with_pty(pty);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    std::thread::sleep(Duration::from_millis(500));
+    drop(guard);
+}
@@ -0,0 +1,70 @@
+use vite_rs_dev_server::{DevServerOptions, RemoteEnvironment};
+
+/// Note: a single #[test] because it modifies environment variables for the whole process.
+#[test]
+fn resolves_the_public_origin() {
+    for var in [
+        "VITE_RS_PUBLIC_URL",
+        "CODESPACES",
+        "CODESPACE_NAME",
+        "GITHUB_CODESPACES_PORT_FORWARDING_DOMAIN",
+        "GITPOD_WORKSPACE_URL",
+    ] {
+        std::env::remove_var(var);
+    }
+
+    assert_eq!(RemoteEnvironment::detect(), None);
+
+    let options = DevServerOptions::new(".", 21012);
+    assert_eq!(options.origin(), "http://localhost:21012");
+    assert_eq!(options.public_origin(), "http://localhost:21012");
+
+    let options = DevServerOptions::new(".", 21012).with_host("::1");
+    assert_eq!(options.origin(), "http://[::1]:21012");
+    assert_eq!(options.public_origin(), "http://[::1]:21012");
+
+    // Gitpod
+    std::env::set_var(
+        "GITPOD_WORKSPACE_URL",
+        "https://wulf-viters-abc123.ws-eu116.gitpod.io",
+    );
+    assert_eq!(RemoteEnvironment::detect(), Some(RemoteEnvironment::Gitpod));
+    assert_eq!(
+        DevServerOptions::new(".", 21012)
+            .with_remote_environment_detection(true)
+            .public_origin(),
+        "https://21012-wulf-viters-abc123.ws-eu116.gitpod.io"
+    );
+    // only when asked to
+    assert_eq!(
+        DevServerOptions::new(".", 21012).public_origin(),
+        "http://localhost:21012"
+    );
+    std::env::remove_var("GITPOD_WORKSPACE_URL");
+
+    // GitHub Codespaces
+    std::env::set_var("CODESPACES", "true");
+    std::env::set_var("CODESPACE_NAME", "fuzzy-space-guide");
+    std::env::set_var("GITHUB_CODESPACES_PORT_FORWARDING_DOMAIN", "app.github.dev");
+
+    assert_eq!(
+        RemoteEnvironment::detect(),
+        Some(RemoteEnvironment::Codespaces)
+    );
+
+    let options = DevServerOptions::new(".", 21012).with_remote_environment_detection(true);
+    assert_eq!(options.origin(), "http://localhost:21012");
+    assert_eq!(
+        options.public_origin(),
+        "https://fuzzy-space-guide-21012.app.github.dev"
+    );
+
+    // configured in code
+    let options = options.with_public_url("https://vite.example.com/");
+    assert_eq!(options.public_origin(), "https://vite.example.com");
+
+    // the environment variable wins, e.g. for devcontainers
+    std::env::set_var("VITE_RS_PUBLIC_URL", "https://localhost:8443");
+    assert_eq!(options.public_origin(), "https://localhost:8443");
+    assert_eq!(options.origin(), "http://localhost:21012");
+}
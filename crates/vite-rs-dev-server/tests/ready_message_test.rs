@@ -0,0 +1,85 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::time::Duration;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Set in the child process, which starts the dev server with the ready message on this port.
+const CHILD_PORT: &str = "VITE_RS_READY_MESSAGE_TEST_PORT";
+const CHILD_DIR: &str = "VITE_RS_READY_MESSAGE_TEST_DIR";
+
+/// Runs this test again in a child process, whose output is checked, with a fake Vite. Something
+/// else answers HTTP requests on its port, standing in for Vite.
+#[test]
+fn prints_where_assets_are_served_from_once_ready() {
+    if let (Some(port), Some(dir)) = (std::env::var_os(CHILD_PORT), std::env::var_os(CHILD_DIR)) {
+        let port = port.to_str().unwrap().parse().unwrap();
+        return run_child(Path::new(&dir), port);
+    }
+
+    let dir = TempDir::new("ready-message-test");
+    fake_vite::vite(&dir, "#!/bin/sh\nexec sleep 30\n");
+
+    let port = vite_rs_dev_server::util::find_free_port(38000..39000).unwrap();
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "prints_where_assets_are_served_from_once_ready",
+            "--exact",
+            "--nocapture",
+        ])
+        .env(CHILD_PORT, port.to_string())
+        .env(CHILD_DIR, dir.as_os_str())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let message = format!(
+        "vite-rs: assets served from http://127.0.0.1:{} (proxied)\n",
+        port
+    );
+    assert_eq!(stdout.matches(&message).count(), 1, "{}", stdout);
+}
+
+/// Without a ready timeout, so the dev server is polled in the background until it's ready.
+fn run_child(dir: &Path, port: u16) {
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_ready_message(true);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    answer_on(port);
+    std::thread::sleep(Duration::from_millis(1500));
+    drop(guard);
+}
+
+fn answer_on(port: u16) {
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+
+            // read the whole request, so closing the connection doesn't reset it
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(n) if n > 0 => request.extend_from_slice(&buf[..n]),
+                    _ => break,
+                }
+            }
+
+            let _ = stream.write_all(
+                b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            );
+        }
+    });
+}
@@ -0,0 +1,83 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerError, DevServerOptions, DevServerRegistry, DevServerStdio};
+
+/// Puts a fake `npx` on `PATH`, which exits with `$FAKE_VITE_EXIT_CODE` if set, or keeps running.
+/// Note: a single #[test] because it modifies `PATH` for the whole process.
+#[test]
+fn waits_until_the_dev_server_is_ready() {
+    let dir = TempDir::new("ready-test");
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\nif [ -n \"$FAKE_VITE_EXIT_CODE\" ]; then exit \"$FAKE_VITE_EXIT_CODE\"; fi\nexec sleep 30\n",
+    );
+    fake_vite::put_on_path(&dir);
+
+    let options = |port| {
+        DevServerOptions::new(dir.to_str().unwrap(), port)
+            .with_host("127.0.0.1")
+            .with_stdio(DevServerStdio::Null)
+            .with_ready_timeout(Duration::from_secs(5))
+    };
+
+    // ready: something starts answering on the port a little later (standing in for Vite)
+    let port = free_port();
+    let server = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+
+        // read the whole request, so closing the connection doesn't reset it
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            assert!(n > 0, "connection closed before the request was read");
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        stream
+            .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .unwrap();
+    });
+
+    let start = Instant::now();
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options(port)).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert!(guard.is_some());
+    server.join().unwrap();
+    drop(guard);
+
+    // exited: the process fails before it's ready
+    let port = free_port();
+    let result = vite_rs_dev_server::try_start_dev_server_with(
+        options(port).with_env("FAKE_VITE_EXIT_CODE", "3"),
+    );
+    match result {
+        Err(DevServerError::Exited(status)) => assert_eq!(status.code(), Some(3)),
+        _ => panic!("expected DevServerError::Exited"),
+    }
+    assert!(DevServerRegistry::global().get(port).is_none());
+
+    // not ready: nothing ever answers
+    let port = free_port();
+    let result = vite_rs_dev_server::try_start_dev_server_with(
+        options(port).with_ready_timeout(Duration::from_millis(300)),
+    );
+    assert!(matches!(
+        result,
+        Err(DevServerError::NotReady { timeout }) if timeout == Duration::from_millis(300)
+    ));
+    assert!(DevServerRegistry::global().get(port).is_none());
+}
+
+fn free_port() -> u16 {
+    vite_rs_dev_server::util::find_free_port(32000..33000).unwrap()
+}
@@ -0,0 +1,101 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use util::temp_dir::TempDir;
+use vite_rs_dev_server::transport::{self, FetchOptions, ResponseCache};
+
+/// Serves `main.js` with an ETag, answering `304 Not Modified` when the client has it already.
+/// Records how many times the body was sent.
+fn serve_with_etag(full_responses: Arc<AtomicUsize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+
+            let mut if_none_match = None;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                if let Some(etag) = line.to_lowercase().strip_prefix("if-none-match: ") {
+                    if_none_match = Some(etag.trim().to_string());
+                }
+                line.clear();
+            }
+
+            let response: &[u8] = if if_none_match.as_deref() == Some("w/\"2-abc\"") {
+                b"HTTP/1.1 304 Not Modified\r\nETag: W/\"2-abc\"\r\nConnection: close\r\n\r\n"
+            } else {
+                full_responses.fetch_add(1, Ordering::SeqCst);
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/javascript\r\nETag: W/\"2-abc\"\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+            };
+            reader.into_inner().write_all(response).unwrap();
+        }
+    });
+
+    vite_rs_dev_server::util::http_origin(&addr.ip().to_string(), addr.port())
+}
+
+#[test]
+fn unchanged_responses_are_not_transferred_again() {
+    let full_responses = Arc::new(AtomicUsize::new(0));
+    let url = format!("{}/main.js", serve_with_etag(full_responses.clone()));
+
+    let options = FetchOptions::default().with_cache(Arc::new(ResponseCache::new()));
+
+    for _ in 0..3 {
+        let res = transport::get_with(&url, &options).unwrap();
+        assert_eq!(res.status, 200);
+        assert_eq!(res.bytes, b"ok");
+        assert_eq!(res.content_type.as_deref(), Some("text/javascript"));
+    }
+
+    assert_eq!(full_responses.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn persistent_cache_survives_restarts() {
+    // created by the cache
+    let dir = TempDir::new("response-cache-test");
+    std::fs::remove_dir_all(&dir).ok();
+
+    let full_responses = Arc::new(AtomicUsize::new(0));
+    let url = format!("{}/main.js", serve_with_etag(full_responses.clone()));
+
+    // a cache per "run" of the binary, sharing the directory
+    let fetch = || {
+        let cache = Arc::new(ResponseCache::persistent(dir.to_path_buf()));
+        let options = FetchOptions::default().with_cache(cache);
+        transport::get_with(&url, &options).unwrap()
+    };
+
+    assert_eq!(fetch().bytes, b"ok");
+    assert_eq!(full_responses.load(Ordering::SeqCst), 1);
+
+    let res = fetch();
+    assert_eq!(res.status, 200);
+    assert_eq!(res.bytes, b"ok");
+    assert_eq!(res.etag.as_deref(), Some("W/\"2-abc\""));
+    assert_eq!(full_responses.load(Ordering::SeqCst), 1);
+
+    // the dev server may run on another port next time
+    let other_url = format!("{}/main.js", serve_with_etag(full_responses.clone()));
+    let cache = Arc::new(ResponseCache::persistent(dir.to_path_buf()));
+    let options = FetchOptions::default().with_cache(cache.clone());
+    assert_eq!(
+        transport::get_with(&other_url, &options).unwrap().bytes,
+        b"ok"
+    );
+    assert_eq!(full_responses.load(Ordering::SeqCst), 1);
+
+    cache.clear();
+    assert!(!dir.exists());
+    assert_eq!(fetch().bytes, b"ok");
+    assert_eq!(full_responses.load(Ordering::SeqCst), 2);
+}
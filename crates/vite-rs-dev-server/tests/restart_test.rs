@@ -0,0 +1,89 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio, RestartPolicy};
+
+/// Puts a fake `npx` on `PATH`, which crashes the first two times it's started and keeps running after that.
+#[test]
+fn restarts_the_dev_server_when_it_exits() {
+    let dir = TempDir::new("restart-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\nn=$(cat starts 2>/dev/null || echo 0)\nn=$((n + 1))\necho $n > starts\nif [ $n -le 2 ]; then exit 1; fi\nexec sleep 30\n",
+    );
+
+    fake_vite::put_on_path(&dir);
+
+    let restarts = Arc::new(Mutex::new(Vec::new()));
+    let restart = RestartPolicy::default()
+        .with_initial_backoff(Duration::from_millis(50))
+        .with_max_backoff(Duration::from_secs(10))
+        .on_restart({
+            let restarts = restarts.clone();
+            move |restart| {
+                restarts.lock().unwrap().push((
+                    restart.attempt,
+                    restart.status.code(),
+                    restart.backoff,
+                ))
+            }
+        });
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_restart(restart);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+
+    let starts = || {
+        std::fs::read_to_string(dir.join("starts"))
+            .map(|starts| starts.trim().parse::<u32>().unwrap())
+            .unwrap_or(0)
+    };
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while starts() < 3 && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert_eq!(starts(), 3);
+
+    assert_eq!(
+        *restarts.lock().unwrap(),
+        vec![
+            (1, Some(1), Duration::from_millis(50)),
+            (2, Some(1), Duration::from_millis(100)),
+        ]
+    );
+
+    // the restarted process is the one the guard and the registry point to
+    let handle = DevServerRegistry::global().get(port).unwrap();
+    assert_eq!(handle.port(), port);
+    assert!(guard
+        .as_ref()
+        .unwrap()
+        .0
+        .lock()
+        .unwrap()
+        .try_wait()
+        .unwrap()
+        .is_none());
+
+    // stopping the dev server doesn't restart it
+    drop(guard);
+    std::thread::sleep(Duration::from_millis(500));
+    assert_eq!(starts(), 3);
+    assert_eq!(restarts.lock().unwrap().len(), 2);
+}
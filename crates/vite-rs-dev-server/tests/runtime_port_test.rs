@@ -0,0 +1,54 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::net::TcpListener;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::util::RuntimePort;
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio};
+
+static PORT: RuntimePort = RuntimePort::new();
+
+/// Puts a fake `npx` on `PATH`, which keeps running.
+/// Note: a single #[test] because it modifies `PATH` for the whole process.
+#[test]
+fn picks_another_port_when_it_is_taken() {
+    let dir = TempDir::new("runtime-port-test");
+
+    fake_vite::npx(&dir, "#!/bin/sh\nexec sleep 30\n");
+    fake_vite::put_on_path(&dir);
+
+    let options = DevServerOptions::new(dir.to_str().unwrap(), 0)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_runtime_port(&PORT);
+    let picked = options.port;
+    assert_eq!(picked, PORT.get());
+
+    // something else takes the port before the dev server starts
+    let _listener = TcpListener::bind(("0.0.0.0", picked)).unwrap();
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+
+    assert_ne!(PORT.get(), picked);
+    assert_eq!(DevServerRegistry::global().ports(), [PORT.get()]);
+
+    drop(guard);
+}
+
+#[test]
+fn prefers_the_given_port_unless_it_is_taken() {
+    let free = vite_rs_dev_server::util::find_free_port(41000..41500).unwrap();
+    assert_eq!(RuntimePort::preferring(free).get(), free);
+
+    let taken = vite_rs_dev_server::util::find_free_port(41500..42000).unwrap();
+    let _listener = TcpListener::bind(("0.0.0.0", taken)).unwrap();
+
+    let port = RuntimePort::preferring(taken);
+    let next = port.get();
+    assert!(next > taken);
+    assert_eq!(port.get(), next);
+    assert!(port.repick() > next);
+}
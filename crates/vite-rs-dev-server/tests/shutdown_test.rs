@@ -0,0 +1,71 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerRegistry, DevServerStdio};
+
+/// Puts a fake `npx` on `PATH`, which handles SIGTERM unless `IGNORE_SIGTERM` is set.
+#[test]
+fn stops_the_dev_server_gracefully_then_kills_it() {
+    let dir = TempDir::new("shutdown-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\nif [ -n \"$IGNORE_SIGTERM\" ]; then trap '' TERM; else trap 'echo terminated > stopped; exit 0' TERM; fi\nwhile true; do sleep 0.1; done\n",
+    );
+
+    fake_vite::put_on_path(&dir);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null);
+
+    // the dev server gets to shut down on its own
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options.clone()).unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+    let handle = DevServerRegistry::global().get(port).unwrap();
+
+    guard.unwrap().shutdown().unwrap();
+    assert!(!handle.is_running());
+    assert_eq!(
+        std::fs::read_to_string(dir.join("stopped")).unwrap().trim(),
+        "terminated"
+    );
+
+    // one that doesn't is killed after the grace period
+    let options = options
+        .with_env("IGNORE_SIGTERM", "1")
+        .with_shutdown_grace_period(Duration::from_millis(500));
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options.clone()).unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+    let handle = DevServerRegistry::global().get(port).unwrap();
+
+    let stopping = Instant::now();
+    guard.unwrap().shutdown().unwrap();
+    assert!(!handle.is_running());
+    assert!(stopping.elapsed() >= Duration::from_millis(500));
+
+    // dropping the guard doesn't wait for the grace period: it's killed on another thread
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+    let handle = DevServerRegistry::global().get(port).unwrap();
+
+    let stopping = Instant::now();
+    drop(guard);
+    assert!(stopping.elapsed() < Duration::from_millis(500));
+    assert!(handle.is_running());
+
+    while handle.is_running() && stopping.elapsed() < Duration::from_secs(10) {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(!handle.is_running());
+    assert!(stopping.elapsed() >= Duration::from_millis(500));
+}
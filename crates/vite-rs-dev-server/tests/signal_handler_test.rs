@@ -0,0 +1,80 @@
+#![cfg(all(unix, feature = "signal-hook", vite_rs_dev))]
+
+mod util;
+
+use std::os::unix::process::ExitStatusExt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Set in the child process, which starts the dev server and waits to be sent SIGTERM.
+const CHILD_DIR: &str = "VITE_RS_SIGNAL_HANDLER_TEST_DIR";
+
+/// Runs this test again in a child process (the handler terminates it), with a fake `npx` which
+/// records that it was asked to shut down.
+#[test]
+fn stops_the_dev_server_on_sigterm() {
+    if let Some(dir) = std::env::var_os(CHILD_DIR) {
+        return run_child(Path::new(&dir));
+    }
+
+    let dir = TempDir::new("signal-handler-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\ntrap 'echo terminated > stopped; exit 0' TERM\nwhile true; do sleep 0.1; done\n",
+    );
+
+    let mut child = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["stops_the_dev_server_on_sigterm", "--exact", "--nocapture"])
+        .env(CHILD_DIR, dir.as_os_str())
+        .spawn()
+        .unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !dir.join("started").exists() {
+        assert!(
+            Instant::now() < deadline,
+            "the child never started the dev server"
+        );
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    let killed = std::process::Command::new("kill")
+        .args(["-TERM", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(killed.success());
+
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(15), "{:?}", status);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("stopped")).unwrap().trim(),
+        "terminated"
+    );
+}
+
+fn run_child(dir: &Path) {
+    fake_vite::put_on_path(dir);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_signal_handler(true);
+
+    let _guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    // give the fake `npx` time to set up its trap
+    std::thread::sleep(Duration::from_millis(300));
+    std::fs::write(dir.join("started"), "").unwrap();
+
+    loop {
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
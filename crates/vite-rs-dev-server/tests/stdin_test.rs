@@ -0,0 +1,99 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Set in the child process, which starts the dev server with stdin inherited.
+const CHILD_DIR: &str = "VITE_RS_STDIN_TEST_DIR";
+
+/// Creates a directory with a fake `npx`, which writes the first line it reads from stdin to
+/// `received`.
+fn fake_npx_dir(name: &str) -> TempDir {
+    let dir = TempDir::new(name);
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\nread line\necho \"$line\" > received.tmp\nmv received.tmp received\nexec sleep 30\n",
+    );
+
+    dir
+}
+
+fn options(dir: &Path) -> DevServerOptions {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+}
+
+fn wait_for_received(dir: &Path) -> String {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !dir.join("received").exists() {
+        assert!(
+            Instant::now() < deadline,
+            "the dev server never read its stdin"
+        );
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    std::fs::read_to_string(dir.join("received")).unwrap()
+}
+
+#[test]
+fn stdin_is_empty_by_default() {
+    let dir = fake_npx_dir("stdin-null-test");
+    fake_vite::put_on_path(&dir);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options(&dir)).unwrap();
+    assert_eq!(wait_for_received(&dir), "\n");
+    drop(guard);
+}
+
+/// Runs this test again in a child process, with a shortcut written to its stdin.
+#[test]
+fn inherit_stdin_passes_stdin_to_the_dev_server() {
+    if let Some(dir) = std::env::var_os(CHILD_DIR) {
+        return run_child(Path::new(&dir));
+    }
+
+    let dir = fake_npx_dir("stdin-inherit-test");
+
+    let mut child = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "inherit_stdin_passes_stdin_to_the_dev_server",
+            "--exact",
+            "--nocapture",
+        ])
+        .env(CHILD_DIR, dir.as_os_str())
+        .stdin(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"r\n").unwrap();
+
+    assert!(child.wait().unwrap().success());
+    assert_eq!(
+        std::fs::read_to_string(dir.join("received")).unwrap(),
+        "r\n"
+    );
+}
+
+fn run_child(dir: &Path) {
+    fake_vite::put_on_path(dir);
+
+    let options = options(dir).with_inherit_stdin(true);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    wait_for_received(dir);
+    drop(guard);
+}
@@ -0,0 +1,92 @@
+#![cfg(all(unix, feature = "log", vite_rs_dev))]
+
+mod util;
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+static RECORDS: Mutex<Vec<(log::Level, String, String)>> = Mutex::new(Vec::new());
+
+struct CapturingLogger;
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        RECORDS.lock().unwrap().push((
+            record.level(),
+            record.target().to_string(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+/// Puts a fake `npx` on `PATH`, which prints a line to stdout and stderr and keeps running.
+#[test]
+fn forwards_output_to_log() {
+    log::set_logger(&CapturingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let dir = TempDir::new("stdio-log-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\necho '  VITE ready'\necho 'something went wrong' >&2\nexec sleep 30\n",
+    );
+    fake_vite::put_on_path(&dir);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Log);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+
+    let expected = [
+        (
+            log::Level::Info,
+            "vite".to_string(),
+            "  VITE ready".to_string(),
+        ),
+        (
+            log::Level::Warn,
+            "vite".to_string(),
+            "something went wrong".to_string(),
+        ),
+    ];
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        let records = RECORDS.lock().unwrap();
+        if expected.iter().all(|record| records.contains(record)) {
+            break;
+        }
+        drop(records);
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    let records = RECORDS.lock().unwrap();
+    for record in &expected {
+        assert!(
+            records.contains(record),
+            "{:?} not in {:?}",
+            record,
+            records
+        );
+    }
+    drop(records);
+
+    drop(guard);
+}
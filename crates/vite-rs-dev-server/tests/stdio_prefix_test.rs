@@ -0,0 +1,67 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::time::Duration;
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+/// Set in the child process, which starts the dev server with prefixed output.
+const CHILD_DIR: &str = "VITE_RS_STDIO_PREFIX_TEST_DIR";
+
+/// Runs this test again in a child process, whose output is checked, with a fake `npx` on `PATH`
+/// which prints a line to stdout and stderr.
+#[test]
+fn prefixes_output_lines() {
+    if let Some(dir) = std::env::var_os(CHILD_DIR) {
+        return run_child(Path::new(&dir));
+    }
+
+    let dir = TempDir::new("stdio-prefix-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\necho '  VITE ready'\necho\necho 'something went wrong' >&2\nexec sleep 30\n",
+    );
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["prefixes_output_lines", "--exact", "--nocapture"])
+        .env(CHILD_DIR, dir.as_os_str())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    // the test harness prints its own output around (and on the same line as) the lines
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stdout.contains("[assets:frontend]   VITE ready\n"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("[assets:frontend] \n"), "{}", stdout);
+    assert!(
+        stderr.contains("[assets:frontend] something went wrong\n"),
+        "{}",
+        stderr
+    );
+}
+
+fn run_child(dir: &Path) {
+    fake_vite::put_on_path(dir);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Prefixed("[assets:frontend]".to_string()));
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    std::thread::sleep(Duration::from_millis(500));
+    drop(guard);
+}
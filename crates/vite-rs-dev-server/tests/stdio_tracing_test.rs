@@ -0,0 +1,109 @@
+#![cfg(all(unix, feature = "tracing", vite_rs_dev))]
+
+mod util;
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+static EVENTS: Mutex<Vec<(Level, String, String)>> = Mutex::new(Vec::new());
+
+struct CapturingSubscriber;
+
+impl Subscriber for CapturingSubscriber {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = Message(String::new());
+        event.record(&mut message);
+
+        EVENTS.lock().unwrap().push((
+            *event.metadata().level(),
+            event.metadata().target().to_string(),
+            message.0,
+        ));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+/// The event's `message` field.
+struct Message(String);
+
+impl Visit for Message {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+/// Puts a fake `npx` on `PATH`, which prints a line to stdout and stderr and keeps running.
+#[test]
+fn forwards_output_to_tracing() {
+    tracing::subscriber::set_global_default(CapturingSubscriber).unwrap();
+
+    let dir = TempDir::new("stdio-tracing-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\necho '  VITE ready'\necho 'something went wrong' >&2\nexec sleep 30\n",
+    );
+    fake_vite::put_on_path(&dir);
+
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Tracing);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+
+    let expected = [
+        (Level::INFO, "vite".to_string(), "  VITE ready".to_string()),
+        (
+            Level::WARN,
+            "vite".to_string(),
+            "something went wrong".to_string(),
+        ),
+    ];
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        let events = EVENTS.lock().unwrap();
+        if expected.iter().all(|event| events.contains(event)) {
+            break;
+        }
+        drop(events);
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    let events = EVENTS.lock().unwrap();
+    for event in &expected {
+        assert!(events.contains(event), "{:?} not in {:?}", event, events);
+    }
+    drop(events);
+
+    drop(guard);
+}
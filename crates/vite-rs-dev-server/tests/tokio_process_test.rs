@@ -0,0 +1,148 @@
+#![cfg(all(unix, feature = "tokio-process", vite_rs_dev))]
+
+mod util;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{tokio_process, DevServerEvent, DevServerOptions, DevServerStdio};
+
+/// Puts a fake `npx` on `PATH`, which starts a background process (recording its PID) and records
+/// whether it was asked to shut down. Something else answers HTTP requests on its port, standing
+/// in for Vite.
+///
+/// Note: a single #[test] because it sets `PATH` for the whole process.
+#[test]
+fn manages_the_dev_server_with_tokio() {
+    let dir = TempDir::new("tokio-process-test");
+
+    fake_vite::npx(
+        &dir,
+        "#!/bin/sh\nsleep 300 &\necho $! > background.pid\ntrap 'touch terminated; exit 0' TERM\nwhile true; do sleep 0.1; done\n",
+    );
+    fake_vite::put_on_path(&dir);
+
+    // stopped gracefully
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    let with_events = options(&dir, port).on_event({
+        let events = events.clone();
+        move |event| events.lock().unwrap().push(event.clone())
+    });
+    answer_once_started(&dir, port);
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(async {
+        let process = tokio_process::start(with_events).await.unwrap().unwrap();
+        assert!(process.is_running());
+        assert_eq!(process.url(), format!("http://127.0.0.1:{}", port));
+
+        let status = process.shutdown().await.unwrap();
+        assert!(status.success(), "{}", status);
+    });
+    assert!(dir.join("terminated").exists());
+
+    let url = format!("http://127.0.0.1:{}", port);
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            DevServerEvent::Starting { port },
+            DevServerEvent::Ready { url },
+            DevServerEvent::Stopped
+        ]
+    );
+
+    // killed with the processes it started when the runtime shuts down
+    std::fs::remove_file(dir.join("background.pid")).unwrap();
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    answer_once_started(&dir, port);
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let process = runtime.block_on(async {
+        tokio_process::start(options(&dir, port))
+            .await
+            .unwrap()
+            .unwrap()
+    });
+    let background = read_pid(&dir.join("background.pid"));
+    assert!(is_alive(&background));
+
+    drop(runtime);
+    assert!(exits(&background));
+    drop(process);
+}
+
+fn options(dir: &Path, port: u16) -> DevServerOptions {
+    DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_ready_timeout(Duration::from_secs(5))
+}
+
+/// Waits for the fake `npx` to record the PID of its background process.
+fn read_pid(path: &Path) -> String {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        match std::fs::read_to_string(path) {
+            Ok(pid) if pid.ends_with('\n') => return pid.trim().to_string(),
+            _ if Instant::now() > deadline => panic!("timed out waiting for {}", path.display()),
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    }
+}
+
+fn is_alive(pid: &str) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", pid])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .unwrap()
+        .success()
+}
+
+/// Whether the process exits within a few seconds (killed processes may take a moment to go away).
+fn exits(pid: &str) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while is_alive(pid) {
+        if Instant::now() > deadline {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    true
+}
+
+/// Answers every request on `port` with a 404, once the fake `npx` in `dir` started (the port has
+/// to be free until then).
+fn answer_once_started(dir: &Path, port: u16) {
+    let started = dir.join("background.pid");
+
+    std::thread::spawn(move || {
+        while !started.exists() {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+
+            // read the whole request, so closing the connection doesn't reset it
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(n) if n > 0 => request.extend_from_slice(&buf[..n]),
+                    _ => break,
+                }
+            }
+
+            let _ = stream.write_all(
+                b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            );
+        }
+    });
+}
@@ -0,0 +1,242 @@
+#![cfg(vite_rs_dev)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+use vite_rs_dev_server::transport;
+
+/// Serves a single canned response on a random local port and returns the base URL.
+fn serve_once(response: &'static str) -> String {
+    serve_once_on(TcpListener::bind("127.0.0.1:0").unwrap(), response)
+}
+
+fn serve_once_on(listener: TcpListener, response: &'static str) -> String {
+    let addr = listener.local_addr().unwrap();
+
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        // drain the request head
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+            line.clear();
+        }
+
+        reader.into_inner().write_all(response.as_bytes()).unwrap();
+    });
+
+    vite_rs_dev_server::util::http_origin(&addr.ip().to_string(), addr.port())
+}
+
+#[test]
+fn reads_content_length_body() {
+    let url = serve_once(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 5\r\nETag: W/\"5-abc\"\r\n\r\nhello",
+    );
+
+    let res = transport::get(&format!("{}/index.html", url)).unwrap();
+
+    assert_eq!(res.status, 200);
+    assert_eq!(res.content_type.as_deref(), Some("text/html"));
+    assert_eq!(res.content_length, Some(5));
+    assert_eq!(res.etag.as_deref(), Some("W/\"5-abc\""));
+    assert_eq!(res.bytes, b"hello");
+}
+
+#[test]
+fn reads_chunked_body() {
+    let url = serve_once(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/javascript\r\nTransfer-Encoding: chunked\r\n\r\n6\r\nconst \r\n6;ext=1\r\na = 1;\r\n0\r\n\r\n",
+    );
+
+    let res = transport::get(&format!("{}/main.js", url)).unwrap();
+
+    assert_eq!(res.status, 200);
+    assert_eq!(res.content_type.as_deref(), Some("text/javascript"));
+    assert_eq!(res.etag, None);
+    assert_eq!(res.bytes, b"const a = 1;");
+}
+
+#[test]
+fn reports_not_found() {
+    let url = serve_once("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n");
+
+    let res = transport::get(&format!("{}/missing.txt", url)).unwrap();
+
+    assert_eq!(res.status, 404);
+    assert!(res.bytes.is_empty());
+}
+
+#[test]
+fn connects_over_ipv6() {
+    // e.g. IPv6 is disabled in some containers
+    let Ok(listener) = TcpListener::bind("[::1]:0") else {
+        return;
+    };
+    let url = serve_once_on(listener, "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok");
+    assert!(url.starts_with("http://[::1]:"));
+
+    let res = transport::get(&format!("{}/index.html", url)).unwrap();
+
+    assert_eq!(res.status, 200);
+    assert_eq!(res.bytes, b"ok");
+}
+
+#[test]
+fn fails_when_nothing_is_listening() {
+    let port = vite_rs_dev_server::util::find_free_port(30000..40000).unwrap();
+
+    let err = transport::get(&format!("http://127.0.0.1:{}/index.html", port)).unwrap_err();
+    assert!(err.is_connect(), "{}", err);
+}
+
+#[test]
+fn invalid_responses_are_not_connection_errors() {
+    let url = serve_once("garbage\r\n\r\n");
+
+    let err = transport::get(&format!("{}/index.html", url)).unwrap_err();
+    assert!(!err.is_connect(), "{}", err);
+}
+
+#[test]
+fn strips_hop_by_hop_headers() {
+    let url = serve_once(
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close, X-Internal\r\nKeep-Alive: timeout=5\r\nX-Internal: 1\r\nCache-Control: no-cache\r\nX-Opaque: café\r\n\r\nok",
+    );
+
+    let res = transport::get(&format!("{}/index.html", url)).unwrap();
+
+    assert_eq!(
+        res.headers,
+        [
+            ("content-length".to_string(), "2".to_string()),
+            ("cache-control".to_string(), "no-cache".to_string()),
+            ("x-opaque".to_string(), "café".to_string()),
+        ]
+    );
+}
+
+// reqwest already refuses responses with control characters in headers
+#[cfg(any(feature = "minimal", not(feature = "reqwest")))]
+#[test]
+fn drops_headers_with_control_characters() {
+    let url = serve_once(
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nX-Split: a\rSet-Cookie: evil\r\nX-Nul: a\0b\r\nX Space: 1\r\nX-Ok: fine\r\n\r\nok",
+    );
+
+    let res = transport::get(&format!("{}/index.html", url)).unwrap();
+
+    assert_eq!(
+        res.headers,
+        [
+            ("content-length".to_string(), "2".to_string()),
+            ("x-ok".to_string(), "fine".to_string()),
+        ]
+    );
+    assert_eq!(res.bytes, b"ok");
+}
+
+#[test]
+fn times_out_when_the_dev_server_hangs() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // accepts connections (in the backlog) but never responds, like a hung Vite plugin
+    let options = transport::FetchOptions::default()
+        .with_timeout(std::time::Duration::from_millis(200))
+        .with_retries(1)
+        .with_retry_delay(std::time::Duration::from_millis(50));
+
+    let started = std::time::Instant::now();
+    let res = transport::get_with(&format!("http://{}/index.html", addr), &options);
+
+    assert!(res.is_err());
+    // two attempts and a retry delay in between
+    assert!(started.elapsed() >= std::time::Duration::from_millis(450));
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    drop(listener);
+}
+
+#[test]
+fn retries_failed_requests() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // the first connection is closed without a response, the second one gets one
+    std::thread::spawn(move || {
+        drop(listener.accept().unwrap());
+        serve_once_on(
+            listener,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\nok",
+        );
+    });
+
+    let options = transport::FetchOptions::default()
+        .with_retries(2)
+        .with_retry_delay(std::time::Duration::from_millis(10));
+    let res = transport::get_with(&format!("http://{}/", addr), &options).unwrap();
+
+    assert_eq!(res.status, 200);
+    assert_eq!(res.bytes, b"ok");
+}
+
+#[cfg(all(feature = "reqwest", not(feature = "minimal")))]
+#[test]
+fn reuses_connections_between_requests() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    std::thread::spawn({
+        let connections = connections.clone();
+        move || {
+            for stream in listener.incoming() {
+                connections.fetch_add(1, Ordering::SeqCst);
+                let mut reader = BufReader::new(stream.unwrap());
+
+                // keeps the connection open for further requests
+                loop {
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).unwrap_or(0) > 0 && line != "\r\n" {
+                        line.clear();
+                    }
+                    if line != "\r\n" {
+                        break;
+                    }
+                    reader
+                        .get_mut()
+                        .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+                        .unwrap();
+                }
+            }
+        }
+    });
+
+    let url = format!(
+        "{}/main.js",
+        vite_rs_dev_server::util::http_origin("127.0.0.1", addr.port())
+    );
+    for _ in 0..3 {
+        assert_eq!(transport::get(&url).unwrap().bytes, b"ok");
+    }
+
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn get_blocking_works_inside_an_async_runtime() {
+    for _ in 0..3 {
+        let url = serve_once("HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok");
+
+        let res = transport::get_blocking(
+            &format!("{}/main.js", url),
+            &transport::FetchOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(res.bytes, b"ok");
+    }
+}
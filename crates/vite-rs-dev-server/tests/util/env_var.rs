@@ -0,0 +1,26 @@
+use std::ffi::{OsStr, OsString};
+
+/// Sets an environment variable until dropped, then restores its previous value (also when the
+/// test panics), so it doesn't leak into the rest of the test binary.
+pub struct EnvVar {
+    name: &'static str,
+    previous: Option<OsString>,
+}
+
+impl EnvVar {
+    pub fn set(name: &'static str, value: impl AsRef<OsStr>) -> Self {
+        let previous = std::env::var_os(name);
+        std::env::set_var(name, value);
+
+        EnvVar { name, previous }
+    }
+}
+
+impl Drop for EnvVar {
+    fn drop(&mut self) {
+        match &self.previous {
+            Some(previous) => std::env::set_var(self.name, previous),
+            None => std::env::remove_var(self.name),
+        }
+    }
+}
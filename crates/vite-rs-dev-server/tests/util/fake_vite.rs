@@ -0,0 +1,30 @@
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// Writes an executable shell script to `path`, creating its parent directories.
+pub fn script(path: &Path, script: &str) -> PathBuf {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, script).unwrap();
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    path.to_path_buf()
+}
+
+/// Writes a fake `npx` into `dir`. It's only run once `dir` is on `PATH` (see `put_on_path`).
+pub fn npx(dir: &Path, script: &str) -> PathBuf {
+    self::script(&dir.join("npx"), script)
+}
+
+/// Installs a fake Vite in the project `root`, where it's run without `npx`.
+pub fn vite(root: &Path, script: &str) -> PathBuf {
+    self::script(&root.join("node_modules/.bin/vite"), script)
+}
+
+/// Puts `dir` in front of `PATH`, so the fake `npx` in it is run rather than the real one.
+///
+/// Note: `PATH` is shared by the whole test binary, so tests which need it have to be in a binary
+/// of their own (or run in a child process).
+pub fn put_on_path(dir: &Path) {
+    let path = std::env::var("PATH").unwrap_or_default();
+    std::env::set_var("PATH", format!("{}:{}", dir.display(), path));
+}
@@ -0,0 +1,6 @@
+#![allow(dead_code)] // not every test binary uses every helper
+
+pub mod env_var;
+#[cfg(unix)]
+pub mod fake_vite;
+pub mod temp_dir;
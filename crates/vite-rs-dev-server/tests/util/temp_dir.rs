@@ -0,0 +1,37 @@
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// A directory in the system's temp dir, which is removed with everything in it when dropped (also
+/// when the test panics).
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Creates `vite-rs-<name>-<pid>`, emptying it first if a previous run left it behind.
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("vite-rs-{}-{}", name, std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+
+        TempDir(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.0).ok();
+    }
+}
@@ -0,0 +1,95 @@
+use std::net::TcpListener;
+
+use vite_rs_dev_server::util;
+
+#[test]
+fn detects_taken_ports() {
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    assert!(!util::is_port_free(port));
+    assert!(!util::is_port_free_on("127.0.0.1", port));
+    assert_eq!(util::find_free_port(port..port + 1), None);
+    assert_eq!(util::find_free_port_on("127.0.0.1", port..port + 1), None);
+
+    drop(listener);
+
+    assert!(util::is_port_free(port));
+    assert!(util::is_port_free_on("127.0.0.1", port));
+    assert_eq!(util::find_free_port(port..port + 1), Some(port));
+}
+
+#[test]
+fn checks_the_given_host_only() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    assert!(!util::is_port_free_on("127.0.0.1", port));
+
+    // Linux routes all of 127.0.0.0/8 to the loopback interface, and doesn't allow listening on
+    // all interfaces while another socket listens on one of them
+    #[cfg(target_os = "linux")]
+    {
+        assert!(util::is_port_free_on("127.0.0.2", port));
+        assert!(!util::is_port_free(port));
+    }
+}
+
+#[test]
+fn unresolvable_hosts_are_not_free() {
+    assert!(!util::is_port_free_on("", 21012));
+    assert_eq!(util::find_free_port_on("", 21012..21022), None);
+}
+
+#[test]
+fn brackets_ipv6_hosts_in_urls() {
+    assert_eq!(
+        util::http_origin("localhost", 21012),
+        "http://localhost:21012"
+    );
+    assert_eq!(util::http_origin("::1", 21012), "http://[::1]:21012");
+    assert_eq!(util::http_origin("[::1]", 21012), "http://[::1]:21012");
+    assert_eq!(util::http_origin("::", 21012), "http://[::]:21012");
+
+    assert_eq!(util::unbracket("[::1]"), "::1");
+    assert_eq!(util::unbracket("::1"), "::1");
+    assert_eq!(util::unbracket("127.0.0.1"), "127.0.0.1");
+}
+
+#[test]
+fn fetches_from_loopback_for_wildcard_hosts() {
+    assert_eq!(util::fetch_host("0.0.0.0"), "127.0.0.1");
+    assert_eq!(util::fetch_host("::"), "::1");
+    assert_eq!(util::fetch_host("[::]"), "::1");
+    assert_eq!(util::fetch_host("::1"), "::1");
+    assert_eq!(util::fetch_host("localhost"), "localhost");
+    assert_eq!(util::fetch_host("dev.example.com"), "dev.example.com");
+
+    let options =
+        |host: &str| vite_rs_dev_server::DevServerOptions::new("/app", 21012).with_host(host);
+    assert_eq!(options("0.0.0.0").origin(), "http://127.0.0.1:21012");
+    assert_eq!(options("::").origin(), "http://[::1]:21012");
+    assert_eq!(options("[::1]").origin(), "http://[::1]:21012");
+    assert_eq!(
+        options("0.0.0.0")
+            .with_fetch_host("frontend.local")
+            .origin(),
+        "http://frontend.local:21012"
+    );
+}
+
+#[test]
+fn checks_ipv6_hosts() {
+    // e.g. IPv6 is disabled in some containers
+    let Ok(listener) = TcpListener::bind("[::1]:0") else {
+        return;
+    };
+    let port = listener.local_addr().unwrap().port();
+
+    assert!(!util::is_port_free_on("::1", port));
+    assert!(!util::is_port_free_on("[::1]", port));
+
+    drop(listener);
+
+    assert!(util::is_port_free_on("[::1]", port));
+}
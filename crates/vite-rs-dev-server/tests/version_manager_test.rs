@@ -0,0 +1,175 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio, VersionManager};
+
+fn temp_dir(name: &str) -> TempDir {
+    TempDir::new(&format!("version-manager-test-{}", name))
+}
+
+#[test]
+fn detects_the_version_manager_the_project_pins_node_with() {
+    let dir = temp_dir("detect");
+    let detect = |dir: &Path| VersionManager::detect(dir.to_str().unwrap());
+
+    let volta = dir.join("volta");
+    std::fs::create_dir_all(&volta).unwrap();
+    std::fs::write(
+        volta.join("package.json"),
+        r#"{ "name": "app", "volta" : { "node": "20.11.0" } }"#,
+    )
+    .unwrap();
+    assert_eq!(detect(&volta), Some(VersionManager::Volta));
+
+    // only a `volta` key counts
+    let named_volta = dir.join("named-volta");
+    std::fs::create_dir_all(&named_volta).unwrap();
+    std::fs::write(named_volta.join("package.json"), r#"{ "name": "volta" }"#).unwrap();
+    assert_eq!(detect(&named_volta), None);
+
+    for file in ["mise.toml", ".mise.toml", ".tool-versions"] {
+        let mise = dir.join(file.trim_start_matches('.'));
+        std::fs::create_dir_all(&mise).unwrap();
+        std::fs::write(mise.join(file), "").unwrap();
+        assert_eq!(detect(&mise), Some(VersionManager::Mise), "{}", file);
+    }
+
+    // from a parent directory, unless a closer one pins it another way
+    let nvm = dir.join("nvm");
+    std::fs::create_dir_all(nvm.join("frontend/mise")).unwrap();
+    std::fs::write(nvm.join(".nvmrc"), "20\n").unwrap();
+    std::fs::write(nvm.join("frontend/mise/mise.toml"), "").unwrap();
+    assert_eq!(detect(&nvm.join("frontend")), Some(VersionManager::Nvm));
+    assert_eq!(
+        detect(&nvm.join("frontend/mise")),
+        Some(VersionManager::Mise)
+    );
+
+    assert_eq!(
+        VersionManager::Auto.resolve(volta.to_str().unwrap()),
+        Some(VersionManager::Volta)
+    );
+    assert_eq!(
+        VersionManager::Nvm.resolve(volta.to_str().unwrap()),
+        Some(VersionManager::Nvm)
+    );
+}
+
+#[test]
+fn runs_commands_through_the_version_manager() {
+    let dir = temp_dir("command");
+    let args = |command: &Command| {
+        std::iter::once(command.get_program())
+            .chain(command.get_args())
+            .map(|arg| arg.to_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    let vite = || {
+        let mut command = Command::new("node_modules/.bin/vite");
+        command.args(["--port", "5173"]);
+        command
+    };
+    let command_in =
+        |version_manager: VersionManager| version_manager.command_in(dir.to_str().unwrap(), vite());
+
+    assert_eq!(
+        args(&command_in(VersionManager::Volta)),
+        ["volta", "run", "node_modules/.bin/vite", "--port", "5173"]
+    );
+    assert_eq!(
+        args(&command_in(VersionManager::Mise)),
+        [
+            "mise",
+            "exec",
+            "--",
+            "node_modules/.bin/vite",
+            "--port",
+            "5173"
+        ]
+    );
+    // nothing to run it through
+    assert_eq!(
+        args(&command_in(VersionManager::Auto)),
+        ["node_modules/.bin/vite", "--port", "5173"]
+    );
+
+    // nvm is loaded into the shell, which switches versions and runs the command
+    let nvm_dir = dir.join("nvm");
+    std::fs::create_dir_all(&nvm_dir).unwrap();
+    std::fs::write(
+        nvm_dir.join("nvm.sh"),
+        "nvm() { echo \"nvm $*\"; export PATH=\"/pinned/node/bin:$PATH\"; }\n",
+    )
+    .unwrap();
+
+    let mut command = Command::new("sh");
+    command.args([
+        "-c",
+        "echo \"$*\"; echo \"${PATH%%:*}\"",
+        "sh",
+        "--port",
+        "5173",
+    ]);
+    let output = VersionManager::Nvm
+        .command_in(dir.to_str().unwrap(), command)
+        .env("NVM_DIR", &nvm_dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "nvm use --silent\n--port 5173\n/pinned/node/bin\n"
+    );
+}
+
+/// Note: the only #[test] here which modifies `PATH` (for the whole process).
+#[test]
+fn starts_the_dev_server_through_the_detected_version_manager() {
+    let dir = temp_dir("dev-server");
+    std::fs::write(
+        dir.join("package.json"),
+        r#"{ "volta": { "node": "20.11.0" } }"#,
+    )
+    .unwrap();
+
+    // a fake `volta` which records how it was started
+    let bin = dir.join("bin");
+    fake_vite::script(
+        &bin.join("volta"),
+        "#!/bin/sh\necho \"volta $@\" > volta.txt\nexec sleep 30\n",
+    );
+
+    fake_vite::put_on_path(&bin);
+
+    let port = vite_rs_dev_server::util::find_free_port(40000..41000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_version_manager(VersionManager::Auto);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    assert!(guard.is_some());
+
+    let recorded = dir.join("volta.txt");
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !recorded.exists() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    let recorded = std::fs::read_to_string(recorded).unwrap();
+    assert!(
+        recorded.starts_with(&format!(
+            "volta run npx vite --host 127.0.0.1 --port {} ",
+            port
+        )),
+        "{}",
+        recorded
+    );
+
+    drop(guard);
+}
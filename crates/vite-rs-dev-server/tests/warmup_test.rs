@@ -0,0 +1,115 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+const INDEX_HTML: &str = r#"<!doctype html>
+<html>
+  <head>
+    <script type="module" src="/@vite/client"></script>
+    <script type="module" src="/src/main.ts"></script>
+    <script src="https://cdn.example.com/analytics.js"></script>
+  </head>
+</html>
+"#;
+
+/// With a fake Vite: something else answers HTTP requests on its port, standing in for Vite.
+#[test]
+fn fetches_the_warmup_paths_and_their_module_scripts_once_ready() {
+    let dir = TempDir::new("warmup-test");
+    fake_vite::vite(&dir, "#!/bin/sh\nexec sleep 30\n");
+
+    let port = vite_rs_dev_server::util::find_free_port(42000..43000).unwrap();
+    let options = DevServerOptions::new(dir.to_str().unwrap(), port)
+        .with_host("127.0.0.1")
+        .with_stdio(DevServerStdio::Null)
+        .with_warmup(["/", "/docs/"]);
+
+    let guard = vite_rs_dev_server::try_start_dev_server_with(options).unwrap();
+    let requests = answer_on(port);
+
+    let mut requested = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !requested.iter().any(|path| path == "/docs/main.ts") && Instant::now() < deadline {
+        if let Ok(path) = requests.recv_timeout(Duration::from_millis(100)) {
+            requested.push(path);
+        }
+    }
+    // in case the script from another origin is requested after all
+    std::thread::sleep(Duration::from_millis(300));
+    requested.extend(requests.try_iter());
+    drop(guard);
+
+    for path in [
+        "/",
+        "/@vite/client",
+        "/src/main.ts",
+        "/docs/",
+        "/docs/main.ts",
+    ] {
+        assert!(
+            requested.iter().any(|requested| requested == path),
+            "{} wasn't requested: {:?}",
+            path,
+            requested
+        );
+    }
+    assert!(
+        !requested.iter().any(|path| path.contains("analytics")),
+        "{:?}",
+        requested
+    );
+}
+
+/// Serves `INDEX_HTML` at `/` and a page with a relative module script at `/docs/`, and sends the
+/// paths of all requests.
+fn answer_on(port: u16) -> Receiver<String> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(n) if n > 0 => request.extend_from_slice(&buf[..n]),
+                    _ => break,
+                }
+            }
+
+            let request = String::from_utf8_lossy(&request);
+            let path = request.split(' ').nth(1).unwrap_or_default().to_string();
+            let (content_type, body) = match path.as_str() {
+                "/" => ("text/html", INDEX_HTML),
+                "/docs/" => (
+                    "text/html",
+                    "<script type=\"module\" src=\"main.ts\"></script>",
+                ),
+                _ => ("text/javascript", ""),
+            };
+            let _ = sender.send(path);
+
+            let _ = stream.write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    content_type,
+                    body.len(),
+                    body
+                )
+                .as_bytes(),
+            );
+        }
+    });
+
+    receiver
+}
@@ -0,0 +1,53 @@
+#![cfg(all(unix, vite_rs_dev))]
+
+mod util;
+
+use std::time::{Duration, Instant};
+
+use util::{fake_vite, temp_dir::TempDir};
+use vite_rs_dev_server::{DevServerOptions, DevServerStdio};
+
+#[test]
+fn runs_vite_in_the_working_dir_with_the_root_as_argument() {
+    let dir = TempDir::new("working-dir-test");
+    let workspace = dir.join("workspace");
+    let root = dir.join("app");
+    std::fs::create_dir_all(&root).unwrap();
+
+    // installed in the workspace only, which records where and how it was started
+    fake_vite::vite(
+        &workspace,
+        "#!/bin/sh\necho \"$(pwd) $@\" > ../vite.txt\nexec sleep 30\n",
+    );
+
+    let port = vite_rs_dev_server::util::find_free_port(33000..34000).unwrap();
+    let options = DevServerOptions::new(root.to_str().unwrap(), port)
+        .with_working_dir(workspace.to_str().unwrap())
+        .with_stdio(DevServerStdio::Null);
+    assert_eq!(options.current_dir(), workspace.to_str().unwrap());
+
+    let _guard = vite_rs_dev_server::start_dev_server_with(options);
+
+    let output = dir.join("vite.txt");
+    let start = Instant::now();
+    let invocation = loop {
+        match std::fs::read_to_string(&output) {
+            Ok(contents) if contents.ends_with('\n') => break contents,
+            _ if start.elapsed() > Duration::from_secs(5) => {
+                panic!("timed out waiting for {}", output.display())
+            }
+            _ => std::thread::sleep(Duration::from_millis(50)),
+        }
+    };
+
+    assert!(
+        invocation.starts_with(&format!(
+            "{} {} --host localhost --port {} ",
+            workspace.canonicalize().unwrap().display(),
+            root.display(),
+            port
+        )),
+        "{}",
+        invocation
+    );
+}
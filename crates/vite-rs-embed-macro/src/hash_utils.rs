@@ -1,8 +1,40 @@
-use sha2::{Digest, Sha256};
+use sha2::Digest;
 
+#[cfg(feature = "content-hash")]
 pub fn get_content_hash(content: &[u8]) -> String {
-    let mut hasher = Sha256::new();
+    let mut hasher = sha2::Sha256::new();
     hasher.update(content);
     let hash = hasher.finalize();
     format!("{:X}", hash)
 }
+
+/// The Subresource Integrity hash of `content`, e.g. `sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC`
+/// (see https://www.w3.org/TR/SRI/#the-integrity-attribute).
+#[cfg(feature = "integrity")]
+pub fn get_integrity(content: &[u8]) -> String {
+    let hash = sha2::Sha384::digest(content);
+    format!("sha384-{}", base64(&hash))
+}
+
+/// Standard base64, with padding.
+#[cfg(feature = "integrity")]
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | (*byte as u32) << (16 - 8 * i)
+        });
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
@@ -1,13 +1,11 @@
 // #![feature(track_path)] // => please see comments @ crates/vite-rs/tests/recompilation_test.rs:43
 #![forbid(unsafe_code)]
 
-#[cfg(all(
-    feature = "content-hash",
-    any(feature = "debug-prod", not(debug_assertions))
-))]
+#[cfg(all(any(feature = "content-hash", feature = "integrity"), not(vite_rs_dev)))]
 mod hash_utils;
 
 mod syn_utils;
+mod tooling;
 mod vite;
 
 use std::{
@@ -29,7 +27,7 @@ fn derive_absolute_root_dir(ast: &syn::DeriveInput) -> syn::Result<String> {
         return Err(syn::Error::new_spanned(ast, "When specifying a custom root directory, #[derive(vite_rs::Embed)] must only contain a single #[root = \"./\"] attribute."));
     }
 
-    let root_dir = if root_attrs.len() == 0 {
+    let root_dir = if root_attrs.is_empty() {
         env::var("CARGO_MANIFEST_DIR").unwrap()
     } else {
         root_attrs.remove(0)
@@ -43,22 +41,63 @@ fn derive_absolute_root_dir(ast: &syn::DeriveInput) -> syn::Result<String> {
     } else {
         root_dir
     };
-    let root_dir = root_dir.canonicalize().expect(&format!(
-        "Could not canonicalize root directory path. Does it exist? (path: {:?})",
-        root_dir
-    ));
+    let root_dir = match root_dir.canonicalize() {
+        Ok(root_dir) => root_dir,
+        // e.g. on docs.rs, the frontend may not be part of the published crate
+        Err(_) if tooling::stub_reason().is_some() => root_dir,
+        Err(_) => panic!(
+            "Could not canonicalize root directory path. Does it exist? (path: {:?})",
+            root_dir
+        ),
+    };
     let root_dir_str = root_dir.to_str().unwrap();
 
     Ok(root_dir_str.to_string())
 }
 
+/// The directory Vite runs in, both for `vite build` (in release builds) and the dev server, when
+/// it isn't the `root_dir`: e.g. the root of a pnpm or yarn workspace, with
+/// `#[working_dir = "../frontend"]`. Like `#[root]`, a relative path is relative to the project
+/// directory. The `root_dir` is passed to Vite as its root, so the assets and the Vite config are
+/// still looked up there.
+fn derive_absolute_working_dir(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let mut working_dir_attrs = syn_utils::find_attribute_values(ast, "working_dir");
+    if working_dir_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[working_dir = \"...\"] attribute.",
+        ));
+    }
+
+    let Some(working_dir) = working_dir_attrs.pop() else {
+        return Ok(None);
+    };
+
+    let working_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(working_dir);
+    let working_dir = match working_dir.canonicalize() {
+        Ok(working_dir) => working_dir,
+        Err(_) if tooling::stub_reason().is_some() => working_dir,
+        Err(_) => {
+            return Err(syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Working directory '{}' specified with #[working_dir] does not exist",
+                    working_dir.display()
+                ),
+            ))
+        }
+    };
+
+    Ok(Some(working_dir.to_str().unwrap().to_string()))
+}
+
 /// The output directory is where the compiled JS/assets are placed, relative to the `root_dir`.
 /// By default, it is set to `./dist` but can be overridden by specifying a `#[output = "./dist"]` attribute under the derive macro.
 ///
 /// Moreover, any output directory specified must be within `root_dir`.
 ///
 /// Since this deals with compiled assets, it shouldn't be necessary for non-release builds.
-#[cfg(any(feature = "debug-prod", not(debug_assertions)))]
+#[cfg(not(vite_rs_dev))]
 fn derive_relative_output_dir(
     ast: &syn::DeriveInput,
     absolute_root_dir: &str,
@@ -71,7 +110,7 @@ fn derive_relative_output_dir(
         ));
     }
 
-    let mut output_dir = PathBuf::from(if output_attrs.len() == 0 {
+    let mut output_dir = PathBuf::from(if output_attrs.is_empty() {
         "dist".to_string()
     } else {
         output_attrs.remove(0)
@@ -82,6 +121,15 @@ fn derive_relative_output_dir(
         output_dir = root_dir.join(&output_dir);
     }
 
+    // nothing is built or embedded, and the project may not even exist (e.g. on docs.rs)
+    if tooling::stub_reason().is_some() {
+        let relative_output_dir = output_dir
+            .strip_prefix(absolute_root_dir)
+            .unwrap_or(&output_dir);
+
+        return Ok(relative_output_dir.to_str().unwrap().to_string());
+    }
+
     // Instead of raising an error when the output directory doesn't exist,
     // we create it in release builds. This is a nicer experience.
     //
@@ -94,7 +142,7 @@ fn derive_relative_output_dir(
     // //         ),
     // //     ));
     // // }
-    create_output_dir_if_not_exists(&ast, &output_dir)?;
+    create_output_dir_if_not_exists(ast, &output_dir)?;
 
     if !output_dir.is_dir() {
         return Err(syn::Error::new_spanned(
@@ -109,7 +157,7 @@ fn derive_relative_output_dir(
     let output_dir = output_dir.canonicalize().unwrap();
 
     let relative_output_dir = output_dir
-            .strip_prefix(&absolute_root_dir)
+            .strip_prefix(absolute_root_dir)
             .expect("output dir specified with #[output = \"...\"] must be within the project root directory.")
             .to_str()
             .unwrap();
@@ -117,12 +165,34 @@ fn derive_relative_output_dir(
     Ok(relative_output_dir.to_string())
 }
 
-#[cfg(any(feature = "debug-prod", not(debug_assertions)))]
+/// With the `preview` feature, `vite preview` serves the output directory at runtime instead of
+/// it being built and embedded. It's passed to Vite as `--outDir` (so it's relative to the
+/// `root_dir`), and has to be built beforehand, e.g. by `vite build` in CI.
+#[cfg(vite_rs_dev)]
+fn derive_preview_dir(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    if !cfg!(feature = "preview") {
+        return Ok(None);
+    }
+
+    let mut output_attrs = syn_utils::find_attribute_values(ast, "output");
+    if output_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "When specifying a custom output directory, #[derive(vite_rs::Embed)] must only contain a single #[output = \"./dist\"] attribute.",
+        ));
+    }
+
+    Ok(Some(
+        output_attrs.pop().unwrap_or_else(|| "./dist".to_string()),
+    ))
+}
+
+#[cfg(not(vite_rs_dev))]
 fn create_output_dir_if_not_exists(
     ast: &syn::DeriveInput,
     output_dir: &PathBuf,
 ) -> syn::Result<()> {
-    let create_output_dir = std::fs::create_dir_all(&output_dir);
+    let create_output_dir = std::fs::create_dir_all(output_dir);
 
     if create_output_dir.is_err_and(|e| e.kind() != std::io::ErrorKind::AlreadyExists) {
         return Err(syn::Error::new_spanned(
@@ -136,7 +206,37 @@ fn create_output_dir_if_not_exists(
 
 /// The dev server port is the port where the vite-rs dev server will run and serve from.
 /// By default, it is set to a free port in the range 21012..22022 but can be overridden by specifying a `#[dev_server_port = "123"]` attribute under the derive macro.
+/// `#[dev_server_port = "auto"]` picks a free port at runtime instead, which is returned as `0`.
+#[cfg(vite_rs_dev)]
 fn derive_dev_server_port(ast: &syn::DeriveInput) -> u16 {
+    derive_configured_dev_server_port(ast).unwrap_or_else(|| {
+        // tooling (e.g. rust-analyzer) expands the macro on every keystroke and never runs the dev
+        // server, so skip scanning for a free port
+        if tooling::stub_reason().is_some() {
+            return 21012;
+        }
+
+        // If the user doesn't specify a dev_server_port, this function
+        // returns a free port in the range 21012..22022.
+        //
+        // This can be a source of hair-pulling because this happens on
+        // macro codegen. In other words, the selected free port may be
+        // available when the macro code is initially compiled, but may
+        // no longer be available in subsequent runs.
+        //
+        // At the same time, this is necessary because we need to
+        // coordinate the dev server's port with the generated code
+        // (to forward requests to the dev server).
+        //
+        // To save everyone's time, we'll strongly encourage users to
+        // specify a #[dev_server_port = 123].
+        vite_rs_dev_server::util::find_free_port(vite_rs_dev_server::util::DEFAULT_PORT_RANGE)
+            .expect("Could not find a free port for the ViteJS dev server")
+    })
+}
+
+/// The port given with `#[dev_server_port]`, if any (`0` for `"auto"`).
+fn derive_configured_dev_server_port(ast: &syn::DeriveInput) -> Option<u16> {
     let dev_server_port_attrs = syn_utils::find_attribute_values(ast, "dev_server_port");
     if dev_server_port_attrs.len() > 1 {
         panic!(
@@ -144,7 +244,12 @@ fn derive_dev_server_port(ast: &syn::DeriveInput) -> u16 {
         );
     }
 
-    let dev_server_port = dev_server_port_attrs.get(0).map(|port| {
+    dev_server_port_attrs.first().map(|port| {
+        // picked at runtime instead (see `vite_rs_dev_server::util::RuntimePort`)
+        if port == "auto" {
+            return 0;
+        }
+
         let port = port
             .parse::<u16>()
             .expect("dev_server_port must be a valid unsigned integer (usize).");
@@ -159,28 +264,943 @@ fn derive_dev_server_port(ast: &syn::DeriveInput) -> u16 {
         // }
 
         port
-    });
-
-    dev_server_port.unwrap_or_else(|| {
-        // If the user doesn't specify a dev_server_port, this function
-        // returns a free port in the range 21012..22022.
-        //
-        // This can be a source of hair-pulling because this happens on
-        // macro codegen. In other words, the selected free port may be
-        // available when the macro code is initially compiled, but may
-        // no longer be available in subsequent runs.
-        //
-        // At the same time, this is necessary because we need to
-        // coordinate the dev server's port with the generated code
-        // (to forward requests to the dev server).
-        //
-        // To save everyone's time, we'll strongly encourage users to
-        // specify a #[dev_server_port = 123].
-        vite_rs_dev_server::util::find_free_port(21012..22022)
-            .expect("Could not find a free port for the ViteJS dev server")
     })
 }
 
+/// Prefixes of paths which are read straight from disk in dev builds instead of being requested
+/// from the ViteJS dev server. Can be specified multiple times: `#[dev_bypass_prefix = "data/"]`.
+///
+/// This is useful for large static files (e.g. datasets under `public/data/`) which the dev server
+/// is slow to serve. Release builds embed these files as usual.
+#[cfg(vite_rs_dev)]
+fn derive_dev_bypass_prefixes(ast: &syn::DeriveInput) -> syn::Result<Vec<String>> {
+    syn_utils::find_attribute_values(ast, "dev_bypass_prefix")
+        .into_iter()
+        .map(|prefix| {
+            // matched by path segment, so `data/` and `data` are the same prefix
+            let prefix = prefix.trim_matches('/');
+            if prefix.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    ast,
+                    "#[dev_bypass_prefix = \"...\"] must not be empty; it would bypass the ViteJS dev server for every file.",
+                ));
+            }
+
+            Ok(prefix.to_string())
+        })
+        .collect()
+}
+
+/// Vite's `publicDir`, relative to the `root_dir`. When specified with `#[dev_public_dir = "./public"]`,
+/// dev builds read files in this directory straight from disk instead of requesting them from the
+/// ViteJS dev server (which serves them as-is anyway).
+///
+/// This is opt-in because the `publicDir` can be changed in the vite config, which we don't parse.
+#[cfg(vite_rs_dev)]
+fn derive_dev_public_dir(
+    ast: &syn::DeriveInput,
+    absolute_root_dir: &str,
+) -> syn::Result<Option<String>> {
+    let mut public_dir_attrs = syn_utils::find_attribute_values(ast, "dev_public_dir");
+    if public_dir_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_public_dir = \"./public\"] attribute.",
+        ));
+    }
+
+    if public_dir_attrs.is_empty() {
+        return Ok(None);
+    }
+
+    let public_dir = Path::new(absolute_root_dir).join(public_dir_attrs.remove(0));
+    if !public_dir.is_dir() && tooling::stub_reason().is_none() {
+        return Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "Public directory '{}' specified with #[dev_public_dir] does not exist",
+                public_dir.display()
+            ),
+        ));
+    }
+
+    let public_dir = public_dir.canonicalize().unwrap_or(public_dir);
+
+    Ok(Some(public_dir.to_str().unwrap().to_string()))
+}
+
+/// The HTML files the dev server serves, for `routes()`. Release builds use the HTML entries of
+/// the Vite manifest instead. The build output (`#[output]`) and public directories are skipped.
+#[cfg(vite_rs_dev)]
+fn derive_dev_html_entries(
+    ast: &syn::DeriveInput,
+    absolute_root_dir: &str,
+    public_dir: Option<&str>,
+) -> Vec<String> {
+    if tooling::stub_reason().is_some() {
+        return Vec::new();
+    }
+
+    let root_dir = Path::new(absolute_root_dir);
+    let output_dir = syn_utils::find_attribute_values(ast, "output")
+        .pop()
+        .unwrap_or_else(|| "dist".to_string());
+    let skipped_dirs = [
+        Some(root_dir.join(output_dir)),
+        Some(root_dir.join("public")),
+        public_dir.map(PathBuf::from),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|dir| dir.canonicalize().unwrap_or(dir))
+    .collect::<Vec<_>>();
+
+    vite::routes::find_html_files(absolute_root_dir, &skipped_dirs)
+}
+
+/// Additional arguments passed to the dev server, after the ones vite-rs sets, e.g.
+/// `#[dev_server_args("--force", "--logLevel", "warn")]`. Can be specified multiple times.
+#[cfg(vite_rs_dev)]
+fn derive_dev_server_args(ast: &syn::DeriveInput) -> syn::Result<Vec<String>> {
+    syn_utils::find_attribute_lists(ast, "dev_server_args")
+}
+
+/// How much the dev server logs, e.g. `#[dev_server_log_level = "warn"]` to hide its banner and
+/// HMR update messages: `info`, `warn`, `error` or `silent`. Vite's default (or the Vite
+/// config's) if not set.
+#[cfg(vite_rs_dev)]
+fn derive_dev_server_log_level(
+    ast: &syn::DeriveInput,
+) -> syn::Result<Option<vite_rs_dev_server::LogLevel>> {
+    let log_level_attrs = syn_utils::find_attribute_values(ast, "dev_server_log_level");
+    if log_level_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_server_log_level = \"...\"] attribute.",
+        ));
+    }
+
+    match log_level_attrs.first() {
+        None => Ok(None),
+        Some(name) => vite_rs_dev_server::LogLevel::from_name(name)
+            .map(Some)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    ast,
+                    format!(
+                        "Unknown #[dev_server_log_level = \"{}\"]. Expected one of: info, warn, error, silent.",
+                        name
+                    ),
+                )
+            }),
+    }
+}
+
+/// Whether Vite may clear the terminal when the dev server logs: `#[clear_screen = true]`. Off
+/// by default, so the output of the app isn't cleared away.
+#[cfg(vite_rs_dev)]
+fn derive_clear_screen(ast: &syn::DeriveInput) -> syn::Result<bool> {
+    let clear_screen_attrs = syn_utils::find_attribute_values(ast, "clear_screen");
+    if clear_screen_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[clear_screen = ...] attribute.",
+        ));
+    }
+
+    match clear_screen_attrs.first().map(String::as_str) {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(value) => Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "Unknown #[clear_screen = {}]. Expected true or false.",
+                value
+            ),
+        )),
+    }
+}
+
+/// What happens when the dev server port is taken when the dev server starts:
+/// `#[dev_server_port_fallback = "next"]` uses the next free port after it instead (like Vite
+/// without `--strictPort`), which the generated code reads at runtime. `"off"` (default) fails.
+#[cfg(vite_rs_dev)]
+fn derive_dev_server_port_fallback(ast: &syn::DeriveInput) -> syn::Result<bool> {
+    let fallback_attrs = syn_utils::find_attribute_values(ast, "dev_server_port_fallback");
+    if fallback_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_server_port_fallback = \"...\"] attribute.",
+        ));
+    }
+
+    let fallback = match fallback_attrs.first().map(String::as_str) {
+        None | Some("off") => false,
+        Some("next") => true,
+        Some(value) => {
+            return Err(syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Unknown dev server port fallback '{}'. Expected one of: off, next.",
+                    value
+                ),
+            ))
+        }
+    };
+
+    if fallback && !syn_utils::find_attribute_values(ast, "external_dev_server").is_empty() {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[dev_server_port_fallback] doesn't apply to an #[external_dev_server]; remove one of them.",
+        ));
+    }
+
+    if fallback
+        && syn_utils::find_attribute_values(ast, "dev_server_port")
+            .iter()
+            .any(|port| port == "auto")
+    {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[dev_server_port = \"auto\"] already picks a free port; remove the #[dev_server_port_fallback] attribute.",
+        ));
+    }
+
+    Ok(fallback)
+}
+
+/// Uses a dev server that is already running (e.g. started with `npm run dev` in another terminal)
+/// instead of starting one, e.g. `#[external_dev_server = "http://localhost:5173"]`.
+/// Returns its host and port (`80` if not specified).
+fn derive_external_dev_server(ast: &syn::DeriveInput) -> syn::Result<Option<(String, u16)>> {
+    let external_dev_server_attrs = syn_utils::find_attribute_values(ast, "external_dev_server");
+    if external_dev_server_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[external_dev_server = \"...\"] attribute.",
+        ));
+    }
+
+    let Some(url) = external_dev_server_attrs.first() else {
+        return Ok(None);
+    };
+
+    if !syn_utils::find_attribute_values(ast, "dev_server_port").is_empty() {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[external_dev_server] already specifies the dev server's port; remove the #[dev_server_port] attribute.",
+        ));
+    }
+
+    let invalid = |reason: &str| {
+        syn::Error::new_spanned(
+            ast,
+            format!(
+                "#[external_dev_server = \"{}\"] {} (expected e.g. \"http://localhost:5173\")",
+                url, reason
+            ),
+        )
+    };
+
+    let address = url
+        .strip_prefix("http://")
+        .ok_or_else(|| invalid("must be an http:// URL"))?
+        .trim_end_matches('/');
+
+    if address.contains('/') {
+        return Err(invalid("must not contain a path"));
+    }
+
+    let unbracket = vite_rs_dev_server::util::unbracket;
+
+    match address.rsplit_once(':') {
+        // `]` is part of an IPv6 address without a port, e.g. `[::1]`
+        Some((host, port)) if !host.is_empty() && !port.contains(']') => {
+            let port = port
+                .parse::<u16>()
+                .map_err(|_| invalid("has an invalid port"))?;
+
+            Ok(Some((unbracket(host).to_string(), port)))
+        }
+        _ if !address.is_empty() => Ok(Some((unbracket(address).to_string(), 80))),
+        _ => Err(invalid("is missing a host")),
+    }
+}
+
+/// The host the dev server listens on, e.g. `#[dev_server_host = "0.0.0.0"]` to make it
+/// reachable from other devices on the network, or `#[dev_server_host = "::1"]` for IPv6.
+/// Defaults to `localhost`. Assets are still fetched through the loopback address for the
+/// wildcard addresses `0.0.0.0` and `::` (see `vite_rs_dev_server::util::fetch_host`).
+fn derive_dev_server_host(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let dev_server_host_attrs = syn_utils::find_attribute_values(ast, "dev_server_host");
+    if dev_server_host_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_server_host = \"...\"] attribute.",
+        ));
+    }
+
+    let Some(host) = dev_server_host_attrs.first() else {
+        return Ok(None);
+    };
+
+    if !syn_utils::find_attribute_values(ast, "external_dev_server").is_empty() {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[external_dev_server] already specifies the dev server's host; remove the #[dev_server_host] attribute.",
+        ));
+    }
+
+    if host.is_empty() || host.contains('/') {
+        return Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "#[dev_server_host = \"{}\"] must be a host name or an IP address (expected e.g. \"0.0.0.0\" or \"::1\")",
+                host
+            ),
+        ));
+    }
+
+    Ok(Some(vite_rs_dev_server::util::unbracket(host).to_string()))
+}
+
+/// Where browsers reach the dev server, if that's not where the Rust side does, e.g. behind port
+/// forwarding: `#[dev_server_public_url = "https://my-app.example.com"]`, or `"auto"` for the URL
+/// a remote environment like GitHub Codespaces forwards the port to. The generated `get()` keeps
+/// fetching from the dev server's host and port.
+#[cfg(vite_rs_dev)]
+fn derive_dev_server_public_url(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let public_url_attrs = syn_utils::find_attribute_values(ast, "dev_server_public_url");
+    if public_url_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_server_public_url = \"...\"] attribute.",
+        ));
+    }
+
+    match public_url_attrs.first() {
+        Some(url)
+            if url != "auto" && !url.starts_with("http://") && !url.starts_with("https://") =>
+        {
+            Err(syn::Error::new_spanned(
+                ast,
+                format!(
+                    "#[dev_server_public_url = \"{}\"] must be an http:// or https:// URL, or auto",
+                    url
+                ),
+            ))
+        }
+        url => Ok(url.cloned()),
+    }
+}
+
+/// The public base path the dev server serves the assets under, e.g. `#[base = "/app/"]` (like
+/// `base` in the Vite config). It's passed as `--base` to the dev server, and the generated `get()`
+/// requests the assets under it. Always starts and ends with a `/`; `/` if not specified.
+#[cfg(vite_rs_dev)]
+fn derive_base(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let base_attrs = syn_utils::find_attribute_values(ast, "base");
+    if base_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[base = \"...\"] attribute.",
+        ));
+    }
+
+    match base_attrs.first() {
+        Some(base) if !base.starts_with('/') => Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "#[base = \"{}\"] must be a path starting with a `/`, e.g. \"/app/\"",
+                base
+            ),
+        )),
+        Some(base) => Ok(Some(format!("{}/", base.trim_end_matches('/')))),
+        None => Ok(None),
+    }
+}
+
+/// A tag printed in front of each line of the dev server's output, e.g.
+/// `#[dev_output_prefix = "[assets:frontend]"]`, so the output of several dev servers can be told
+/// apart (see `DevServerStdio::Prefixed`).
+#[cfg(vite_rs_dev)]
+fn derive_dev_output_prefix(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let prefix_attrs = syn_utils::find_attribute_values(ast, "dev_output_prefix");
+    if prefix_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_output_prefix = \"...\"] attribute.",
+        ));
+    }
+
+    Ok(prefix_attrs.first().cloned())
+}
+
+/// How long the generated `get()` waits for the dev server, e.g. `#[dev_fetch_timeout = "10s"]` or
+/// `"500ms"`; `"none"` waits indefinitely. Returns `None` if not specified (the default of
+/// `vite_rs_dev_server::transport::FetchOptions` applies), and `Some(None)` for `"none"`.
+#[cfg(vite_rs_dev)]
+fn derive_dev_fetch_timeout(
+    ast: &syn::DeriveInput,
+) -> syn::Result<Option<Option<std::time::Duration>>> {
+    let timeout_attrs = syn_utils::find_attribute_values(ast, "dev_fetch_timeout");
+    if timeout_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_fetch_timeout = \"...\"] attribute.",
+        ));
+    }
+
+    let Some(timeout) = timeout_attrs.first() else {
+        return Ok(None);
+    };
+
+    if timeout == "none" {
+        return Ok(Some(None));
+    }
+
+    let duration = match timeout.strip_suffix("ms") {
+        Some(millis) => millis.parse().ok().map(std::time::Duration::from_millis),
+        None => timeout
+            .strip_suffix('s')
+            .and_then(|secs| secs.parse().ok())
+            .map(std::time::Duration::from_secs),
+    };
+
+    match duration {
+        Some(duration) if !duration.is_zero() => Ok(Some(Some(duration))),
+        _ => Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "#[dev_fetch_timeout = \"{}\"] must be a positive duration like \"10s\" or \"500ms\", or \"none\"",
+                timeout
+            ),
+        )),
+    }
+}
+
+/// How many more times the generated `get()` sends a request that failed (e.g. timed out), e.g.
+/// `#[dev_fetch_retries = "2"]`. Defaults to `0`.
+#[cfg(vite_rs_dev)]
+fn derive_dev_fetch_retries(ast: &syn::DeriveInput) -> syn::Result<u32> {
+    let retries_attrs = syn_utils::find_attribute_values(ast, "dev_fetch_retries");
+    if retries_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_fetch_retries = \"...\"] attribute.",
+        ));
+    }
+
+    match retries_attrs.first() {
+        Some(retries) => retries.parse::<u32>().map_err(|_| {
+            syn::Error::new_spanned(
+                ast,
+                format!(
+                    "#[dev_fetch_retries = \"{}\"] must be a non-negative integer",
+                    retries
+                ),
+            )
+        }),
+        None => Ok(0),
+    }
+}
+
+/// How many requests the generated `get()` sends to the dev server at once, e.g.
+/// `#[dev_fetch_concurrency = "16"]`; further requests wait. Unlimited if not specified.
+#[cfg(vite_rs_dev)]
+fn derive_dev_fetch_concurrency(ast: &syn::DeriveInput) -> syn::Result<Option<usize>> {
+    let concurrency_attrs = syn_utils::find_attribute_values(ast, "dev_fetch_concurrency");
+    if concurrency_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_fetch_concurrency = \"...\"] attribute.",
+        ));
+    }
+
+    match concurrency_attrs.first() {
+        Some(concurrency) => match concurrency.parse::<usize>() {
+            Ok(concurrency) if concurrency > 0 => Ok(Some(concurrency)),
+            _ => Err(syn::Error::new_spanned(
+                ast,
+                format!(
+                    "#[dev_fetch_concurrency = \"{}\"] must be a positive integer",
+                    concurrency
+                ),
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Where the generated `get()` keeps the dev server's responses between runs, e.g.
+/// `#[dev_response_cache = "target/vite-rs-cache"]` (relative to the crate's directory), so
+/// unchanged files aren't transferred again after restarting the binary. Each struct gets a
+/// subdirectory. Not cached if not specified.
+#[cfg(vite_rs_dev)]
+fn derive_dev_response_cache(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let response_cache_attrs = syn_utils::find_attribute_values(ast, "dev_response_cache");
+    if response_cache_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_response_cache = \"...\"] attribute.",
+        ));
+    }
+
+    Ok(response_cache_attrs.first().map(|dir| {
+        let base = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+
+        Path::new(&base)
+            .join(dir)
+            .join(ast.ident.to_string())
+            .to_str()
+            .unwrap()
+            .to_string()
+    }))
+}
+
+/// Whether the generated `get()` checks the content types the dev server sends against the ones
+/// expected from the paths: `#[dev_content_type_check = "warn"]` prints a warning for each
+/// mismatch (e.g. `src/main.ts` served as `text/html` by a misconfigured plugin), `"allow"`
+/// (default) doesn't check. Only used in development builds.
+#[cfg(vite_rs_dev)]
+fn derive_dev_content_type_check(ast: &syn::DeriveInput) -> syn::Result<bool> {
+    let content_type_check_attrs = syn_utils::find_attribute_values(ast, "dev_content_type_check");
+    if content_type_check_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[dev_content_type_check = \"...\"] attribute.",
+        ));
+    }
+
+    match content_type_check_attrs.first().map(String::as_str) {
+        None | Some("allow") => Ok(false),
+        Some("warn") => Ok(true),
+        Some(name) => Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "Unknown #[dev_content_type_check = \"{}\"]. Expected one of: allow, warn.",
+                name
+            ),
+        )),
+    }
+}
+
+/// Whether `get()` ignores the query string of a path, so cache-busted URLs like `logo.png?v=3`
+/// find the embedded `logo.png`: `#[query_strings = "strip"]` (default) or `"keep"`, which looks
+/// up the path as given. Only used in release builds; the dev server gets the query string either
+/// way.
+#[cfg(not(vite_rs_dev))]
+fn derive_strip_query(ast: &syn::DeriveInput) -> syn::Result<bool> {
+    let query_strings_attrs = syn_utils::find_attribute_values(ast, "query_strings");
+    if query_strings_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[query_strings = \"...\"] attribute.",
+        ));
+    }
+
+    match query_strings_attrs.first().map(String::as_str) {
+        None | Some("strip") => Ok(true),
+        Some("keep") => Ok(false),
+        Some(name) => Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "Unknown #[query_strings = \"{}\"]. Expected one of: strip, keep.",
+                name
+            ),
+        )),
+    }
+}
+
+/// Files which Vite plugins emit next to the bundles, e.g. a service worker, to register as
+/// manifest entries: `#[generated_entry("sw.js", alias = "service-worker", service_worker_allowed = "/")]`.
+/// The glob matches output paths like `ViteServe`'s patterns; `alias` names the entry (for a
+/// single file) and `service_worker_allowed` sets the `Service-Worker-Allowed` header. Only used
+/// in release builds; the dev server serves these files itself, by their paths (so aliases don't
+/// resolve there).
+#[cfg(not(vite_rs_dev))]
+fn derive_generated_entries(
+    ast: &syn::DeriveInput,
+) -> syn::Result<Vec<vite::build::GeneratedEntry>> {
+    let mut generated_entries = Vec::new();
+
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("generated_entry"))
+    {
+        let generated_entry = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let glob = input.parse::<syn::LitStr>()?.value();
+            let (mut alias, mut service_worker_allowed) = (None, None);
+
+            while !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+                if input.is_empty() {
+                    break;
+                }
+
+                let key = input.parse::<syn::Ident>()?;
+                input.parse::<syn::Token![=]>()?;
+                let value = input.parse::<syn::LitStr>()?.value();
+
+                match key.to_string().as_str() {
+                    "alias" => alias = Some(value),
+                    "service_worker_allowed" => service_worker_allowed = Some(value),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            "Expected one of: alias, service_worker_allowed.",
+                        ))
+                    }
+                }
+            }
+
+            Ok(vite::build::GeneratedEntry {
+                glob,
+                alias,
+                service_worker_allowed,
+            })
+        });
+
+        generated_entries.push(generated_entry.map_err(|e| {
+            syn::Error::new_spanned(
+                attr,
+                format!(
+                    "expected #[generated_entry(\"<glob>\", alias = \"...\", service_worker_allowed = \"...\")]: {}",
+                    e
+                ),
+            )
+        })?);
+    }
+
+    Ok(generated_entries)
+}
+
+/// Which embedded files never change at their paths, because they have a content hash in their
+/// names, e.g. `#[immutable_assets = "auto"]` for Vite's default `assets/[name]-[hash][extname]`,
+/// or a pattern like `#[immutable_assets = "js/*.[hash].js"]` (see `ImmutablePattern`). Can be
+/// specified multiple times. Only used in release builds.
+#[cfg(not(vite_rs_dev))]
+fn derive_immutable_assets(
+    ast: &syn::DeriveInput,
+) -> syn::Result<Vec<vite::build::ImmutablePattern>> {
+    syn_utils::find_attribute_values(ast, "immutable_assets")
+        .iter()
+        .map(|pattern| {
+            vite::build::ImmutablePattern::parse(pattern).map_err(|e| {
+                syn::Error::new_spanned(
+                    ast,
+                    format!("Invalid #[immutable_assets = \"{}\"]: {}.", pattern, e),
+                )
+            })
+        })
+        .collect()
+}
+
+/// The zstd level (1 to 22) the assets are compressed with at compile time, with the `zstd`
+/// feature, e.g. `#[zstd_level = 22]` for the smallest files or `#[zstd_level = 3]` for faster
+/// release builds. 19 if not specified. Only used in release builds.
+#[cfg(not(vite_rs_dev))]
+fn derive_zstd_level(ast: &syn::DeriveInput) -> syn::Result<Option<i32>> {
+    let level_attrs = syn_utils::find_attribute_values(ast, "zstd_level");
+    if level_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[zstd_level = ...] attribute.",
+        ));
+    }
+
+    level_attrs
+        .first()
+        .map(|level| match level.parse::<i32>() {
+            Ok(level) if (1..=22).contains(&level) => Ok(level),
+            _ => Err(syn::Error::new_spanned(
+                ast,
+                format!("#[zstd_level = {}] must be an integer from 1 to 22", level),
+            )),
+        })
+        .transpose()
+}
+
+/// How `get()` percent-decodes the paths it's given, so `caf%C3%A9.png` (as browsers request it)
+/// finds `café.png`: `#[url_decoding = "loose"]` (default) decodes what it can, `"strict"`
+/// doesn't find paths with malformed or ambiguous escapes like `%2F`, and `"off"` looks up paths
+/// as given. In development, paths are encoded again when requesting them from the dev server.
+fn derive_url_decoding(ast: &syn::DeriveInput) -> syn::Result<vite::UrlDecoding> {
+    let url_decoding_attrs = syn_utils::find_attribute_values(ast, "url_decoding");
+    if url_decoding_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[url_decoding = \"...\"] attribute.",
+        ));
+    }
+
+    match url_decoding_attrs.first() {
+        None => Ok(vite::UrlDecoding::default()),
+        Some(name) => vite::UrlDecoding::from_name(name).ok_or_else(|| {
+            syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Unknown #[url_decoding = \"{}\"]. Expected one of: loose, strict, off.",
+                    name
+                ),
+            )
+        }),
+    }
+}
+
+/// What to do when the embedded JS and CSS bundles don't look minified, e.g. because `vite build`
+/// ran with `--mode development` or `build.minify: false`: `#[unminified_output = "warn"]`
+/// (default) prints a warning while compiling, `"deny"` fails compilation and `"allow"` skips the
+/// check. Only used in release builds.
+#[cfg(not(vite_rs_dev))]
+fn derive_unminified_output(ast: &syn::DeriveInput) -> syn::Result<vite::build::UnminifiedOutput> {
+    let unminified_output_attrs = syn_utils::find_attribute_values(ast, "unminified_output");
+    if unminified_output_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[unminified_output = \"...\"] attribute.",
+        ));
+    }
+
+    match unminified_output_attrs.first() {
+        None => Ok(vite::build::UnminifiedOutput::default()),
+        Some(name) => vite::build::UnminifiedOutput::from_name(name).ok_or_else(|| {
+            syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Unknown #[unminified_output = \"{}\"]. Expected one of: allow, warn, deny.",
+                    name
+                ),
+            )
+        }),
+    }
+}
+
+/// A file to write the embedded asset paths and entry names to during release builds, relative to
+/// the `root_dir`, e.g. `#[asset_paths_file = "./src/assets.d.ts"]`. Files ending with `.d.ts` get
+/// TypeScript types (`AssetPath` and `EntryName`); others get JSON.
+#[cfg(not(vite_rs_dev))]
+fn derive_asset_paths_file(
+    ast: &syn::DeriveInput,
+    absolute_root_dir: &str,
+) -> syn::Result<Option<String>> {
+    let asset_paths_file_attrs = syn_utils::find_attribute_values(ast, "asset_paths_file");
+    if asset_paths_file_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[asset_paths_file = \"...\"] attribute.",
+        ));
+    }
+
+    Ok(asset_paths_file_attrs.first().map(|path| {
+        PathBuf::from_iter(&[absolute_root_dir, path])
+            .to_str()
+            .unwrap()
+            .to_string()
+    }))
+}
+
+/// The package manager used to run Vite, both for `vite build` (in release builds) and the dev server.
+/// Defaults to `npm` (`npx vite`) but can be overridden with `#[package_manager = "pnpm"]`.
+/// Supported values are `npm`, `pnpm`, `yarn` and `bun`.
+fn derive_package_manager(
+    ast: &syn::DeriveInput,
+) -> syn::Result<vite_rs_dev_server::PackageManager> {
+    let package_manager_attrs = syn_utils::find_attribute_values(ast, "package_manager");
+    if package_manager_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[package_manager = \"...\"] attribute.",
+        ));
+    }
+
+    match package_manager_attrs.first() {
+        None => Ok(vite_rs_dev_server::PackageManager::default()),
+        Some(name) => vite_rs_dev_server::PackageManager::from_name(name).ok_or_else(|| {
+            syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Unknown package manager '{}'. Expected one of: npm, pnpm, yarn, bun.",
+                    name
+                ),
+            )
+        }),
+    }
+}
+
+/// Arguments for the package manager (or `deno run`), placed before `vite`, both for `vite build`
+/// (in release builds) and the dev server. For example, behind a corporate proxy:
+/// `#[package_manager_args("--registry=https://npm.example.com")]`. Can be specified multiple times.
+///
+/// Machine-specific arguments can be set with the `VITE_RS_PACKAGE_MANAGER_ARGS` environment variable instead.
+fn derive_package_manager_args(ast: &syn::DeriveInput) -> syn::Result<Vec<String>> {
+    syn_utils::find_attribute_lists(ast, "package_manager_args")
+}
+
+/// The executable run instead of the package manager's (e.g. `npx`), both for `vite build` (in
+/// release builds) and the dev server, e.g. `#[npx_path = "/opt/node/bin/npx"]` for Node.js
+/// installs that aren't on the `PATH`. Only applies to the `node` runtime.
+///
+/// Machine-specific paths can be set with the `VITE_RS_NPX_PATH` environment variable instead,
+/// which takes precedence.
+fn derive_npx_path(ast: &syn::DeriveInput) -> syn::Result<Option<String>> {
+    let mut npx_path_attrs = syn_utils::find_attribute_values(ast, "npx_path");
+    if npx_path_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[npx_path = \"...\"] attribute.",
+        ));
+    }
+
+    Ok(npx_path_attrs.pop())
+}
+
+/// The Node.js version manager Vite is run through, both for `vite build` (in release builds) and
+/// the dev server, so it gets the Node.js version the project pins rather than the first `node`
+/// on `PATH`: `volta`, `nvm` (`.nvmrc`), `mise`, or `auto` for whichever the project is set up
+/// for, e.g. `#[version_manager = "auto"]`. Only applies to the `node` runtime.
+fn derive_version_manager(
+    ast: &syn::DeriveInput,
+    runtime: vite_rs_dev_server::Runtime,
+) -> syn::Result<Option<vite_rs_dev_server::VersionManager>> {
+    let version_manager_attrs = syn_utils::find_attribute_values(ast, "version_manager");
+    if version_manager_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[version_manager = \"...\"] attribute.",
+        ));
+    }
+
+    let Some(name) = version_manager_attrs.first() else {
+        return Ok(None);
+    };
+
+    if runtime != vite_rs_dev_server::Runtime::Node {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[version_manager] only applies to the `node` runtime; remove it or the #[runtime] attribute.",
+        ));
+    }
+
+    vite_rs_dev_server::VersionManager::from_name(name)
+        .map(Some)
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Unknown version manager '{}'. Expected one of: auto, volta, nvm, mise.",
+                    name
+                ),
+            )
+        })
+}
+
+/// The runtime used to run Vite: `node` (default) or `deno`, e.g. `#[runtime = "deno"]`.
+/// With Deno, Vite is run with `deno run -A npm:vite` and `#[package_manager]` doesn't apply.
+fn derive_runtime(ast: &syn::DeriveInput) -> syn::Result<vite_rs_dev_server::Runtime> {
+    let runtime_attrs = syn_utils::find_attribute_values(ast, "runtime");
+    if runtime_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[runtime = \"...\"] attribute.",
+        ));
+    }
+
+    let runtime = match runtime_attrs.first() {
+        None => vite_rs_dev_server::Runtime::default(),
+        Some(name) => vite_rs_dev_server::Runtime::from_name(name).ok_or_else(|| {
+            syn::Error::new_spanned(
+                ast,
+                format!("Unknown runtime '{}'. Expected one of: node, deno.", name),
+            )
+        })?,
+    };
+
+    let has_package_manager = !syn_utils::find_attribute_values(ast, "package_manager").is_empty();
+    if runtime != vite_rs_dev_server::Runtime::Node && has_package_manager {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[package_manager] only applies to the `node` runtime; remove it or the #[runtime] attribute.",
+        ));
+    }
+
+    let has_npx_path = !syn_utils::find_attribute_values(ast, "npx_path").is_empty();
+    if runtime != vite_rs_dev_server::Runtime::Node && has_npx_path {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[npx_path] only applies to the `node` runtime; remove it or the #[runtime] attribute.",
+        ));
+    }
+
+    Ok(runtime)
+}
+
+/// The Vite config file, relative to the `root_dir`. When specified with `#[vite_config = "vite.custom.config.ts"]`,
+/// it is passed as `--config` to both the dev server and `vite build`. Otherwise, Vite looks for a
+/// `vite.config.*` file in the `root_dir`.
+fn derive_vite_config(
+    ast: &syn::DeriveInput,
+    absolute_root_dir: &str,
+) -> syn::Result<Option<String>> {
+    let mut vite_config_attrs = syn_utils::find_attribute_values(ast, "vite_config");
+    if vite_config_attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "#[derive(vite_rs::Embed)] must only contain a single #[vite_config = \"vite.config.ts\"] attribute.",
+        ));
+    }
+
+    if vite_config_attrs.is_empty() {
+        return Ok(None);
+    }
+
+    let vite_config = Path::new(absolute_root_dir).join(vite_config_attrs.remove(0));
+    if !vite_config.is_file() && tooling::stub_reason().is_none() {
+        return Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "Vite config file '{}' specified with #[vite_config] does not exist",
+                vite_config.display()
+            ),
+        ));
+    }
+
+    let vite_config = vite_config.canonicalize().unwrap_or(vite_config);
+
+    Ok(Some(vite_config.to_str().unwrap().to_string()))
+}
+
+/// Environment variables for the Vite process, e.g. `#[vite_env = "VITE_API_URL=http://localhost:3000"]`.
+/// Can be specified multiple times. They're set for both `vite build` (in release builds) and the dev
+/// server, on top of (or overriding) the variables inherited from the environment.
+fn derive_vite_envs(ast: &syn::DeriveInput) -> syn::Result<Vec<(String, String)>> {
+    syn_utils::find_attribute_values(ast, "vite_env")
+        .into_iter()
+        .map(|env| match env.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+            _ => Err(syn::Error::new_spanned(
+                ast,
+                format!(
+                    "Invalid #[vite_env = \"{}\"]; expected #[vite_env = \"NAME=value\"].",
+                    env
+                ),
+            )),
+        })
+        .collect()
+}
+
+/// Node.js flags for the Vite process, e.g. `#[node_options = "--max-old-space-size=8192"]` so
+/// large projects don't run out of memory during `vite build`. Can be specified multiple times.
+/// They're added to `NODE_OPTIONS` (after the inherited flags) for both `vite build` (in release
+/// builds) and the dev server.
+fn derive_node_options(ast: &syn::DeriveInput) -> syn::Result<Vec<String>> {
+    Ok(syn_utils::find_attribute_values(ast, "node_options")
+        .iter()
+        .flat_map(|options| options.split_whitespace().map(String::from))
+        .collect())
+}
+
 /// If crate_path is defined, use that as a syn::Path, otherwise use the crate's name.
 /// This is useful when someone is using this crate from a crate path that is different from
 /// the default: `crate::vite_rs`. In that case, they can specify something like:
@@ -195,50 +1215,297 @@ fn derive_crate_path(ast: &syn::DeriveInput) -> syn::Result<syn::Path> {
     }
 
     let crate_path = {
-        if crate_path_attrs.len() == 0 {
+        if crate_path_attrs.is_empty() {
             // we don't use env!("CARGO_PKG_NAME") because this code is in the vite-rs-embed-macro, but the end user will be using vite-rs
             "vite_rs"
         } else {
-            &crate_path_attrs.get(0).unwrap()
+            crate_path_attrs.first().unwrap()
         }
     };
 
-    Ok(syn::parse_str::<syn::Path>(crate_path)?)
+    syn::parse_str::<syn::Path>(crate_path)
+}
+
+/// Path to the `vite_rs_interface` crate (which defines `ViteFile` and `GetFromVite`) in the
+/// generated code. Defaults to the `#[crate_path]`, which re-exports it, but can be overridden
+/// with `#[interface_crate_path = "my_facade::vite_rs_interface"]`.
+fn derive_interface_crate_path(
+    ast: &syn::DeriveInput,
+    crate_path: &syn::Path,
+) -> syn::Result<syn::Path> {
+    derive_crate_path_override(ast, "interface_crate_path")
+        .map(|path| path.unwrap_or_else(|| crate_path.clone()))
+}
+
+/// Path to the `vite_rs_dev_server` crate in the generated (dev) code. Defaults to
+/// `<crate_path>::vite_rs_dev_server`, but can be overridden with
+/// `#[dev_server_crate_path = "my_facade::vite_rs_dev_server"]`.
+#[cfg(vite_rs_dev)]
+fn derive_dev_server_crate_path(
+    ast: &syn::DeriveInput,
+    crate_path: &syn::Path,
+) -> syn::Result<syn::Path> {
+    derive_crate_path_override(ast, "dev_server_crate_path")
+        .map(|path| path.unwrap_or_else(|| syn::parse_quote! { #crate_path::vite_rs_dev_server }))
+}
+
+fn derive_crate_path_override(
+    ast: &syn::DeriveInput,
+    attribute: &str,
+) -> syn::Result<Option<syn::Path>> {
+    let attrs = syn_utils::find_attribute_values(ast, attribute);
+    if attrs.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            ast,
+            format!(
+                "#[derive(vite_rs::Embed)] must only contain a single #[{} = \"...\"] attribute.",
+                attribute
+            ),
+        ));
+    }
+
+    attrs
+        .first()
+        .map(|path| syn::parse_str::<syn::Path>(path))
+        .transpose()
 }
 
 fn impl_vitejs_embed(ast: &syn::DeriveInput) -> syn::Result<TokenStream2> {
     syn_utils::ensure_unit_struct(ast)?;
 
     let absolute_root_dir = derive_absolute_root_dir(ast)?;
-    #[cfg(any(feature = "debug-prod", not(debug_assertions)))]
+    let absolute_working_dir = derive_absolute_working_dir(ast)?;
+    #[cfg(not(vite_rs_dev))]
     let relative_output_dir = derive_relative_output_dir(ast, &absolute_root_dir)?;
+    #[cfg(not(vite_rs_dev))]
+    let unminified_output = derive_unminified_output(ast)?;
+    #[cfg(not(vite_rs_dev))]
+    let asset_paths_file = derive_asset_paths_file(ast, &absolute_root_dir)?;
+    #[cfg(not(vite_rs_dev))]
+    let strip_query = derive_strip_query(ast)?;
+    #[cfg(not(vite_rs_dev))]
+    let generated_entries = derive_generated_entries(ast)?;
+    #[cfg(not(vite_rs_dev))]
+    let immutable_patterns = derive_immutable_assets(ast)?;
+    #[cfg(not(vite_rs_dev))]
+    let zstd_level = derive_zstd_level(ast)?;
+    let url_decoding = derive_url_decoding(ast)?;
     let crate_path = derive_crate_path(ast)?;
+    let interface_crate_path = derive_interface_crate_path(ast, &crate_path)?;
+    #[cfg(vite_rs_dev)]
+    let dev_server_crate_path = derive_dev_server_crate_path(ast, &crate_path)?;
+    let runtime = derive_runtime(ast)?;
+    let package_manager = derive_package_manager(ast)?;
+    let package_manager_args = derive_package_manager_args(ast)?;
+    let npx_path = derive_npx_path(ast)?;
+    let version_manager = derive_version_manager(ast, runtime)?;
+    let vite_config = derive_vite_config(ast, &absolute_root_dir)?;
+    let vite_envs = derive_vite_envs(ast)?;
+    let node_options = derive_node_options(ast)?;
 
-    let dev_server_host = "localhost";
-    let dev_server_port = derive_dev_server_port(ast);
+    let external_dev_server = derive_external_dev_server(ast)?;
+    let dev_server_host = derive_dev_server_host(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_server_port_fallback = derive_dev_server_port_fallback(ast)?;
+    let (dev_server_host, dev_server_port) = match &external_dev_server {
+        Some((host, port)) => (host.as_str(), *port),
+        None => (
+            dev_server_host.as_deref().unwrap_or("localhost"),
+            #[cfg(vite_rs_dev)]
+            derive_dev_server_port(ast),
+            // no dev server runs, so none is picked
+            #[cfg(not(vite_rs_dev))]
+            derive_configured_dev_server_port(ast).unwrap_or(0),
+        ),
+    };
+    #[cfg(vite_rs_dev)]
+    let dev_bypass_prefixes = derive_dev_bypass_prefixes(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_public_dir = derive_dev_public_dir(ast, &absolute_root_dir)?;
+    #[cfg(vite_rs_dev)]
+    let dev_server_args = derive_dev_server_args(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_server_log_level = derive_dev_server_log_level(ast)?;
+    #[cfg(vite_rs_dev)]
+    let clear_screen = derive_clear_screen(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_html_entries =
+        derive_dev_html_entries(ast, &absolute_root_dir, dev_public_dir.as_deref());
+    #[cfg(vite_rs_dev)]
+    let dev_server_public_url = derive_dev_server_public_url(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_output_prefix = derive_dev_output_prefix(ast)?;
+    #[cfg(vite_rs_dev)]
+    let base = derive_base(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_fetch_timeout = derive_dev_fetch_timeout(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_fetch_retries = derive_dev_fetch_retries(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_fetch_concurrency = derive_dev_fetch_concurrency(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_response_cache = derive_dev_response_cache(ast)?;
+    #[cfg(vite_rs_dev)]
+    let dev_content_type_check = derive_dev_content_type_check(ast)?;
+    #[cfg(vite_rs_dev)]
+    let preview_dir = derive_preview_dir(ast)?;
 
-    vite::build::generate_rust_code(
+    let code = vite::build::generate_rust_code(
         /* dev-only */
-        #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-        dev_server_host,
-        /* dev-only */
-        #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-        dev_server_port,
-        &crate_path,
+        #[cfg(vite_rs_dev)]
+        vite::build::DevConfig {
+            host: dev_server_host,
+            port: dev_server_port,
+            port_fallback: dev_server_port_fallback,
+            external: external_dev_server.is_some(),
+            public_url: dev_server_public_url.as_deref(),
+            output_prefix: dev_output_prefix.as_deref(),
+            base: base.as_deref(),
+            bypass_prefixes: &dev_bypass_prefixes,
+            public_dir: dev_public_dir.as_deref(),
+            args: &dev_server_args,
+            log_level: dev_server_log_level,
+            clear_screen,
+            fetch_timeout: dev_fetch_timeout,
+            fetch_retries: dev_fetch_retries,
+            fetch_concurrency: dev_fetch_concurrency,
+            response_cache: dev_response_cache.as_deref(),
+            content_type_check: dev_content_type_check,
+            html_entries: &dev_html_entries,
+            dev_server_crate_path: &dev_server_crate_path,
+            preview_dir: preview_dir.as_deref(),
+            url_decoding,
+        },
+        /* prod-only */
+        #[cfg(not(vite_rs_dev))]
+        vite::build::ReleaseConfig {
+            output_dir: &relative_output_dir,
+            unminified_output,
+            asset_paths_file: asset_paths_file.as_deref(),
+            strip_query,
+            url_decoding,
+            generated_entries,
+            immutable_patterns,
+            zstd_level,
+        },
+        vite::ViteCommand {
+            working_dir: absolute_working_dir.as_deref(),
+            runtime,
+            package_manager,
+            package_manager_args: &package_manager_args,
+            npx_path: npx_path.as_deref(),
+            version_manager,
+            config_file: vite_config.as_deref(),
+            envs: &vite_envs,
+            node_options: &node_options,
+        },
+        &interface_crate_path,
         &ast.ident,
         &absolute_root_dir,
-        /* prod-only */
-        #[cfg(any(feature = "debug-prod", not(debug_assertions)))]
-        &relative_output_dir,
-    )
+    )?;
+
+    let struct_ident = &ast.ident;
+    let tracked_env = tooling::tracked_env();
+    Ok(quote::quote! {
+        #tracked_env
+        #code
+
+        impl #struct_ident {
+            /// The host the dev server listens on, e.g. `localhost` or `0.0.0.0` (the external
+            /// one's with `#[external_dev_server]`).
+            pub const DEV_SERVER_HOST: &'static str = #dev_server_host;
+
+            /// The dev server's port, as chosen at compile time. `0` with
+            /// `#[dev_server_port = "auto"]`, and the preferred port with
+            /// `#[dev_server_port_fallback]`: `dev_server_url()` has the one picked at runtime.
+            /// In release builds, which don't pick one, `0` unless it's configured.
+            pub const DEV_SERVER_PORT: u16 = #dev_server_port;
+        }
+    })
 }
 
 /// For explanations of the attributes, please see:
 /// - #[root]: derive_absolute_root_dir (define above)
-/// - #[output]: derive_relative_output_dir (define above)
+/// - #[working_dir]: derive_absolute_working_dir (define above)
+/// - #[output]: derive_relative_output_dir, derive_preview_dir (define above)
 /// - #[dev_server_port]: derive_dev_server_port (define above)
+/// - #[dev_server_port_fallback]: derive_dev_server_port_fallback (define above)
+/// - #[external_dev_server]: derive_external_dev_server (define above)
+/// - #[dev_server_host]: derive_dev_server_host (define above)
+/// - #[dev_server_public_url]: derive_dev_server_public_url (define above)
+/// - #[dev_output_prefix]: derive_dev_output_prefix (define above)
+/// - #[base]: derive_base (define above)
+/// - #[dev_fetch_timeout]: derive_dev_fetch_timeout (define above)
+/// - #[dev_fetch_retries]: derive_dev_fetch_retries (define above)
+/// - #[dev_fetch_concurrency]: derive_dev_fetch_concurrency (define above)
+/// - #[dev_response_cache]: derive_dev_response_cache (define above)
+/// - #[dev_content_type_check]: derive_dev_content_type_check (define above)
+/// - #[dev_bypass_prefix]: derive_dev_bypass_prefixes (define above)
+/// - #[dev_public_dir]: derive_dev_public_dir (define above)
+/// - #[dev_server_args]: derive_dev_server_args (define above)
+/// - #[dev_server_log_level]: derive_dev_server_log_level (define above)
+/// - #[clear_screen]: derive_clear_screen (define above)
+/// - #[unminified_output]: derive_unminified_output (define above)
+/// - #[asset_paths_file]: derive_asset_paths_file (define above)
+/// - #[query_strings]: derive_strip_query (define above)
+/// - #[url_decoding]: derive_url_decoding (define above)
+/// - #[generated_entry]: derive_generated_entries (define above)
+/// - #[immutable_assets]: derive_immutable_assets (define above)
+/// - #[zstd_level]: derive_zstd_level (define above)
+/// - #[runtime]: derive_runtime (define above)
+/// - #[package_manager]: derive_package_manager (define above)
+/// - #[package_manager_args]: derive_package_manager_args (define above)
+/// - #[npx_path]: derive_npx_path (define above)
+/// - #[version_manager]: derive_version_manager (define above)
+/// - #[vite_config]: derive_vite_config (define above)
+/// - #[vite_env]: derive_vite_envs (define above)
+/// - #[node_options]: derive_node_options (define above)
 /// - #[crate_path]: derive_crate_path (define above)
-#[proc_macro_derive(Embed, attributes(root, output, dev_server_port, crate_path))]
+/// - #[interface_crate_path]: derive_interface_crate_path (define above)
+/// - #[dev_server_crate_path]: derive_dev_server_crate_path (define above)
+#[proc_macro_derive(
+    Embed,
+    attributes(
+        root,
+        working_dir,
+        output,
+        dev_server_port,
+        dev_server_port_fallback,
+        external_dev_server,
+        dev_server_host,
+        dev_server_public_url,
+        dev_output_prefix,
+        base,
+        dev_fetch_timeout,
+        dev_fetch_retries,
+        dev_fetch_concurrency,
+        dev_response_cache,
+        dev_content_type_check,
+        dev_bypass_prefix,
+        dev_public_dir,
+        dev_server_args,
+        dev_server_log_level,
+        clear_screen,
+        unminified_output,
+        asset_paths_file,
+        query_strings,
+        url_decoding,
+        generated_entry,
+        immutable_assets,
+        zstd_level,
+        runtime,
+        package_manager,
+        package_manager_args,
+        npx_path,
+        version_manager,
+        vite_config,
+        vite_env,
+        node_options,
+        crate_path,
+        interface_crate_path,
+        dev_server_crate_path
+    )
+)]
 pub fn derive_input_object(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     match impl_vitejs_embed(&ast) {
@@ -1,4 +1,4 @@
-use syn::{Data, Expr, ExprLit, Fields, Lit, Meta, MetaNameValue};
+use syn::{punctuated::Punctuated, Data, Expr, ExprLit, Lit, LitStr, Meta, MetaNameValue, Token};
 
 /// Find all pairs of the `name = "value"` attribute from the derive input
 pub fn find_attribute_values(ast: &syn::DeriveInput, attr_name: &str) -> Vec<String> {
@@ -22,19 +22,57 @@ pub fn find_attribute_values(ast: &syn::DeriveInput, attr_name: &str) -> Vec<Str
                     }),
                 ..
             }) => Some(val.base10_digits().to_string()),
+            // `name = true`
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Bool(val),
+                        ..
+                    }),
+                ..
+            }) => Some(val.value.to_string()),
             // other
             _ => None,
         })
         .collect()
 }
 
-/// Returns an Err if the DeriveInput is not a unit struct
+/// Find all values of the `name("value1", "value2")` attribute from the derive input, in order.
+/// The attribute can be specified multiple times; the values are concatenated.
+pub fn find_attribute_lists(ast: &syn::DeriveInput, attr_name: &str) -> syn::Result<Vec<String>> {
+    let mut values = Vec::new();
+
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(attr_name))
+    {
+        let list = attr
+            .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+            .map_err(|_| {
+                syn::Error::new_spanned(
+                    attr,
+                    format!(
+                        "expected a list of strings: #[{}(\"...\", \"...\")]",
+                        attr_name
+                    ),
+                )
+            })?;
+
+        values.extend(list.iter().map(LitStr::value));
+    }
+
+    Ok(values)
+}
+
+/// Returns an Err if the DeriveInput is not a zero-sized struct: the generated code creates
+/// values of it out of thin air (`Assets::boxed()`, `Default`).
 ///
 /// # Example
 ///
 /// ```ignore
 /// #[derive(vite_rs::Embed)]
-/// struct MyStruct;
+/// struct MyStruct; // or `struct MyStruct {}`
 /// ```
 ///
 /// Instead of:
@@ -46,23 +84,26 @@ pub fn find_attribute_values(ast: &syn::DeriveInput, attr_name: &str) -> Vec<Str
 /// }
 /// ```
 pub fn ensure_unit_struct(ast: &syn::DeriveInput) -> syn::Result<()> {
-    match ast.data {
-        Data::Struct(ref data) => match data.fields {
-            Fields::Unit => {}
-            _ => {
-                return Err(syn::Error::new_spanned(
-                    ast,
-                    "Embed can only be derived for unit structs",
-                ))
-            }
-        },
-        _ => {
-            return Err(syn::Error::new_spanned(
-                ast,
-                "Embed can only be derived for unit structs",
-            ))
-        }
+    let Data::Struct(ref data) = ast.data else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "Embed can only be derived for unit structs, e.g. `struct Assets;`",
+        ));
     };
 
+    if !data.fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "Embed can only be derived for structs without fields, e.g. `struct Assets;`: the assets belong to the type, so keep other state in a separate struct",
+        ));
+    }
+
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "Embed can't be derived for generic structs: derive it for a separate struct for each set of assets",
+        ));
+    }
+
     Ok(())
 }
@@ -0,0 +1,60 @@
+//! Detects macro expansion by tooling which only needs the generated API, not the assets:
+//! rust-analyzer, `cargo doc` on docs.rs, or anything run with `VITE_RS_ANALYZER=1`.
+//!
+//! In these cases, release builds skip `vite build` (and embed no files) and dev builds skip
+//! looking for a free dev server port, which keeps IDEs responsive. The generated API is the
+//! same, and missing project files (e.g. a frontend excluded from the published crate, which
+//! docs.rs builds from) aren't an error.
+
+use std::env;
+use std::path::Path;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Returns the reason assets shouldn't be built, if any.
+pub fn stub_reason() -> Option<&'static str> {
+    if env::var("VITE_RS_ANALYZER").is_ok_and(|value| value == "1" || value == "true") {
+        return Some("VITE_RS_ANALYZER is set");
+    }
+
+    if env::var_os("DOCS_RS").is_some() {
+        return Some("building on docs.rs");
+    }
+
+    if is_rust_analyzer() {
+        return Some("expanded by rust-analyzer");
+    }
+
+    None
+}
+
+/// Whether rust-analyzer runs `cargo check` with itself as the workspace wrapper. It sets
+/// `RA_RUSTC_WRAPPER` for it; otherwise the wrapper's file name is checked (e.g.
+/// `rust-analyzer.exe` or `rust-analyzer-x86_64-unknown-linux-gnu`), not the whole path, which
+/// may contain `rust-analyzer` for unrelated reasons.
+fn is_rust_analyzer() -> bool {
+    if env::var_os("RA_RUSTC_WRAPPER").is_some() {
+        return true;
+    }
+
+    let Some(wrapper) = env::var_os("RUSTC_WORKSPACE_WRAPPER") else {
+        return false;
+    };
+
+    Path::new(&wrapper)
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name == "rust-analyzer" || name.starts_with("rust-analyzer-"))
+}
+
+/// Makes rustc record the environment variables `stub_reason` reads as dependencies of the
+/// generated code, which Cargo doesn't know about otherwise: without this, a build with
+/// `VITE_RS_ANALYZER=1` would be reused by the next build without it, and embed no assets.
+/// (Cargo rebuilds when `RUSTC_WORKSPACE_WRAPPER` changes by itself.)
+pub fn tracked_env() -> TokenStream2 {
+    quote! {
+        const _: ::core::option::Option<&str> = ::core::option_env!("VITE_RS_ANALYZER");
+        const _: ::core::option::Option<&str> = ::core::option_env!("DOCS_RS");
+    }
+}
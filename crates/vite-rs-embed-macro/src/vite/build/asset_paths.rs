@@ -0,0 +1,45 @@
+//! Writes the embedded asset paths to a file (`#[asset_paths_file]`), so frontend code and CI
+//! can check the paths Rust code refers to.
+
+/// Writes `assets` (the embedded files) and `entries` (the manifest keys of entry chunks, which
+/// `get()` also accepts) to `absolute_path`: as TypeScript declarations if it ends with `.d.ts`,
+/// as JSON otherwise. The file is only written when its contents changed, so tools watching it
+/// (e.g. the dev server) aren't triggered needlessly.
+pub fn write(absolute_path: &str, assets: &[String], entries: &[String]) -> std::io::Result<()> {
+    let contents = if absolute_path.ends_with(".d.ts") {
+        typescript(assets, entries)
+    } else {
+        let json = serde_json::json!({ "assets": assets, "entries": entries });
+        format!("{:#}\n", json)
+    };
+
+    if std::fs::read_to_string(absolute_path).ok().as_deref() == Some(contents.as_str()) {
+        return Ok(());
+    }
+
+    if let Some(parent) = std::path::Path::new(absolute_path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(absolute_path, contents)
+}
+
+fn typescript(assets: &[String], entries: &[String]) -> String {
+    format!(
+        "// Generated by vite-rs; do not edit.\n\nexport type AssetPath ={};\n\nexport type EntryName ={};\n",
+        union(assets),
+        union(entries)
+    )
+}
+
+/// `\n  | "a"\n  | "b"`, or ` never` for no strings.
+fn union(strings: &[String]) -> String {
+    if strings.is_empty() {
+        return " never".to_string();
+    }
+
+    strings
+        .iter()
+        // JSON strings are valid TypeScript string literals
+        .map(|string| format!("\n  | {}", serde_json::Value::from(string.as_str())))
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Build duration and output size, embedded as `Assets::BUILD_STATS` and written to
+//! `target/vite-rs/build-stats/` (see `vite_rs::BuildStats`).
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+pub struct BuildStats {
+    /// `None` if the output directory was prebuilt (`hermetic`).
+    pub duration: Option<Duration>,
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub vite_version: Option<String>,
+}
+
+impl BuildStats {
+    /// Stats for the `compiled_files` (relative to `absolute_output_path`).
+    pub fn new(
+        duration: Option<Duration>,
+        absolute_root_dir: &str,
+        absolute_output_path: &str,
+        compiled_files: &[String],
+    ) -> Self {
+        let total_bytes = compiled_files
+            .iter()
+            .filter_map(|file| std::fs::metadata(Path::new(absolute_output_path).join(file)).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+
+        Self {
+            duration,
+            file_count: compiled_files.len(),
+            total_bytes,
+            vite_version: vite_version(absolute_root_dir),
+        }
+    }
+
+    /// A `BuildStats` expression for the generated code.
+    pub fn to_tokens(&self, crate_path: &syn::Path) -> TokenStream2 {
+        let duration = match self.duration {
+            Some(duration) => {
+                let millis = duration.as_millis() as u64;
+                quote! { Some(::std::time::Duration::from_millis(#millis)) }
+            }
+            None => quote! { None },
+        };
+        let file_count = self.file_count;
+        let total_bytes = self.total_bytes;
+        let vite_version = match &self.vite_version {
+            Some(version) => quote! { Some(#version) },
+            None => quote! { None },
+        };
+
+        quote! {
+            #crate_path::BuildStats::new(#duration, #file_count, #total_bytes, #vite_version)
+        }
+    }
+
+    /// Writes the stats as JSON to `target/vite-rs/build-stats/<crate>.<struct>.json`.
+    pub fn write(&self, struct_name: &str) -> std::io::Result<PathBuf> {
+        let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+        let json = serde_json::json!({
+            "crate": crate_name,
+            "struct": struct_name,
+            "duration_ms": self.duration.map(|duration| duration.as_millis() as u64),
+            "file_count": self.file_count,
+            "total_bytes": self.total_bytes,
+            "vite_version": self.vite_version,
+        });
+
+        let dir = target_dir().join("vite-rs").join("build-stats");
+        std::fs::create_dir_all(&dir)?;
+
+        let path = dir.join(format!("{}.{}.json", crate_name, struct_name));
+        std::fs::write(&path, format!("{}\n", json))?;
+        Ok(path)
+    }
+}
+
+/// The version in `node_modules/vite/package.json`, looked up from `absolute_root_dir` upwards
+/// (like Node.js resolves packages).
+fn vite_version(absolute_root_dir: &str) -> Option<String> {
+    Path::new(absolute_root_dir).ancestors().find_map(|dir| {
+        let package_json =
+            std::fs::read_to_string(dir.join("node_modules/vite/package.json")).ok()?;
+        let package_json = serde_json::from_str::<serde_json::Value>(&package_json).ok()?;
+
+        package_json["version"].as_str().map(String::from)
+    })
+}
+
+/// `CARGO_TARGET_DIR`, or the `target` directory of the workspace (the closest directory with a
+/// `Cargo.lock`) being compiled. Macros aren't told where the target directory is, so a
+/// `--target-dir` passed on the command line isn't picked up.
+fn target_dir() -> PathBuf {
+    if let Some(target_dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(target_dir);
+    }
+
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let workspace_dir = manifest_dir
+        .ancestors()
+        .find(|dir| dir.join("Cargo.lock").is_file())
+        .unwrap_or(&manifest_dir);
+
+    workspace_dir.join("target")
+}
@@ -14,14 +14,29 @@ pub struct FileEntry {
     /// The MIME type of the file. Useful for serving the file.
     content_type: String,
 
-    /// The length of the file in bytes. Useful for serving the file.
-    content_length: u64,
+    /// The `Service-Worker-Allowed` header to serve the file with (see `#[generated_entry]`).
+    service_worker_allowed: Option<String>,
+
+    /// Whether the file's contents never change at its path (see `#[immutable_assets]`).
+    immutable: bool,
+
+    /// The zstd level to compress the file with (see `#[zstd_level]`).
+    zstd_level: i32,
 }
 
 impl FileEntry {
     pub fn new(key: String, absolute_file_path: String) -> std::io::Result<Self> {
         let metadata = std::fs::metadata(&absolute_file_path)?;
-        let last_modified = metadata.modified().ok().map(|last_modified| {
+        let last_modified = if cfg!(feature = "hermetic") {
+            // modification times differ between checkouts; see https://reproducible-builds.org/specs/source-date-epoch/
+            std::env::var("SOURCE_DATE_EPOCH")
+                .ok()
+                .and_then(|epoch| epoch.parse::<u64>().ok())
+                .map(|epoch| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(epoch))
+        } else {
+            metadata.modified().ok()
+        };
+        let last_modified = last_modified.map(|last_modified| {
             let last_modified_secs = last_modified
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .expect("Time before the UNIX epoch is unsupported")
@@ -36,61 +51,273 @@ impl FileEntry {
 
         Ok(Self {
             key,
-            last_modified: last_modified,
+            last_modified,
             content_type: mime_guess::from_path(&absolute_file_path)
                 .first_or_octet_stream()
                 .to_string(),
-            content_length: metadata.len(),
             absolute_file_path,
+            service_worker_allowed: None,
+            immutable: false,
+            zstd_level: DEFAULT_ZSTD_LEVEL,
         })
     }
 
+    pub fn with_service_worker_allowed(mut self, scope: Option<String>) -> Self {
+        self.service_worker_allowed = scope;
+        self
+    }
+
+    pub fn with_immutable(mut self, immutable: bool) -> Self {
+        self.immutable = immutable;
+        self
+    }
+
+    pub fn with_zstd_level(mut self, level: Option<i32>) -> Self {
+        self.zstd_level = level.unwrap_or(DEFAULT_ZSTD_LEVEL);
+        self
+    }
+
     pub fn match_key(&self) -> &String {
         &self.key
     }
 
-    pub fn match_value(&self, crate_path: &syn::Path) -> proc_macro2::TokenStream {
+    /// The code for the file's `ViteFile`, and its Subresource Integrity hash with the
+    /// `integrity` feature (from the same read of the file).
+    pub fn match_value(
+        &self,
+        crate_path: &syn::Path,
+    ) -> (proc_macro2::TokenStream, Option<String>) {
         self.code(crate_path)
     }
 
-    fn code(&self, crate_path: &syn::Path) -> proc_macro2::TokenStream {
+    fn code(&self, crate_path: &syn::Path) -> (proc_macro2::TokenStream, Option<String>) {
         use quote::quote;
 
         let absolute_file_path = &self.absolute_file_path;
 
         let last_modified = if let Some(last_modified) = &self.last_modified {
-            quote! { ::std::option::Option::Some(#last_modified) }
+            quote! { .with_last_modified(#last_modified) }
         } else {
-            quote! { ::std::option::Option::None }
+            quote! {}
         };
 
         let content_type = &self.content_type;
-        let content_length = self.content_length;
-
-        let content_hash = if cfg!(feature = "content-hash") {
-            // We have to read the file here because it's currently not possible to use sha2 in const fns until https://github.com/RustCrypto/hashes/issues/288 is resolved.
-            // And without a const fn, we cant generate a const HASH: &'static str = "..." for each FileEntry (which would be nice and in-line with the const BYTES array).
-            // Once the above is resolved, we won't have to read the file here and in the include_bytes!.
-            let bytes =
-                std::fs::read(absolute_file_path).expect("Failed to read file to compute hash");
-            let content_hash = crate::hash_utils::get_content_hash(&bytes);
-            quote! { content_hash: #content_hash, }
+
+        // We have to read the file here because it's currently not possible to use sha2 in const fns until https://github.com/RustCrypto/hashes/issues/288 is resolved.
+        // And without a const fn, we cant generate a const HASH: &'static str = "..." for each FileEntry (which would be nice and in-line with the const BYTES array).
+        // Once the above is resolved, we won't have to read the file here and in the include_bytes!.
+        let bytes = if cfg!(any(
+            feature = "content-hash",
+            feature = "integrity",
+            feature = "zstd",
+            feature = "gzip",
+            feature = "brotli"
+        )) {
+            std::fs::read(absolute_file_path).expect("Failed to read file to embed")
+        } else {
+            Vec::new()
+        };
+
+        let content_hash = match content_hash(&bytes) {
+            Some(content_hash) => quote! { .with_content_hash(#content_hash) },
+            None => quote! {},
+        };
+
+        let (zstd, gzip, brotli) = if is_compressible(content_type) {
+            (
+                zstd_copy(&bytes, self.zstd_level),
+                gzip_copy(&bytes),
+                brotli_copy(&bytes),
+            )
+        } else {
+            Default::default()
+        };
+        // copies which don't make the file smaller aren't worth embedding (e.g. for tiny files)
+        let smaller = |copy: Option<Vec<u8>>| copy.filter(|copy| copy.len() < bytes.len());
+        let (zstd, gzip) = (smaller(zstd), smaller(gzip));
+        // clients which accept both are sent the Brotli copy, so it has to beat the gzip one
+        let brotli = smaller(brotli).filter(|brotli| match &gzip {
+            Some(gzip) => brotli.len() < gzip.len(),
+            None => true,
+        });
+        let zstd = copy_field(quote! { with_zstd }, zstd);
+        let gzip = copy_field(quote! { with_gzip }, gzip);
+        let brotli = copy_field(quote! { with_brotli }, brotli);
+
+        let service_worker_allowed = match &self.service_worker_allowed {
+            Some(scope) => quote! { .with_service_worker_allowed(#scope) },
+            None => quote! {},
+        };
+
+        let immutable = if self.immutable {
+            quote! { .with_immutable(true) }
         } else {
             quote! {}
         };
 
-        quote! {
+        let include_bytes = if cfg!(feature = "hermetic") {
+            // keep absolute paths out of the generated code, so it's the same wherever the crate is built
+            let relative_file_path = manifest_relative_path(absolute_file_path);
+            quote! { include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #relative_file_path)) }
+        } else {
+            quote! { include_bytes!(#absolute_file_path) }
+        };
+
+        let code = quote! {
             {
-                const BYTES: &'static [u8] = include_bytes!(#absolute_file_path);
+                const BYTES: &'static [u8] = #include_bytes;
 
-                #crate_path::ViteFile {
-                    bytes: ::std::borrow::Cow::Borrowed(&BYTES),
-                    last_modified: #last_modified,
-                    content_type: #content_type,
-                    content_length: #content_length,
+                #crate_path::ViteFile::new(BYTES, #content_type)
+                    #last_modified
                     #content_hash
-                }
+                    #zstd
+                    #gzip
+                    #brotli
+                    #service_worker_allowed
+                    #immutable
             }
+        };
+
+        (code, integrity(&bytes))
+    }
+}
+
+/// zstd level used for embedded assets unless `#[zstd_level]` says otherwise; they're compressed
+/// once, at compile time.
+const DEFAULT_ZSTD_LEVEL: i32 = 19;
+
+/// Sets a compressed copy of the generated `ViteFile` with `method`, if there is one.
+fn copy_field(method: proc_macro2::TokenStream, copy: Option<Vec<u8>>) -> proc_macro2::TokenStream {
+    use quote::quote;
+
+    match copy {
+        Some(copy) => {
+            let copy = proc_macro2::Literal::byte_string(&copy);
+            quote! { .#method(#copy) }
         }
+        None => quote! {},
     }
 }
+
+/// The file's content hash, with the `content-hash` feature.
+#[cfg(feature = "content-hash")]
+fn content_hash(bytes: &[u8]) -> Option<String> {
+    Some(crate::hash_utils::get_content_hash(bytes))
+}
+
+#[cfg(not(feature = "content-hash"))]
+fn content_hash(_bytes: &[u8]) -> Option<String> {
+    None
+}
+
+/// The file's Subresource Integrity hash, with the `integrity` feature.
+#[cfg(feature = "integrity")]
+fn integrity(bytes: &[u8]) -> Option<String> {
+    Some(crate::hash_utils::get_integrity(bytes))
+}
+
+#[cfg(not(feature = "integrity"))]
+fn integrity(_bytes: &[u8]) -> Option<String> {
+    None
+}
+
+/// The file compressed with zstd, with the `zstd` feature.
+#[cfg(feature = "zstd")]
+fn zstd_copy(bytes: &[u8], level: i32) -> Option<Vec<u8>> {
+    Some(zstd::bulk::compress(bytes, level).expect("Failed to compress file with zstd"))
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_copy(_bytes: &[u8], _level: i32) -> Option<Vec<u8>> {
+    None
+}
+
+/// The file compressed with gzip, with the `gzip` feature.
+#[cfg(feature = "gzip")]
+fn gzip_copy(bytes: &[u8]) -> Option<Vec<u8>> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder
+        .write_all(bytes)
+        .expect("Failed to compress file with gzip");
+    Some(encoder.finish().expect("Failed to compress file with gzip"))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip_copy(_bytes: &[u8]) -> Option<Vec<u8>> {
+    None
+}
+
+/// The file compressed with Brotli, with the `brotli` feature.
+#[cfg(feature = "brotli")]
+fn brotli_copy(bytes: &[u8]) -> Option<Vec<u8>> {
+    let params = brotli::enc::BrotliEncoderParams {
+        quality: BROTLI_QUALITY,
+        lgwin: BROTLI_WINDOW_BITS,
+        size_hint: bytes.len(),
+        ..Default::default()
+    };
+
+    let mut compressed = Vec::new();
+    brotli::BrotliCompress(&mut &bytes[..], &mut compressed, &params)
+        .expect("Failed to compress file with Brotli");
+    Some(compressed)
+}
+
+#[cfg(not(feature = "brotli"))]
+fn brotli_copy(_bytes: &[u8]) -> Option<Vec<u8>> {
+    None
+}
+
+/// Brotli quality and window size (4 MiB) used for embedded assets: the highest quality, as for
+/// `brotli -q 11`.
+#[cfg(feature = "brotli")]
+const BROTLI_QUALITY: i32 = 11;
+#[cfg(feature = "brotli")]
+const BROTLI_WINDOW_BITS: i32 = 22;
+
+/// Whether compressed copies of files with `content_type` are worth embedding: not for formats
+/// which are compressed already, like most images, WOFF fonts, audio, video and archives.
+fn is_compressible(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+
+    match essence.split_once('/') {
+        Some(("image", subtype)) => {
+            matches!(subtype, "svg+xml" | "bmp" | "x-icon" | "vnd.microsoft.icon")
+        }
+        Some(("audio" | "video", _)) => false,
+        Some(("font", subtype)) => !matches!(subtype, "woff" | "woff2"),
+        _ => !matches!(
+            essence,
+            "application/gzip"
+                | "application/x-gzip"
+                | "application/zip"
+                | "application/zstd"
+                | "application/x-7z-compressed"
+                | "application/x-bzip2"
+                | "application/x-rar-compressed"
+                | "application/x-xz"
+        ),
+    }
+}
+
+/// `absolute_file_path` relative to the crate being compiled, with `/` separators.
+fn manifest_relative_path(absolute_file_path: &str) -> String {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_dir = std::path::Path::new(&manifest_dir)
+        .canonicalize()
+        .expect("Could not canonicalize CARGO_MANIFEST_DIR");
+
+    std::path::Path::new(absolute_file_path)
+        .strip_prefix(&manifest_dir)
+        .unwrap_or_else(|_| {
+            panic!(
+                "hermetic builds require the output directory to be inside the crate directory ({})",
+                manifest_dir.display()
+            )
+        })
+        .to_str()
+        .unwrap()
+        .replace('\\', "/")
+}
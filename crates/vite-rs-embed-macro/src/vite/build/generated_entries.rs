@@ -0,0 +1,120 @@
+//! Files which Vite plugins emit next to the bundles in Vite's manifest, e.g. the service worker
+//! and web app manifest of `vite-plugin-pwa`. Registered with `#[generated_entry]`, they become
+//! manifest entries like the bundles, instead of files only `get()` knows about.
+
+use std::collections::BTreeMap;
+
+use super::vite_manifest::{ViteManifest, ViteManifestEntry};
+
+/// A `#[generated_entry("<glob>", ...)]` attribute.
+pub struct GeneratedEntry {
+    /// Matched against the paths in the output directory, e.g. `workbox-*.js`.
+    pub glob: String,
+    /// The manifest key (and `get()` path) of the matching file, e.g. `service-worker`.
+    pub alias: Option<String>,
+    /// The `Service-Worker-Allowed` header sent with the matching files.
+    pub service_worker_allowed: Option<String>,
+}
+
+/// Adds the files matching each entry's glob to `vite_manifest` as entries, under their alias or
+/// their own path. Returns the `Service-Worker-Allowed` scopes by file.
+pub fn register(
+    generated_entries: &[GeneratedEntry],
+    compiled_files: &[String],
+    vite_manifest: &mut ViteManifest,
+) -> syn::Result<BTreeMap<String, String>> {
+    let error = |message: String| syn::Error::new(proc_macro2::Span::call_site(), message);
+    let mut service_worker_scopes = BTreeMap::new();
+
+    for entry in generated_entries {
+        let files = compiled_files
+            .iter()
+            .filter(|file| matches(&entry.glob, file))
+            .collect::<Vec<_>>();
+
+        if files.is_empty() {
+            return Err(error(format!(
+                "#[generated_entry(\"{}\")] doesn't match any file in the output directory. Is the plugin emitting it enabled for release builds?",
+                entry.glob
+            )));
+        }
+
+        if let (Some(alias), [_, _, ..]) = (&entry.alias, files.as_slice()) {
+            return Err(error(format!(
+                "#[generated_entry(\"{}\", alias = \"{}\")] matches {} files ({}); an alias needs exactly one.",
+                entry.glob,
+                alias,
+                files.len(),
+                files.iter().map(|file| file.as_str()).collect::<Vec<_>>().join(", ")
+            )));
+        }
+
+        for file in files {
+            let key = entry.alias.as_ref().unwrap_or(file);
+
+            match vite_manifest.get(key) {
+                // e.g. matched by two globs
+                Some(existing) if existing.file == *file => {}
+                Some(existing) => {
+                    return Err(error(format!(
+                        "#[generated_entry(\"{}\")]: '{}' is already the manifest key of '{}'.",
+                        entry.glob, key, existing.file
+                    )))
+                }
+                None => {
+                    vite_manifest.insert(
+                        key.clone(),
+                        ViteManifestEntry {
+                            file: file.clone(),
+                            imports: None,
+                            dynamicImports: None,
+                            css: None,
+                            isEntry: Some(true),
+                            isDynamicEntry: None,
+                        },
+                    );
+                }
+            }
+
+            if let Some(scope) = &entry.service_worker_allowed {
+                service_worker_scopes.insert(file.clone(), scope.clone());
+            }
+        }
+    }
+
+    Ok(service_worker_scopes)
+}
+
+/// Matches a path against a simple glob pattern, where `*` matches any sequence of characters
+/// (including `/`) and `?` matches a single character, like `ViteServe`'s patterns.
+fn matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches('/').as_bytes();
+    let path = path.as_bytes();
+
+    // iterative wildcard matching with backtracking to the last `*`
+    let (mut p, mut s) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while s < path.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, s));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == path[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match star {
+                Some((star_p, star_s)) => {
+                    p = star_p + 1;
+                    s = star_s + 1;
+                    star = Some((star_p, star_s + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
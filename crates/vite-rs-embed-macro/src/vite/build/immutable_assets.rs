@@ -0,0 +1,97 @@
+//! Which embedded files are immutable (see `#[immutable_assets]`): the ones whose paths have a
+//! content hash in them, which changes with their contents, so they can be cached for good.
+
+/// Vite's default output file names of chunks and assets: `assets/[name]-[hash][extname]`.
+const VITE_DEFAULT: &str = "assets/*-[hash].*";
+
+/// The shortest hash `[hash]` matches: Vite's default length.
+const MIN_HASH_LENGTH: usize = 8;
+
+/// A pattern written like Vite's output file names (e.g. `build.rollupOptions.output.chunkFileNames`):
+/// `[hash]` matches a content hash (8 or more characters from Vite's hash alphabet, see `is_hash`),
+/// `*` matches any sequence of characters (including `/`) and `?` a single character.
+pub struct ImmutablePattern(Vec<Token>);
+
+enum Token {
+    Byte(u8),
+    Any,
+    Star,
+    Hash,
+}
+
+impl ImmutablePattern {
+    /// Parses a pattern, or `auto` for Vite's default output file names.
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let pattern = match pattern {
+            "auto" => VITE_DEFAULT,
+            pattern => pattern.trim_start_matches('/'),
+        };
+
+        let mut tokens = Vec::new();
+        let mut rest = pattern;
+
+        while let Some(c) = rest.chars().next() {
+            if let Some(after) = rest.strip_prefix("[hash]") {
+                tokens.push(Token::Hash);
+                rest = after;
+                continue;
+            }
+
+            match c {
+                '[' => {
+                    let placeholder = rest.split_once(']').map_or(rest, |(name, _)| name);
+                    return Err(format!(
+                        "unsupported placeholder '{}]': only [hash] is supported (use * for [name])",
+                        placeholder
+                    ));
+                }
+                '*' => tokens.push(Token::Star),
+                '?' => tokens.push(Token::Any),
+                c => tokens.extend(c.to_string().bytes().map(Token::Byte)),
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+
+        Ok(Self(tokens))
+    }
+
+    pub fn matches(&self, path: &str) -> bool {
+        matches(&self.0, path.as_bytes())
+    }
+}
+
+fn matches(tokens: &[Token], path: &[u8]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return path.is_empty();
+    };
+
+    match token {
+        Token::Byte(byte) => path.first() == Some(byte) && matches(rest, &path[1..]),
+        Token::Any => !path.is_empty() && matches(rest, &path[1..]),
+        Token::Star => (0..=path.len()).any(|skipped| matches(rest, &path[skipped..])),
+        Token::Hash => {
+            // base64url, which includes the base36 and hex alphabets of `build.hashCharacters`
+            let hash_length = path
+                .iter()
+                .take_while(|byte| byte.is_ascii_alphanumeric() || **byte == b'-' || **byte == b'_')
+                .count();
+
+            (MIN_HASH_LENGTH..=hash_length)
+                .rev()
+                .any(|length| is_hash(&path[..length]) && matches(rest, &path[length..]))
+        }
+    }
+}
+
+/// Whether a run of hash characters looks like a content hash rather than a word, e.g. `BPvgi06w`
+/// but not `settings`, `_vendored` or `myComponent`: it has a digit, or two uppercase letters
+/// after the first character. The few hashes without either aren't flagged, so those files are
+/// just revalidated.
+fn is_hash(candidate: &[u8]) -> bool {
+    candidate.iter().any(u8::is_ascii_digit)
+        || candidate[1..]
+            .iter()
+            .filter(|byte| byte.is_ascii_uppercase())
+            .count()
+            >= 2
+}
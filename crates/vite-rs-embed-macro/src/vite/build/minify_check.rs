@@ -0,0 +1,88 @@
+//! Detects unminified bundles in the build output (e.g. from `vite build --mode development` or
+//! `build.minify: false`), so they don't end up in release binaries by accident.
+
+/// What to do when the build output contains unminified bundles (`#[unminified_output]`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UnminifiedOutput {
+    Allow,
+    /// Print a warning while compiling (default).
+    #[default]
+    Warn,
+    /// Fail compilation.
+    Deny,
+}
+
+impl UnminifiedOutput {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// Smaller files are never reported: there's little to gain from minifying them, and too few
+/// lines to tell.
+const MIN_SIZE: usize = 2 * 1024;
+
+/// Checks the JS and CSS files among `compiled_files` (relative to `absolute_output_path`).
+pub fn check(
+    unminified_output: UnminifiedOutput,
+    absolute_output_path: &str,
+    compiled_files: &[String],
+) -> syn::Result<()> {
+    if unminified_output == UnminifiedOutput::Allow {
+        return Ok(());
+    }
+
+    let unminified = compiled_files
+        .iter()
+        .filter(|path| {
+            [".js", ".mjs", ".css"]
+                .iter()
+                .any(|ext| path.ends_with(ext))
+        })
+        .filter(|path| {
+            let absolute_file_path =
+                std::path::PathBuf::from_iter(&[absolute_output_path, path.as_str()]);
+
+            std::fs::read_to_string(absolute_file_path)
+                .is_ok_and(|contents| looks_unminified(&contents))
+        })
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+
+    if unminified.is_empty() {
+        return Ok(());
+    }
+
+    let message = format!(
+        "these bundles don't look minified: {}. Was `vite build` run with `--mode development`, or with `build.minify` disabled? Set #[unminified_output = \"allow\"] if this is intended.",
+        unminified.join(", ")
+    );
+
+    match unminified_output {
+        UnminifiedOutput::Deny => Err(syn::Error::new(proc_macro2::Span::call_site(), message)),
+        _ => {
+            eprintln!("vite-rs: warning: {}", message);
+            Ok(())
+        }
+    }
+}
+
+/// Minifiers put whole modules on a few long lines; unminified code has short, indented lines.
+fn looks_unminified(contents: &str) -> bool {
+    if contents.len() < MIN_SIZE {
+        return false;
+    }
+
+    let lines = contents.lines().count().max(1);
+    let indented = contents
+        .lines()
+        .filter(|line| line.starts_with(' ') || line.starts_with('\t'))
+        .count();
+
+    contents.len() / lines < 100 && indented * 5 >= lines
+}
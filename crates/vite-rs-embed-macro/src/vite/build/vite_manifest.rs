@@ -6,6 +6,9 @@ pub struct ViteManifestEntry {
     /// Script content to load for this entry
     pub file: String,
 
+    /// Chunks imported statically by this entry (manifest keys)
+    pub imports: Option<Vec<String>>,
+
     /// Script content to lazy-load for this entry
     pub dynamicImports: Option<Vec<String>>, // using `import(..)`
 
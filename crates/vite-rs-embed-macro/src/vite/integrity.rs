@@ -0,0 +1,51 @@
+//! Generates `Assets::integrity()`, with the Subresource Integrity hashes of the embedded files
+//! (with the `integrity` feature).
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates `integrity()`, looking up `integrities` (SRI hashes by asset path), or always
+/// returning `None` without them (in development builds). Nothing without the `integrity` feature.
+pub fn generate_integrity_fn(integrities: Option<&BTreeMap<String, String>>) -> TokenStream2 {
+    if !cfg!(feature = "integrity") {
+        return quote! {};
+    }
+
+    let Some(integrities) = integrities else {
+        return quote! {
+            /// Always `None` in development builds: the dev server's modules change as they're
+            /// edited, so the `<script>` and `<link>` tags for them go without `integrity`.
+            pub fn integrity(_path: &str) -> Option<&'static str> {
+                None
+            }
+        };
+    };
+
+    let integrities = integrities.iter().map(|(path, integrity)| {
+        quote! {
+            (#path, #integrity),
+        }
+    });
+
+    quote! {
+        /// The Subresource Integrity hash (SHA-384) of the embedded file at `path`, or of the file
+        /// of the manifest chunk with that key (e.g. `src/main.ts`), for the `integrity`
+        /// attribute of its `<script>` or `<link>` tag.
+        pub fn integrity(path: &str) -> Option<&'static str> {
+            const INTEGRITIES: &'static [(&'static str, &'static str)] = &[
+                #(#integrities)*
+            ];
+
+            let find = |path: &str| {
+                INTEGRITIES
+                    .binary_search_by_key(&path, |entry| entry.0)
+                    .ok()
+                    .map(|index| INTEGRITIES[index].1)
+            };
+
+            find(path).or_else(|| find(Self::manifest().get(path)?.file))
+        }
+    }
+}
@@ -1,12 +1,90 @@
+pub mod integrity;
+pub mod routes;
+
+/// How Vite is run, derived from the struct's attributes. Used for both `vite build` (release)
+/// and the dev server.
+pub struct ViteCommand<'a> {
+    /// Absolute path of the `#[working_dir]`, if Vite doesn't run in the root directory.
+    pub working_dir: Option<&'a str>,
+    pub runtime: vite_rs_dev_server::Runtime,
+    pub package_manager: vite_rs_dev_server::PackageManager,
+    /// Arguments from `#[package_manager_args]`.
+    pub package_manager_args: &'a [String],
+    /// Path from `#[npx_path]`.
+    pub npx_path: Option<&'a str>,
+    /// From `#[version_manager]`.
+    pub version_manager: Option<vite_rs_dev_server::VersionManager>,
+    /// Absolute path of the `#[vite_config]` file.
+    pub config_file: Option<&'a str>,
+    /// Environment variables from `#[vite_env]`.
+    pub envs: &'a [(String, String)],
+    /// Flags from `#[node_options]`.
+    pub node_options: &'a [String],
+}
+
+/// How `get()` percent-decodes the paths it's given (`#[url_decoding]`), e.g. `caf%C3%A9.png`
+/// as requested by browsers for `café.png`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UrlDecoding {
+    /// Look up paths as given.
+    Off,
+    /// Decode what can be decoded, keep the rest (default).
+    #[default]
+    Loose,
+    /// Don't find paths which don't decode unambiguously, e.g. `a%2Fb.png`.
+    Strict,
+}
+
+impl UrlDecoding {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "off" => Some(Self::Off),
+            "loose" => Some(Self::Loose),
+            "strict" => Some(Self::Strict),
+            _ => None,
+        }
+    }
+
+    /// Generated code which shadows `path` with the decoded path, or evaluates to `rejected` if
+    /// it's rejected.
+    fn decode(
+        self,
+        crate_path: &syn::Path,
+        rejected: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            Self::Off => quote::quote! {},
+            Self::Loose => quote::quote! {
+                let path = #crate_path::http::decode_path(path);
+                let path = &*path;
+            },
+            Self::Strict => quote::quote! {
+                let Some(path) = #crate_path::http::decode_path_strict(path) else {
+                    return #rejected;
+                };
+                let path = &*path;
+            },
+        }
+    }
+}
+
 /// PROD build
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 pub mod build {
     use proc_macro2::TokenStream as TokenStream2;
     use quote::quote;
     use std::{collections::BTreeMap, path::PathBuf};
 
+    mod asset_paths;
+    mod build_stats;
     mod file_entry;
     use file_entry::FileEntry;
+    mod generated_entries;
+    pub use generated_entries::GeneratedEntry;
+    mod immutable_assets;
+    pub use immutable_assets::ImmutablePattern;
+    mod minify_check;
+    pub use minify_check::UnminifiedOutput;
     mod vite_manifest;
 
     fn list_compiled_files(absolute_output_path: &str) -> Vec<String> {
@@ -24,39 +102,77 @@ pub mod build {
             .filter(|path| !path.starts_with(".vite")) // ignore vite manifest or other vite-internal files
             .collect::<Vec<_>>();
 
+        // the directory listing order depends on the filesystem; keep `iter()` reproducible
+        let mut compiled_files = compiled_files;
+        compiled_files.sort();
+
         compiled_files
     }
 
-    pub fn generate_rust_code(
-        crate_path: &syn::Path,
-        struct_ident: &syn::Ident,
+    /// Runs `vite build` into `absolute_output_path` and returns the generated manifest.
+    fn vite_build(
+        vite: &super::ViteCommand,
         absolute_root_dir: &str,
-        relative_output_dir: &str,
-    ) -> syn::Result<TokenStream2> {
-        // proc_macro::tracked_path::path(absolute_root_dir); // => please see comments @ crates/vite-rs/tests/recompilation_test.rs:43
+        absolute_output_path: &str,
+    ) -> syn::Result<vite_manifest::ViteManifest> {
+        let mut package_manager_args = vite.package_manager_args.to_vec();
+        package_manager_args.extend(vite_rs_dev_server::command::package_manager_args_from_env());
 
-        let absolute_output_path = {
-            let p = PathBuf::from_iter(&[absolute_root_dir, relative_output_dir]);
-            let p = p.canonicalize().expect(&format!(
-                "Could not canonicalize output directory path. Does it exist? (path: {:?})",
-                p
-            ));
+        let npx_path = vite_rs_dev_server::command::npx_path_from_env()
+            .or_else(|| vite.npx_path.map(String::from));
 
-            p.to_str().unwrap().to_string()
-        };
-        #[cfg(windows)]
-        pub const NPX: &'static str = "npx.cmd";
-        #[cfg(not(windows))]
-        pub const NPX: &'static str = "npx";
-        let vite_build = std::process::Command::new(NPX)
-            .arg("vite")
-            .arg("build")
+        let working_dir = vite.working_dir.unwrap_or(absolute_root_dir);
+
+        let mut vite_build = vite.runtime.vite_command_in(
+            working_dir,
+            vite.package_manager,
+            npx_path.as_deref(),
+            &package_manager_args,
+        );
+
+        let version_manager = vite
+            .version_manager
+            .and_then(|version_manager| version_manager.resolve(working_dir));
+        if let Some(version_manager) = version_manager {
+            vite_build = version_manager.command_in(working_dir, vite_build);
+        }
+        vite_build.arg("build");
+
+        // `vite build [root]`, when it isn't the working directory
+        if vite.working_dir.is_some() {
+            vite_build.arg(absolute_root_dir);
+        }
+
+        vite_build
             .arg("--manifest") // force manifest generation to `.vite/manifest.json`
             .arg("--outDir")
-            .arg(&absolute_output_path)
-            .current_dir(absolute_root_dir)
+            .arg(absolute_output_path)
+            .envs(
+                vite_rs_dev_server::command::node_options_with(vite.node_options)
+                    .map(|node_options| ("NODE_OPTIONS", node_options)),
+            )
+            .envs(vite.envs.iter().map(|(key, value)| (key, value)))
+            .current_dir(working_dir);
+
+        if let Some(config_file) = vite.config_file {
+            vite_build.arg("--config").arg(config_file);
+        }
+
+        let program = vite_build.get_program().to_string_lossy().into_owned();
+        let vite_build = vite_build
             .spawn()
-            .expect("failed to build")
+            .unwrap_or_else(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => panic!(
+                    "failed to build: `{}` was not found.\n{}",
+                    program,
+                    vite_rs_dev_server::command::NotFoundDiagnostics::collect(
+                        &program,
+                        vite.runtime
+                            .local_vite_dir(working_dir, npx_path.as_deref())
+                    )
+                ),
+                _ => panic!("failed to build: could not run `{}` ({})", program, e),
+            })
             .wait()
             .expect("failed to wait for build to complete")
             .success();
@@ -69,23 +185,177 @@ pub mod build {
         }
 
         // the vite manifest is only available AFTER the build, so don't move this line up :)
+        Ok(load_vite_manifest(absolute_output_path))
+    }
+
+    /// With the `hermetic` feature, the output directory is expected to be built beforehand
+    /// (e.g. in CI, with `vite build --manifest`), so compiling never runs external commands.
+    fn prebuilt(absolute_output_path: &str) -> syn::Result<vite_manifest::ViteManifest> {
+        let manifest_path = PathBuf::from_iter(&[absolute_output_path, ".vite", "manifest.json"]);
+        if !manifest_path.is_file() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "hermetic builds embed a prebuilt output directory, but '{}' does not exist. Run `vite build --manifest` first.",
+                    manifest_path.display()
+                ),
+            ));
+        }
+
+        Ok(load_vite_manifest(absolute_output_path))
+    }
+
+    fn load_vite_manifest(absolute_output_path: &str) -> vite_manifest::ViteManifest {
         let absolute_vite_manifest_path = {
-            let p = PathBuf::from_iter(&[&absolute_output_path, ".vite", "manifest.json"])
+            let p = PathBuf::from_iter(&[absolute_output_path, ".vite", "manifest.json"])
                 .canonicalize()
-                .expect(&format!(
-                    "Could not canonicalize ViteJS manifest path. Does it exist? (path: {:?})",
-                    absolute_output_path
-                ));
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "Could not canonicalize ViteJS manifest path. Does it exist? (path: {:?})",
+                        absolute_output_path
+                    )
+                });
+
+            p.to_str().unwrap().to_string()
+        };
+
+        vite_manifest::load_vite_manifest(&absolute_vite_manifest_path)
+    }
+
+    /// Release-only settings, derived from the struct's attributes.
+    pub struct ReleaseConfig<'a> {
+        /// Where `vite build` writes its output, relative to the root directory.
+        pub output_dir: &'a str,
+        /// What to do if the output doesn't look minified (`#[unminified_output]`).
+        pub unminified_output: UnminifiedOutput,
+        /// Where to write the embedded asset paths (`#[asset_paths_file]`).
+        pub asset_paths_file: Option<&'a str>,
+        /// Whether `get()` ignores query strings (`#[query_strings]`).
+        pub strip_query: bool,
+        /// How `get()` decodes paths before looking them up.
+        pub url_decoding: super::UrlDecoding,
+        /// Files emitted outside the manifest, e.g. by plugins (`#[generated_entry]`).
+        pub generated_entries: Vec<GeneratedEntry>,
+        /// Files whose contents never change at their path (`#[immutable_assets]`).
+        pub immutable_patterns: Vec<ImmutablePattern>,
+        /// zstd level for the compressed copies (`#[zstd_level]`).
+        pub zstd_level: Option<i32>,
+    }
+
+    pub fn generate_rust_code(
+        release: ReleaseConfig,
+        vite: super::ViteCommand,
+        crate_path: &syn::Path,
+        struct_ident: &syn::Ident,
+        absolute_root_dir: &str,
+    ) -> syn::Result<TokenStream2> {
+        let ReleaseConfig {
+            output_dir: relative_output_dir,
+            unminified_output,
+            asset_paths_file,
+            strip_query,
+            url_decoding,
+            generated_entries,
+            immutable_patterns,
+            zstd_level,
+        } = release;
+
+        // proc_macro::tracked_path::path(absolute_root_dir); // => please see comments @ crates/vite-rs/tests/recompilation_test.rs:43
+
+        let stub_reason = crate::tooling::stub_reason();
+
+        let absolute_output_path = {
+            let p = PathBuf::from_iter(&[absolute_root_dir, relative_output_dir]);
+            // when stubbing, nothing is read from the output directory (which may not exist)
+            let p = match stub_reason {
+                Some(_) => p,
+                None => p.canonicalize().unwrap_or_else(|_| {
+                    panic!(
+                        "Could not canonicalize output directory path. Does it exist? (path: {:?})",
+                        p
+                    )
+                }),
+            };
 
             p.to_str().unwrap().to_string()
         };
+        let (mut vite_manifest, compiled_files, build_duration) = match stub_reason {
+            Some(reason) => {
+                eprintln!(
+                    "vite-rs: skipping `vite build` ({}); no files will be embedded",
+                    reason
+                );
+                (vite_manifest::ViteManifest::new(), Vec::new(), None)
+            }
+            None if cfg!(feature = "hermetic") => (
+                prebuilt(&absolute_output_path)?,
+                list_compiled_files(&absolute_output_path),
+                None,
+            ),
+            None => {
+                let started = std::time::Instant::now();
+                let vite_manifest = vite_build(&vite, absolute_root_dir, &absolute_output_path)?;
+                (
+                    vite_manifest,
+                    list_compiled_files(&absolute_output_path),
+                    Some(started.elapsed()),
+                )
+            }
+        };
+
+        minify_check::check(unminified_output, &absolute_output_path, &compiled_files)?;
+
+        // e.g. a service worker emitted by a plugin (see `#[generated_entry]`)
+        let service_worker_scopes = match stub_reason {
+            Some(_) => BTreeMap::new(),
+            None => generated_entries::register(
+                &generated_entries,
+                &compiled_files,
+                &mut vite_manifest,
+            )?,
+        };
 
-        let vite_manifest = vite_manifest::load_vite_manifest(&absolute_vite_manifest_path);
+        let build_stats = match stub_reason {
+            Some(_) => quote! { None },
+            None => {
+                let build_stats = build_stats::BuildStats::new(
+                    build_duration,
+                    absolute_root_dir,
+                    &absolute_output_path,
+                    &compiled_files,
+                );
+
+                // only informational, so it doesn't fail the build
+                if let Err(e) = build_stats.write(&struct_ident.to_string()) {
+                    eprintln!("vite-rs: could not write the build stats: {}", e);
+                }
+
+                let build_stats = build_stats.to_tokens(crate_path);
+                quote! { Some(#build_stats) }
+            }
+        };
+
+        if let (Some(asset_paths_file), None) = (asset_paths_file, stub_reason) {
+            let mut entries = vite_manifest
+                .iter()
+                .filter(|(_, entry)| entry.isEntry.unwrap_or(false))
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+            entries.sort();
+
+            asset_paths::write(asset_paths_file, &compiled_files, &entries).map_err(|e| {
+                syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("Failed to write '{}': {}", asset_paths_file, e),
+                )
+            })?;
+        }
 
         let mut match_values = BTreeMap::new();
         let mut list_values = Vec::<String>::new();
+        let mut integrities = BTreeMap::new();
 
-        list_compiled_files(&absolute_output_path)
+        compiled_files
             .iter()
             .flat_map(|relative_file_path| {
                 let absolute_file_path = {
@@ -102,15 +372,32 @@ pub mod build {
                     &relative_file_path, absolute_file_path
                 );
 
-                FileEntry::new(relative_file_path.clone(), absolute_file_path).map_err(|e| {
-                    return syn::Error::new(
-                        proc_macro2::Span::call_site(),
-                        format!("Failed to read Vite manifest: {}", e),
-                    );
-                })
+                FileEntry::new(relative_file_path.clone(), absolute_file_path)
+                    .map(|entry| {
+                        entry
+                            .with_service_worker_allowed(
+                                service_worker_scopes.get(relative_file_path).cloned(),
+                            )
+                            .with_immutable(
+                                immutable_patterns
+                                    .iter()
+                                    .any(|pattern| pattern.matches(relative_file_path)),
+                            )
+                            .with_zstd_level(zstd_level)
+                    })
+                    .map_err(|e| {
+                        syn::Error::new(
+                            proc_macro2::Span::call_site(),
+                            format!("Failed to read Vite manifest: {}", e),
+                        )
+                    })
             })
             .for_each(|entry| {
-                match_values.insert(entry.match_key().clone(), entry.match_value(&crate_path));
+                let (match_value, integrity) = entry.match_value(crate_path);
+                if let Some(integrity) = integrity {
+                    integrities.insert(entry.match_key().clone(), integrity);
+                }
+                match_values.insert(entry.match_key().clone(), match_value);
             });
 
         // Aliases help us refer to entrypoints from their uncompiled name.
@@ -143,10 +430,65 @@ pub mod build {
             }
         });
 
+        // the chunk graph, sorted by key (see `Manifest`)
+        let manifest_chunks = vite_manifest
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(key, entry)| {
+                let file = &entry.file;
+                let is_entry = entry.isEntry.unwrap_or(false);
+                let is_dynamic_entry = entry.isDynamicEntry.unwrap_or(false);
+                let imports = entry.imports.as_deref().unwrap_or_default();
+                let dynamic_imports = entry.dynamicImports.as_deref().unwrap_or_default();
+                let css = entry.css.as_deref().unwrap_or_default();
+                let integrity = integrities
+                    .get(file)
+                    .map(|integrity| quote! { .with_integrity(#integrity) });
+
+                quote! {
+                    #crate_path::ManifestChunk::new(#key, #file, #is_entry, #is_dynamic_entry, &[#(#imports),*], &[#(#dynamic_imports),*], &[#(#css),*])#integrity,
+                }
+            });
+
+        let html_entries = vite_manifest
+            .values()
+            .filter(|entry| entry.isEntry.unwrap_or(false) && entry.file.ends_with(".html"))
+            .map(|entry| entry.file.clone())
+            .collect::<Vec<_>>();
+        let routes_fn = super::routes::generate_routes_fn(crate_path, &html_entries);
+        let integrity_fn = super::integrity::generate_integrity_fn(Some(&integrities));
+
         let array_len = list_values.len();
 
+        // e.g. `logo.png?v=3`, a cache-busted URL (see `#[query_strings]`)
+        let strip_query = if strip_query {
+            quote! {
+                let path = path.split_once('?').map_or(path, |(path, _query)| path);
+            }
+        } else {
+            quote! {}
+        };
+        // e.g. `caf%C3%A9.png` (see `#[url_decoding]`)
+        let decode_path = url_decoding.decode(crate_path, quote! { None });
+
+        // `last_modified` comes from SOURCE_DATE_EPOCH in hermetic builds (see `FileEntry::new`), so
+        // changing it has to rebuild the crate; Cargo doesn't track what the macro reads itself
+        let tracked_env = if cfg!(feature = "hermetic") {
+            quote! {
+                const _: ::core::option::Option<&str> = ::core::option_env!("SOURCE_DATE_EPOCH");
+            }
+        } else {
+            quote! {}
+        };
+
         Ok(quote! {
+            #tracked_env
+
             impl #struct_ident {
+                /// Duration and output size of the `vite build` whose output is embedded.
+                pub const BUILD_STATS: Option<#crate_path::BuildStats> = #build_stats;
+
                 /// Path resolution; handles aliasing for file paths
                 fn resolve(path: &str) -> &str {
                     const ALIASES: &'static [(&'static str, &'static str)] = &[
@@ -159,13 +501,31 @@ pub mod build {
                 }
 
                 pub fn get(path: &str) -> Option<#crate_path::ViteFile> {
-                    let path = Self::resolve(path);
+                    #strip_query
+                    #decode_path
+                    let resolved = Self::resolve(path);
 
                     const ENTRIES: &'static [(&'static str, #crate_path::ViteFile)] = &[
                         #(#match_values)*
                     ];
-                    let position = ENTRIES.binary_search_by_key(&path, |entry| entry.0);
-                    position.ok().map(|index| ENTRIES[index].1.clone())
+                    let position = ENTRIES.binary_search_by_key(&resolved, |entry| entry.0);
+                    // an alias like `src/pack1.ts` serves whichever file the entry was built to,
+                    // so only the hashed path itself is immutable
+                    position.ok().map(|index| match resolved == path {
+                        true => ENTRIES[index].1.clone(),
+                        false => ENTRIES[index].1.clone().with_immutable(false),
+                    })
+                }
+
+                /// Never fails in release builds: the assets are embedded. See the dev build's `try_get`.
+                pub fn try_get(path: &str) -> Result<Option<#crate_path::ViteFile>, #crate_path::GetError> {
+                    Ok(Self::get(path))
+                }
+
+                /// `get(path)`, or `get(fallback)` if there's no asset at `path`, e.g. `index.html` for
+                /// the client-side routes of a single-page app.
+                pub fn get_or_fallback(path: &str, fallback: &str) -> Option<#crate_path::ViteFile> {
+                    Self::get(path).or_else(|| Self::get(fallback))
                 }
 
                 fn names() -> ::std::slice::Iter<'static, &'static str> {
@@ -181,6 +541,46 @@ pub mod build {
                 pub fn boxed() -> ::std::boxed::Box<dyn #crate_path::GetFromVite> {
                     ::std::boxed::Box::new(#struct_ident {})
                 }
+
+                /// Panics if `path` isn't an embedded asset (or an entry's source path), e.g. to check
+                /// the asset paths your Rust code refers to at startup.
+                #[track_caller]
+                pub fn assert_exists(path: &str) {
+                    if Self::get(path).is_none() {
+                        panic!("vite-rs: '{}' is not an embedded asset of `{}`", path, stringify!(#struct_ident));
+                    }
+                }
+
+                /// Does nothing in release builds: the assets are embedded. See the dev build's `prewarm`.
+                pub fn prewarm(_paths: &[&str]) {}
+
+                /// The chunk graph from Vite's build manifest, e.g. to prefetch lazily loaded routes.
+                pub fn manifest() -> #crate_path::Manifest {
+                    const CHUNKS: &'static [#crate_path::ManifestChunk] = &[
+                        #(#manifest_chunks)*
+                    ];
+
+                    #crate_path::Manifest::new(CHUNKS)
+                }
+
+                #routes_fn
+
+                #integrity_fn
+            }
+
+            impl ::std::default::Default for #struct_ident {
+                fn default() -> Self {
+                    #struct_ident {}
+                }
+            }
+
+            impl ::std::fmt::Debug for #struct_ident {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct(stringify!(#struct_ident))
+                        .field("mode", &"embedded")
+                        .field("assets", &#array_len)
+                        .finish()
+                }
             }
 
             impl #crate_path::GetFromVite for #struct_ident {
@@ -197,62 +597,562 @@ pub mod build {
 }
 
 /// DEV build
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 pub mod build {
     use proc_macro2::TokenStream as TokenStream2;
     use quote::quote;
 
+    /// Dev-only settings, derived from the struct's attributes.
+    pub struct DevConfig<'a> {
+        /// The host the dev server listens on (`#[dev_server_host]`, or the external one's).
+        pub host: &'a str,
+        /// `0` if the port is picked at runtime (`#[dev_server_port = "auto"]`).
+        pub port: u16,
+        /// Another port is picked at runtime if `port` is taken (`#[dev_server_port_fallback]`).
+        pub port_fallback: bool,
+        /// The dev server is started by the user (`#[external_dev_server]`), not by vite-rs.
+        pub external: bool,
+        /// Where browsers reach the dev server, if it differs from `host` and `port`.
+        pub public_url: Option<&'a str>,
+        /// Tag printed in front of each line of the dev server's output.
+        pub output_prefix: Option<&'a str>,
+        /// Public base path the dev server serves the assets under, e.g. `/app/`.
+        pub base: Option<&'a str>,
+        pub bypass_prefixes: &'a [String],
+        pub public_dir: Option<&'a str>,
+        /// Additional arguments for the dev server.
+        pub args: &'a [String],
+        /// Vite's `--logLevel` for the dev server, if set.
+        pub log_level: Option<vite_rs_dev_server::LogLevel>,
+        /// Whether Vite may clear the terminal (`--clearScreen`).
+        pub clear_screen: bool,
+        /// Timeout for fetching from the dev server, if set: `Some(None)` waits indefinitely.
+        pub fetch_timeout: Option<Option<std::time::Duration>>,
+        pub fetch_retries: u32,
+        /// How many requests `get()` sends to the dev server at once, if limited.
+        pub fetch_concurrency: Option<usize>,
+        /// Directory where `get()` keeps the dev server's responses, if set.
+        pub response_cache: Option<&'a str>,
+        /// Whether `get()` warns about unexpected content types.
+        pub content_type_check: bool,
+        /// HTML files served by the dev server, for `routes()`.
+        pub html_entries: &'a [String],
+        /// Path to the `vite_rs_dev_server` crate in the generated code.
+        pub dev_server_crate_path: &'a syn::Path,
+        /// The build `vite preview` serves instead of the dev server (the `preview` feature).
+        pub preview_dir: Option<&'a str>,
+        /// How `get()` decodes paths before reading them from disk or requesting them.
+        pub url_decoding: super::UrlDecoding,
+    }
+
     pub fn generate_rust_code(
-        dev_server_host: &str,
-        dev_server_port: u16,
+        dev: DevConfig,
+        vite: super::ViteCommand,
         crate_path: &syn::Path,
         struct_ident: &syn::Ident,
         absolute_root_dir: &str,
     ) -> syn::Result<TokenStream2> {
+        let DevConfig {
+            host: dev_server_host,
+            port: dev_server_port,
+            port_fallback,
+            external,
+            public_url: dev_server_public_url,
+            output_prefix: dev_output_prefix,
+            base,
+            bypass_prefixes: dev_bypass_prefixes,
+            public_dir: dev_public_dir,
+            args: dev_server_args,
+            log_level,
+            clear_screen,
+            fetch_timeout: dev_fetch_timeout,
+            fetch_retries: dev_fetch_retries,
+            fetch_concurrency: dev_fetch_concurrency,
+            response_cache: dev_response_cache,
+            content_type_check,
+            html_entries,
+            dev_server_crate_path,
+            preview_dir,
+            url_decoding,
+        } = dev;
+
+        // e.g. `127.0.0.1` for a dev server listening on `0.0.0.0`
+        let fetch_host = vite_rs_dev_server::util::fetch_host(dev_server_host);
+        let dev_server_origin = vite_rs_dev_server::util::http_origin(fetch_host, dev_server_port);
+
+        // with `#[dev_server_port = "auto"]` (or `#[dev_server_port_fallback]`), starting the dev
+        // server and `get()` share a port picked at runtime
+        let picked_at_runtime = dev_server_port == 0 || port_fallback;
+        let (runtime_port, dev_server_port_expr, dev_server_origin_expr) = if picked_at_runtime {
+            (
+                quote! {
+                    fn __vite_rs_runtime_port() -> &'static #dev_server_crate_path::util::RuntimePort {
+                        static PORT: #dev_server_crate_path::util::RuntimePort =
+                            #dev_server_crate_path::util::RuntimePort::preferring(#dev_server_port);
+                        &PORT
+                    }
+                },
+                quote! { Self::__vite_rs_runtime_port().get() },
+                quote! {
+                    #dev_server_crate_path::util::http_origin(#fetch_host, Self::__vite_rs_runtime_port().get())
+                },
+            )
+        } else {
+            (
+                quote! {},
+                quote! { #dev_server_port },
+                quote! { #dev_server_origin },
+            )
+        };
+        let with_runtime_port = if picked_at_runtime {
+            quote! { .with_runtime_port(Self::__vite_rs_runtime_port()) }
+        } else {
+            quote! {}
+        };
+        let external_note = format!(
+            "vite-rs: not starting a ViteJS dev server; using the one at {}",
+            dev_server_origin
+        );
+
+        let is_dev_server_running = if external {
+            quote! {
+                /// Whether the dev server responds to HTTP requests. It runs externally, so this is
+                /// the only thing we can check (see `#[external_dev_server]`).
+                pub fn is_dev_server_running() -> bool {
+                    #dev_server_crate_path::is_responding(&Self::dev_server_url())
+                }
+            }
+        } else {
+            quote! {
+                /// Whether the dev server process started by `start_dev_server` is running. It may
+                /// not be ready to serve requests yet.
+                pub fn is_dev_server_running() -> bool {
+                    #dev_server_crate_path::is_dev_server_running(#dev_server_port_expr)
+                }
+            }
+        };
+
         #[cfg(feature = "ctrlc")]
-        let start_dev_server = quote! {
-            pub fn start_dev_server(
-                register_ctrl_c_handler: bool,
-            ) -> Option<#crate_path::vite_rs_dev_server::ViteProcess> {
-                #crate_path::vite_rs_dev_server::start_dev_server(#absolute_root_dir, #dev_server_host, #dev_server_port, register_ctrl_c_handler)
+        let start_dev_server = if external {
+            quote! {
+                /// Does nothing: the dev server runs externally (see `#[external_dev_server]`).
+                pub fn start_dev_server(
+                    _register_ctrl_c_handler: bool,
+                ) -> Option<#dev_server_crate_path::ViteProcess> {
+                    eprintln!("{}", #external_note);
+                    None
+                }
+
+                /// Does nothing: the dev server runs externally (see `#[external_dev_server]`).
+                pub fn try_start_dev_server(
+                    _register_ctrl_c_handler: bool,
+                ) -> Result<Option<#dev_server_crate_path::ViteProcess>, #dev_server_crate_path::DevServerError> {
+                    eprintln!("{}", #external_note);
+                    Ok(None)
+                }
+            }
+        } else {
+            quote! {
+                pub fn start_dev_server(
+                    register_ctrl_c_handler: bool,
+                ) -> Option<#dev_server_crate_path::ViteProcess> {
+                    #dev_server_crate_path::start_dev_server_with(
+                        Self::dev_server_options().with_ctrl_c_handler(register_ctrl_c_handler),
+                    )
+                }
+
+                pub fn try_start_dev_server(
+                    register_ctrl_c_handler: bool,
+                ) -> Result<Option<#dev_server_crate_path::ViteProcess>, #dev_server_crate_path::DevServerError> {
+                    #dev_server_crate_path::try_start_dev_server_with(
+                        Self::dev_server_options().with_ctrl_c_handler(register_ctrl_c_handler),
+                    )
+                }
             }
         };
 
         #[cfg(not(feature = "ctrlc"))]
-        let start_dev_server = quote! {
-            pub fn start_dev_server() -> Option<#crate_path::vite_rs_dev_server::ViteProcess> {
-                #crate_path::vite_rs_dev_server::start_dev_server(#absolute_root_dir, #dev_server_host, #dev_server_port)
+        let start_dev_server = if external {
+            quote! {
+                /// Does nothing: the dev server runs externally (see `#[external_dev_server]`).
+                pub fn start_dev_server() -> Option<#dev_server_crate_path::ViteProcess> {
+                    eprintln!("{}", #external_note);
+                    None
+                }
+
+                /// Does nothing: the dev server runs externally (see `#[external_dev_server]`).
+                pub fn try_start_dev_server() -> Result<Option<#dev_server_crate_path::ViteProcess>, #dev_server_crate_path::DevServerError> {
+                    eprintln!("{}", #external_note);
+                    Ok(None)
+                }
+            }
+        } else {
+            quote! {
+                pub fn start_dev_server() -> Option<#dev_server_crate_path::ViteProcess> {
+                    #dev_server_crate_path::start_dev_server_with(Self::dev_server_options())
+                }
+
+                pub fn try_start_dev_server() -> Result<Option<#dev_server_crate_path::ViteProcess>, #dev_server_crate_path::DevServerError> {
+                    #dev_server_crate_path::try_start_dev_server_with(Self::dev_server_options())
+                }
             }
         };
 
         let etag = if cfg!(feature = "content-hash") {
             quote! {
                 let etag = res
-                    .headers()
-                    .get(#crate_path::vite_rs_dev_server::reqwest::header::ETAG)
-                    .expect("FATAL: ViteJS dev server did not return an `ETag` header.")
-                    .to_str()
-                    .unwrap()
-                    .to_string();
+                    .etag
+                    .expect("FATAL: ViteJS dev server did not return an `ETag` header.");
             }
         } else {
             quote! {}
         };
 
         let content_hash = if cfg!(feature = "content-hash") {
-            quote! { content_hash: etag, }
+            quote! { .with_content_hash(etag) }
+        } else {
+            quote! {}
+        };
+
+        // turns the dev server's response (`res`) into the file returned by `get()`
+        let content_type_check = if content_type_check {
+            quote! { #dev_server_crate_path::transport::check_content_type(path, &content_type); }
+        } else {
+            quote! {}
+        };
+
+        let file_from_response = quote! {
+            match res {
+                Ok(res) => {
+                    if res.status == 404 {
+                        return Ok(None);
+                    }
+
+                    let content_type = res
+                        .content_type
+                        .expect("FATAL: ViteJS dev server did not return a content type!");
+
+                    #content_type_check
+
+                    let content_length = res
+                        .content_length
+                        .expect("FATAL: ViteJS dev server did not return a `Content-Length` header.");
+
+                    #etag
+
+                    // `last_modified` isn't sent in dev!
+                    let mut file = #crate_path::ViteFile::new(res.bytes, content_type)
+                        #content_hash;
+                    file.content_length = content_length;
+
+                    Ok(Some(file))
+                }
+                // e.g. while the dev server is starting
+                Err(e) if e.is_connect() => Err(#crate_path::GetError::DevServerUnavailable),
+                Err(e) => {
+                    println!("ERR! {:#?}", e);
+                    Ok(None)
+                },
+            }
+        };
+
+        // e.g. `Pnpm`, to refer to the variants in the generated code
+        let runtime = quote::format_ident!("{}", format!("{:?}", vite.runtime));
+        let package_manager = quote::format_ident!("{}", format!("{:?}", vite.package_manager));
+
+        // `VITE_RS_PACKAGE_MANAGER_ARGS` is added when the dev server starts
+        let package_manager_args = if vite.package_manager_args.is_empty() {
+            quote! {}
+        } else {
+            let args = vite.package_manager_args;
+            quote! { .with_package_manager_args([#(#args),*]) }
+        };
+
+        // `VITE_RS_NPX_PATH` is checked when the dev server starts
+        let npx_path = match vite.npx_path {
+            Some(npx_path) => quote! { .with_npx_path(#npx_path) },
+            None => quote! {},
+        };
+
+        let version_manager = match vite.version_manager {
+            Some(version_manager) => {
+                let version_manager = quote::format_ident!("{}", format!("{:?}", version_manager));
+                quote! { .with_version_manager(#dev_server_crate_path::VersionManager::#version_manager) }
+            }
+            None => quote! {},
+        };
+
+        let dev_server_public_url = match dev_server_public_url {
+            Some("auto") => quote! { .with_remote_environment_detection(true) },
+            Some(public_url) => quote! { .with_public_url(#public_url) },
+            None => quote! {},
+        };
+
+        // with `#[external_dev_server]`, its Vite config has to set the same `base`
+        let (with_base, base) = match base {
+            Some(base) => (quote! { .with_base(#base) }, base),
+            None => (quote! {}, "/"),
+        };
+
+        let dev_output_prefix = match dev_output_prefix {
+            Some(prefix) => quote! {
+                .with_stdio(#dev_server_crate_path::DevServerStdio::Prefixed(String::from(#prefix)))
+            },
+            None => quote! {},
+        };
+
+        let preview = match preview_dir {
+            Some(preview_dir) => quote! { .with_preview(#preview_dir) },
+            None => quote! {},
+        };
+
+        let working_dir = match vite.working_dir {
+            Some(working_dir) => quote! { .with_working_dir(#working_dir) },
+            None => quote! {},
+        };
+
+        let vite_config = match vite.config_file {
+            Some(config_file) => quote! { .with_config_file(#config_file) },
+            None => quote! {},
+        };
+
+        let vite_envs = vite
+            .envs
+            .iter()
+            .map(|(key, value)| quote! { .with_env(#key, #value) });
+
+        // the inherited `NODE_OPTIONS` are added when the dev server starts
+        let node_options = if vite.node_options.is_empty() {
+            quote! {}
+        } else {
+            let node_options = vite.node_options;
+            quote! { .with_node_options([#(#node_options),*]) }
+        };
+
+        let dev_server_args = if dev_server_args.is_empty() {
+            quote! {}
+        } else {
+            quote! { .with_args([#(#dev_server_args),*]) }
+        };
+
+        // e.g. `Warn`, to refer to the variant in the generated code
+        let log_level = log_level.map(|log_level| {
+            let log_level = quote::format_ident!("{}", format!("{:?}", log_level));
+            quote! { .with_log_level(#dev_server_crate_path::LogLevel::#log_level) }
+        });
+
+        let clear_screen = if clear_screen {
+            quote! { .with_clear_screen(true) }
+        } else {
+            quote! {}
+        };
+
+        let disk_content_hash = if cfg!(feature = "content-hash") {
+            quote! { .with_content_hash(file.etag) }
+        } else {
+            quote! {}
+        };
+
+        let from_disk = if dev_bypass_prefixes.is_empty() && dev_public_dir.is_none() {
+            quote! {}
         } else {
+            quote! {
+                let from_disk = |file: #dev_server_crate_path::disk::DiskFile| {
+                    #crate_path::ViteFile::new(file.bytes, file.content_type)
+                        #disk_content_hash
+                };
+            }
+        };
+
+        let dev_bypass = if dev_bypass_prefixes.is_empty() {
             quote! {}
+        } else {
+            quote! {
+                const DEV_BYPASS_PREFIXES: &[&str] = &[#(#dev_bypass_prefixes),*];
+
+                // `data` covers `data/points.json`, but not `data-old/points.json`
+                let bypassed = DEV_BYPASS_PREFIXES.iter().any(|prefix| {
+                    let path = path.trim_start_matches('/');
+                    path == *prefix || path.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/'))
+                });
+                if bypassed {
+                    return Ok(#dev_server_crate_path::disk::read(#absolute_root_dir, path).map(from_disk));
+                }
+            }
+        };
+
+        let dev_public_dir = match dev_public_dir {
+            Some(dev_public_dir) => quote! {
+                // the dev server serves these files as-is, so we can skip the round-trip
+                if let Some(file) = #dev_server_crate_path::disk::read_from(#dev_public_dir, path) {
+                    return Ok(Some(from_disk(file)));
+                }
+            },
+            None => quote! {},
+        };
+
+        // the path is decoded, so `caf%C3%A9.png` and `café.png` are read from disk alike, and
+        // encoded again for the request; the query string (e.g. Vite's `?raw`) is passed on as-is
+        let (decode_path, url_path) = match url_decoding {
+            super::UrlDecoding::Off => (quote! {}, quote! { path }),
+            _ => {
+                let decode = url_decoding.decode(crate_path, quote! { Ok(None) });
+                (
+                    quote! {
+                        let (path, query) = path.split_at(path.find('?').unwrap_or(path.len()));
+                        #decode
+                    },
+                    quote! { format!("{}{}", #crate_path::http::encode_path(path), query) },
+                )
+            }
+        };
+
+        // with the `async` feature, `get_async()` fetches without blocking the async runtime
+        let (get_async, get_async_impl) = if cfg!(feature = "async") {
+            (
+                quote! {
+                    /// Like `get`, but doesn't block the async runtime (nor a thread) while the
+                    /// file is fetched from the dev server. Needs a Tokio runtime.
+                    pub async fn get_async(path: &str) -> Option<#crate_path::ViteFile> {
+                        Self::try_get_async(path).await.unwrap_or_else(|e| {
+                            eprintln!("vite-rs: failed to get '{}': {}", path, e);
+                            None
+                        })
+                    }
+
+                    /// Like `try_get`, but doesn't block the async runtime (see `get_async`).
+                    pub async fn try_get_async(path: &str) -> Result<Option<#crate_path::ViteFile>, #crate_path::GetError> {
+                        #decode_path
+
+                        #from_disk
+
+                        #dev_bypass
+
+                        #dev_public_dir
+
+                        let Some(origin) = #dev_server_crate_path::fetch_origin(Self::dev_server_url()) else {
+                            return Ok(None);
+                        };
+                        let url = format!("{}{}{}", origin, #base, #url_path);
+                        let res = #dev_server_crate_path::transport::get_async(&url, &Self::dev_fetch_options()).await;
+
+                        #file_from_response
+                    }
+                },
+                quote! {
+                    fn get_async<'a>(
+                        &'a self,
+                        file_path: &'a str,
+                    ) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = Option<#crate_path::ViteFile>> + Send + 'a>> {
+                        ::std::boxed::Box::pin(#struct_ident::get_async(file_path))
+                    }
+
+                    fn try_get_async_with_context<'a>(
+                        &'a self,
+                        file_path: &'a str,
+                        _context: &'a #crate_path::RequestContext,
+                    ) -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = Result<Option<#crate_path::ViteFile>, #crate_path::GetError>> + Send + 'a>> {
+                        ::std::boxed::Box::pin(#struct_ident::try_get_async(file_path))
+                    }
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        // shared by all requests of the struct, like the runtime port
+        let dev_fetch_concurrency = match dev_fetch_concurrency {
+            Some(max_concurrent) => quote! {
+                .with_concurrency_limit({
+                    static LIMIT: ::std::sync::OnceLock<::std::sync::Arc<#dev_server_crate_path::transport::FetchLimit>> = ::std::sync::OnceLock::new();
+                    LIMIT.get_or_init(|| ::std::sync::Arc::new(#dev_server_crate_path::transport::FetchLimit::new(#max_concurrent))).clone()
+                })
+            },
+            None => quote! {},
+        };
+
+        let dev_response_cache = match dev_response_cache {
+            Some(dir) => quote! {
+                .with_cache({
+                    static CACHE: ::std::sync::OnceLock<::std::sync::Arc<#dev_server_crate_path::transport::ResponseCache>> = ::std::sync::OnceLock::new();
+                    CACHE.get_or_init(|| ::std::sync::Arc::new(#dev_server_crate_path::transport::ResponseCache::persistent(#dir))).clone()
+                })
+            },
+            None => quote! {},
+        };
+
+        let dev_fetch_timeout = match dev_fetch_timeout {
+            Some(Some(timeout)) => {
+                let millis = timeout.as_millis() as u64;
+                quote! { .with_timeout(::std::time::Duration::from_millis(#millis)) }
+            }
+            Some(None) => quote! { .without_timeout() },
+            None => quote! {},
         };
 
+        let routes_fn = super::routes::generate_routes_fn(crate_path, html_entries);
+        let integrity_fn = super::integrity::generate_integrity_fn(None);
+
         Ok(quote! {
             impl #struct_ident {
+                /// `None` in development builds: nothing is built. See the release build's `BUILD_STATS`.
+                pub const BUILD_STATS: Option<#crate_path::BuildStats> = None;
+
+                /// Options used by `start_dev_server`. Customize these and pass them to
+                /// `vite_rs_dev_server::start_dev_server_with` to configure the dev server process.
+                pub fn dev_server_options() -> #dev_server_crate_path::DevServerOptions {
+                    #dev_server_crate_path::DevServerOptions::new(#absolute_root_dir, #dev_server_port_expr)
+                        .with_host(#dev_server_host)
+                        #with_runtime_port
+                        #dev_server_public_url
+                        #dev_output_prefix
+                        #with_base
+                        .with_runtime(#dev_server_crate_path::Runtime::#runtime)
+                        .with_package_manager(#dev_server_crate_path::PackageManager::#package_manager)
+                        #package_manager_args
+                        #version_manager
+                        #npx_path
+                        #working_dir
+                        #preview
+                        #vite_config
+                        #node_options
+                        #(#vite_envs)*
+                        #log_level
+                        #clear_screen
+                        #dev_server_args
+                }
+
+                /// Timeout, retries, concurrency limit and cache used by `get()` when fetching from the dev server.
+                pub fn dev_fetch_options() -> #dev_server_crate_path::transport::FetchOptions {
+                    #dev_server_crate_path::transport::FetchOptions::default()
+                        #dev_fetch_timeout
+                        .with_retries(#dev_fetch_retries)
+                        #dev_fetch_concurrency
+                        #dev_response_cache
+                }
+
+                #runtime_port
+
                 #start_dev_server
 
+                /// Stops the dev server started by `start_dev_server`. Dev servers of other structs keep running.
                 pub fn stop_dev_server() {
-                    #crate_path::vite_rs_dev_server::stop_dev_server()
+                    #dev_server_crate_path::stop_dev_server_on(#dev_server_port_expr)
+                }
+
+                /// Restarts the dev server started by `start_dev_server` in place, e.g. after changing
+                /// config Vite doesn't reload by itself. `Ok(false)` if it isn't running.
+                pub fn restart_dev_server() -> Result<bool, #dev_server_crate_path::DevServerError> {
+                    #dev_server_crate_path::restart_dev_server_on(#dev_server_port_expr)
+                }
+
+                /// Where the dev server is reached, e.g. `http://localhost:21012`. `get()` fetches assets from here.
+                pub fn dev_server_url() -> String {
+                    String::from(#dev_server_origin_expr)
                 }
 
+                #is_dev_server_running
+
                 pub fn iter() -> impl ::std::iter::Iterator<Item = ::std::borrow::Cow<'static, str>> {
                     // https://github.com/rust-lang/rust/issues/36375
                     if true {
@@ -263,60 +1163,103 @@ pub mod build {
                 }
 
                 pub fn get(path: &str) -> Option<#crate_path::ViteFile> {
-                    let path = path.to_string();
-
-                    std::thread::spawn(move || {
-                        let client = #crate_path::vite_rs_dev_server::reqwest::blocking::Client::new();
-                        let url = format!(
-                            "http://{}:{}/{}",
-                            #dev_server_host,
-                            #dev_server_port,
-                            path
-                        );
-
-                        match client.get(&url).send() {
-                            Ok(res) => {
-                                if res.status() == 404 {
-                                    return None;
-                                }
-
-                                let content_type = res
-                                    .headers()
-                                    .get(#crate_path::vite_rs_dev_server::reqwest::header::CONTENT_TYPE)
-                                    .expect("FATAL: ViteJS dev server did not return a content type!")
-                                    .to_str()
-                                    .unwrap()
-                                    .to_string();
-
-                                let content_length = res
-                                    .content_length()
-                                    .expect("FATAL: ViteJS dev server did not return a `Content-Length` header.");
-
-                                #etag
-
-                                let mut bytes = res.bytes().unwrap().to_vec();
-
-                                Some(#crate_path::ViteFile {
-                                    last_modified: None, /* we don't send this in dev! */
-                                    content_type: content_type,
-                                    content_length: content_length,
-                                    bytes: bytes,
-                                    #content_hash
-                                })
-                            }
-                            Err(e) => {
-                                println!("ERR! {:#?}", e);
-                                None
-                            },
-                        }
+                    Self::try_get(path).unwrap_or_else(|e| {
+                        eprintln!("vite-rs: failed to get '{}': {}", path, e);
+                        None
                     })
-                    .join()
-                    .expect("Failed to spawn thread to fetch ViteJS dev server resource.")
                 }
 
+                /// Like `get`, but returns `Err(GetError::DevServerUnavailable)` instead of `None`
+                /// while the dev server doesn't accept connections, e.g. right after it's started.
+                pub fn try_get(path: &str) -> Result<Option<#crate_path::ViteFile>, #crate_path::GetError> {
+                    #decode_path
+
+                    #from_disk
+
+                    #dev_bypass
+
+                    #dev_public_dir
+
+                    // e.g. `None` with `VITE_RS_NO_DEV_SERVER` set
+                    let Some(origin) = #dev_server_crate_path::fetch_origin(Self::dev_server_url()) else {
+                        return Ok(None);
+                    };
+                    let url = format!("{}{}{}", origin, #base, #url_path);
+
+                    // works inside an async runtime too, which the default (reqwest) transport can't do by itself
+                    let res = #dev_server_crate_path::transport::get_blocking(&url, &Self::dev_fetch_options());
+
+                    #file_from_response
+                }
+
+                /// `get(path)`, or `get(fallback)` if the dev server doesn't serve `path`. While the
+                /// dev server is unavailable (see `try_get`), this returns `None` without trying
+                /// `fallback`.
+                pub fn get_or_fallback(path: &str, fallback: &str) -> Option<#crate_path::ViteFile> {
+                    match Self::try_get(path) {
+                        Ok(Some(file)) => Some(file),
+                        Ok(None) => Self::get(fallback),
+                        Err(e) => {
+                            eprintln!("vite-rs: failed to get '{}': {}", path, e);
+                            None
+                        }
+                    }
+                }
+
+                #get_async
+
                 pub fn boxed() -> ::std::boxed::Box<dyn #crate_path::GetFromVite> {
                     ::std::boxed::Box::new(#struct_ident {})
                 }
+
+                /// Panics if `path` isn't served by the ViteJS dev server (which has to be running), e.g.
+                /// to check the asset paths your Rust code refers to at startup. Note: in development,
+                /// source files which aren't embedded in release builds (e.g. `src/main.ts`) exist too.
+                #[track_caller]
+                pub fn assert_exists(path: &str) {
+                    if Self::get(path).is_none() {
+                        panic!("vite-rs: '{}' is not served by the ViteJS dev server of `{}`", path, stringify!(#struct_ident));
+                    }
+                }
+
+                /// Fetches `paths` (e.g. `["index.html", "src/main.ts"]`) from the dev server in the
+                /// background, once it responds, so the first page load after a restart doesn't wait
+                /// for Vite to transform them one by one. Returns right away.
+                pub fn prewarm(paths: &[&str]) {
+                    let Some(origin) = #dev_server_crate_path::fetch_origin(Self::dev_server_url()) else {
+                        return;
+                    };
+                    let urls = paths
+                        .iter()
+                        .map(|path| format!("{}{}{}", origin, #base, path))
+                        .collect();
+
+                    #dev_server_crate_path::transport::prewarm(urls, Self::dev_fetch_options());
+                }
+
+                /// Empty in development builds: the dev server serves modules without bundling them.
+                pub fn manifest() -> #crate_path::Manifest {
+                    #crate_path::Manifest::new(&[])
+                }
+
+                #routes_fn
+
+                #integrity_fn
+            }
+
+            impl ::std::default::Default for #struct_ident {
+                fn default() -> Self {
+                    #struct_ident {}
+                }
+            }
+
+            impl ::std::fmt::Debug for #struct_ident {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct(stringify!(#struct_ident))
+                        .field("mode", &"dev server")
+                        .field("dev_server_url", &#struct_ident::dev_server_url())
+                        .finish()
+                }
             }
 
             impl #crate_path::GetFromVite for #struct_ident {
@@ -324,6 +1267,12 @@ pub mod build {
                     #struct_ident::get(file_path)
                 }
 
+                fn try_get(&self, file_path: &str) -> Result<Option<#crate_path::ViteFile>, #crate_path::GetError> {
+                    #struct_ident::try_get(file_path)
+                }
+
+                #get_async_impl
+
                 fn clone_box(&self) -> ::std::boxed::Box<dyn #crate_path::GetFromVite> {
                     ::std::boxed::Box::new(#struct_ident {})
                 }
@@ -0,0 +1,71 @@
+//! Derives the SPA routes (`Assets::routes()`, see `RouteManifest`) from the HTML entries.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// The URL pattern an HTML entry serves: `index.html` serves `/*`, `admin/index.html` serves
+/// `/admin/*` and `about.html` serves `/about`.
+fn route_pattern(html_entry: &str) -> String {
+    match html_entry.strip_suffix("index.html") {
+        Some(dir) if dir.is_empty() || dir.ends_with('/') => format!("/{}*", dir),
+        _ => format!("/{}", html_entry.trim_end_matches(".html")),
+    }
+}
+
+/// Generates `routes()`, serving each of `html_entries` (asset paths).
+pub fn generate_routes_fn(crate_path: &syn::Path, html_entries: &[String]) -> TokenStream2 {
+    let mut html_entries = html_entries.to_vec();
+    html_entries.sort();
+
+    let entries = html_entries.iter().map(|entry| {
+        let pattern = route_pattern(entry);
+        quote! { .with_entry(#pattern, #entry) }
+    });
+
+    quote! {
+        /// The URL paths served by each HTML entry, e.g. for SPA fallbacks. See `RouteManifest`.
+        pub fn routes() -> #crate_path::RouteManifest {
+            #crate_path::RouteManifest::new()
+                #(#entries)*
+        }
+    }
+}
+
+/// The HTML files the dev server serves from `absolute_root_dir` (relative paths), leaving out
+/// `node_modules`, Cargo target directories, hidden directories and `skipped_dirs` (e.g. the
+/// build output).
+#[cfg(vite_rs_dev)]
+pub fn find_html_files(
+    absolute_root_dir: &str,
+    skipped_dirs: &[std::path::PathBuf],
+) -> Vec<String> {
+    walkdir::WalkDir::new(absolute_root_dir)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            let skipped = entry.depth() > 0
+                && entry.file_type().is_dir()
+                && (name == "node_modules"
+                    || is_cargo_target_dir(entry.path())
+                    || name.starts_with('.')
+                    || skipped_dirs.iter().any(|dir| dir == entry.path()));
+
+            !skipped
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let path = entry.path().strip_prefix(absolute_root_dir).ok()?;
+            let path = path.to_str()?.replace('\\', "/");
+
+            path.ends_with(".html").then_some(path)
+        })
+        .collect()
+}
+
+/// Whether `dir` is a Cargo target directory: `target`, or one Cargo marked with `CACHEDIR.TAG`
+/// (e.g. a custom `CARGO_TARGET_DIR`).
+#[cfg(vite_rs_dev)]
+fn is_cargo_target_dir(dir: &std::path::Path) -> bool {
+    dir.file_name().is_some_and(|name| name == "target") || dir.join("CACHEDIR.TAG").is_file()
+}
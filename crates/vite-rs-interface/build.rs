@@ -0,0 +1,26 @@
+//! Sets `cfg(vite_rs_dev)` for builds which get the assets from a Vite server instead of embedding
+//! them: debug builds (unless the `debug-prod` feature is enabled) and builds with the `preview`
+//! feature.
+//!
+//! The other vite-rs crates don't work this out themselves: their build scripts set the cfg when
+//! this one says so (through the `DEP_VITE_RS_INTERFACE_DEV` variable, see `links` in
+//! `Cargo.toml`), so all of them agree on it.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(vite_rs_dev)");
+
+    let preview = env::var_os("CARGO_FEATURE_PREVIEW").is_some();
+    let debug_prod = env::var_os("CARGO_FEATURE_DEBUG_PROD").is_some();
+    let debug_assertions = env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_some();
+
+    let dev = preview || (debug_assertions && !debug_prod);
+    if dev {
+        println!("cargo:rustc-cfg=vite_rs_dev");
+    }
+
+    // read by dependents as `DEP_VITE_RS_INTERFACE_DEV`
+    println!("cargo:dev={}", dev);
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+/// Numbers about the `vite build` whose output a release build embeds, e.g. to track frontend
+/// build times in your own telemetry. Available as `Assets::BUILD_STATS` (`None` in development
+/// builds, and when no files were built, e.g. for rust-analyzer).
+///
+/// The macro also writes them to `target/vite-rs/build-stats/<crate>.<struct>.json` while
+/// compiling, for CI to pick up:
+///
+/// ```json
+/// {"crate":"app","struct":"Assets","duration_ms":5120,"file_count":12,"total_bytes":482113,"vite_version":"5.4.2"}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BuildStats {
+    /// How long `vite build` took. `None` with the `hermetic` feature, which embeds a prebuilt
+    /// output directory.
+    pub duration: Option<Duration>,
+    /// Number of embedded files.
+    pub file_count: usize,
+    /// Size of the embedded files (before compression), in bytes.
+    pub total_bytes: u64,
+    /// The version of Vite installed in the project's `node_modules`, if found.
+    pub vite_version: Option<&'static str>,
+}
+
+impl BuildStats {
+    #[doc(hidden)] // used by the generated code
+    pub const fn new(
+        duration: Option<Duration>,
+        file_count: usize,
+        total_bytes: u64,
+        vite_version: Option<&'static str>,
+    ) -> Self {
+        Self {
+            duration,
+            file_count,
+            total_bytes,
+            vite_version,
+        }
+    }
+}
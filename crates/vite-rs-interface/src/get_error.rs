@@ -0,0 +1,23 @@
+/// Why `GetFromVite::try_get` couldn't tell whether an asset exists. Only happens in development
+/// builds; embedded assets are always available.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GetError {
+    /// The ViteJS dev server isn't accepting connections, e.g. during the first second or so
+    /// after `start_dev_server`. Retrying shortly will likely succeed, so servers should answer
+    /// `503 Service Unavailable` rather than `404 Not Found`.
+    DevServerUnavailable,
+}
+
+impl std::fmt::Display for GetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetError::DevServerUnavailable => write!(
+                f,
+                "the ViteJS dev server isn't accepting connections (is it still starting?)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GetError {}
@@ -0,0 +1,170 @@
+//! HTTP logic for serving assets which doesn't depend on a server framework: which asset a
+//! request path resolves to, percent-decoding, conditional requests and content encoding
+//! negotiation. Header values are passed as strings, so any HTTP library can use these.
+
+use std::borrow::Cow;
+
+/// The asset path for a request path: `/assets/app.js` is `assets/app.js`, and `/` is
+/// `index.html`.
+pub fn asset_path(request_path: &str) -> &str {
+    match request_path.trim_start_matches('/') {
+        "" => "index.html",
+        path => path,
+    }
+}
+
+/// The HTML entry serving a directory-like request path, which is tried before the path itself:
+/// `docs` and `docs/` are `docs/index.html`, and `/` is `index.html`.
+pub fn index_path(request_path: &str) -> String {
+    match request_path.trim_matches('/') {
+        "" => "index.html".to_string(),
+        path => format!("{}/index.html", path),
+    }
+}
+
+/// Percent-decodes a request path, e.g. `caf%C3%A9.png` is `café.png`. Hex digits may be upper
+/// or lower case. `+` is kept: it only means a space in form data, not in paths. Escapes which
+/// aren't two hex digits are kept as-is, and so is the whole path if it doesn't decode to UTF-8.
+/// See `decode_path_strict` to reject these instead.
+pub fn decode_path(path: &str) -> Cow<'_, str> {
+    match decode(path) {
+        Some((bytes, _)) => match String::from_utf8(bytes) {
+            Ok(decoded) => Cow::Owned(decoded),
+            Err(_) => Cow::Borrowed(path),
+        },
+        None => Cow::Borrowed(path),
+    }
+}
+
+/// Like `decode_path`, but `None` for paths which don't decode unambiguously: malformed escapes
+/// (`%zz`, `%4`), escapes which don't decode to UTF-8, and escaped `/`, `\` and control
+/// characters, which would let `a%2Fb` and `a/b` (or a path outside a directory) name the same
+/// asset.
+pub fn decode_path_strict(path: &str) -> Option<Cow<'_, str>> {
+    match decode(path) {
+        Some((bytes, true)) => String::from_utf8(bytes).ok().map(Cow::Owned),
+        Some((_, false)) => None,
+        None => Some(Cow::Borrowed(path)),
+    }
+}
+
+/// The decoded bytes, and whether they were escaped unambiguously. `None` if there's nothing to
+/// decode.
+fn decode(path: &str) -> Option<(Vec<u8>, bool)> {
+    if !path.contains('%') {
+        return None;
+    }
+
+    let mut unambiguous = true;
+    let mut decoded = Vec::with_capacity(path.len());
+    let bytes = path.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            decoded.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+
+        let value = match bytes.get(i + 1..i + 3) {
+            Some([high, low]) => hex(*high).zip(hex(*low)).map(|(high, low)| high << 4 | low),
+            _ => None,
+        };
+
+        match value {
+            Some(value) => {
+                if value == b'/' || value == b'\\' || value.is_ascii_control() {
+                    unambiguous = false;
+                }
+                decoded.push(value);
+                i += 3;
+            }
+            None => {
+                // kept as-is
+                unambiguous = false;
+                decoded.push(b'%');
+                i += 1;
+            }
+        }
+    }
+
+    Some((decoded, unambiguous))
+}
+
+fn hex(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
+
+/// Percent-encodes what can't appear as-is in the path of a URL, e.g. `café.png` is
+/// `caf%C3%A9.png` and `what?.png` is `what%3F.png`. `/` separates segments and is kept, and
+/// so is `+`.
+pub fn encode_path(path: &str) -> Cow<'_, str> {
+    // unreserved characters, sub-delimiters, `:`, `@` and `/` (RFC 3986)
+    let keep = |byte: u8| byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/".contains(&byte);
+
+    if path.bytes().all(keep) {
+        return Cow::Borrowed(path);
+    }
+
+    let mut encoded = String::with_capacity(path.len() + 8);
+    for byte in path.bytes() {
+        if keep(byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    Cow::Owned(encoded)
+}
+
+/// Whether an `If-None-Match` header value matches `etag`, so `304 Not Modified` can be sent:
+/// it's `*` or lists `etag`, comparing weakly (`W/"abc"` matches `"abc"`), as RFC 9110 requires
+/// for `If-None-Match`.
+pub fn if_none_match(if_none_match: &str, etag: &str) -> bool {
+    let etag = etag.trim();
+
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || (!etag.is_empty() && weak(candidate) == weak(etag)))
+}
+
+fn weak(etag: &str) -> &str {
+    etag.strip_prefix("W/").unwrap_or(etag)
+}
+
+/// Whether an `Accept-Encoding` header value accepts `coding` (e.g. `zstd`), by name or through
+/// `*`, with a quality value above 0.
+pub fn accepts_encoding(accept_encoding: &str, coding: &str) -> bool {
+    let mut wildcard = false;
+
+    for entry in accept_encoding.split(',') {
+        let mut params = entry.trim().split(';');
+        let name = params.next().unwrap_or_default().trim();
+
+        let quality = params
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+
+        if name.eq_ignore_ascii_case(coding) {
+            return quality > 0.0;
+        }
+        if name == "*" {
+            wildcard = quality > 0.0;
+        }
+    }
+
+    wildcard
+}
+
+/// The ETag of the `coding`-encoded representation of an asset, which needs one of its own:
+/// `"abc"` is `"abc-zstd"`, `W/"abc"` is `W/"abc-zstd"`, and `abc` is `abc-zstd`.
+pub fn encoded_etag(etag: &str, coding: &str) -> String {
+    match etag.strip_suffix('"') {
+        Some(opaque) => format!("{}-{}\"", opaque, coding),
+        None => format!("{}-{}", etag, coding),
+    }
+}
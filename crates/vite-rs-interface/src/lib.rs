@@ -1,6 +1,29 @@
+//! The types shared by `vite-rs` and the server integrations built on it (e.g.
+//! `vite-rs-axum-0-8`): `ViteFile`, the `GetFromVite` trait, and the HTTP logic which doesn't
+//! depend on a framework (see `http`).
+//!
+//! Compatibility: integrations only depend on this crate, so it changes more conservatively
+//! than the others. Within a major version (or minor version, before 1.0), items are only
+//! added: the structs and enums are `#[non_exhaustive]` (build `ViteFile`s with `ViteFile::new`),
+//! and new `GetFromVite` methods come with a default implementation.
+
+mod build_stats;
+mod get_error;
+pub mod http;
+mod manifest;
+mod request_context;
+mod routes;
+
+pub use build_stats::BuildStats;
+pub use get_error::GetError;
+pub use manifest::{Manifest, ManifestChunk};
+pub use request_context::RequestContext;
+pub use routes::{RouteEntry, RouteManifest};
+
 // Production File
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 /// File retrieved from a ViteJS-compiled project
 pub struct ViteFile {
     pub bytes: ::std::borrow::Cow<'static, [u8]>,
@@ -10,19 +33,184 @@ pub struct ViteFile {
     #[cfg(feature = "content-hash")]
     /// SHA-256 hash of the file contents.
     pub content_hash: &'static str,
+    #[cfg(feature = "zstd")]
+    /// The file compressed with zstd, if that made it smaller. Sent instead of `bytes` to clients
+    /// which accept `Content-Encoding: zstd`.
+    pub zstd: Option<&'static [u8]>,
+    #[cfg(feature = "gzip")]
+    /// The file compressed with gzip, if that made it smaller. Sent instead of `bytes` to clients
+    /// which accept `Content-Encoding: gzip` but neither zstd nor Brotli.
+    pub gzip: Option<&'static [u8]>,
+    #[cfg(feature = "brotli")]
+    /// The file compressed with Brotli, if that made it smaller than both `bytes` and `gzip`. Sent
+    /// instead of `bytes` to clients which accept `Content-Encoding: br` but not zstd.
+    pub brotli: Option<&'static [u8]>,
+    /// The `Service-Worker-Allowed` header to send with a service worker, e.g. `/` for one served
+    /// from `/assets/sw.js` which controls the whole site.
+    pub service_worker_allowed: Option<&'static str>,
+    /// The file's contents never change at its path, e.g. `assets/index-BPvgi06w.js` with a
+    /// content hash in its name, so it can be cached for good (see `#[immutable_assets]`).
+    pub immutable: bool,
+}
+
+#[cfg(not(vite_rs_dev))]
+impl ViteFile {
+    /// A file with the given contents, e.g. for custom `GetFromVite` implementations. Its
+    /// `content_hash` is empty until set with `with_content_hash`.
+    pub const fn new(bytes: &'static [u8], content_type: &'static str) -> Self {
+        Self {
+            bytes: ::std::borrow::Cow::Borrowed(bytes),
+            last_modified: None,
+            content_type,
+            content_length: bytes.len() as u64,
+            #[cfg(feature = "content-hash")]
+            content_hash: "",
+            #[cfg(feature = "zstd")]
+            zstd: None,
+            #[cfg(feature = "gzip")]
+            gzip: None,
+            #[cfg(feature = "brotli")]
+            brotli: None,
+            service_worker_allowed: None,
+            immutable: false,
+        }
+    }
+
+    pub const fn with_last_modified(mut self, last_modified: &'static str) -> Self {
+        self.last_modified = Some(last_modified);
+        self
+    }
+
+    #[cfg(feature = "content-hash")]
+    pub const fn with_content_hash(mut self, content_hash: &'static str) -> Self {
+        self.content_hash = content_hash;
+        self
+    }
+
+    #[cfg(feature = "zstd")]
+    pub const fn with_zstd(mut self, zstd: &'static [u8]) -> Self {
+        self.zstd = Some(zstd);
+        self
+    }
+
+    #[cfg(feature = "gzip")]
+    pub const fn with_gzip(mut self, gzip: &'static [u8]) -> Self {
+        self.gzip = Some(gzip);
+        self
+    }
+
+    #[cfg(feature = "brotli")]
+    pub const fn with_brotli(mut self, brotli: &'static [u8]) -> Self {
+        self.brotli = Some(brotli);
+        self
+    }
+
+    pub const fn with_service_worker_allowed(mut self, scope: &'static str) -> Self {
+        self.service_worker_allowed = Some(scope);
+        self
+    }
+
+    pub const fn with_immutable(mut self, immutable: bool) -> Self {
+        self.immutable = immutable;
+        self
+    }
+
+    /// The file's copy compressed with `content_encoding` (`zstd`, `br` or `gzip`, as in
+    /// `Content-Encoding`), if it was embedded with one; e.g. to serve it from a handler of
+    /// your own, or to hand it to a proxy or CDN which negotiates the encoding itself.
+    pub fn encoded(&self, content_encoding: &str) -> Option<&'static [u8]> {
+        self.encodings()
+            .find(|(coding, _)| coding.eq_ignore_ascii_case(content_encoding))
+            .map(|(_, bytes)| bytes)
+    }
+
+    /// The file's compressed copies, as `(content_encoding, bytes)`, in the order servers prefer
+    /// them: zstd, then Brotli (only embedded when it's smaller than the gzip copy), then gzip.
+    pub fn encodings(&self) -> impl Iterator<Item = (&'static str, &'static [u8])> {
+        // typed, as it's empty when none of the features are enabled
+        let copies: [Option<(&'static str, &'static [u8])>; _] = [
+            #[cfg(feature = "zstd")]
+            self.zstd.map(|bytes| ("zstd", bytes)),
+            #[cfg(feature = "brotli")]
+            self.brotli.map(|bytes| ("br", bytes)),
+            #[cfg(feature = "gzip")]
+            self.gzip.map(|bytes| ("gzip", bytes)),
+        ];
+        copies.into_iter().flatten()
+    }
 }
 
 // Production Struct Trait
 /// Note: this is used to allow dynamic usage of embedded asset structs.
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 pub trait GetFromVite: Send + Sync + 'static {
     fn get(&self, file_path: &str) -> Option<ViteFile>;
     fn clone_box(&self) -> Box<dyn GetFromVite>;
+
+    /// Like `get`, but doesn't block the async runtime while an asset is fetched from the ViteJS
+    /// dev server (with the `async` feature of `vite-rs`). Otherwise, this calls `get`.
+    #[cfg(feature = "async")]
+    fn get_async<'a>(
+        &'a self,
+        file_path: &'a str,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<dyn ::std::future::Future<Output = Option<ViteFile>> + Send + 'a>,
+    > {
+        ::std::boxed::Box::pin(async move { self.get(file_path) })
+    }
+
+    /// Like `get`, for a request with the given metadata (see `RequestContext`). Otherwise, this
+    /// calls `get`.
+    fn get_with_context(&self, file_path: &str, _context: &RequestContext) -> Option<ViteFile> {
+        self.get(file_path)
+    }
+
+    /// Like `get_async`, for a request with the given metadata. Otherwise, this calls
+    /// `get_async`, so implementations which look at the context override this as well as
+    /// `get_with_context` (servers with the `async` feature, like `vite-rs-axum-0-8`, call this
+    /// one through `try_get_async_with_context`).
+    #[cfg(feature = "async")]
+    fn get_async_with_context<'a>(
+        &'a self,
+        file_path: &'a str,
+        _context: &'a RequestContext,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<dyn ::std::future::Future<Output = Option<ViteFile>> + Send + 'a>,
+    > {
+        self.get_async(file_path)
+    }
+
+    /// Like `get`, but tells a missing asset (`Ok(None)`) apart from one which can't be retrieved
+    /// right now (see `GetError`), e.g. while the ViteJS dev server is starting. Otherwise, this
+    /// calls `get`.
+    fn try_get(&self, file_path: &str) -> Result<Option<ViteFile>, GetError> {
+        Ok(self.get(file_path))
+    }
+
+    /// `try_get` and `get_async_with_context` combined, for servers. Otherwise, this calls
+    /// `get_async_with_context`, and an asset it doesn't return is missing: implementations which
+    /// tell why override this as well as `try_get` (it isn't called here, since it may block the
+    /// async runtime).
+    #[cfg(feature = "async")]
+    fn try_get_async_with_context<'a>(
+        &'a self,
+        file_path: &'a str,
+        context: &'a RequestContext,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<Output = Result<Option<ViteFile>, GetError>> + Send + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(
+            async move { Ok(self.get_async_with_context(file_path, context).await) },
+        )
+    }
 }
 
 // Development File
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 /// File retreived from the ViteJS dev server
 pub struct ViteFile {
     pub bytes: Vec<u8>,
@@ -32,12 +220,129 @@ pub struct ViteFile {
     #[cfg(feature = "content-hash")]
     /// Note: in development mode, this is a weak hash returned by the ViteJS dev server.
     pub content_hash: String,
+    /// The `Service-Worker-Allowed` header to send with a service worker. Not set by the dev
+    /// server, which sends its own headers.
+    pub service_worker_allowed: Option<String>,
+    /// The file's contents never change at its path. Not set by the dev server, whose files change
+    /// as they're edited.
+    pub immutable: bool,
+}
+
+#[cfg(vite_rs_dev)]
+impl ViteFile {
+    /// A file with the given contents, e.g. for custom `GetFromVite` implementations. Its
+    /// `content_hash` is empty until set with `with_content_hash`.
+    pub fn new(bytes: impl Into<Vec<u8>>, content_type: impl Into<String>) -> Self {
+        let bytes = bytes.into();
+
+        Self {
+            content_length: bytes.len() as u64,
+            bytes,
+            last_modified: None,
+            content_type: content_type.into(),
+            #[cfg(feature = "content-hash")]
+            content_hash: String::new(),
+            service_worker_allowed: None,
+            immutable: false,
+        }
+    }
+
+    pub fn with_last_modified(mut self, last_modified: impl Into<String>) -> Self {
+        self.last_modified = Some(last_modified.into());
+        self
+    }
+
+    #[cfg(feature = "content-hash")]
+    pub fn with_content_hash(mut self, content_hash: impl Into<String>) -> Self {
+        self.content_hash = content_hash.into();
+        self
+    }
+
+    pub fn with_service_worker_allowed(mut self, scope: impl Into<String>) -> Self {
+        self.service_worker_allowed = Some(scope.into());
+        self
+    }
+
+    pub fn with_immutable(mut self, immutable: bool) -> Self {
+        self.immutable = immutable;
+        self
+    }
+
+    /// Always `None`: files from the dev server aren't compressed (it compresses its responses
+    /// itself). Here so code using the release build's `encoded` compiles in development.
+    pub fn encoded(&self, _content_encoding: &str) -> Option<&'static [u8]> {
+        None
+    }
+
+    /// Always empty, like `encoded`.
+    pub fn encodings(&self) -> impl Iterator<Item = (&'static str, &'static [u8])> {
+        std::iter::empty()
+    }
 }
 
 // Development Struct Trait
 /// Note: this is used to allow dynamic usage of embedded asset structs.
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 pub trait GetFromVite: Send + Sync + 'static {
     fn get(&self, file_path: &str) -> Option<ViteFile>;
     fn clone_box(&self) -> Box<dyn GetFromVite>;
+
+    /// Like `get`, but doesn't block the async runtime while an asset is fetched from the ViteJS
+    /// dev server (with the `async` feature of `vite-rs`). Otherwise, this calls `get`.
+    #[cfg(feature = "async")]
+    fn get_async<'a>(
+        &'a self,
+        file_path: &'a str,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<dyn ::std::future::Future<Output = Option<ViteFile>> + Send + 'a>,
+    > {
+        ::std::boxed::Box::pin(async move { self.get(file_path) })
+    }
+
+    /// Like `get`, for a request with the given metadata (see `RequestContext`). Otherwise, this
+    /// calls `get`.
+    fn get_with_context(&self, file_path: &str, _context: &RequestContext) -> Option<ViteFile> {
+        self.get(file_path)
+    }
+
+    /// Like `get_async`, for a request with the given metadata. Otherwise, this calls
+    /// `get_async`, so implementations which look at the context override this as well as
+    /// `get_with_context` (servers with the `async` feature, like `vite-rs-axum-0-8`, call this
+    /// one through `try_get_async_with_context`).
+    #[cfg(feature = "async")]
+    fn get_async_with_context<'a>(
+        &'a self,
+        file_path: &'a str,
+        _context: &'a RequestContext,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<dyn ::std::future::Future<Output = Option<ViteFile>> + Send + 'a>,
+    > {
+        self.get_async(file_path)
+    }
+
+    /// Like `get`, but tells a missing asset (`Ok(None)`) apart from one which can't be retrieved
+    /// right now (see `GetError`), e.g. while the ViteJS dev server is starting. Otherwise, this
+    /// calls `get`.
+    fn try_get(&self, file_path: &str) -> Result<Option<ViteFile>, GetError> {
+        Ok(self.get(file_path))
+    }
+
+    /// `try_get` and `get_async_with_context` combined, for servers. Otherwise, this calls
+    /// `get_async_with_context`, and an asset it doesn't return is missing: implementations which
+    /// tell why override this as well as `try_get` (it isn't called here, since it may block the
+    /// async runtime).
+    #[cfg(feature = "async")]
+    fn try_get_async_with_context<'a>(
+        &'a self,
+        file_path: &'a str,
+        context: &'a RequestContext,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<Output = Result<Option<ViteFile>, GetError>> + Send + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(
+            async move { Ok(self.get_async_with_context(file_path, context).await) },
+        )
+    }
 }
@@ -0,0 +1,153 @@
+/// The chunk graph from Vite's build manifest (`.vite/manifest.json`), embedded in release builds
+/// and returned by `Assets::manifest()`. In development builds, it's empty: the dev server serves
+/// modules without bundling them.
+///
+/// Chunks are referred to by their manifest key, usually the source path (e.g.
+/// `src/routes/settings.tsx`). For router-driven apps, the route components a chunk loads with
+/// `import()` are its `dynamic_imports`, so the files of the next likely routes can be prefetched:
+///
+/// ```ignore
+/// let manifest = Assets::manifest();
+///
+/// for (_route, files) in manifest.dynamic_imports("src/main.tsx") {
+///     for file in files {
+///         html.push_str(&format!(r#"<link rel="prefetch" href="/{}">"#, file));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Manifest {
+    chunks: &'static [ManifestChunk],
+}
+
+/// A chunk in the `Manifest`.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ManifestChunk {
+    /// The manifest key, e.g. `src/routes/settings.tsx`.
+    pub key: &'static str,
+    /// The output file, relative to the output directory, e.g. `assets/settings-4f2a9c1e.js`.
+    pub file: &'static str,
+    pub is_entry: bool,
+    /// Whether the chunk is loaded with `import()` by another chunk.
+    pub is_dynamic_entry: bool,
+    /// Keys of the chunks this chunk imports statically.
+    pub imports: &'static [&'static str],
+    /// Keys of the chunks this chunk loads with `import()`.
+    pub dynamic_imports: &'static [&'static str],
+    /// CSS files (output paths) this chunk loads.
+    pub css: &'static [&'static str],
+    #[cfg(feature = "integrity")]
+    /// The Subresource Integrity hash of `file`, e.g. `sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC`,
+    /// for the `integrity` attribute of its `<script>` tag. `None` if `file` isn't embedded. See
+    /// `Assets::integrity` for the other files, like `css`.
+    pub integrity: Option<&'static str>,
+}
+
+impl ManifestChunk {
+    #[doc(hidden)] // used by the generated code
+    pub const fn new(
+        key: &'static str,
+        file: &'static str,
+        is_entry: bool,
+        is_dynamic_entry: bool,
+        imports: &'static [&'static str],
+        dynamic_imports: &'static [&'static str],
+        css: &'static [&'static str],
+    ) -> Self {
+        Self {
+            key,
+            file,
+            is_entry,
+            is_dynamic_entry,
+            imports,
+            dynamic_imports,
+            css,
+            #[cfg(feature = "integrity")]
+            integrity: None,
+        }
+    }
+
+    #[cfg(feature = "integrity")]
+    #[doc(hidden)] // used by the generated code
+    pub const fn with_integrity(mut self, integrity: &'static str) -> Self {
+        self.integrity = Some(integrity);
+        self
+    }
+}
+
+impl Manifest {
+    /// `chunks` must be sorted by key.
+    #[doc(hidden)] // used by the generated code
+    pub const fn new(chunks: &'static [ManifestChunk]) -> Self {
+        Self { chunks }
+    }
+
+    /// All chunks, sorted by key.
+    pub fn chunks(&self) -> &'static [ManifestChunk] {
+        self.chunks
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'static ManifestChunk> {
+        let chunks = self.chunks;
+        chunks
+            .binary_search_by_key(&key, |chunk| chunk.key)
+            .ok()
+            .map(|index| &chunks[index])
+    }
+
+    /// The files needed to run the chunk: its own file, then the files of the chunks it imports
+    /// statically (recursively) and their CSS. Each file is listed once. Empty for unknown keys.
+    pub fn files(&self, key: &str) -> Vec<&'static str> {
+        let mut files = Vec::new();
+        let mut visited = Vec::new();
+        self.collect_files(key, &mut visited, &mut files);
+        files
+    }
+
+    /// For each chunk that `key` loads with `import()` (e.g. lazily loaded routes), its key and
+    /// the files it needs (see `files`). Files `key` already loaded itself are left out, so these
+    /// are the files to prefetch for the next navigation.
+    pub fn dynamic_imports(&self, key: &str) -> Vec<(&'static str, Vec<&'static str>)> {
+        let Some(chunk) = self.get(key) else {
+            return Vec::new();
+        };
+
+        let loaded = self.files(key);
+
+        chunk
+            .dynamic_imports
+            .iter()
+            .map(|import| {
+                let mut files = self.files(import);
+                files.retain(|file| !loaded.contains(file));
+                (*import, files)
+            })
+            .collect()
+    }
+
+    fn collect_files(
+        &self,
+        key: &str,
+        visited: &mut Vec<&'static str>,
+        files: &mut Vec<&'static str>,
+    ) {
+        let Some(chunk) = self.get(key) else {
+            return;
+        };
+        if visited.contains(&chunk.key) {
+            return;
+        }
+        visited.push(chunk.key);
+
+        for file in std::iter::once(&chunk.file).chain(chunk.css) {
+            if !files.contains(file) {
+                files.push(file);
+            }
+        }
+
+        for import in chunk.imports {
+            self.collect_files(import, visited, files);
+        }
+    }
+}
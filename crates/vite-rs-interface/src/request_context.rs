@@ -0,0 +1,63 @@
+use std::net::SocketAddr;
+
+/// Metadata about the HTTP request an asset is served for, passed to
+/// `GetFromVite::get_with_context` by servers which have it (e.g. `vite-rs-axum-0-8`). Lets
+/// custom `GetFromVite` implementations depend on the request, e.g. for authorization,
+/// geo-specific assets or A/B tests:
+///
+/// ```ignore
+/// impl GetFromVite for Authorized {
+///     fn get(&self, file_path: &str) -> Option<ViteFile> {
+///         self.public.get(file_path)
+///     }
+///
+///     fn get_with_context(&self, file_path: &str, context: &RequestContext) -> Option<ViteFile> {
+///         match context.header("authorization") {
+///             Some(token) if self.is_valid(token) => self.private.get(file_path),
+///             _ => self.get(file_path),
+///         }
+///     }
+///
+///     // ...
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestContext {
+    /// e.g. `GET`
+    pub method: String,
+    /// `(name, value)` pairs in request order, with lowercase names. Headers which aren't valid
+    /// UTF-8 are left out.
+    pub headers: Vec<(String, String)>,
+    /// The client's address, if the server knows it.
+    pub peer_addr: Option<SocketAddr>,
+}
+
+impl RequestContext {
+    pub fn new(method: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a header (a repeated header is added once per value).
+    pub fn with_header(mut self, name: impl AsRef<str>, value: impl Into<String>) -> Self {
+        self.headers
+            .push((name.as_ref().to_ascii_lowercase(), value.into()));
+        self
+    }
+
+    pub fn with_peer_addr(mut self, peer_addr: SocketAddr) -> Self {
+        self.peer_addr = Some(peer_addr);
+        self
+    }
+
+    /// The first value of the header `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
@@ -0,0 +1,165 @@
+/// Which HTML entry serves which URL path, so client-side (SPA) routes can be served without
+/// configuring a fallback by hand. Returned by `Assets::routes()`, which derives the routes from
+/// the HTML entries:
+///
+/// - `index.html` serves `/*`, i.e. every path no other route or asset matches.
+/// - `admin/index.html` serves `/admin/*`.
+/// - `about.html` serves `/about` only.
+///
+/// The Rust side's own routes can be added with `with_api_route`, so they never fall back to an
+/// HTML entry, and the whole thing can be handed to the frontend router with `to_json`:
+///
+/// ```ignore
+/// let routes = Assets::routes()
+///     .with_api_route("/api/*")
+///     .with_entry("/settings/*", "index.html");
+///
+/// assert_eq!(routes.resolve("/admin/users/42"), Some("admin/index.html"));
+/// assert_eq!(routes.resolve("/api/users"), None);
+/// ```
+///
+/// Patterns are URL paths, optionally ending with `/*` to match a path and everything below it.
+/// When several patterns match, the most specific one wins: the one with the longest path, and an
+/// exact pattern over a `/*` one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteManifest {
+    entries: Vec<RouteEntry>,
+    api_routes: Vec<String>,
+}
+
+/// A route of the `RouteManifest` served by an HTML entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RouteEntry {
+    /// e.g. `/admin/*`
+    pub pattern: String,
+    /// The HTML entry (an asset path) serving the route, e.g. `admin/index.html`.
+    pub entry: String,
+}
+
+impl RouteManifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves the paths matching `pattern` with the HTML entry `entry` (an asset path). Replaces a
+    /// route with the same pattern.
+    pub fn with_entry(mut self, pattern: impl Into<String>, entry: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        self.entries.retain(|route| route.pattern != pattern);
+        self.entries.push(RouteEntry {
+            pattern,
+            entry: entry.into(),
+        });
+        self
+    }
+
+    /// Marks the paths matching `pattern` as handled by the Rust side, e.g. `/api/*`, so they're
+    /// never served an HTML entry.
+    pub fn with_api_route(mut self, pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        if !self.api_routes.contains(&pattern) {
+            self.api_routes.push(pattern);
+        }
+        self
+    }
+
+    pub fn entries(&self) -> &[RouteEntry] {
+        &self.entries
+    }
+
+    pub fn api_routes(&self) -> &[String] {
+        &self.api_routes
+    }
+
+    /// Whether `path` matches one of the API routes.
+    pub fn is_api_route(&self, path: &str) -> bool {
+        self.api_routes
+            .iter()
+            .any(|pattern| specificity(pattern, path).is_some())
+    }
+
+    /// The HTML entry serving `path`, unless it's an API route.
+    pub fn resolve(&self, path: &str) -> Option<&str> {
+        if self.is_api_route(path) {
+            return None;
+        }
+
+        self.entries
+            .iter()
+            .filter_map(|route| Some((specificity(&route.pattern, path)?, route)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, route)| route.entry.as_str())
+    }
+
+    /// The routes as JSON, for the frontend router:
+    /// `{"entries":[{"pattern":"/admin/*","entry":"admin/index.html"}],"apiRoutes":["/api/*"]}`
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|route| {
+                format!(
+                    "{{\"pattern\":{},\"entry\":{}}}",
+                    json_string(&route.pattern),
+                    json_string(&route.entry)
+                )
+            })
+            .collect::<Vec<_>>();
+        let api_routes = self
+            .api_routes
+            .iter()
+            .map(|pattern| json_string(pattern))
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\"entries\":[{}],\"apiRoutes\":[{}]}}",
+            entries.join(","),
+            api_routes.join(",")
+        )
+    }
+}
+
+/// How specifically `pattern` matches `path`, if it does: the length of the matched path, and
+/// whether the match is exact. A trailing slash is ignored.
+fn specificity(pattern: &str, path: &str) -> Option<(usize, bool)> {
+    let path = trim_slash(path);
+
+    match pattern.strip_suffix("/*") {
+        Some(prefix) => {
+            let prefix = trim_slash(prefix);
+            let matches = prefix.is_empty()
+                || path == prefix
+                || path
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with('/'));
+
+            matches.then_some((prefix.len(), false))
+        }
+        None => {
+            let pattern = trim_slash(pattern);
+            (path == pattern).then_some((pattern.len(), true))
+        }
+    }
+}
+
+fn trim_slash(path: &str) -> &str {
+    path.trim_end_matches('/')
+}
+
+fn json_string(string: &str) -> String {
+    let mut json = String::with_capacity(string.len() + 2);
+    json.push('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
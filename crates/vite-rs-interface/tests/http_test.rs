@@ -0,0 +1,103 @@
+use vite_rs_interface::http;
+
+#[test]
+fn resolves_request_paths() {
+    assert_eq!(http::asset_path("/"), "index.html");
+    assert_eq!(http::asset_path(""), "index.html");
+    assert_eq!(http::asset_path("/assets/app.js"), "assets/app.js");
+
+    assert_eq!(http::index_path("/"), "index.html");
+    assert_eq!(http::index_path("docs"), "docs/index.html");
+    assert_eq!(http::index_path("/docs/"), "docs/index.html");
+}
+
+#[test]
+fn matches_if_none_match_weakly() {
+    assert!(http::if_none_match("\"abc\"", "\"abc\""));
+    assert!(http::if_none_match("W/\"abc\"", "\"abc\""));
+    assert!(http::if_none_match("\"abc\"", "W/\"abc\""));
+    assert!(http::if_none_match("\"xyz\", W/\"abc\"", "W/\"abc\""));
+    assert!(http::if_none_match("*", "\"abc\""));
+
+    assert!(!http::if_none_match("\"xyz\"", "\"abc\""));
+    assert!(!http::if_none_match("", "\"abc\""));
+    assert!(!http::if_none_match("", ""));
+}
+
+#[test]
+fn negotiates_encodings() {
+    assert!(http::accepts_encoding("gzip, zstd", "zstd"));
+    assert!(http::accepts_encoding("gzip, ZSTD;q=0.5", "zstd"));
+    assert!(http::accepts_encoding("*", "zstd"));
+    assert!(!http::accepts_encoding("gzip, br", "zstd"));
+    assert!(!http::accepts_encoding("zstd;q=0", "zstd"));
+    assert!(!http::accepts_encoding("*, zstd;q=0", "zstd"));
+    assert!(!http::accepts_encoding("", "zstd"));
+
+    assert_eq!(http::encoded_etag("\"abc\"", "zstd"), "\"abc-zstd\"");
+    assert_eq!(http::encoded_etag("W/\"abc\"", "zstd"), "W/\"abc-zstd\"");
+    assert_eq!(http::encoded_etag("abc", "br"), "abc-br");
+}
+
+#[test]
+fn decodes_paths() {
+    assert_eq!(http::decode_path("caf%C3%A9.png"), "café.png");
+    assert_eq!(http::decode_path("caf%c3%a9.png"), "café.png");
+    assert_eq!(http::decode_path("caf%C3%a9.png"), "café.png");
+    assert_eq!(http::decode_path("a%20b.png"), "a b.png");
+    assert_eq!(http::decode_path("assets/app.js"), "assets/app.js");
+
+    // `+` is only a space in form data
+    assert_eq!(http::decode_path("a+b.png"), "a+b.png");
+    assert_eq!(http::decode_path("a%2Bb.png"), "a+b.png");
+
+    assert_eq!(http::decode_path("a%2Fb.png"), "a/b.png");
+    assert_eq!(http::decode_path("a%2fb.png"), "a/b.png");
+
+    // kept as-is
+    assert_eq!(http::decode_path("100%.png"), "100%.png");
+    assert_eq!(http::decode_path("a%zzb.png"), "a%zzb.png");
+    assert_eq!(http::decode_path("a%4"), "a%4");
+    assert_eq!(http::decode_path("a%FF.png"), "a%FF.png");
+}
+
+#[test]
+fn rejects_ambiguous_paths_when_strict() {
+    assert_eq!(
+        http::decode_path_strict("caf%C3%a9.png").as_deref(),
+        Some("café.png")
+    );
+    assert_eq!(
+        http::decode_path_strict("a+b.png").as_deref(),
+        Some("a+b.png")
+    );
+    assert_eq!(
+        http::decode_path_strict("a%2Bb.png").as_deref(),
+        Some("a+b.png")
+    );
+
+    assert_eq!(http::decode_path_strict("a%2Fb.png"), None);
+    assert_eq!(http::decode_path_strict("a%2fb.png"), None);
+    assert_eq!(http::decode_path_strict("..%5Csecret"), None);
+    assert_eq!(http::decode_path_strict("a%00.png"), None);
+    assert_eq!(http::decode_path_strict("100%.png"), None);
+    assert_eq!(http::decode_path_strict("a%zzb.png"), None);
+    assert_eq!(http::decode_path_strict("a%FF.png"), None);
+}
+
+#[test]
+fn encodes_paths() {
+    assert_eq!(http::encode_path("café.png"), "caf%C3%A9.png");
+    assert_eq!(http::encode_path("a b/what?.png"), "a%20b/what%3F.png");
+    assert_eq!(http::encode_path("100%.png"), "100%25.png");
+    assert_eq!(http::encode_path("a+b.png"), "a+b.png");
+    assert_eq!(
+        http::encode_path("assets/app-B2m_tRuS.js"),
+        "assets/app-B2m_tRuS.js"
+    );
+
+    assert_eq!(
+        http::decode_path(&http::encode_path("ü #1.png")),
+        "ü #1.png"
+    );
+}
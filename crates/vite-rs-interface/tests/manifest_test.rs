@@ -0,0 +1,119 @@
+use vite_rs_interface::{Manifest, ManifestChunk};
+
+/// `src/main.ts` lazily loads two routes; both share `_shared` (and its CSS) with each other, and
+/// `settings` also imports `_vendor`, which `src/main.ts` already loaded.
+const MANIFEST: Manifest = Manifest::new(&[
+    ManifestChunk::new(
+        "_shared-b2.js",
+        "assets/shared-b2.js",
+        false,
+        false,
+        &[],
+        &[],
+        &["assets/shared-b2.css"],
+    ),
+    ManifestChunk::new(
+        "_vendor-a1.js",
+        "assets/vendor-a1.js",
+        false,
+        false,
+        &[],
+        &[],
+        &[],
+    ),
+    ManifestChunk::new(
+        "src/main.ts",
+        "assets/main-c3.js",
+        true,
+        false,
+        &["_vendor-a1.js"],
+        &["src/routes/about.ts", "src/routes/settings.ts"],
+        &["assets/main-c3.css"],
+    ),
+    ManifestChunk::new(
+        "src/routes/about.ts",
+        "assets/about-d4.js",
+        false,
+        true,
+        &["_shared-b2.js"],
+        &[],
+        &[],
+    ),
+    ManifestChunk::new(
+        "src/routes/settings.ts",
+        "assets/settings-e5.js",
+        false,
+        true,
+        // `src/main.ts` is imported back, e.g. for a shared store
+        &["_shared-b2.js", "_vendor-a1.js", "src/main.ts"],
+        &[],
+        &["assets/settings-e5.css"],
+    ),
+]);
+
+#[test]
+fn looks_up_chunks_by_key() {
+    let chunk = MANIFEST.get("src/main.ts").unwrap();
+    assert_eq!(chunk.file, "assets/main-c3.js");
+    assert!(chunk.is_entry);
+
+    assert!(MANIFEST.get("src/missing.ts").is_none());
+    assert_eq!(MANIFEST.chunks().len(), 5);
+}
+
+#[test]
+fn collects_the_files_of_static_imports() {
+    assert_eq!(
+        MANIFEST.files("src/main.ts"),
+        [
+            "assets/main-c3.js",
+            "assets/main-c3.css",
+            "assets/vendor-a1.js"
+        ]
+    );
+
+    // cycles are followed once
+    assert_eq!(
+        MANIFEST.files("src/routes/settings.ts"),
+        [
+            "assets/settings-e5.js",
+            "assets/settings-e5.css",
+            "assets/shared-b2.js",
+            "assets/shared-b2.css",
+            "assets/vendor-a1.js",
+            "assets/main-c3.js",
+            "assets/main-c3.css"
+        ]
+    );
+
+    assert!(MANIFEST.files("src/missing.ts").is_empty());
+}
+
+#[test]
+fn maps_dynamic_imports_to_the_files_to_prefetch() {
+    assert_eq!(
+        MANIFEST.dynamic_imports("src/main.ts"),
+        [
+            (
+                "src/routes/about.ts",
+                vec![
+                    "assets/about-d4.js",
+                    "assets/shared-b2.js",
+                    "assets/shared-b2.css"
+                ]
+            ),
+            (
+                "src/routes/settings.ts",
+                vec![
+                    "assets/settings-e5.js",
+                    "assets/settings-e5.css",
+                    "assets/shared-b2.js",
+                    "assets/shared-b2.css"
+                ]
+            ),
+        ]
+    );
+
+    assert!(MANIFEST.dynamic_imports("src/routes/about.ts").is_empty());
+    assert!(MANIFEST.dynamic_imports("src/missing.ts").is_empty());
+}
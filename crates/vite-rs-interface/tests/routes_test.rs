@@ -0,0 +1,55 @@
+use vite_rs_interface::RouteManifest;
+
+fn routes() -> RouteManifest {
+    RouteManifest::new()
+        .with_entry("/*", "index.html")
+        .with_entry("/admin/*", "admin/index.html")
+        .with_entry("/about", "about.html")
+        .with_api_route("/api/*")
+}
+
+#[test]
+fn resolves_the_most_specific_route() {
+    let routes = routes();
+
+    assert_eq!(routes.resolve("/"), Some("index.html"));
+    assert_eq!(routes.resolve("/settings/profile"), Some("index.html"));
+    assert_eq!(routes.resolve("/admin"), Some("admin/index.html"));
+    assert_eq!(routes.resolve("/admin/users/42"), Some("admin/index.html"));
+    assert_eq!(routes.resolve("/administrator"), Some("index.html"));
+    assert_eq!(routes.resolve("/about/"), Some("about.html"));
+    assert_eq!(routes.resolve("/about/team"), Some("index.html"));
+}
+
+#[test]
+fn api_routes_never_resolve() {
+    let routes = routes();
+
+    assert!(routes.is_api_route("/api"));
+    assert!(routes.is_api_route("/api/users"));
+    assert_eq!(routes.resolve("/api/users"), None);
+    assert!(!routes.is_api_route("/apis"));
+}
+
+#[test]
+fn without_a_catch_all_route_unknown_paths_dont_resolve() {
+    let routes = RouteManifest::new().with_entry("/admin/*", "admin/index.html");
+
+    assert_eq!(routes.resolve("/settings"), None);
+}
+
+#[test]
+fn entries_replace_entries_with_the_same_pattern() {
+    let routes = routes().with_entry("/admin/*", "index.html");
+
+    assert_eq!(routes.entries().len(), 3);
+    assert_eq!(routes.resolve("/admin/users"), Some("index.html"));
+}
+
+#[test]
+fn serializes_to_json() {
+    assert_eq!(
+        routes().with_api_route("/api/\"quoted\"").to_json(),
+        r#"{"entries":[{"pattern":"/*","entry":"index.html"},{"pattern":"/admin/*","entry":"admin/index.html"},{"pattern":"/about","entry":"about.html"}],"apiRoutes":["/api/*","/api/\"quoted\""]}"#
+    );
+}
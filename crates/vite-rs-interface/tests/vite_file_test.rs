@@ -0,0 +1,45 @@
+use vite_rs_interface::ViteFile;
+
+/// Note: the same calls work in development and release builds.
+fn js_file() -> ViteFile {
+    ViteFile::new(b"console.log(1)", "text/javascript")
+        .with_last_modified("Thu, 01 Jan 2026 00:00:00 GMT")
+}
+
+#[test]
+fn builds_files_outside_of_the_macro() {
+    let file = js_file();
+
+    assert_eq!(&file.bytes[..], b"console.log(1)");
+    assert_eq!(file.content_length, 14);
+    assert_eq!(file.content_type, "text/javascript");
+    assert!(file
+        .last_modified
+        .is_some_and(|last_modified| last_modified == "Thu, 01 Jan 2026 00:00:00 GMT"));
+
+    #[cfg(feature = "content-hash")]
+    {
+        assert_eq!(file.content_hash, "");
+        assert_eq!(
+            js_file().with_content_hash("\"abc\"").content_hash,
+            "\"abc\""
+        );
+    }
+}
+
+/// Embedded files are constants.
+#[cfg(not(vite_rs_dev))]
+#[test]
+fn builds_files_in_constants() {
+    const FILE: ViteFile = ViteFile::new(b"<html></html>", "text/html");
+
+    assert_eq!(FILE.content_length, 13);
+}
+
+#[test]
+fn has_no_compressed_copies_unless_embedded_with_them() {
+    let file = js_file();
+
+    assert!(file.encoded("gzip").is_none());
+    assert!(file.encodings().next().is_none());
+}
@@ -0,0 +1,93 @@
+//! Test helpers for crates using vite-rs.
+//!
+//! [`scaffold_fixture`] writes a minimal ViteJS project, so integration tests (e.g. of your
+//! `ViteServe` wiring) don't need a full frontend committed to the repository. Since
+//! `#[derive(vite_rs::Embed)]` reads its `#[root]` at compile time, generate the fixture from a
+//! build script:
+//!
+//! ```ignore
+//! // build.rs (with `vite-rs-test` in [build-dependencies])
+//! fn main() {
+//!     vite_rs_test::scaffold_fixture("tests/fixture").unwrap();
+//! }
+//!
+//! // tests/serve_test.rs
+//! #[derive(vite_rs::Embed)]
+//! #[root = "./tests/fixture"]
+//! struct Assets;
+//! ```
+//!
+//! Vite itself still has to be installed (`npm install` in the fixture directory, or in one of
+//! its parents).
+
+use std::path::Path;
+
+/// The files written by [`scaffold_fixture`], relative to its directory.
+const FIXTURE_FILES: &[(&str, &str)] = &[
+    (
+        "package.json",
+        r#"{
+  "name": "vite-rs-fixture",
+  "private": true,
+  "type": "module",
+  "devDependencies": {
+    "vite": "^5.2.0"
+  }
+}
+"#,
+    ),
+    (
+        "vite.config.ts",
+        r#"import { defineConfig } from "vite";
+
+export default defineConfig({
+  build: {
+    rollupOptions: {
+      input: ["index.html"],
+    },
+    manifest: true, // required by vite-rs
+  },
+});
+"#,
+    ),
+    (
+        "index.html",
+        r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <title>vite-rs fixture</title>
+  </head>
+  <body>
+    <script type="module" src="./main.ts"></script>
+  </body>
+</html>
+"#,
+    ),
+    (
+        "main.ts",
+        r#"document.body.textContent = "Hello from vite-rs!";
+"#,
+    ),
+];
+
+/// Writes a minimal ViteJS project to `dir` (created if needed): `package.json`,
+/// `vite.config.ts`, `index.html` and `main.ts`, which `index.html` loads. Once built, it serves
+/// `index.html` and a hashed `assets/index-*.js` bundle.
+///
+/// Files are only written when their contents changed, so calling this from a build script
+/// doesn't trigger rebuilds. Other files in `dir` are left alone.
+pub fn scaffold_fixture(dir: impl AsRef<Path>) -> std::io::Result<()> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    for (name, contents) in FIXTURE_FILES {
+        let path = dir.join(name);
+
+        if std::fs::read_to_string(&path).ok().as_deref() != Some(*contents) {
+            std::fs::write(path, contents)?;
+        }
+    }
+
+    Ok(())
+}
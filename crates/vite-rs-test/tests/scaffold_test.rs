@@ -0,0 +1,31 @@
+#[test]
+fn scaffolds_a_vite_project() {
+    let dir = std::env::temp_dir()
+        .join(format!("vite-rs-scaffold-test-{}", std::process::id()))
+        .join("fixture");
+
+    vite_rs_test::scaffold_fixture(&dir).unwrap();
+
+    let read = |name: &str| std::fs::read_to_string(dir.join(name)).unwrap();
+    assert!(read("package.json").contains("\"vite\""));
+    assert!(read("vite.config.ts").contains("manifest: true"));
+    assert!(read("index.html").contains("src=\"./main.ts\""));
+    assert!(!read("main.ts").is_empty());
+
+    // unchanged files aren't rewritten, and changed ones are restored
+    let modified = || {
+        std::fs::metadata(dir.join("package.json"))
+            .unwrap()
+            .modified()
+            .unwrap()
+    };
+    let before = modified();
+    std::fs::write(dir.join("main.ts"), "").unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+
+    vite_rs_test::scaffold_fixture(&dir).unwrap();
+    assert_eq!(modified(), before);
+    assert!(!read("main.ts").is_empty());
+
+    std::fs::remove_dir_all(dir.parent().unwrap()).ok();
+}
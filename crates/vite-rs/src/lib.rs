@@ -1,9 +1,16 @@
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 #[cfg(feature = "ctrlc")]
 #[cfg(not(doctest))] // for some reason, the cfgs above don't apply to doc tests
 pub use vite_rs_dev_server::ctrlc;
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
-pub use vite_rs_dev_server::{self, ViteProcess};
+#[cfg(vite_rs_dev)]
+pub use vite_rs_dev_server::{
+    self, DevServerError, DevServerEvent, DevServerOptions, DevServerRestart, DevServerStdio,
+    RestartPolicy, ViteProcess,
+};
 pub use vite_rs_embed_macro::Embed;
 
+/// The Axum 0.8 integration (`vite-rs-axum-0-8`), with the `axum` feature.
+#[cfg(feature = "axum")]
+pub use vite_rs_axum_0_8 as axum;
+
 pub use vite_rs_interface::*;
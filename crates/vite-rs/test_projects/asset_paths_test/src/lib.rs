@@ -0,0 +1,10 @@
+// `dist/` is prebuilt and committed (hermetic builds never run `vite build`).
+#[derive(vite_rs::Embed)]
+#[root = "./"]
+#[asset_paths_file = "./generated/assets.d.ts"]
+pub struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./"]
+#[asset_paths_file = "./generated/assets.json"]
+pub struct JsonAssets;
@@ -0,0 +1,13 @@
+use asset_paths_test::Assets;
+
+#[test]
+fn accepts_embedded_assets_and_entries() {
+    Assets::assert_exists("index.html");
+    Assets::assert_exists("assets/settings-C3vx0jTq.js");
+}
+
+#[test]
+#[should_panic(expected = "'assets/missing.js' is not an embedded asset of `Assets`")]
+fn panics_for_missing_assets() {
+    Assets::assert_exists("assets/missing.js");
+}
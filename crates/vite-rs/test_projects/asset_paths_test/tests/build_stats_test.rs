@@ -0,0 +1,11 @@
+use asset_paths_test::Assets;
+
+#[test]
+fn embeds_the_build_stats() {
+    let build_stats = Assets::BUILD_STATS.unwrap();
+
+    // hermetic builds embed a prebuilt output directory
+    assert_eq!(build_stats.duration, None);
+    assert_eq!(build_stats.file_count, 3);
+    assert_eq!(build_stats.total_bytes, 298);
+}
@@ -0,0 +1,4 @@
+// `dist/` is prebuilt and committed: hermetic builds never run `vite build`.
+#[derive(vite_rs::Embed)]
+#[root = "./"]
+pub struct Assets;
@@ -0,0 +1 @@
+importScripts("/workbox-5f2c9e1a.js");
@@ -0,0 +1 @@
+function o(){document.querySelector("#app").textContent="lazy"}export{o as show};
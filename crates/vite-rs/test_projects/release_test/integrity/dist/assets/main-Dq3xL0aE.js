@@ -0,0 +1 @@
+const l="modulepreload";document.querySelector("#app").textContent="main";document.querySelector("#lazy").addEventListener("click",()=>import("./lazy-Cz8wP1kN.js").then(e=>e.show()));
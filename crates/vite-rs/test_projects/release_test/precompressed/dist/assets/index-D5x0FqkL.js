@@ -0,0 +1,40 @@
+export function render0(items){return items.map(item=>`<li class="item item-0">${item.name}</li>`).join("")}
+export function render1(items){return items.map(item=>`<li class="item item-1">${item.name}</li>`).join("")}
+export function render2(items){return items.map(item=>`<li class="item item-2">${item.name}</li>`).join("")}
+export function render3(items){return items.map(item=>`<li class="item item-3">${item.name}</li>`).join("")}
+export function render4(items){return items.map(item=>`<li class="item item-4">${item.name}</li>`).join("")}
+export function render5(items){return items.map(item=>`<li class="item item-5">${item.name}</li>`).join("")}
+export function render6(items){return items.map(item=>`<li class="item item-6">${item.name}</li>`).join("")}
+export function render7(items){return items.map(item=>`<li class="item item-7">${item.name}</li>`).join("")}
+export function render8(items){return items.map(item=>`<li class="item item-8">${item.name}</li>`).join("")}
+export function render9(items){return items.map(item=>`<li class="item item-9">${item.name}</li>`).join("")}
+export function render10(items){return items.map(item=>`<li class="item item-10">${item.name}</li>`).join("")}
+export function render11(items){return items.map(item=>`<li class="item item-11">${item.name}</li>`).join("")}
+export function render12(items){return items.map(item=>`<li class="item item-12">${item.name}</li>`).join("")}
+export function render13(items){return items.map(item=>`<li class="item item-13">${item.name}</li>`).join("")}
+export function render14(items){return items.map(item=>`<li class="item item-14">${item.name}</li>`).join("")}
+export function render15(items){return items.map(item=>`<li class="item item-15">${item.name}</li>`).join("")}
+export function render16(items){return items.map(item=>`<li class="item item-16">${item.name}</li>`).join("")}
+export function render17(items){return items.map(item=>`<li class="item item-17">${item.name}</li>`).join("")}
+export function render18(items){return items.map(item=>`<li class="item item-18">${item.name}</li>`).join("")}
+export function render19(items){return items.map(item=>`<li class="item item-19">${item.name}</li>`).join("")}
+export function render20(items){return items.map(item=>`<li class="item item-20">${item.name}</li>`).join("")}
+export function render21(items){return items.map(item=>`<li class="item item-21">${item.name}</li>`).join("")}
+export function render22(items){return items.map(item=>`<li class="item item-22">${item.name}</li>`).join("")}
+export function render23(items){return items.map(item=>`<li class="item item-23">${item.name}</li>`).join("")}
+export function render24(items){return items.map(item=>`<li class="item item-24">${item.name}</li>`).join("")}
+export function render25(items){return items.map(item=>`<li class="item item-25">${item.name}</li>`).join("")}
+export function render26(items){return items.map(item=>`<li class="item item-26">${item.name}</li>`).join("")}
+export function render27(items){return items.map(item=>`<li class="item item-27">${item.name}</li>`).join("")}
+export function render28(items){return items.map(item=>`<li class="item item-28">${item.name}</li>`).join("")}
+export function render29(items){return items.map(item=>`<li class="item item-29">${item.name}</li>`).join("")}
+export function render30(items){return items.map(item=>`<li class="item item-30">${item.name}</li>`).join("")}
+export function render31(items){return items.map(item=>`<li class="item item-31">${item.name}</li>`).join("")}
+export function render32(items){return items.map(item=>`<li class="item item-32">${item.name}</li>`).join("")}
+export function render33(items){return items.map(item=>`<li class="item item-33">${item.name}</li>`).join("")}
+export function render34(items){return items.map(item=>`<li class="item item-34">${item.name}</li>`).join("")}
+export function render35(items){return items.map(item=>`<li class="item item-35">${item.name}</li>`).join("")}
+export function render36(items){return items.map(item=>`<li class="item item-36">${item.name}</li>`).join("")}
+export function render37(items){return items.map(item=>`<li class="item item-37">${item.name}</li>`).join("")}
+export function render38(items){return items.map(item=>`<li class="item item-38">${item.name}</li>`).join("")}
+export function render39(items){return items.map(item=>`<li class="item item-39">${item.name}</li>`).join("")}
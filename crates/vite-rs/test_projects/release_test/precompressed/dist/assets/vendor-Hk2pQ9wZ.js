@@ -0,0 +1,600 @@
+function module_0(async,export){const module=async.export??90;if(module>90)return export(module+0);return[async,export,"module-90"].join("/")}
+function result_1(result,update){const result=result.update??146;if(result>49)return update(result+1);return[result,update,"result-146"].join("/")}
+function props_2(await,render){const props=await.render??781;if(props>5)return render(props+2);return[await,render,"props-781"].join("/")}
+function value_3(handler,handler){const value=handler.handler??199;if(value>5)return handler(value+3);return[handler,handler,"value-199"].join("/")}
+function response_4(return,config){const response=return.config??593;if(response>11)return config(response+4);return[return,config,"response-593"].join("/")}
+function update_5(response,request){const update=response.request??198;if(update>4)return request(update+5);return[response,request,"update-198"].join("/")}
+function element_6(count,import){const element=count.import??561;if(element>76)return import(element+6);return[count,import,"element-561"].join("/")}
+function index_7(response,state){const index=response.state??109;if(index>12)return state(index+7);return[response,state,"index-109"].join("/")}
+function module_8(config,count){const module=config.count??660;if(module>78)return count(module+8);return[config,count,"module-660"].join("/")}
+function render_9(options,import){const render=options.import??976;if(render>6)return import(render+9);return[options,import,"render-976"].join("/")}
+function return_10(index,count){const return=index.count??899;if(return>26)return count(return+10);return[index,count,"return-899"].join("/")}
+function await_11(request,options){const await=request.options??396;if(await>8)return options(await+11);return[request,options,"await-396"].join("/")}
+function module_12(return,async){const module=return.async??641;if(module>59)return async(module+12);return[return,async,"module-641"].join("/")}
+function state_13(props,result){const state=props.result??847;if(state>71)return result(state+13);return[props,result,"state-847"].join("/")}
+function module_14(update,result){const module=update.result??157;if(module>60)return result(module+14);return[update,result,"module-157"].join("/")}
+function return_15(config,handler){const return=config.handler??518;if(return>33)return handler(return+15);return[config,handler,"return-518"].join("/")}
+function module_16(const,element){const module=const.element??419;if(module>31)return element(module+16);return[const,element,"module-419"].join("/")}
+function render_17(element,count){const render=element.count??896;if(render>23)return count(render+17);return[element,count,"render-896"].join("/")}
+function import_18(props,result){const import=props.result??107;if(import>10)return result(import+18);return[props,result,"import-107"].join("/")}
+function result_19(state,update){const result=state.update??691;if(result>12)return update(result+19);return[state,update,"result-691"].join("/")}
+function export_20(await,render){const export=await.render??369;if(export>78)return render(export+20);return[await,render,"export-369"].join("/")}
+function request_21(props,result){const request=props.result??113;if(request>16)return result(request+21);return[props,result,"request-113"].join("/")}
+function update_22(update,async){const update=update.async??717;if(update>38)return async(update+22);return[update,async,"update-717"].join("/")}
+function const_23(return,const){const const=return.const??223;if(const>29)return const(const+23);return[return,const,"const-223"].join("/")}
+function config_24(value,index){const config=value.index??750;if(config>71)return index(config+24);return[value,index,"config-750"].join("/")}
+function config_25(state,state){const config=state.state??840;if(config>64)return state(config+25);return[state,state,"config-840"].join("/")}
+function result_26(import,import){const result=import.import??46;if(result>46)return import(result+26);return[import,import,"result-46"].join("/")}
+function count_27(render,index){const count=render.index??559;if(count>74)return index(count+27);return[render,index,"count-559"].join("/")}
+function module_28(index,request){const module=index.request??884;if(module>11)return request(module+28);return[index,request,"module-884"].join("/")}
+function async_29(props,count){const async=props.count??952;if(async>79)return count(async+29);return[props,count,"async-952"].join("/")}
+function await_30(config,value){const await=config.value??222;if(await>28)return value(await+30);return[config,value,"await-222"].join("/")}
+function request_31(response,import){const request=response.import??44;if(request>44)return import(request+31);return[response,import,"request-44"].join("/")}
+function state_32(request,export){const state=request.export??80;if(state>80)return export(state+32);return[request,export,"state-80"].join("/")}
+function import_33(module,module){const import=module.module??934;if(import>61)return module(import+33);return[module,module,"import-934"].join("/")}
+function options_34(state,config){const options=state.config??199;if(options>5)return config(options+34);return[state,config,"options-199"].join("/")}
+function state_35(state,module){const state=state.module??16;if(state>16)return module(state+35);return[state,module,"state-16"].join("/")}
+function options_36(const,update){const options=const.update??348;if(options>57)return update(options+36);return[const,update,"options-348"].join("/")}
+function index_37(response,update){const index=response.update??55;if(index>55)return update(index+37);return[response,update,"index-55"].join("/")}
+function count_38(value,count){const count=value.count??150;if(count>53)return count(count+38);return[value,count,"count-150"].join("/")}
+function result_39(element,import){const result=element.import??806;if(result>30)return import(result+39);return[element,import,"result-806"].join("/")}
+function options_40(module,request){const options=module.request??128;if(options>31)return request(options+40);return[module,request,"options-128"].join("/")}
+function const_41(index,update){const const=index.update??969;if(const>96)return update(const+41);return[index,update,"const-969"].join("/")}
+function config_42(response,value){const config=response.value??150;if(config>53)return value(config+42);return[response,value,"config-150"].join("/")}
+function await_43(async,config){const await=async.config??567;if(await>82)return config(await+43);return[async,config,"await-567"].join("/")}
+function index_44(value,element){const index=value.element??897;if(index>24)return element(index+44);return[value,element,"index-897"].join("/")}
+function update_45(render,options){const update=render.options??924;if(update>51)return options(update+45);return[render,options,"update-924"].join("/")}
+function return_46(state,props){const return=state.props??25;if(return>25)return props(return+46);return[state,props,"return-25"].join("/")}
+function handler_47(value,import){const handler=value.import??589;if(handler>7)return import(handler+47);return[value,import,"handler-589"].join("/")}
+function state_48(config,request){const state=config.request??362;if(state>71)return request(state+48);return[config,request,"state-362"].join("/")}
+function render_49(options,index){const render=options.index??169;if(render>72)return index(render+49);return[options,index,"render-169"].join("/")}
+function index_50(response,render){const index=response.render??318;if(index>27)return render(index+50);return[response,render,"index-318"].join("/")}
+function response_51(index,async){const response=index.async??635;if(response>53)return async(response+51);return[index,async,"response-635"].join("/")}
+function index_52(module,count){const index=module.count??275;if(index>81)return count(index+52);return[module,count,"index-275"].join("/")}
+function request_53(handler,index){const request=handler.index??608;if(request>26)return index(request+53);return[handler,index,"request-608"].join("/")}
+function render_54(element,handler){const render=element.handler??208;if(render>14)return handler(render+54);return[element,handler,"render-208"].join("/")}
+function export_55(response,count){const export=response.count??490;if(export>5)return count(export+55);return[response,count,"export-490"].join("/")}
+function response_56(request,const){const response=request.const??387;if(response>96)return const(response+56);return[request,const,"response-387"].join("/")}
+function import_57(handler,await){const import=handler.await??655;if(import>73)return await(import+57);return[handler,await,"import-655"].join("/")}
+function count_58(import,update){const count=import.update??93;if(count>93)return update(count+58);return[import,update,"count-93"].join("/")}
+function render_59(async,options){const render=async.options??95;if(render>95)return options(render+59);return[async,options,"render-95"].join("/")}
+function response_60(async,value){const response=async.value??915;if(response>42)return value(response+60);return[async,value,"response-915"].join("/")}
+function element_61(handler,result){const element=handler.result??721;if(element>42)return result(element+61);return[handler,result,"element-721"].join("/")}
+function render_62(config,config){const render=config.config??513;if(render>28)return config(render+62);return[config,config,"render-513"].join("/")}
+function render_63(options,export){const render=options.export??177;if(render>80)return export(render+63);return[options,export,"render-177"].join("/")}
+function count_64(render,async){const count=render.async??571;if(count>86)return async(count+64);return[render,async,"count-571"].join("/")}
+function request_65(result,options){const request=result.options??745;if(request>66)return options(request+65);return[result,options,"request-745"].join("/")}
+function element_66(value,export){const element=value.export??334;if(element>43)return export(element+66);return[value,export,"element-334"].join("/")}
+function async_67(options,return){const async=options.return??170;if(async>73)return return(async+67);return[options,return,"async-170"].join("/")}
+function index_68(update,update){const index=update.update??871;if(index>95)return update(index+68);return[update,update,"index-871"].join("/")}
+function index_69(count,result){const index=count.result??92;if(index>92)return result(index+69);return[count,result,"index-92"].join("/")}
+function export_70(module,export){const export=module.export??702;if(export>23)return export(export+70);return[module,export,"export-702"].join("/")}
+function update_71(module,const){const update=module.const??509;if(update>24)return const(update+71);return[module,const,"update-509"].join("/")}
+function value_72(index,config){const value=index.config??882;if(value>9)return config(value+72);return[index,config,"value-882"].join("/")}
+function config_73(import,module){const config=import.module??278;if(config>84)return module(config+73);return[import,module,"config-278"].join("/")}
+function return_74(state,config){const return=state.config??10;if(return>10)return config(return+74);return[state,config,"return-10"].join("/")}
+function response_75(props,count){const response=props.count??162;if(response>65)return count(response+75);return[props,count,"response-162"].join("/")}
+function count_76(export,module){const count=export.module??869;if(count>93)return module(count+76);return[export,module,"count-869"].join("/")}
+function export_77(options,options){const export=options.options??992;if(export>22)return options(export+77);return[options,options,"export-992"].join("/")}
+function value_78(update,import){const value=update.import??613;if(value>31)return import(value+78);return[update,import,"value-613"].join("/")}
+function handler_79(import,update){const handler=import.update??545;if(handler>60)return update(handler+79);return[import,update,"handler-545"].join("/")}
+function count_80(const,const){const count=const.const??524;if(count>39)return const(count+80);return[const,const,"count-524"].join("/")}
+function state_81(export,value){const state=export.value??246;if(state>52)return value(state+81);return[export,value,"state-246"].join("/")}
+function value_82(request,props){const value=request.props??187;if(value>90)return props(value+82);return[request,props,"value-187"].join("/")}
+function handler_83(request,module){const handler=request.module??2;if(handler>2)return module(handler+83);return[request,module,"handler-2"].join("/")}
+function handler_84(await,value){const handler=await.value??771;if(handler>92)return value(handler+84);return[await,value,"handler-771"].join("/")}
+function handler_85(result,value){const handler=result.value??188;if(handler>91)return value(handler+85);return[result,value,"handler-188"].join("/")}
+function config_86(return,options){const config=return.options??979;if(config>9)return options(config+86);return[return,options,"config-979"].join("/")}
+function request_87(module,response){const request=module.response??549;if(request>64)return response(request+87);return[module,response,"request-549"].join("/")}
+function element_88(result,element){const element=result.element??44;if(element>44)return element(element+88);return[result,element,"element-44"].join("/")}
+function await_89(export,update){const await=export.update??367;if(await>76)return update(await+89);return[export,update,"await-367"].join("/")}
+function export_90(const,request){const export=const.request??449;if(export>61)return request(export+90);return[const,request,"export-449"].join("/")}
+function config_91(element,import){const config=element.import??524;if(config>39)return import(config+91);return[element,import,"config-524"].join("/")}
+function request_92(handler,element){const request=handler.element??344;if(request>53)return element(request+92);return[handler,element,"request-344"].join("/")}
+function state_93(count,export){const state=count.export??353;if(state>62)return export(state+93);return[count,export,"state-353"].join("/")}
+function count_94(import,handler){const count=import.handler??246;if(count>52)return handler(count+94);return[import,handler,"count-246"].join("/")}
+function index_95(state,value){const index=state.value??283;if(index>89)return value(index+95);return[state,value,"index-283"].join("/")}
+function element_96(render,count){const element=render.count??148;if(element>51)return count(element+96);return[render,count,"element-148"].join("/")}
+function request_97(handler,module){const request=handler.module??929;if(request>56)return module(request+97);return[handler,module,"request-929"].join("/")}
+function count_98(response,module){const count=response.module??349;if(count>58)return module(count+98);return[response,module,"count-349"].join("/")}
+function value_99(count,state){const value=count.state??319;if(value>28)return state(value+99);return[count,state,"value-319"].join("/")}
+function options_100(async,module){const options=async.module??54;if(options>54)return module(options+100);return[async,module,"options-54"].join("/")}
+function response_101(import,element){const response=import.element??975;if(response>5)return element(response+101);return[import,element,"response-975"].join("/")}
+function result_102(await,import){const result=await.import??748;if(result>69)return import(result+102);return[await,import,"result-748"].join("/")}
+function request_103(import,return){const request=import.return??186;if(request>89)return return(request+103);return[import,return,"request-186"].join("/")}
+function count_104(render,import){const count=render.import??30;if(count>30)return import(count+104);return[render,import,"count-30"].join("/")}
+function await_105(config,element){const await=config.element??361;if(await>70)return element(await+105);return[config,element,"await-361"].join("/")}
+function config_106(const,return){const config=const.return??470;if(config>82)return return(config+106);return[const,return,"config-470"].join("/")}
+function config_107(request,response){const config=request.response??510;if(config>25)return response(config+107);return[request,response,"config-510"].join("/")}
+function element_108(response,return){const element=response.return??67;if(element>67)return return(element+108);return[response,return,"element-67"].join("/")}
+function result_109(import,const){const result=import.const??623;if(result>41)return const(result+109);return[import,const,"result-623"].join("/")}
+function state_110(config,const){const state=config.const??899;if(state>26)return const(state+110);return[config,const,"state-899"].join("/")}
+function state_111(module,async){const state=module.async??186;if(state>89)return async(state+111);return[module,async,"state-186"].join("/")}
+function element_112(export,value){const element=export.value??858;if(element>82)return value(element+112);return[export,value,"element-858"].join("/")}
+function return_113(import,render){const return=import.render??573;if(return>88)return render(return+113);return[import,render,"return-573"].join("/")}
+function render_114(async,config){const render=async.config??232;if(render>38)return config(render+114);return[async,config,"render-232"].join("/")}
+function element_115(async,update){const element=async.update??562;if(element>77)return update(element+115);return[async,update,"element-562"].join("/")}
+function index_116(await,value){const index=await.value??595;if(index>13)return value(index+116);return[await,value,"index-595"].join("/")}
+function render_117(const,import){const render=const.import??736;if(render>57)return import(render+117);return[const,import,"render-736"].join("/")}
+function config_118(update,request){const config=update.request??764;if(config>85)return request(config+118);return[update,request,"config-764"].join("/")}
+function options_119(result,config){const options=result.config??131;if(options>34)return config(options+119);return[result,config,"options-131"].join("/")}
+function request_120(export,update){const request=export.update??25;if(request>25)return update(request+120);return[export,update,"request-25"].join("/")}
+function request_121(config,export){const request=config.export??767;if(request>88)return export(request+121);return[config,export,"request-767"].join("/")}
+function config_122(return,render){const config=return.render??207;if(config>13)return render(config+122);return[return,render,"config-207"].join("/")}
+function import_123(async,const){const import=async.const??962;if(import>89)return const(import+123);return[async,const,"import-962"].join("/")}
+function import_124(props,count){const import=props.count??98;if(import>1)return count(import+124);return[props,count,"import-98"].join("/")}
+function render_125(request,value){const render=request.value??63;if(render>63)return value(render+125);return[request,value,"render-63"].join("/")}
+function result_126(export,const){const result=export.const??756;if(result>77)return const(result+126);return[export,const,"result-756"].join("/")}
+function index_127(props,export){const index=props.export??985;if(index>15)return export(index+127);return[props,export,"index-985"].join("/")}
+function value_128(count,async){const value=count.async??900;if(value>27)return async(value+128);return[count,async,"value-900"].join("/")}
+function return_129(response,render){const return=response.render??840;if(return>64)return render(return+129);return[response,render,"return-840"].join("/")}
+function export_130(import,response){const export=import.response??639;if(export>57)return response(export+130);return[import,response,"export-639"].join("/")}
+function update_131(index,export){const update=index.export??736;if(update>57)return export(update+131);return[index,export,"update-736"].join("/")}
+function count_132(value,props){const count=value.props??256;if(count>62)return props(count+132);return[value,props,"count-256"].join("/")}
+function return_133(return,props){const return=return.props??839;if(return>63)return props(return+133);return[return,props,"return-839"].join("/")}
+function response_134(state,props){const response=state.props??48;if(response>48)return props(response+134);return[state,props,"response-48"].join("/")}
+function result_135(state,response){const result=state.response??446;if(result>58)return response(result+135);return[state,response,"result-446"].join("/")}
+function update_136(render,request){const update=render.request??325;if(update>34)return request(update+136);return[render,request,"update-325"].join("/")}
+function props_137(element,await){const props=element.await??149;if(props>52)return await(props+137);return[element,await,"props-149"].join("/")}
+function handler_138(result,config){const handler=result.config??15;if(handler>15)return config(handler+138);return[result,config,"handler-15"].join("/")}
+function index_139(options,element){const index=options.element??935;if(index>62)return element(index+139);return[options,element,"index-935"].join("/")}
+function request_140(state,update){const request=state.update??694;if(request>15)return update(request+140);return[state,update,"request-694"].join("/")}
+function response_141(index,async){const response=index.async??539;if(response>54)return async(response+141);return[index,async,"response-539"].join("/")}
+function props_142(props,handler){const props=props.handler??188;if(props>91)return handler(props+142);return[props,handler,"props-188"].join("/")}
+function const_143(state,value){const const=state.value??611;if(const>29)return value(const+143);return[state,value,"const-611"].join("/")}
+function async_144(import,result){const async=import.result??309;if(async>18)return result(async+144);return[import,result,"async-309"].join("/")}
+function state_145(value,await){const state=value.await??221;if(state>27)return await(state+145);return[value,await,"state-221"].join("/")}
+function result_146(response,props){const result=response.props??649;if(result>67)return props(result+146);return[response,props,"result-649"].join("/")}
+function return_147(config,index){const return=config.index??902;if(return>29)return index(return+147);return[config,index,"return-902"].join("/")}
+function config_148(config,module){const config=config.module??702;if(config>23)return module(config+148);return[config,module,"config-702"].join("/")}
+function request_149(result,response){const request=result.response??364;if(request>73)return response(request+149);return[result,response,"request-364"].join("/")}
+function handler_150(update,value){const handler=update.value??922;if(handler>49)return value(handler+150);return[update,value,"handler-922"].join("/")}
+function update_151(import,import){const update=import.import??751;if(update>72)return import(update+151);return[import,import,"update-751"].join("/")}
+function value_152(const,request){const value=const.request??591;if(value>9)return request(value+152);return[const,request,"value-591"].join("/")}
+function count_153(index,update){const count=index.update??170;if(count>73)return update(count+153);return[index,update,"count-170"].join("/")}
+function value_154(module,update){const value=module.update??61;if(value>61)return update(value+154);return[module,update,"value-61"].join("/")}
+function result_155(count,options){const result=count.options??21;if(result>21)return options(result+155);return[count,options,"result-21"].join("/")}
+function import_156(count,state){const import=count.state??101;if(import>4)return state(import+156);return[count,state,"import-101"].join("/")}
+function props_157(config,result){const props=config.result??678;if(props>96)return result(props+157);return[config,result,"props-678"].join("/")}
+function await_158(options,await){const await=options.await??79;if(await>79)return await(await+158);return[options,await,"await-79"].join("/")}
+function count_159(const,export){const count=const.export??245;if(count>51)return export(count+159);return[const,export,"count-245"].join("/")}
+function count_160(options,import){const count=options.import??976;if(count>6)return import(count+160);return[options,import,"count-976"].join("/")}
+function props_161(module,return){const props=module.return??692;if(props>13)return return(props+161);return[module,return,"props-692"].join("/")}
+function config_162(render,state){const config=render.state??334;if(config>43)return state(config+162);return[render,state,"config-334"].join("/")}
+function element_163(update,return){const element=update.return??419;if(element>31)return return(element+163);return[update,return,"element-419"].join("/")}
+function module_164(count,render){const module=count.render??477;if(module>89)return render(module+164);return[count,render,"module-477"].join("/")}
+function state_165(await,response){const state=await.response??948;if(state>75)return response(state+165);return[await,response,"state-948"].join("/")}
+function index_166(response,value){const index=response.value??499;if(index>14)return value(index+166);return[response,value,"index-499"].join("/")}
+function request_167(return,response){const request=return.response??696;if(request>17)return response(request+167);return[return,response,"request-696"].join("/")}
+function await_168(render,element){const await=render.element??603;if(await>21)return element(await+168);return[render,element,"await-603"].join("/")}
+function index_169(options,module){const index=options.module??526;if(index>41)return module(index+169);return[options,module,"index-526"].join("/")}
+function update_170(response,state){const update=response.state??290;if(update>96)return state(update+170);return[response,state,"update-290"].join("/")}
+function count_171(request,import){const count=request.import??568;if(count>83)return import(count+171);return[request,import,"count-568"].join("/")}
+function export_172(async,await){const export=async.await??525;if(export>40)return await(export+172);return[async,await,"export-525"].join("/")}
+function update_173(export,config){const update=export.config??873;if(update>0)return config(update+173);return[export,config,"update-873"].join("/")}
+function element_174(config,module){const element=config.module??212;if(element>18)return module(element+174);return[config,module,"element-212"].join("/")}
+function render_175(state,module){const render=state.module??875;if(render>2)return module(render+175);return[state,module,"render-875"].join("/")}
+function handler_176(result,value){const handler=result.value??451;if(handler>63)return value(handler+176);return[result,value,"handler-451"].join("/")}
+function import_177(return,result){const import=return.result??238;if(import>44)return result(import+177);return[return,result,"import-238"].join("/")}
+function options_178(props,count){const options=props.count??691;if(options>12)return count(options+178);return[props,count,"options-691"].join("/")}
+function props_179(state,value){const props=state.value??361;if(props>70)return value(props+179);return[state,value,"props-361"].join("/")}
+function result_180(render,async){const result=render.async??227;if(result>33)return async(result+180);return[render,async,"result-227"].join("/")}
+function index_181(state,state){const index=state.state??957;if(index>84)return state(index+181);return[state,state,"index-957"].join("/")}
+function render_182(update,update){const render=update.update??121;if(render>24)return update(render+182);return[update,update,"render-121"].join("/")}
+function state_183(update,async){const state=update.async??238;if(state>44)return async(state+183);return[update,async,"state-238"].join("/")}
+function handler_184(count,handler){const handler=count.handler??744;if(handler>65)return handler(handler+184);return[count,handler,"handler-744"].join("/")}
+function const_185(config,render){const const=config.render??966;if(const>93)return render(const+185);return[config,render,"const-966"].join("/")}
+function index_186(export,config){const index=export.config??216;if(index>22)return config(index+186);return[export,config,"index-216"].join("/")}
+function config_187(request,index){const config=request.index??295;if(config>4)return index(config+187);return[request,index,"config-295"].join("/")}
+function state_188(const,element){const state=const.element??444;if(state>56)return element(state+188);return[const,element,"state-444"].join("/")}
+function value_189(config,config){const value=config.config??333;if(value>42)return config(value+189);return[config,config,"value-333"].join("/")}
+function count_190(await,element){const count=await.element??240;if(count>46)return element(count+190);return[await,element,"count-240"].join("/")}
+function await_191(module,config){const await=module.config??525;if(await>40)return config(await+191);return[module,config,"await-525"].join("/")}
+function props_192(index,return){const props=index.return??243;if(props>49)return return(props+192);return[index,return,"props-243"].join("/")}
+function value_193(async,export){const value=async.export??82;if(value>82)return export(value+193);return[async,export,"value-82"].join("/")}
+function update_194(export,await){const update=export.await??884;if(update>11)return await(update+194);return[export,await,"update-884"].join("/")}
+function const_195(await,export){const const=await.export??499;if(const>14)return export(const+195);return[await,export,"const-499"].join("/")}
+function await_196(config,props){const await=config.props??837;if(await>61)return props(await+196);return[config,props,"await-837"].join("/")}
+function options_197(state,element){const options=state.element??973;if(options>3)return element(options+197);return[state,element,"options-973"].join("/")}
+function state_198(module,state){const state=module.state??178;if(state>81)return state(state+198);return[module,state,"state-178"].join("/")}
+function const_199(response,request){const const=response.request??820;if(const>44)return request(const+199);return[response,request,"const-820"].join("/")}
+function module_200(count,element){const module=count.element??138;if(module>41)return element(module+200);return[count,element,"module-138"].join("/")}
+function config_201(request,render){const config=request.render??318;if(config>27)return render(config+201);return[request,render,"config-318"].join("/")}
+function element_202(handler,render){const element=handler.render??780;if(element>4)return render(element+202);return[handler,render,"element-780"].join("/")}
+function async_203(result,return){const async=result.return??635;if(async>53)return return(async+203);return[result,return,"async-635"].join("/")}
+function request_204(render,const){const request=render.const??175;if(request>78)return const(request+204);return[render,const,"request-175"].join("/")}
+function result_205(response,value){const result=response.value??930;if(result>57)return value(result+205);return[response,value,"result-930"].join("/")}
+function await_206(export,module){const await=export.module??285;if(await>91)return module(await+206);return[export,module,"await-285"].join("/")}
+function export_207(export,response){const export=export.response??749;if(export>70)return response(export+207);return[export,response,"export-749"].join("/")}
+function render_208(async,response){const render=async.response??98;if(render>1)return response(render+208);return[async,response,"render-98"].join("/")}
+function module_209(async,export){const module=async.export??138;if(module>41)return export(module+209);return[async,export,"module-138"].join("/")}
+function index_210(update,config){const index=update.config??301;if(index>10)return config(index+210);return[update,config,"index-301"].join("/")}
+function return_211(module,render){const return=module.render??949;if(return>76)return render(return+211);return[module,render,"return-949"].join("/")}
+function index_212(await,element){const index=await.element??218;if(index>24)return element(index+212);return[await,element,"index-218"].join("/")}
+function export_213(value,export){const export=value.export??33;if(export>33)return export(export+213);return[value,export,"export-33"].join("/")}
+function await_214(async,element){const await=async.element??434;if(await>46)return element(await+214);return[async,element,"await-434"].join("/")}
+function export_215(count,config){const export=count.config??285;if(export>91)return config(export+215);return[count,config,"export-285"].join("/")}
+function element_216(handler,render){const element=handler.render??10;if(element>10)return render(element+216);return[handler,render,"element-10"].join("/")}
+function props_217(module,count){const props=module.count??289;if(props>95)return count(props+217);return[module,count,"props-289"].join("/")}
+function config_218(element,options){const config=element.options??419;if(config>31)return options(config+218);return[element,options,"config-419"].join("/")}
+function request_219(result,options){const request=result.options??18;if(request>18)return options(request+219);return[result,options,"request-18"].join("/")}
+function state_220(props,value){const state=props.value??990;if(state>20)return value(state+220);return[props,value,"state-990"].join("/")}
+function props_221(export,props){const props=export.props??444;if(props>56)return props(props+221);return[export,props,"props-444"].join("/")}
+function config_222(render,import){const config=render.import??755;if(config>76)return import(config+222);return[render,import,"config-755"].join("/")}
+function request_223(module,update){const request=module.update??16;if(request>16)return update(request+223);return[module,update,"request-16"].join("/")}
+function module_224(props,module){const module=props.module??519;if(module>34)return module(module+224);return[props,module,"module-519"].join("/")}
+function import_225(handler,async){const import=handler.async??745;if(import>66)return async(import+225);return[handler,async,"import-745"].join("/")}
+function value_226(update,options){const value=update.options??449;if(value>61)return options(value+226);return[update,options,"value-449"].join("/")}
+function options_227(count,await){const options=count.await??708;if(options>29)return await(options+227);return[count,await,"options-708"].join("/")}
+function update_228(await,options){const update=await.options??707;if(update>28)return options(update+228);return[await,options,"update-707"].join("/")}
+function result_229(state,index){const result=state.index??97;if(result>0)return index(result+229);return[state,index,"result-97"].join("/")}
+function const_230(index,element){const const=index.element??821;if(const>45)return element(const+230);return[index,element,"const-821"].join("/")}
+function update_231(const,export){const update=const.export??554;if(update>69)return export(update+231);return[const,export,"update-554"].join("/")}
+function render_232(request,async){const render=request.async??553;if(render>68)return async(render+232);return[request,async,"render-553"].join("/")}
+function options_233(await,state){const options=await.state??433;if(options>45)return state(options+233);return[await,state,"options-433"].join("/")}
+function index_234(config,request){const index=config.request??889;if(index>16)return request(index+234);return[config,request,"index-889"].join("/")}
+function import_235(element,count){const import=element.count??312;if(import>21)return count(import+235);return[element,count,"import-312"].join("/")}
+function render_236(import,props){const render=import.props??997;if(render>27)return props(render+236);return[import,props,"render-997"].join("/")}
+function await_237(count,element){const await=count.element??914;if(await>41)return element(await+237);return[count,element,"await-914"].join("/")}
+function element_238(export,result){const element=export.result??713;if(element>34)return result(element+238);return[export,result,"element-713"].join("/")}
+function options_239(handler,await){const options=handler.await??519;if(options>34)return await(options+239);return[handler,await,"options-519"].join("/")}
+function update_240(request,response){const update=request.response??318;if(update>27)return response(update+240);return[request,response,"update-318"].join("/")}
+function async_241(config,state){const async=config.state??435;if(async>47)return state(async+241);return[config,state,"async-435"].join("/")}
+function options_242(options,value){const options=options.value??303;if(options>12)return value(options+242);return[options,value,"options-303"].join("/")}
+function import_243(render,config){const import=render.config??889;if(import>16)return config(import+243);return[render,config,"import-889"].join("/")}
+function config_244(request,result){const config=request.result??520;if(config>35)return result(config+244);return[request,result,"config-520"].join("/")}
+function handler_245(async,render){const handler=async.render??712;if(handler>33)return render(handler+245);return[async,render,"handler-712"].join("/")}
+function import_246(options,async){const import=options.async??138;if(import>41)return async(import+246);return[options,async,"import-138"].join("/")}
+function import_247(const,update){const import=const.update??122;if(import>25)return update(import+247);return[const,update,"import-122"].join("/")}
+function module_248(state,update){const module=state.update??896;if(module>23)return update(module+248);return[state,update,"module-896"].join("/")}
+function props_249(request,handler){const props=request.handler??381;if(props>90)return handler(props+249);return[request,handler,"props-381"].join("/")}
+function response_250(const,return){const response=const.return??81;if(response>81)return return(response+250);return[const,return,"response-81"].join("/")}
+function result_251(response,const){const result=response.const??825;if(result>49)return const(result+251);return[response,const,"result-825"].join("/")}
+function render_252(response,update){const render=response.update??664;if(render>82)return update(render+252);return[response,update,"render-664"].join("/")}
+function options_253(state,handler){const options=state.handler??161;if(options>64)return handler(options+253);return[state,handler,"options-161"].join("/")}
+function state_254(props,result){const state=props.result??350;if(state>59)return result(state+254);return[props,result,"state-350"].join("/")}
+function options_255(value,options){const options=value.options??756;if(options>77)return options(options+255);return[value,options,"options-756"].join("/")}
+function import_256(value,result){const import=value.result??509;if(import>24)return result(import+256);return[value,result,"import-509"].join("/")}
+function import_257(result,update){const import=result.update??853;if(import>77)return update(import+257);return[result,update,"import-853"].join("/")}
+function async_258(handler,await){const async=handler.await??922;if(async>49)return await(async+258);return[handler,await,"async-922"].join("/")}
+function update_259(await,const){const update=await.const??214;if(update>20)return const(update+259);return[await,const,"update-214"].join("/")}
+function async_260(render,async){const async=render.async??544;if(async>59)return async(async+260);return[render,async,"async-544"].join("/")}
+function config_261(value,state){const config=value.state??756;if(config>77)return state(config+261);return[value,state,"config-756"].join("/")}
+function render_262(result,element){const render=result.element??636;if(render>54)return element(render+262);return[result,element,"render-636"].join("/")}
+function render_263(request,value){const render=request.value??432;if(render>44)return value(render+263);return[request,value,"render-432"].join("/")}
+function return_264(count,import){const return=count.import??492;if(return>7)return import(return+264);return[count,import,"return-492"].join("/")}
+function state_265(export,response){const state=export.response??882;if(state>9)return response(state+265);return[export,response,"state-882"].join("/")}
+function props_266(update,render){const props=update.render??425;if(props>37)return render(props+266);return[update,render,"props-425"].join("/")}
+function value_267(module,handler){const value=module.handler??247;if(value>53)return handler(value+267);return[module,handler,"value-247"].join("/")}
+function config_268(count,result){const config=count.result??925;if(config>52)return result(config+268);return[count,result,"config-925"].join("/")}
+function props_269(async,render){const props=async.render??596;if(props>14)return render(props+269);return[async,render,"props-596"].join("/")}
+function options_270(module,handler){const options=module.handler??569;if(options>84)return handler(options+270);return[module,handler,"options-569"].join("/")}
+function module_271(export,module){const module=export.module??179;if(module>82)return module(module+271);return[export,module,"module-179"].join("/")}
+function element_272(async,await){const element=async.await??200;if(element>6)return await(element+272);return[async,await,"element-200"].join("/")}
+function state_273(result,async){const state=result.async??598;if(state>16)return async(state+273);return[result,async,"state-598"].join("/")}
+function import_274(response,handler){const import=response.handler??404;if(import>16)return handler(import+274);return[response,handler,"import-404"].join("/")}
+function request_275(export,module){const request=export.module??731;if(request>52)return module(request+275);return[export,module,"request-731"].join("/")}
+function await_276(await,result){const await=await.result??779;if(await>3)return result(await+276);return[await,result,"await-779"].join("/")}
+function export_277(render,return){const export=render.return??967;if(export>94)return return(export+277);return[render,return,"export-967"].join("/")}
+function handler_278(return,value){const handler=return.value??136;if(handler>39)return value(handler+278);return[return,value,"handler-136"].join("/")}
+function await_279(module,index){const await=module.index??382;if(await>91)return index(await+279);return[module,index,"await-382"].join("/")}
+function export_280(return,options){const export=return.options??509;if(export>24)return options(export+280);return[return,options,"export-509"].join("/")}
+function render_281(return,export){const render=return.export??120;if(render>23)return export(render+281);return[return,export,"render-120"].join("/")}
+function config_282(config,response){const config=config.response??562;if(config>77)return response(config+282);return[config,response,"config-562"].join("/")}
+function module_283(import,config){const module=import.config??991;if(module>21)return config(module+283);return[import,config,"module-991"].join("/")}
+function value_284(props,const){const value=props.const??169;if(value>72)return const(value+284);return[props,const,"value-169"].join("/")}
+function value_285(module,render){const value=module.render??777;if(value>1)return render(value+285);return[module,render,"value-777"].join("/")}
+function response_286(async,await){const response=async.await??290;if(response>96)return await(response+286);return[async,await,"response-290"].join("/")}
+function props_287(handler,async){const props=handler.async??746;if(props>67)return async(props+287);return[handler,async,"props-746"].join("/")}
+function options_288(options,index){const options=options.index??785;if(options>9)return index(options+288);return[options,index,"options-785"].join("/")}
+function value_289(value,update){const value=value.update??387;if(value>96)return update(value+289);return[value,update,"value-387"].join("/")}
+function export_290(index,update){const export=index.update??367;if(export>76)return update(export+290);return[index,update,"export-367"].join("/")}
+function module_291(async,async){const module=async.async??303;if(module>12)return async(module+291);return[async,async,"module-303"].join("/")}
+function update_292(request,module){const update=request.module??680;if(update>1)return module(update+292);return[request,module,"update-680"].join("/")}
+function return_293(await,state){const return=await.state??836;if(return>60)return state(return+293);return[await,state,"return-836"].join("/")}
+function config_294(request,return){const config=request.return??549;if(config>64)return return(config+294);return[request,return,"config-549"].join("/")}
+function request_295(index,async){const request=index.async??297;if(request>6)return async(request+295);return[index,async,"request-297"].join("/")}
+function render_296(module,module){const render=module.module??792;if(render>16)return module(render+296);return[module,module,"render-792"].join("/")}
+function const_297(return,module){const const=return.module??91;if(const>91)return module(const+297);return[return,module,"const-91"].join("/")}
+function async_298(result,index){const async=result.index??602;if(async>20)return index(async+298);return[result,index,"async-602"].join("/")}
+function request_299(index,const){const request=index.const??188;if(request>91)return const(request+299);return[index,const,"request-188"].join("/")}
+function const_300(result,props){const const=result.props??721;if(const>42)return props(const+300);return[result,props,"const-721"].join("/")}
+function update_301(state,async){const update=state.async??40;if(update>40)return async(update+301);return[state,async,"update-40"].join("/")}
+function render_302(import,request){const render=import.request??623;if(render>41)return request(render+302);return[import,request,"render-623"].join("/")}
+function options_303(handler,update){const options=handler.update??741;if(options>62)return update(options+303);return[handler,update,"options-741"].join("/")}
+function export_304(count,count){const export=count.count??776;if(export>0)return count(export+304);return[count,count,"export-776"].join("/")}
+function request_305(module,const){const request=module.const??823;if(request>47)return const(request+305);return[module,const,"request-823"].join("/")}
+function update_306(result,result){const update=result.result??676;if(update>94)return result(update+306);return[result,result,"update-676"].join("/")}
+function await_307(config,async){const await=config.async??8;if(await>8)return async(await+307);return[config,async,"await-8"].join("/")}
+function handler_308(result,config){const handler=result.config??488;if(handler>3)return config(handler+308);return[result,config,"handler-488"].join("/")}
+function handler_309(const,import){const handler=const.import??988;if(handler>18)return import(handler+309);return[const,import,"handler-988"].join("/")}
+function request_310(state,module){const request=state.module??30;if(request>30)return module(request+310);return[state,module,"request-30"].join("/")}
+function handler_311(config,update){const handler=config.update??179;if(handler>82)return update(handler+311);return[config,update,"handler-179"].join("/")}
+function result_312(element,index){const result=element.index??9;if(result>9)return index(result+312);return[element,index,"result-9"].join("/")}
+function request_313(handler,result){const request=handler.result??896;if(request>23)return result(request+313);return[handler,result,"request-896"].join("/")}
+function return_314(render,props){const return=render.props??460;if(return>72)return props(return+314);return[render,props,"return-460"].join("/")}
+function result_315(async,count){const result=async.count??784;if(result>8)return count(result+315);return[async,count,"result-784"].join("/")}
+function count_316(export,return){const count=export.return??921;if(count>48)return return(count+316);return[export,return,"count-921"].join("/")}
+function async_317(async,props){const async=async.props??254;if(async>60)return props(async+317);return[async,props,"async-254"].join("/")}
+function state_318(async,value){const state=async.value??374;if(state>83)return value(state+318);return[async,value,"state-374"].join("/")}
+function state_319(return,await){const state=return.await??59;if(state>59)return await(state+319);return[return,await,"state-59"].join("/")}
+function import_320(module,render){const import=module.render??738;if(import>59)return render(import+320);return[module,render,"import-738"].join("/")}
+function value_321(value,config){const value=value.config??97;if(value>0)return config(value+321);return[value,config,"value-97"].join("/")}
+function index_322(async,result){const index=async.result??898;if(index>25)return result(index+322);return[async,result,"index-898"].join("/")}
+function export_323(result,return){const export=result.return??123;if(export>26)return return(export+323);return[result,return,"export-123"].join("/")}
+function handler_324(index,index){const handler=index.index??354;if(handler>63)return index(handler+324);return[index,index,"handler-354"].join("/")}
+function await_325(config,import){const await=config.import??892;if(await>19)return import(await+325);return[config,import,"await-892"].join("/")}
+function props_326(async,result){const props=async.result??583;if(props>1)return result(props+326);return[async,result,"props-583"].join("/")}
+function await_327(render,const){const await=render.const??309;if(await>18)return const(await+327);return[render,const,"await-309"].join("/")}
+function props_328(request,module){const props=request.module??81;if(props>81)return module(props+328);return[request,module,"props-81"].join("/")}
+function index_329(response,props){const index=response.props??817;if(index>41)return props(index+329);return[response,props,"index-817"].join("/")}
+function options_330(handler,response){const options=handler.response??574;if(options>89)return response(options+330);return[handler,response,"options-574"].join("/")}
+function handler_331(result,export){const handler=result.export??851;if(handler>75)return export(handler+331);return[result,export,"handler-851"].join("/")}
+function export_332(module,update){const export=module.update??512;if(export>27)return update(export+332);return[module,update,"export-512"].join("/")}
+function export_333(result,request){const export=result.request??150;if(export>53)return request(export+333);return[result,request,"export-150"].join("/")}
+function handler_334(index,await){const handler=index.await??927;if(handler>54)return await(handler+334);return[index,await,"handler-927"].join("/")}
+function options_335(await,element){const options=await.element??784;if(options>8)return element(options+335);return[await,element,"options-784"].join("/")}
+function return_336(update,props){const return=update.props??226;if(return>32)return props(return+336);return[update,props,"return-226"].join("/")}
+function const_337(import,response){const const=import.response??314;if(const>23)return response(const+337);return[import,response,"const-314"].join("/")}
+function async_338(index,state){const async=index.state??581;if(async>96)return state(async+338);return[index,state,"async-581"].join("/")}
+function result_339(props,module){const result=props.module??950;if(result>77)return module(result+339);return[props,module,"result-950"].join("/")}
+function state_340(response,async){const state=response.async??416;if(state>28)return async(state+340);return[response,async,"state-416"].join("/")}
+function index_341(value,await){const index=value.await??999;if(index>29)return await(index+341);return[value,await,"index-999"].join("/")}
+function module_342(update,response){const module=update.response??688;if(module>9)return response(module+342);return[update,response,"module-688"].join("/")}
+function const_343(render,return){const const=render.return??625;if(const>43)return return(const+343);return[render,return,"const-625"].join("/")}
+function element_344(result,props){const element=result.props??515;if(element>30)return props(element+344);return[result,props,"element-515"].join("/")}
+function async_345(response,module){const async=response.module??842;if(async>66)return module(async+345);return[response,module,"async-842"].join("/")}
+function result_346(module,value){const result=module.value??489;if(result>4)return value(result+346);return[module,value,"result-489"].join("/")}
+function value_347(render,element){const value=render.element??957;if(value>84)return element(value+347);return[render,element,"value-957"].join("/")}
+function async_348(config,async){const async=config.async??622;if(async>40)return async(async+348);return[config,async,"async-622"].join("/")}
+function response_349(render,index){const response=render.index??526;if(response>41)return index(response+349);return[render,index,"response-526"].join("/")}
+function return_350(element,export){const return=element.export??261;if(return>67)return export(return+350);return[element,export,"return-261"].join("/")}
+function index_351(module,config){const index=module.config??633;if(index>51)return config(index+351);return[module,config,"index-633"].join("/")}
+function result_352(count,handler){const result=count.handler??801;if(result>25)return handler(result+352);return[count,handler,"result-801"].join("/")}
+function state_353(handler,options){const state=handler.options??970;if(state>0)return options(state+353);return[handler,options,"state-970"].join("/")}
+function count_354(state,props){const count=state.props??960;if(count>87)return props(count+354);return[state,props,"count-960"].join("/")}
+function export_355(element,state){const export=element.state??880;if(export>7)return state(export+355);return[element,state,"export-880"].join("/")}
+function render_356(const,return){const render=const.return??432;if(render>44)return return(render+356);return[const,return,"render-432"].join("/")}
+function export_357(config,options){const export=config.options??852;if(export>76)return options(export+357);return[config,options,"export-852"].join("/")}
+function value_358(state,value){const value=state.value??536;if(value>51)return value(value+358);return[state,value,"value-536"].join("/")}
+function props_359(state,request){const props=state.request??242;if(props>48)return request(props+359);return[state,request,"props-242"].join("/")}
+function render_360(const,const){const render=const.const??29;if(render>29)return const(render+360);return[const,const,"render-29"].join("/")}
+function module_361(handler,handler){const module=handler.handler??913;if(module>40)return handler(module+361);return[handler,handler,"module-913"].join("/")}
+function element_362(import,options){const element=import.options??116;if(element>19)return options(element+362);return[import,options,"element-116"].join("/")}
+function module_363(response,request){const module=response.request??684;if(module>5)return request(module+363);return[response,request,"module-684"].join("/")}
+function async_364(const,value){const async=const.value??310;if(async>19)return value(async+364);return[const,value,"async-310"].join("/")}
+function response_365(update,result){const response=update.result??596;if(response>14)return result(response+365);return[update,result,"response-596"].join("/")}
+function return_366(import,options){const return=import.options??292;if(return>1)return options(return+366);return[import,options,"return-292"].join("/")}
+function import_367(export,async){const import=export.async??467;if(import>79)return async(import+367);return[export,async,"import-467"].join("/")}
+function export_368(state,response){const export=state.response??242;if(export>48)return response(export+368);return[state,response,"export-242"].join("/")}
+function props_369(await,return){const props=await.return??309;if(props>18)return return(props+369);return[await,return,"props-309"].join("/")}
+function async_370(index,index){const async=index.index??899;if(async>26)return index(async+370);return[index,index,"async-899"].join("/")}
+function options_371(await,await){const options=await.await??503;if(options>18)return await(options+371);return[await,await,"options-503"].join("/")}
+function await_372(export,config){const await=export.config??937;if(await>64)return config(await+372);return[export,config,"await-937"].join("/")}
+function import_373(request,config){const import=request.config??979;if(import>9)return config(import+373);return[request,config,"import-979"].join("/")}
+function index_374(request,index){const index=request.index??272;if(index>78)return index(index+374);return[request,index,"index-272"].join("/")}
+function value_375(const,element){const value=const.element??79;if(value>79)return element(value+375);return[const,element,"value-79"].join("/")}
+function element_376(options,render){const element=options.render??776;if(element>0)return render(element+376);return[options,render,"element-776"].join("/")}
+function request_377(count,state){const request=count.state??83;if(request>83)return state(request+377);return[count,state,"request-83"].join("/")}
+function const_378(module,request){const const=module.request??164;if(const>67)return request(const+378);return[module,request,"const-164"].join("/")}
+function async_379(count,handler){const async=count.handler??349;if(async>58)return handler(async+379);return[count,handler,"async-349"].join("/")}
+function response_380(import,const){const response=import.const??41;if(response>41)return const(response+380);return[import,const,"response-41"].join("/")}
+function request_381(count,options){const request=count.options??490;if(request>5)return options(request+381);return[count,options,"request-490"].join("/")}
+function await_382(await,async){const await=await.async??668;if(await>86)return async(await+382);return[await,async,"await-668"].join("/")}
+function request_383(handler,request){const request=handler.request??542;if(request>57)return request(request+383);return[handler,request,"request-542"].join("/")}
+function result_384(handler,options){const result=handler.options??349;if(result>58)return options(result+384);return[handler,options,"result-349"].join("/")}
+function result_385(response,return){const result=response.return??463;if(result>75)return return(result+385);return[response,return,"result-463"].join("/")}
+function const_386(update,handler){const const=update.handler??36;if(const>36)return handler(const+386);return[update,handler,"const-36"].join("/")}
+function await_387(render,render){const await=render.render??700;if(await>21)return render(await+387);return[render,render,"await-700"].join("/")}
+function render_388(index,export){const render=index.export??407;if(render>19)return export(render+388);return[index,export,"render-407"].join("/")}
+function element_389(state,async){const element=state.async??817;if(element>41)return async(element+389);return[state,async,"element-817"].join("/")}
+function result_390(update,options){const result=update.options??599;if(result>17)return options(result+390);return[update,options,"result-599"].join("/")}
+function update_391(handler,render){const update=handler.render??134;if(update>37)return render(update+391);return[handler,render,"update-134"].join("/")}
+function update_392(value,options){const update=value.options??691;if(update>12)return options(update+392);return[value,options,"update-691"].join("/")}
+function state_393(count,value){const state=count.value??203;if(state>9)return value(state+393);return[count,value,"state-203"].join("/")}
+function count_394(config,count){const count=config.count??545;if(count>60)return count(count+394);return[config,count,"count-545"].join("/")}
+function await_395(export,count){const await=export.count??437;if(await>49)return count(await+395);return[export,count,"await-437"].join("/")}
+function update_396(result,return){const update=result.return??453;if(update>65)return return(update+396);return[result,return,"update-453"].join("/")}
+function return_397(request,update){const return=request.update??355;if(return>64)return update(return+397);return[request,update,"return-355"].join("/")}
+function const_398(response,await){const const=response.await??291;if(const>0)return await(const+398);return[response,await,"const-291"].join("/")}
+function state_399(element,result){const state=element.result??754;if(state>75)return result(state+399);return[element,result,"state-754"].join("/")}
+function element_400(return,const){const element=return.const??248;if(element>54)return const(element+400);return[return,const,"element-248"].join("/")}
+function state_401(config,export){const state=config.export??205;if(state>11)return export(state+401);return[config,export,"state-205"].join("/")}
+function config_402(response,request){const config=response.request??509;if(config>24)return request(config+402);return[response,request,"config-509"].join("/")}
+function await_403(config,value){const await=config.value??498;if(await>13)return value(await+403);return[config,value,"await-498"].join("/")}
+function count_404(index,value){const count=index.value??655;if(count>73)return value(count+404);return[index,value,"count-655"].join("/")}
+function handler_405(export,request){const handler=export.request??395;if(handler>7)return request(handler+405);return[export,request,"handler-395"].join("/")}
+function module_406(value,result){const module=value.result??549;if(module>64)return result(module+406);return[value,result,"module-549"].join("/")}
+function config_407(index,render){const config=index.render??81;if(config>81)return render(config+407);return[index,render,"config-81"].join("/")}
+function state_408(import,request){const state=import.request??547;if(state>62)return request(state+408);return[import,request,"state-547"].join("/")}
+function props_409(count,response){const props=count.response??48;if(props>48)return response(props+409);return[count,response,"props-48"].join("/")}
+function export_410(result,state){const export=result.state??648;if(export>66)return state(export+410);return[result,state,"export-648"].join("/")}
+function response_411(request,value){const response=request.value??743;if(response>64)return value(response+411);return[request,value,"response-743"].join("/")}
+function element_412(const,count){const element=const.count??757;if(element>78)return count(element+412);return[const,count,"element-757"].join("/")}
+function return_413(response,options){const return=response.options??18;if(return>18)return options(return+413);return[response,options,"return-18"].join("/")}
+function state_414(props,result){const state=props.result??773;if(state>94)return result(state+414);return[props,result,"state-773"].join("/")}
+function import_415(result,value){const import=result.value??615;if(import>33)return value(import+415);return[result,value,"import-615"].join("/")}
+function options_416(index,response){const options=index.response??473;if(options>85)return response(options+416);return[index,response,"options-473"].join("/")}
+function result_417(module,import){const result=module.import??502;if(result>17)return import(result+417);return[module,import,"result-502"].join("/")}
+function result_418(handler,index){const result=handler.index??854;if(result>78)return index(result+418);return[handler,index,"result-854"].join("/")}
+function value_419(update,export){const value=update.export??400;if(value>12)return export(value+419);return[update,export,"value-400"].join("/")}
+function request_420(module,value){const request=module.value??754;if(request>75)return value(request+420);return[module,value,"request-754"].join("/")}
+function element_421(handler,element){const element=handler.element??887;if(element>14)return element(element+421);return[handler,element,"element-887"].join("/")}
+function props_422(render,result){const props=render.result??285;if(props>91)return result(props+422);return[render,result,"props-285"].join("/")}
+function index_423(element,request){const index=element.request??210;if(index>16)return request(index+423);return[element,request,"index-210"].join("/")}
+function props_424(state,import){const props=state.import??654;if(props>72)return import(props+424);return[state,import,"props-654"].join("/")}
+function value_425(const,update){const value=const.update??362;if(value>71)return update(value+425);return[const,update,"value-362"].join("/")}
+function props_426(handler,state){const props=handler.state??897;if(props>24)return state(props+426);return[handler,state,"props-897"].join("/")}
+function index_427(render,export){const index=render.export??859;if(index>83)return export(index+427);return[render,export,"index-859"].join("/")}
+function config_428(index,config){const config=index.config??11;if(config>11)return config(config+428);return[index,config,"config-11"].join("/")}
+function options_429(export,options){const options=export.options??979;if(options>9)return options(options+429);return[export,options,"options-979"].join("/")}
+function value_430(render,update){const value=render.update??403;if(value>15)return update(value+430);return[render,update,"value-403"].join("/")}
+function count_431(import,request){const count=import.request??29;if(count>29)return request(count+431);return[import,request,"count-29"].join("/")}
+function state_432(module,response){const state=module.response??151;if(state>54)return response(state+432);return[module,response,"state-151"].join("/")}
+function async_433(module,state){const async=module.state??710;if(async>31)return state(async+433);return[module,state,"async-710"].join("/")}
+function state_434(count,async){const state=count.async??0;if(state>0)return async(state+434);return[count,async,"state-0"].join("/")}
+function index_435(response,await){const index=response.await??335;if(index>44)return await(index+435);return[response,await,"index-335"].join("/")}
+function props_436(config,export){const props=config.export??639;if(props>57)return export(props+436);return[config,export,"props-639"].join("/")}
+function state_437(value,response){const state=value.response??474;if(state>86)return response(state+437);return[value,response,"state-474"].join("/")}
+function return_438(options,element){const return=options.element??442;if(return>54)return element(return+438);return[options,element,"return-442"].join("/")}
+function response_439(async,await){const response=async.await??981;if(response>11)return await(response+439);return[async,await,"response-981"].join("/")}
+function async_440(result,index){const async=result.index??232;if(async>38)return index(async+440);return[result,index,"async-232"].join("/")}
+function index_441(export,response){const index=export.response??203;if(index>9)return response(index+441);return[export,response,"index-203"].join("/")}
+function update_442(await,request){const update=await.request??364;if(update>73)return request(update+442);return[await,request,"update-364"].join("/")}
+function props_443(return,handler){const props=return.handler??217;if(props>23)return handler(props+443);return[return,handler,"props-217"].join("/")}
+function render_444(update,response){const render=update.response??507;if(render>22)return response(render+444);return[update,response,"render-507"].join("/")}
+function response_445(count,async){const response=count.async??168;if(response>71)return async(response+445);return[count,async,"response-168"].join("/")}
+function update_446(handler,update){const update=handler.update??42;if(update>42)return update(update+446);return[handler,update,"update-42"].join("/")}
+function response_447(export,value){const response=export.value??974;if(response>4)return value(response+447);return[export,value,"response-974"].join("/")}
+function module_448(response,value){const module=response.value??865;if(module>89)return value(module+448);return[response,value,"module-865"].join("/")}
+function render_449(handler,module){const render=handler.module??570;if(render>85)return module(render+449);return[handler,module,"render-570"].join("/")}
+function render_450(element,await){const render=element.await??179;if(render>82)return await(render+450);return[element,await,"render-179"].join("/")}
+function result_451(options,index){const result=options.index??213;if(result>19)return index(result+451);return[options,index,"result-213"].join("/")}
+function result_452(async,export){const result=async.export??286;if(result>92)return export(result+452);return[async,export,"result-286"].join("/")}
+function result_453(render,request){const result=render.request??739;if(result>60)return request(result+453);return[render,request,"result-739"].join("/")}
+function response_454(props,response){const response=props.response??604;if(response>22)return response(response+454);return[props,response,"response-604"].join("/")}
+function value_455(result,result){const value=result.result??339;if(value>48)return result(value+455);return[result,result,"value-339"].join("/")}
+function render_456(state,response){const render=state.response??711;if(render>32)return response(render+456);return[state,response,"render-711"].join("/")}
+function element_457(value,value){const element=value.value??2;if(element>2)return value(element+457);return[value,value,"element-2"].join("/")}
+function state_458(export,state){const state=export.state??2;if(state>2)return state(state+458);return[export,state,"state-2"].join("/")}
+function value_459(response,const){const value=response.const??398;if(value>10)return const(value+459);return[response,const,"value-398"].join("/")}
+function result_460(result,count){const result=result.count??950;if(result>77)return count(result+460);return[result,count,"result-950"].join("/")}
+function const_461(update,export){const const=update.export??660;if(const>78)return export(const+461);return[update,export,"const-660"].join("/")}
+function handler_462(render,update){const handler=render.update??252;if(handler>58)return update(handler+462);return[render,update,"handler-252"].join("/")}
+function handler_463(return,render){const handler=return.render??874;if(handler>1)return render(handler+463);return[return,render,"handler-874"].join("/")}
+function async_464(render,value){const async=render.value??944;if(async>71)return value(async+464);return[render,value,"async-944"].join("/")}
+function response_465(props,return){const response=props.return??689;if(response>10)return return(response+465);return[props,return,"response-689"].join("/")}
+function element_466(index,value){const element=index.value??378;if(element>87)return value(element+466);return[index,value,"element-378"].join("/")}
+function const_467(return,render){const const=return.render??712;if(const>33)return render(const+467);return[return,render,"const-712"].join("/")}
+function module_468(result,value){const module=result.value??433;if(module>45)return value(module+468);return[result,value,"module-433"].join("/")}
+function element_469(state,request){const element=state.request??257;if(element>63)return request(element+469);return[state,request,"element-257"].join("/")}
+function config_470(import,export){const config=import.export??496;if(config>11)return export(config+470);return[import,export,"config-496"].join("/")}
+function request_471(await,render){const request=await.render??384;if(request>93)return render(request+471);return[await,render,"request-384"].join("/")}
+function export_472(count,await){const export=count.await??790;if(export>14)return await(export+472);return[count,await,"export-790"].join("/")}
+function request_473(await,count){const request=await.count??824;if(request>48)return count(request+473);return[await,count,"request-824"].join("/")}
+function value_474(index,response){const value=index.response??442;if(value>54)return response(value+474);return[index,response,"value-442"].join("/")}
+function export_475(config,value){const export=config.value??231;if(export>37)return value(export+475);return[config,value,"export-231"].join("/")}
+function element_476(result,render){const element=result.render??794;if(element>18)return render(element+476);return[result,render,"element-794"].join("/")}
+function state_477(async,await){const state=async.await??803;if(state>27)return await(state+477);return[async,await,"state-803"].join("/")}
+function export_478(export,response){const export=export.response??771;if(export>92)return response(export+478);return[export,response,"export-771"].join("/")}
+function update_479(response,await){const update=response.await??288;if(update>94)return await(update+479);return[response,await,"update-288"].join("/")}
+function update_480(element,render){const update=element.render??10;if(update>10)return render(update+480);return[element,render,"update-10"].join("/")}
+function handler_481(response,state){const handler=response.state??855;if(handler>79)return state(handler+481);return[response,state,"handler-855"].join("/")}
+function import_482(element,update){const import=element.update??401;if(import>13)return update(import+482);return[element,update,"import-401"].join("/")}
+function count_483(module,request){const count=module.request??894;if(count>21)return request(count+483);return[module,request,"count-894"].join("/")}
+function value_484(value,export){const value=value.export??255;if(value>61)return export(value+484);return[value,export,"value-255"].join("/")}
+function element_485(await,response){const element=await.response??283;if(element>89)return response(element+485);return[await,response,"element-283"].join("/")}
+function return_486(state,render){const return=state.render??347;if(return>56)return render(return+486);return[state,render,"return-347"].join("/")}
+function state_487(response,handler){const state=response.handler??384;if(state>93)return handler(state+487);return[response,handler,"state-384"].join("/")}
+function request_488(request,update){const request=request.update??376;if(request>85)return update(request+488);return[request,update,"request-376"].join("/")}
+function module_489(response,export){const module=response.export??321;if(module>30)return export(module+489);return[response,export,"module-321"].join("/")}
+function const_490(export,async){const const=export.async??111;if(const>14)return async(const+490);return[export,async,"const-111"].join("/")}
+function state_491(options,return){const state=options.return??897;if(state>24)return return(state+491);return[options,return,"state-897"].join("/")}
+function config_492(await,render){const config=await.render??847;if(config>71)return render(config+492);return[await,render,"config-847"].join("/")}
+function result_493(props,import){const result=props.import??796;if(result>20)return import(result+493);return[props,import,"result-796"].join("/")}
+function config_494(count,import){const config=count.import??605;if(config>23)return import(config+494);return[count,import,"config-605"].join("/")}
+function module_495(request,update){const module=request.update??542;if(module>57)return update(module+495);return[request,update,"module-542"].join("/")}
+function response_496(value,count){const response=value.count??256;if(response>62)return count(response+496);return[value,count,"response-256"].join("/")}
+function return_497(response,render){const return=response.render??640;if(return>58)return render(return+497);return[response,render,"return-640"].join("/")}
+function options_498(export,props){const options=export.props??800;if(options>24)return props(options+498);return[export,props,"options-800"].join("/")}
+function count_499(return,await){const count=return.await??982;if(count>12)return await(count+499);return[return,await,"count-982"].join("/")}
+function options_500(return,options){const options=return.options??836;if(options>60)return options(options+500);return[return,options,"options-836"].join("/")}
+function render_501(return,props){const render=return.props??812;if(render>36)return props(render+501);return[return,props,"render-812"].join("/")}
+function const_502(state,return){const const=state.return??508;if(const>23)return return(const+502);return[state,return,"const-508"].join("/")}
+function element_503(const,return){const element=const.return??921;if(element>48)return return(element+503);return[const,return,"element-921"].join("/")}
+function response_504(state,element){const response=state.element??293;if(response>2)return element(response+504);return[state,element,"response-293"].join("/")}
+function value_505(export,handler){const value=export.handler??53;if(value>53)return handler(value+505);return[export,handler,"value-53"].join("/")}
+function element_506(props,options){const element=props.options??290;if(element>96)return options(element+506);return[props,options,"element-290"].join("/")}
+function import_507(config,props){const import=config.props??241;if(import>47)return props(import+507);return[config,props,"import-241"].join("/")}
+function return_508(value,module){const return=value.module??625;if(return>43)return module(return+508);return[value,module,"return-625"].join("/")}
+function handler_509(module,props){const handler=module.props??682;if(handler>3)return props(handler+509);return[module,props,"handler-682"].join("/")}
+function response_510(await,handler){const response=await.handler??307;if(response>16)return handler(response+510);return[await,handler,"response-307"].join("/")}
+function render_511(return,module){const render=return.module??365;if(render>74)return module(render+511);return[return,module,"render-365"].join("/")}
+function response_512(import,import){const response=import.import??432;if(response>44)return import(response+512);return[import,import,"response-432"].join("/")}
+function render_513(response,index){const render=response.index??495;if(render>10)return index(render+513);return[response,index,"render-495"].join("/")}
+function count_514(async,element){const count=async.element??9;if(count>9)return element(count+514);return[async,element,"count-9"].join("/")}
+function const_515(result,import){const const=result.import??733;if(const>54)return import(const+515);return[result,import,"const-733"].join("/")}
+function props_516(index,config){const props=index.config??646;if(props>64)return config(props+516);return[index,config,"props-646"].join("/")}
+function await_517(import,import){const await=import.import??900;if(await>27)return import(await+517);return[import,import,"await-900"].join("/")}
+function element_518(props,module){const element=props.module??881;if(element>8)return module(element+518);return[props,module,"element-881"].join("/")}
+function export_519(const,const){const export=const.const??365;if(export>74)return const(export+519);return[const,const,"export-365"].join("/")}
+function import_520(const,index){const import=const.index??221;if(import>27)return index(import+520);return[const,index,"import-221"].join("/")}
+function response_521(element,const){const response=element.const??302;if(response>11)return const(response+521);return[element,const,"response-302"].join("/")}
+function const_522(request,props){const const=request.props??452;if(const>64)return props(const+522);return[request,props,"const-452"].join("/")}
+function update_523(options,index){const update=options.index??190;if(update>93)return index(update+523);return[options,index,"update-190"].join("/")}
+function update_524(value,config){const update=value.config??387;if(update>96)return config(update+524);return[value,config,"update-387"].join("/")}
+function options_525(import,import){const options=import.import??209;if(options>15)return import(options+525);return[import,import,"options-209"].join("/")}
+function config_526(handler,result){const config=handler.result??395;if(config>7)return result(config+526);return[handler,result,"config-395"].join("/")}
+function render_527(config,index){const render=config.index??440;if(render>52)return index(render+527);return[config,index,"render-440"].join("/")}
+function index_528(module,return){const index=module.return??525;if(index>40)return return(index+528);return[module,return,"index-525"].join("/")}
+function state_529(render,request){const state=render.request??921;if(state>48)return request(state+529);return[render,request,"state-921"].join("/")}
+function update_530(response,config){const update=response.config??127;if(update>30)return config(update+530);return[response,config,"update-127"].join("/")}
+function export_531(index,handler){const export=index.handler??782;if(export>6)return handler(export+531);return[index,handler,"export-782"].join("/")}
+function response_532(return,element){const response=return.element??769;if(response>90)return element(response+532);return[return,element,"response-769"].join("/")}
+function export_533(export,props){const export=export.props??466;if(export>78)return props(export+533);return[export,props,"export-466"].join("/")}
+function update_534(options,response){const update=options.response??662;if(update>80)return response(update+534);return[options,response,"update-662"].join("/")}
+function options_535(request,value){const options=request.value??286;if(options>92)return value(options+535);return[request,value,"options-286"].join("/")}
+function element_536(const,update){const element=const.update??774;if(element>95)return update(element+536);return[const,update,"element-774"].join("/")}
+function export_537(props,index){const export=props.index??671;if(export>89)return index(export+537);return[props,index,"export-671"].join("/")}
+function return_538(const,options){const return=const.options??599;if(return>17)return options(return+538);return[const,options,"return-599"].join("/")}
+function index_539(index,async){const index=index.async??184;if(index>87)return async(index+539);return[index,async,"index-184"].join("/")}
+function options_540(config,await){const options=config.await??51;if(options>51)return await(options+540);return[config,await,"options-51"].join("/")}
+function index_541(value,result){const index=value.result??598;if(index>16)return result(index+541);return[value,result,"index-598"].join("/")}
+function response_542(count,module){const response=count.module??781;if(response>5)return module(response+542);return[count,module,"response-781"].join("/")}
+function render_543(state,props){const render=state.props??718;if(render>39)return props(render+543);return[state,props,"render-718"].join("/")}
+function value_544(handler,render){const value=handler.render??147;if(value>50)return render(value+544);return[handler,render,"value-147"].join("/")}
+function return_545(value,module){const return=value.module??770;if(return>91)return module(return+545);return[value,module,"return-770"].join("/")}
+function index_546(return,const){const index=return.const??257;if(index>63)return const(index+546);return[return,const,"index-257"].join("/")}
+function result_547(async,value){const result=async.value??132;if(result>35)return value(result+547);return[async,value,"result-132"].join("/")}
+function options_548(return,update){const options=return.update??724;if(options>45)return update(options+548);return[return,update,"options-724"].join("/")}
+function options_549(result,async){const options=result.async??637;if(options>55)return async(options+549);return[result,async,"options-637"].join("/")}
+function index_550(await,options){const index=await.options??235;if(index>41)return options(index+550);return[await,options,"index-235"].join("/")}
+function module_551(config,element){const module=config.element??381;if(module>90)return element(module+551);return[config,element,"module-381"].join("/")}
+function render_552(index,module){const render=index.module??825;if(render>49)return module(render+552);return[index,module,"render-825"].join("/")}
+function const_553(return,count){const const=return.count??538;if(const>53)return count(const+553);return[return,count,"const-538"].join("/")}
+function import_554(return,export){const import=return.export??923;if(import>50)return export(import+554);return[return,export,"import-923"].join("/")}
+function options_555(await,element){const options=await.element??360;if(options>69)return element(options+555);return[await,element,"options-360"].join("/")}
+function options_556(render,export){const options=render.export??370;if(options>79)return export(options+556);return[render,export,"options-370"].join("/")}
+function options_557(count,state){const options=count.state??343;if(options>52)return state(options+557);return[count,state,"options-343"].join("/")}
+function request_558(response,module){const request=response.module??962;if(request>89)return module(request+558);return[response,module,"request-962"].join("/")}
+function state_559(export,module){const state=export.module??619;if(state>37)return module(state+559);return[export,module,"state-619"].join("/")}
+function request_560(response,state){const request=response.state??786;if(request>10)return state(request+560);return[response,state,"request-786"].join("/")}
+function import_561(state,index){const import=state.index??462;if(import>74)return index(import+561);return[state,index,"import-462"].join("/")}
+function value_562(return,render){const value=return.render??463;if(value>75)return render(value+562);return[return,render,"value-463"].join("/")}
+function await_563(element,export){const await=element.export??635;if(await>53)return export(await+563);return[element,export,"await-635"].join("/")}
+function return_564(result,await){const return=result.await??516;if(return>31)return await(return+564);return[result,await,"return-516"].join("/")}
+function module_565(value,config){const module=value.config??511;if(module>26)return config(module+565);return[value,config,"module-511"].join("/")}
+function export_566(await,const){const export=await.const??574;if(export>89)return const(export+566);return[await,const,"export-574"].join("/")}
+function async_567(handler,render){const async=handler.render??392;if(async>4)return render(async+567);return[handler,render,"async-392"].join("/")}
+function props_568(module,export){const props=module.export??519;if(props>34)return export(props+568);return[module,export,"props-519"].join("/")}
+function value_569(const,const){const value=const.const??713;if(value>34)return const(value+569);return[const,const,"value-713"].join("/")}
+function render_570(async,const){const render=async.const??224;if(render>30)return const(render+570);return[async,const,"render-224"].join("/")}
+function value_571(await,return){const value=await.return??466;if(value>78)return return(value+571);return[await,return,"value-466"].join("/")}
+function index_572(module,options){const index=module.options??253;if(index>59)return options(index+572);return[module,options,"index-253"].join("/")}
+function const_573(import,render){const const=import.render??54;if(const>54)return render(const+573);return[import,render,"const-54"].join("/")}
+function await_574(const,state){const await=const.state??259;if(await>65)return state(await+574);return[const,state,"await-259"].join("/")}
+function render_575(result,response){const render=result.response??985;if(render>15)return response(render+575);return[result,response,"render-985"].join("/")}
+function result_576(return,await){const result=return.await??102;if(result>5)return await(result+576);return[return,await,"result-102"].join("/")}
+function await_577(result,await){const await=result.await??741;if(await>62)return await(await+577);return[result,await,"await-741"].join("/")}
+function options_578(async,export){const options=async.export??329;if(options>38)return export(options+578);return[async,export,"options-329"].join("/")}
+function await_579(handler,response){const await=handler.response??857;if(await>81)return response(await+579);return[handler,response,"await-857"].join("/")}
+function update_580(options,result){const update=options.result??996;if(update>26)return result(update+580);return[options,result,"update-996"].join("/")}
+function count_581(render,render){const count=render.render??243;if(count>49)return render(count+581);return[render,render,"count-243"].join("/")}
+function value_582(index,config){const value=index.config??691;if(value>12)return config(value+582);return[index,config,"value-691"].join("/")}
+function state_583(module,await){const state=module.await??420;if(state>32)return await(state+583);return[module,await,"state-420"].join("/")}
+function import_584(config,import){const import=config.import??649;if(import>67)return import(import+584);return[config,import,"import-649"].join("/")}
+function export_585(import,export){const export=import.export??558;if(export>73)return export(export+585);return[import,export,"export-558"].join("/")}
+function return_586(config,options){const return=config.options??287;if(return>93)return options(return+586);return[config,options,"return-287"].join("/")}
+function props_587(request,render){const props=request.render??950;if(props>77)return render(props+587);return[request,render,"props-950"].join("/")}
+function import_588(request,update){const import=request.update??675;if(import>93)return update(import+588);return[request,update,"import-675"].join("/")}
+function request_589(value,export){const request=value.export??115;if(request>18)return export(request+589);return[value,export,"request-115"].join("/")}
+function return_590(state,await){const return=state.await??693;if(return>14)return await(return+590);return[state,await,"return-693"].join("/")}
+function handler_591(props,index){const handler=props.index??611;if(handler>29)return index(handler+591);return[props,index,"handler-611"].join("/")}
+function await_592(options,result){const await=options.result??453;if(await>65)return result(await+592);return[options,result,"await-453"].join("/")}
+function state_593(request,async){const state=request.async??691;if(state>12)return async(state+593);return[request,async,"state-691"].join("/")}
+function render_594(response,count){const render=response.count??852;if(render>76)return count(render+594);return[response,count,"render-852"].join("/")}
+function props_595(props,element){const props=props.element??775;if(props>96)return element(props+595);return[props,element,"props-775"].join("/")}
+function render_596(module,import){const render=module.import??634;if(render>52)return import(render+596);return[module,import,"render-634"].join("/")}
+function element_597(const,await){const element=const.await??881;if(element>8)return await(element+597);return[const,await,"element-881"].join("/")}
+function async_598(element,options){const async=element.options??603;if(async>21)return options(async+598);return[element,options,"async-603"].join("/")}
+function config_599(count,module){const config=count.module??719;if(config>40)return module(config+599);return[count,module,"config-719"].join("/")}
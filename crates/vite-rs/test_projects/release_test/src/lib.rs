@@ -0,0 +1,43 @@
+// Each module embeds its own `dist/`, which is prebuilt and committed (hermetic builds never run
+// `vite build`).
+
+pub mod generated_entry {
+    // `sw.js`, `workbox-*.js` and `manifest.webmanifest` stand in for the files `vite-plugin-pwa`
+    // emits, which aren't in Vite's manifest.
+    #[derive(vite_rs::Embed)]
+    #[root = "./generated_entry"]
+    #[generated_entry("sw.js", alias = "service-worker", service_worker_allowed = "/")]
+    #[generated_entry("workbox-*.js", service_worker_allowed = "/")]
+    #[generated_entry("manifest.webmanifest")]
+    pub struct Assets;
+}
+
+pub mod immutable_assets {
+    #[derive(vite_rs::Embed)]
+    #[root = "./immutable_assets"]
+    #[immutable_assets = "auto"]
+    pub struct Assets;
+
+    #[derive(vite_rs::Embed)]
+    #[root = "./immutable_assets"]
+    #[immutable_assets = "auto"]
+    #[immutable_assets = "js/*.[hash].js"]
+    pub struct CustomAssets;
+
+    #[derive(vite_rs::Embed)]
+    #[root = "./immutable_assets"]
+    pub struct DefaultAssets;
+}
+
+pub mod precompressed {
+    #[derive(vite_rs::Embed)]
+    #[root = "./precompressed"]
+    #[zstd_level = 3]
+    pub struct Assets;
+}
+
+pub mod integrity {
+    #[derive(vite_rs::Embed)]
+    #[root = "./integrity"]
+    pub struct Assets;
+}
@@ -0,0 +1,52 @@
+use release_test::generated_entry::Assets;
+
+#[test]
+fn registers_generated_files_as_entries() {
+    let manifest = Assets::manifest();
+
+    let service_worker = manifest.get("service-worker").unwrap();
+    assert_eq!(service_worker.file, "sw.js");
+    assert!(service_worker.is_entry);
+
+    let workbox = manifest.get("workbox-5f2c9e1a.js").unwrap();
+    assert_eq!(workbox.file, "workbox-5f2c9e1a.js");
+    assert!(workbox.is_entry);
+
+    assert!(manifest.get("manifest.webmanifest").unwrap().is_entry);
+
+    // the bundles are still there
+    assert_eq!(
+        manifest.get("index.html").unwrap().file,
+        "assets/index-BPvgi06w.js"
+    );
+}
+
+#[test]
+fn finds_generated_files_by_alias() {
+    let file = Assets::get("service-worker").unwrap();
+    assert_eq!(file.bytes, Assets::get("sw.js").unwrap().bytes);
+}
+
+#[test]
+fn sets_service_worker_allowed() {
+    assert_eq!(
+        Assets::get("sw.js").unwrap().service_worker_allowed,
+        Some("/")
+    );
+    assert_eq!(
+        Assets::get("workbox-5f2c9e1a.js")
+            .unwrap()
+            .service_worker_allowed,
+        Some("/")
+    );
+    assert_eq!(
+        Assets::get("manifest.webmanifest")
+            .unwrap()
+            .service_worker_allowed,
+        None
+    );
+    assert_eq!(
+        Assets::get("index.html").unwrap().service_worker_allowed,
+        None
+    );
+}
@@ -0,0 +1,34 @@
+use release_test::immutable_assets::{Assets, CustomAssets, DefaultAssets};
+
+#[test]
+fn flags_vite_hashed_file_names() {
+    assert!(Assets::get("assets/index-BPvgi06w.js").unwrap().immutable);
+
+    // no hash in the name
+    assert!(!Assets::get("assets/vendor-legacy.js").unwrap().immutable);
+    assert!(!Assets::get("assets/app-settings.js").unwrap().immutable);
+    assert!(!Assets::get("assets/react-dom_vendored.js").unwrap().immutable);
+    assert!(!Assets::get("index.html").unwrap().immutable);
+    assert!(!Assets::get("favicon.svg").unwrap().immutable);
+    // not in Vite's default output file names
+    assert!(!Assets::get("js/app.C3vx0jTq.js").unwrap().immutable);
+}
+
+#[test]
+fn doesnt_flag_entry_aliases() {
+    // `src/main.ts` serves the hashed bundle, but its own contents change at that path
+    assert!(!Assets::get("src/main.ts").unwrap().immutable);
+}
+
+#[test]
+fn flags_custom_patterns() {
+    assert!(CustomAssets::get("assets/index-BPvgi06w.js").unwrap().immutable);
+    assert!(CustomAssets::get("js/app.C3vx0jTq.js").unwrap().immutable);
+    assert!(!CustomAssets::get("index.html").unwrap().immutable);
+}
+
+#[test]
+fn flags_nothing_by_default() {
+    assert!(!DefaultAssets::get("assets/index-BPvgi06w.js").unwrap().immutable);
+    assert!(!DefaultAssets::get("js/app.C3vx0jTq.js").unwrap().immutable);
+}
@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use release_test::integrity::Assets;
+
+/// The SRI hash of `bytes`, computed by Node.js (around anyway, for Vite), so the hashes are
+/// checked against another implementation.
+fn node_integrity(bytes: &[u8]) -> String {
+    let script = "const hash = require('crypto').createHash('sha384'); \
+        process.stdin.on('data', data => hash.update(data)); \
+        process.stdin.on('end', () => process.stdout.write('sha384-' + hash.digest('base64')))";
+
+    let mut child = Command::new("node")
+        .args(["-e", script])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run node");
+    child.stdin.take().unwrap().write_all(bytes).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "couldn't hash the file");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn hashes_every_embedded_file() {
+    for path in Assets::iter() {
+        let file = Assets::get(&path).unwrap();
+
+        assert_eq!(
+            Assets::integrity(&path),
+            Some(node_integrity(&file.bytes).as_str()),
+            "{}",
+            path
+        );
+    }
+}
+
+#[test]
+fn finds_hashes_by_manifest_key() {
+    assert_eq!(
+        Assets::integrity("src/main.ts"),
+        Assets::integrity("assets/main-Dq3xL0aE.js")
+    );
+    assert_eq!(
+        Assets::integrity("src/lazy.ts"),
+        Assets::integrity("assets/lazy-Cz8wP1kN.js")
+    );
+    assert!(Assets::integrity("src/main.ts").is_some());
+    assert_eq!(Assets::integrity("src/missing.ts"), None);
+}
+
+#[test]
+fn adds_hashes_to_the_manifest() {
+    let manifest = Assets::manifest();
+
+    for chunk in manifest.chunks() {
+        assert_eq!(chunk.integrity, Assets::integrity(chunk.file));
+    }
+
+    let main = manifest.get("src/main.ts").unwrap();
+    assert!(main.integrity.unwrap().starts_with("sha384-"));
+    assert!(Assets::integrity(main.css[0]).is_some());
+}
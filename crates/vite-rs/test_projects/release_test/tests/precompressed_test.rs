@@ -0,0 +1,128 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use release_test::precompressed::Assets;
+
+/// Small and large (more than gzip's 32 KiB window) text, and binary data.
+const COMPRESSIBLE: [&str; 4] = [
+    "index.html",
+    "assets/index-D5x0FqkL.js",
+    "assets/vendor-Hk2pQ9wZ.js",
+    "assets/data-Rt5mX1bV.wasm",
+];
+
+/// Pipes `compressed` through `command` and returns what it prints, so the copies are checked
+/// against other implementations.
+fn decompress(command: &mut Command, compressed: &[u8]) -> Vec<u8> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run the decompressor");
+    child.stdin.take().unwrap().write_all(compressed).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "couldn't decompress the copy");
+    output.stdout
+}
+
+#[test]
+fn embeds_gzip_copies() {
+    for path in COMPRESSIBLE {
+        let file = Assets::get(path).unwrap();
+        let gzip = file.gzip.expect("no gzip copy");
+
+        assert!(gzip.len() < file.bytes.len());
+        assert_eq!(
+            decompress(Command::new("gzip").arg("-dc"), gzip),
+            file.bytes.as_ref()
+        );
+    }
+}
+
+#[test]
+fn embeds_brotli_copies() {
+    // Node.js is around anyway, for Vite
+    let script = "process.stdin.pipe(require('zlib').createBrotliDecompress()).pipe(process.stdout)";
+
+    for path in COMPRESSIBLE {
+        let file = Assets::get(path).unwrap();
+        let brotli = file.brotli.expect("no brotli copy");
+
+        assert!(brotli.len() < file.bytes.len());
+        assert_eq!(
+            decompress(Command::new("node").args(["-e", script]), brotli),
+            file.bytes.as_ref()
+        );
+    }
+}
+
+#[test]
+fn brotli_copies_are_smaller_than_gzip_copies() {
+    for path in COMPRESSIBLE {
+        let file = Assets::get(path).unwrap();
+        let (brotli, gzip) = (file.brotli.unwrap(), file.gzip.unwrap());
+
+        assert!(brotli.len() < gzip.len(), "{}", path);
+    }
+
+    // Brotli's static dictionary and context modeling pay off on larger bundles
+    let file = Assets::get("assets/vendor-Hk2pQ9wZ.js").unwrap();
+    let (brotli, gzip) = (file.brotli.unwrap(), file.gzip.unwrap());
+    assert!(
+        brotli.len() * 100 < gzip.len() * 85,
+        "brotli: {} bytes, gzip: {} bytes",
+        brotli.len(),
+        gzip.len()
+    );
+}
+
+#[test]
+fn embeds_zstd_copies_at_the_configured_level() {
+    for path in COMPRESSIBLE {
+        let file = Assets::get(path).unwrap();
+        let zstd = file.zstd.expect("no zstd copy");
+
+        assert!(zstd.len() < file.bytes.len());
+        assert_eq!(zstd::bulk::compress(&file.bytes, 3).unwrap(), zstd);
+        assert_eq!(zstd::decode_all(zstd).unwrap(), file.bytes.as_ref());
+    }
+}
+
+#[test]
+fn finds_copies_by_content_encoding() {
+    let file = Assets::get("index.html").unwrap();
+
+    assert_eq!(file.encoded("zstd"), file.zstd);
+    assert_eq!(file.encoded("br"), file.brotli);
+    assert_eq!(file.encoded("GZIP"), file.gzip);
+    assert_eq!(file.encoded("deflate"), None);
+    assert_eq!(
+        file.encodings()
+            .map(|(content_encoding, _)| content_encoding)
+            .collect::<Vec<_>>(),
+        ["zstd", "br", "gzip"]
+    );
+}
+
+#[test]
+fn skips_copies_which_arent_smaller() {
+    for path in ["robots.txt", "empty.txt"] {
+        let file = Assets::get(path).unwrap();
+
+        assert!(file.gzip.is_none(), "{}", path);
+        assert!(file.brotli.is_none(), "{}", path);
+        assert!(file.zstd.is_none(), "{}", path);
+    }
+}
+
+#[test]
+fn skips_compressed_formats() {
+    for path in ["assets/font-Cq1xT9aZ.woff2", "assets/logo-B7n2RkQe.png"] {
+        let file = Assets::get(path).unwrap();
+
+        assert!(file.gzip.is_none(), "{}", path);
+        assert!(file.brotli.is_none(), "{}", path);
+        assert!(file.encodings().next().is_none(), "{}", path);
+    }
+}
@@ -0,0 +1,327 @@
+(function polyfill() {
+  const relList = document.createElement("link").relList;
+  if (relList && relList.supports && relList.supports("modulepreload")) {
+    return;
+  }
+})();
+function renderItem0(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 0) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem1(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 1) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem2(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 2) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem3(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 3) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem4(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 4) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem5(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 5) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem6(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 6) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem7(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 7) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem8(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 8) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem9(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 9) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem10(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 10) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem11(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 11) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem12(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 12) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem13(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 13) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem14(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 14) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem15(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 15) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem16(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 16) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem17(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 17) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem18(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 18) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem19(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 19) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem20(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 20) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem21(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 21) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem22(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 22) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem23(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 23) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem24(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 24) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem25(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 25) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem26(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 26) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem27(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 27) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem28(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 28) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem29(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 29) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem30(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 30) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem31(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 31) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem32(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 32) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem33(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 33) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem34(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 34) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem35(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 35) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem36(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 36) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem37(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 37) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem38(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 38) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+function renderItem39(container, item) {
+  const element = document.createElement("li");
+  element.textContent = `${item.name} (${item.count})`;
+  if (item.count > 39) {
+    element.classList.add("highlighted");
+  }
+  container.appendChild(element);
+}
+document.querySelectorAll("ul").forEach((list) => renderItem0(list, { name: "item", count: 1 }));
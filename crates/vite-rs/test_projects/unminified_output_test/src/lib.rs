@@ -0,0 +1,5 @@
+// `dist/` holds a prebuilt development-mode bundle (like `vite build --mode development`).
+#[derive(vite_rs::Embed)]
+#[root = "./"]
+#[unminified_output = "deny"]
+pub struct Assets;
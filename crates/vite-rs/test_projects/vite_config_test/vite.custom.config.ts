@@ -0,0 +1,10 @@
+import { defineConfig } from "vite";
+
+// used instead of vite.config.ts via #[vite_config = "vite.custom.config.ts"]
+export default defineConfig({
+  build: {
+    rollupOptions: {
+      input: ["index.html"],
+    },
+  },
+});
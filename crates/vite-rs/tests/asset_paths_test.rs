@@ -0,0 +1,79 @@
+//! Runs the tests of `test_projects/asset_paths_test` (a release build, which checks
+//! `Assets::assert_exists` and `Assets::BUILD_STATS`) and checks the files written for
+//! `#[asset_paths_file]` and the build stats.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn writes_the_asset_paths_file() {
+    let project = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_projects/asset_paths_test");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("asset_paths_test");
+
+    // the files are written while compiling, so make sure it happens
+    let _ = std::fs::remove_dir_all(project.join("generated"));
+    let _ = Command::new(env!("CARGO"))
+        .args([
+            "clean",
+            "--release",
+            "-p",
+            "asset_paths_test",
+            "--manifest-path",
+        ])
+        .arg(project.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status();
+
+    let status = Command::new(env!("CARGO"))
+        .args(["test", "--release", "--manifest-path"])
+        .arg(project.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        // where the macro writes the build stats
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "the tests of asset_paths_test failed");
+
+    assert_eq!(
+        std::fs::read_to_string(project.join("generated/assets.d.ts")).unwrap(),
+        r#"// Generated by vite-rs; do not edit.
+
+export type AssetPath =
+  | "assets/index-BPvgi06w.js"
+  | "assets/settings-C3vx0jTq.js"
+  | "index.html";
+
+export type EntryName =
+  | "index.html";
+"#
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(project.join("generated/assets.json")).unwrap(),
+        r#"{
+  "assets": [
+    "assets/index-BPvgi06w.js",
+    "assets/settings-C3vx0jTq.js",
+    "index.html"
+  ],
+  "entries": [
+    "index.html"
+  ]
+}
+"#
+    );
+
+    let build_stats = std::fs::read_to_string(
+        target_dir.join("vite-rs/build-stats/asset_paths_test.JsonAssets.json"),
+    )
+    .unwrap();
+    let build_stats = serde_json::from_str::<serde_json::Value>(&build_stats).unwrap();
+    assert_eq!(build_stats["crate"], "asset_paths_test");
+    assert_eq!(build_stats["struct"], "JsonAssets");
+    // hermetic builds embed a prebuilt output directory
+    assert_eq!(build_stats["duration_ms"], serde_json::Value::Null);
+    assert_eq!(build_stats["file_count"], 3);
+    assert_eq!(build_stats["total_bytes"], 298);
+}
@@ -0,0 +1,15 @@
+#![cfg(vite_rs_dev)]
+
+use vite_rs::axum::ViteServe;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+/// The integration is used through `vite_rs` alone, with the same `GetFromVite` trait.
+#[test]
+fn reexports_the_axum_integration() {
+    let assets: Box<dyn vite_rs::GetFromVite> = Assets::boxed();
+    let _service = ViteServe::new(assets);
+}
@@ -0,0 +1,37 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+#[base = "/app"]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+struct DefaultAssets;
+
+#[test]
+fn passes_the_base_to_the_dev_server() {
+    assert_eq!(Assets::dev_server_options().base.as_deref(), Some("/app/"));
+    assert_eq!(DefaultAssets::dev_server_options().base, None);
+}
+
+#[test]
+fn requests_assets_under_the_base() {
+    // stands in for a dev server whose Vite config sets `base: '/app/'`
+    let listener = util::fake_dev_server(Assets::dev_server_options());
+    let server = util::respond_once(listener, b"HTTP/1.1 200 OK\r\nContent-Type: text/javascript\r\nContent-Length: 2\r\nETag: W/\"2-0\"\r\nConnection: close\r\n\r\nok");
+
+    let file = Assets::get("src/main.ts").unwrap();
+    assert_eq!(file.bytes, b"ok");
+
+    let request = server.join().unwrap();
+    assert!(
+        request.starts_with("GET /app/src/main.ts HTTP/1.1\r\n"),
+        "{}",
+        request
+    );
+}
@@ -0,0 +1,37 @@
+// Release builds would run `vite build` at compile time, so this only checks the dev code.
+#![cfg(vite_rs_dev)]
+
+// simulates depending on the vite-rs crates under different names
+extern crate vite_rs_dev_server as renamed_dev_server;
+extern crate vite_rs_interface as renamed_interface;
+
+/// A crate which re-exports vite-rs under its own path.
+mod facade {
+    pub mod assets {
+        pub use vite_rs::*;
+    }
+}
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[crate_path = "not_a_crate"] // unused once both paths are overridden
+#[interface_crate_path = "renamed_interface"]
+#[dev_server_crate_path = "renamed_dev_server"]
+struct RenamedAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[crate_path = "facade::assets"]
+struct FacadeAssets;
+
+#[test]
+fn uses_the_renamed_crate_paths() {
+    let _: renamed_dev_server::DevServerOptions = RenamedAssets::dev_server_options();
+    let _: Box<dyn vite_rs::GetFromVite> = RenamedAssets::boxed();
+}
+
+#[test]
+fn uses_the_facade_crate_path() {
+    let _: vite_rs::DevServerOptions = FacadeAssets::dev_server_options();
+    let _: Box<dyn vite_rs::GetFromVite> = FacadeAssets::boxed();
+}
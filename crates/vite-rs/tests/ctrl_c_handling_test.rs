@@ -2,13 +2,12 @@
 /// since the vite dev server can't be started multiple times.
 #[test]
 fn test() {
-    const CTRL_HANDLER_FEATURE_MATRIX: [&str; 2] =
-        ["builtin-ctrl-c-handler", "custom-ctrl-c-handler"];
-
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     {
         #[cfg(unix)]
         {
+            const CTRL_HANDLER_FEATURE_MATRIX: [&str; 2] =
+                ["builtin-ctrl-c-handler", "custom-ctrl-c-handler"];
             const UNIX_SIGNAL_MATRIX: [nix::sys::signal::Signal; 3] = [
                 nix::sys::signal::Signal::SIGINT,
                 nix::sys::signal::Signal::SIGTERM,
@@ -28,7 +27,7 @@ fn test() {
     }
 }
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+#[cfg(vite_rs_dev)]
 mod dev_tests {
     #[cfg(unix)]
     pub fn unix_ensure_dev_server_exits_on_signal(feature: &str, signal: nix::sys::signal::Signal) {
@@ -103,10 +102,7 @@ mod dev_tests {
         // let's make sure this comment is correct by doing this assertion:
         assert!(workspace_dir.ends_with("crates/vite-rs"));
 
-        let test_project_path =
-            std::path::PathBuf::from_iter(&[&workspace_dir, "test_projects/ctrl_c_handling_test"]);
-
-        test_project_path
+        std::path::PathBuf::from_iter(&[&workspace_dir, "test_projects/ctrl_c_handling_test"])
     }
 
     fn run(features: &str) -> std::process::Child {
@@ -0,0 +1,49 @@
+// Bypassed paths are read straight from disk, so this test doesn't start the dev server.
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/dev_bypass_test"]
+#[dev_bypass_prefix = "data/"]
+#[dev_bypass_prefix = "/other/"]
+#[dev_bypass_prefix = "static"]
+struct Assets;
+
+#[test]
+fn reads_public_dir_files_from_disk() {
+    let file = Assets::get("data/points.json").unwrap();
+
+    assert_eq!(file.content_type, "application/json");
+    assert_eq!(file.content_length, 17);
+    assert_eq!(file.bytes, b"[[0, 0], [1, 1]]\n");
+
+    #[cfg(feature = "content-hash")]
+    assert!(file.content_hash.starts_with("W/\"11-"));
+}
+
+#[test]
+fn falls_back_to_root_dir() {
+    let file = Assets::get("/data/notes.txt").unwrap();
+
+    assert_eq!(file.content_type, "text/plain");
+    assert_eq!(file.bytes, b"not in public\n");
+}
+
+#[test]
+fn missing_files_are_not_found() {
+    assert!(Assets::get("data/missing.json").is_none());
+    assert!(Assets::get("other/missing.json").is_none());
+}
+
+#[test]
+fn does_not_escape_root_dir() {
+    assert!(Assets::get("data/../../../Cargo.toml").is_none());
+}
+
+#[test]
+fn only_matches_whole_path_segments() {
+    assert_eq!(Assets::get("static/app.css").unwrap().bytes, b"body {}\n");
+
+    // requested from the dev server, which isn't running
+    assert!(Assets::try_get("static-old/app.css").is_err());
+    assert!(Assets::try_get("staticfoo.css").is_err());
+}
@@ -0,0 +1,24 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+#[dev_content_type_check = "warn"]
+struct Assets;
+
+/// A mismatch is only reported: the response is returned as it is.
+#[test]
+fn returns_responses_with_unexpected_content_types() {
+    // stands in for a dev server with a plugin answering a module request with `index.html`
+    let listener = util::fake_dev_server(Assets::dev_server_options());
+    let server = util::respond_once(listener, b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 2\r\nETag: W/\"2-0\"\r\nConnection: close\r\n\r\nok");
+
+    let file = Assets::get("src/main.ts").unwrap();
+    assert_eq!(file.content_type, "text/html");
+    assert_eq!(file.bytes, b"ok");
+
+    server.join().unwrap();
+}
@@ -0,0 +1,66 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::time::{Duration, Instant};
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+#[dev_fetch_timeout = "300ms"]
+#[dev_fetch_retries = "1"]
+struct Assets;
+
+#[test]
+fn gives_up_on_a_hung_dev_server() {
+    let options = Assets::dev_fetch_options();
+    assert_eq!(options.timeout, Some(Duration::from_millis(300)));
+    assert_eq!(options.retries, 1);
+
+    // accepts connections (in the backlog) but never responds
+    let _listener = util::fake_dev_server(Assets::dev_server_options());
+
+    let started = Instant::now();
+    assert!(Assets::get("index.html").is_none());
+    assert!(started.elapsed() >= Duration::from_millis(600));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_fetch_concurrency = "16"]
+struct LimitedAssets;
+
+#[test]
+fn shares_the_concurrency_limit_between_requests() {
+    let limit = LimitedAssets::dev_fetch_options()
+        .concurrency_limit
+        .unwrap();
+    assert_eq!(limit.max_concurrent(), 16);
+
+    // every request of the struct waits for the same limit
+    assert_eq!(
+        LimitedAssets::dev_fetch_options().concurrency_limit,
+        Some(limit)
+    );
+    assert_eq!(Assets::dev_fetch_options().concurrency_limit, None);
+}
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_response_cache = "../../target/vite-rs-cache"]
+struct CachedAssets;
+
+#[test]
+fn keeps_the_response_cache_in_a_directory_per_struct() {
+    let cache = CachedAssets::dev_fetch_options().cache.unwrap();
+    let expected = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../target/vite-rs-cache")
+        .join("CachedAssets");
+    assert_eq!(cache.dir(), Some(expected.as_path()));
+
+    // every request of the struct shares the cache
+    assert_eq!(CachedAssets::dev_fetch_options().cache, Some(cache));
+    assert_eq!(Assets::dev_fetch_options().cache, None);
+}
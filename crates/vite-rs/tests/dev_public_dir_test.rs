@@ -0,0 +1,20 @@
+// Files in the public dir are read straight from disk, so this test doesn't start the dev server.
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/dev_bypass_test"]
+#[dev_public_dir = "./public"]
+struct Assets;
+
+#[test]
+fn reads_public_dir_files_from_disk() {
+    let file = Assets::get("data/points.json").unwrap();
+
+    assert_eq!(file.content_type, "application/json");
+    assert_eq!(file.bytes, b"[[0, 0], [1, 1]]\n");
+
+    // served from the cache the second time around
+    let file = Assets::get("/data/points.json").unwrap();
+
+    assert_eq!(file.bytes, b"[[0, 0], [1, 1]]\n");
+}
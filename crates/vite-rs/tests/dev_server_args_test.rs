@@ -0,0 +1,27 @@
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_args("--force", "--logLevel")]
+#[dev_server_args("warn")]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+struct DefaultAssets;
+
+#[test]
+fn passes_the_dev_server_args() {
+    assert_eq!(
+        Assets::dev_server_options().args,
+        ["--force", "--logLevel", "warn"]
+    );
+    assert!(DefaultAssets::dev_server_options().args.is_empty());
+}
+
+#[test]
+fn dev_server_args_can_be_extended_at_runtime() {
+    let options = Assets::dev_server_options().with_arg("--cors");
+
+    assert_eq!(options.args, ["--force", "--logLevel", "warn", "--cors"]);
+}
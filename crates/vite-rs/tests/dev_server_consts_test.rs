@@ -0,0 +1,40 @@
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_host = "0.0.0.0"]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[external_dev_server = "http://frontend.internal:5173"]
+struct ExternalAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_port = "auto"]
+struct AutoAssets;
+
+#[test]
+fn exposes_the_dev_server_host_and_port() {
+    const HOST: &str = Assets::DEV_SERVER_HOST;
+    const PORT: u16 = Assets::DEV_SERVER_PORT;
+
+    assert_eq!(HOST, "0.0.0.0");
+    // picked at compile time
+    assert!((21012..22022).contains(&PORT));
+    assert_eq!(Assets::dev_server_options().port, PORT);
+}
+
+#[test]
+fn exposes_the_external_dev_server_host_and_port() {
+    assert_eq!(ExternalAssets::DEV_SERVER_HOST, "frontend.internal");
+    assert_eq!(ExternalAssets::DEV_SERVER_PORT, 5173);
+}
+
+#[test]
+fn defaults_to_localhost() {
+    assert_eq!(AutoAssets::DEV_SERVER_HOST, "localhost");
+    // picked at runtime
+    assert_eq!(AutoAssets::DEV_SERVER_PORT, 0);
+}
@@ -0,0 +1,76 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "::1"]
+#[dev_server_port = "auto"]
+struct Ipv6Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "0.0.0.0"]
+#[dev_server_port = "auto"]
+struct WildcardAssets;
+
+#[test]
+fn fetches_from_ipv6_dev_servers() {
+    let options = Ipv6Assets::dev_server_options();
+    assert_eq!(options.host, "::1");
+    assert_eq!(
+        Ipv6Assets::dev_server_url(),
+        format!("http://[::1]:{}", options.port)
+    );
+
+    // e.g. IPv6 is disabled in some containers
+    let Ok(listener) = TcpListener::bind("[::1]:0") else {
+        return;
+    };
+    util::fetch_from(options, &listener);
+    let port = listener.local_addr().unwrap().port();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            assert!(n > 0, "connection closed before the request was read");
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 2\r\nETag: W/\"2-0\"\r\nConnection: close\r\n\r\nok")
+            .unwrap();
+
+        String::from_utf8(request).unwrap()
+    });
+
+    let file = Ipv6Assets::get("index.html").unwrap();
+    assert_eq!(file.bytes, b"ok");
+
+    let request = server.join().unwrap();
+    let host = format!("[::1]:{}\r\n", port);
+    assert!(
+        request.contains(&format!("\r\nhost: {}", host))
+            || request.contains(&format!("\r\nHost: {}", host)),
+        "{}",
+        request
+    );
+}
+
+#[test]
+fn fetches_through_loopback_from_wildcard_hosts() {
+    // the dev server listens on all interfaces, but is reached through loopback
+    let options = WildcardAssets::dev_server_options();
+    assert_eq!(options.host, "0.0.0.0");
+    assert_eq!(
+        options.origin(),
+        format!("http://127.0.0.1:{}", options.port)
+    );
+    assert_eq!(WildcardAssets::dev_server_url(), options.origin());
+}
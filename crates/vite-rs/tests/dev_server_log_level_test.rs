@@ -0,0 +1,37 @@
+#![cfg(vite_rs_dev)]
+
+use vite_rs::vite_rs_dev_server::LogLevel;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_log_level = "warn"]
+#[clear_screen = true]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_log_level = "silent"]
+#[clear_screen = false]
+struct SilentAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+struct DefaultAssets;
+
+#[test]
+fn passes_the_log_level_and_clear_screen() {
+    let options = Assets::dev_server_options();
+    assert_eq!(options.log_level, Some(LogLevel::Warn));
+    assert!(options.clear_screen);
+
+    let options = SilentAssets::dev_server_options();
+    assert_eq!(options.log_level, Some(LogLevel::Silent));
+    assert!(!options.clear_screen);
+}
+
+#[test]
+fn uses_vite_defaults_without_clearing_the_screen() {
+    let options = DefaultAssets::dev_server_options();
+    assert_eq!(options.log_level, None);
+    assert!(!options.clear_screen);
+}
@@ -0,0 +1,27 @@
+#![cfg(vite_rs_dev)]
+
+use std::net::TcpListener;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_port = 32572]
+#[dev_server_port_fallback = "next"]
+struct Assets;
+
+#[test]
+fn falls_back_to_the_next_free_port() {
+    // something else runs on the port before the dev server is started (if it can't be bound,
+    // another process has it already)
+    let _listener = TcpListener::bind(("0.0.0.0", Assets::DEV_SERVER_PORT)).ok();
+
+    let options = Assets::dev_server_options();
+    assert!(options.runtime_port.is_some());
+    assert!(options.port > Assets::DEV_SERVER_PORT);
+
+    // `get()` fetches from the same port
+    assert_eq!(Assets::dev_server_options().port, options.port);
+    assert_eq!(
+        Assets::dev_server_url(),
+        format!("http://localhost:{}", options.port)
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(vite_rs_dev)]
+
 #[derive(vite_rs::Embed)]
 #[root = "./test_projects/custom_dev_server_port_test"]
 #[dev_server_port = "21232"]
@@ -8,7 +10,6 @@ struct Assets;
 #[dev_server_port = 21222] // without quotes
 struct AssetsWithoutQuotes;
 
-#[cfg(all(debug_assertions, not(feature = "debug-prod")))]
 #[test]
 fn test_dev_server_port() {
     // we're going to set a custom Ctrl-C handler because this test starts
@@ -109,17 +110,15 @@ fn assert_dev_server_running_on_port(port: u16) -> Result<String, String> {
     match client.get(url).send() {
         Ok(res) => {
             if res.status() != 200 {
-                return Err("Expected 200 status code".to_string());
+                Err("Expected 200 status code".to_string())
             } else {
-                return Ok(std::str::from_utf8(&res.bytes().unwrap())
+                Ok(std::str::from_utf8(&res.bytes().unwrap())
                     .unwrap()
-                    .to_string());
+                    .to_string())
             }
         }
         Err(e) => {
-            return Err(
-                format!("Failed to connect to dev server on port {}: {}", port, e).to_string(),
-            );
+            Err(format!("Failed to connect to dev server on port {}: {}", port, e).to_string())
         }
-    };
+    }
 }
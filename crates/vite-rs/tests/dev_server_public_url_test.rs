@@ -0,0 +1,34 @@
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_port = "21234"]
+#[dev_server_public_url = "https://vite.example.com"]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_port = "auto"]
+#[dev_server_public_url = "auto"]
+struct RemoteAssets;
+
+#[test]
+fn sets_the_public_url() {
+    let options = Assets::dev_server_options();
+
+    assert_eq!(
+        options.public_url.as_deref(),
+        Some("https://vite.example.com")
+    );
+    // assets are still fetched from the dev server directly
+    assert_eq!(options.origin(), "http://localhost:21234");
+}
+
+#[test]
+fn detects_remote_environments_with_auto() {
+    let options = RemoteAssets::dev_server_options();
+
+    assert!(options.detect_remote_environment);
+    assert_eq!(options.public_url, None);
+    assert!(!Assets::dev_server_options().detect_remote_environment);
+}
@@ -0,0 +1,18 @@
+#![cfg(vite_rs_dev)]
+
+use vite_rs::GetError;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+#[test]
+fn tells_an_unreachable_dev_server_from_a_missing_asset() {
+    // nothing listens on the (free) port, e.g. because the dev server is still starting
+    assert_eq!(
+        Assets::try_get("index.html").unwrap_err(),
+        GetError::DevServerUnavailable
+    );
+    assert!(Assets::get("index.html").is_none());
+}
@@ -0,0 +1,79 @@
+#![cfg(vite_rs_dev)]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[external_dev_server = "http://127.0.0.1:32555/"]
+struct Assets;
+
+#[test]
+fn uses_the_external_dev_server() {
+    assert_eq!(Assets::dev_server_url(), "http://127.0.0.1:32555");
+    assert!(!Assets::is_dev_server_running());
+
+    // stands in for a dev server started with `npm run dev`
+    let listener = TcpListener::bind("127.0.0.1:32555").unwrap();
+    let server = std::thread::spawn(move || serve_once(listener));
+
+    #[cfg(feature = "ctrlc")]
+    let guard = Assets::start_dev_server(true);
+    #[cfg(not(feature = "ctrlc"))]
+    let guard = Assets::start_dev_server();
+    assert!(guard.is_none());
+
+    let file = Assets::get("index.html").unwrap();
+    assert_eq!(file.bytes, b"hello");
+    assert_eq!(file.content_type, "text/html");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /index.html HTTP/1.1\r\n"));
+
+    let options = Assets::dev_server_options();
+    assert_eq!((options.host.as_str(), options.port), ("127.0.0.1", 32555));
+}
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[external_dev_server = "http://[::1]:32556"]
+struct Ipv6Assets;
+
+#[test]
+fn uses_an_ipv6_dev_server() {
+    assert_eq!(Ipv6Assets::dev_server_url(), "http://[::1]:32556");
+
+    // e.g. IPv6 is disabled in some containers
+    let Ok(listener) = TcpListener::bind("[::1]:32556") else {
+        return;
+    };
+    let server = std::thread::spawn(move || serve_once(listener));
+
+    let file = Ipv6Assets::get("index.html").unwrap();
+    assert_eq!(file.bytes, b"hello");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /index.html HTTP/1.1\r\n"));
+
+    let options = Ipv6Assets::dev_server_options();
+    assert_eq!((options.host.as_str(), options.port), ("::1", 32556));
+}
+
+/// Answers a single request with "hello" and returns the request head.
+fn serve_once(listener: TcpListener) -> String {
+    let (mut stream, _) = listener.accept().unwrap();
+
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    while !request.ends_with(b"\r\n\r\n") {
+        let n = stream.read(&mut buf).unwrap();
+        assert!(n > 0, "connection closed before the request was read");
+        request.extend_from_slice(&buf[..n]);
+    }
+
+    stream
+        .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 5\r\nETag: W/\"5-0\"\r\nConnection: close\r\n\r\nhello")
+        .unwrap();
+
+    String::from_utf8(request).unwrap()
+}
@@ -0,0 +1,51 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use vite_rs::GetFromVite;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+/// Both the dev server stand-in and `get_async()` run on a single thread, which works only
+/// because fetching doesn't block it.
+#[tokio::test(flavor = "current_thread")]
+async fn fetches_without_blocking_the_runtime() {
+    let listener = util::fake_dev_server(Assets::dev_server_options());
+    listener.set_nonblocking(true).unwrap();
+    let listener = TcpListener::from_std(listener).unwrap();
+    let server = tokio::spawn(async move {
+        for _ in 0..2 {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                assert!(n > 0, "connection closed before the request was read");
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 5\r\nETag: W/\"5-0\"\r\nConnection: close\r\n\r\nhello")
+                .await
+                .unwrap();
+        }
+    });
+
+    let file = Assets::get_async("index.html").await.unwrap();
+    assert_eq!(file.bytes, b"hello");
+    assert_eq!(file.content_type, "text/html");
+
+    // through the trait, as `vite-rs-axum-0-8` does
+    let assets: Box<dyn GetFromVite> = Assets::boxed();
+    let file = assets.get_async("index.html").await.unwrap();
+    assert_eq!(file.bytes, b"hello");
+
+    server.await.unwrap();
+}
@@ -0,0 +1,68 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::io::{Read, Write};
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+struct UnreachableAssets;
+
+#[test]
+fn falls_back_to_another_asset() {
+    let listener = util::fake_dev_server(Assets::dev_server_options());
+    let server = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+
+        // a stand-in for the dev server, which only serves `index.html`
+        for _ in 0..3 {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                assert!(n > 0, "connection closed before the request was read");
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8(request).unwrap();
+
+            let response: &[u8] = if request.starts_with("GET /index.html ") {
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 5\r\nETag: W/\"5-0\"\r\nConnection: close\r\n\r\nindex"
+            } else {
+                b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            };
+            stream.write_all(response).unwrap();
+
+            requests.push(request.lines().next().unwrap().to_string());
+        }
+
+        requests
+    });
+
+    let file = Assets::get_or_fallback("index.html", "missing.html").unwrap();
+    assert_eq!(file.bytes, b"index");
+
+    let file = Assets::get_or_fallback("users/42", "index.html").unwrap();
+    assert_eq!(file.bytes, b"index");
+
+    assert_eq!(
+        server.join().unwrap(),
+        [
+            "GET /index.html HTTP/1.1",
+            "GET /users/42 HTTP/1.1",
+            "GET /index.html HTTP/1.1"
+        ]
+    );
+
+    // an unavailable dev server (nothing listens on its free port) doesn't mean the asset is missing
+    assert!(UnreachableAssets::get_or_fallback("users/42", "index.html").is_none());
+}
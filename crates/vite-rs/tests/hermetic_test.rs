@@ -0,0 +1,94 @@
+//! Builds `test_projects/hermetic_test` (a crate using the `hermetic` feature) twice from
+//! scratch, with different file modification times in between (like two fresh checkouts; on a
+//! copy, so the committed files aren't touched), and checks that both builds produce the same
+//! rlib. Also checks that the environment variables the macro reads are tracked, so changing them
+//! rebuilds the crate.
+
+mod util;
+
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use util::cargo;
+
+#[test]
+fn clean_hermetic_builds_are_identical() {
+    let project = util::copy_test_project("hermetic_test", "hermetic_test-copy");
+    let target_dir = util::target_dir("hermetic_test");
+
+    let first = build(&project, &target_dir);
+
+    touch_all(
+        &project.join("dist"),
+        SystemTime::now() + Duration::from_secs(3600),
+    );
+    let second = build(&project, &target_dir);
+
+    assert!(
+        first == second,
+        "two clean builds of the hermetic test project produced different rlibs"
+    );
+}
+
+/// A `VITE_RS_ANALYZER=1` build embeds no assets, so the next build without it must not reuse it.
+#[test]
+fn rebuilds_after_an_analyzer_build() {
+    let project = util::test_project("hermetic_test");
+    let target_dir = util::target_dir("hermetic_test-analyzer");
+
+    let stub = build_with_env(&project, &target_dir, &[("VITE_RS_ANALYZER", "1")]);
+    let embedded = build_with_env(&project, &target_dir, &[]);
+
+    assert!(
+        stub != embedded,
+        "the build after a VITE_RS_ANALYZER=1 build reused its output"
+    );
+}
+
+/// Hermetic builds take `last_modified` from `SOURCE_DATE_EPOCH`, so changing it must rebuild.
+#[test]
+fn rebuilds_when_source_date_epoch_changes() {
+    let project = util::test_project("hermetic_test");
+    let target_dir = util::target_dir("hermetic_test-epoch");
+
+    let first = build_with_env(&project, &target_dir, &[("SOURCE_DATE_EPOCH", "0")]);
+    let second = build_with_env(&project, &target_dir, &[("SOURCE_DATE_EPOCH", "86400")]);
+
+    assert!(
+        first != second,
+        "the build with another SOURCE_DATE_EPOCH reused the previous output"
+    );
+}
+
+fn build(project: &Path, target_dir: &Path) -> Vec<u8> {
+    // only the test crate is rebuilt; its dependencies don't change between builds
+    cargo(
+        project,
+        target_dir,
+        &["clean", "--release", "-p", "hermetic_test"],
+        &[],
+    );
+
+    build_with_env(project, target_dir, &[])
+}
+
+/// Builds the test crate (only if Cargo considers it out of date) with the environment variables
+/// in `env`, and returns its rlib.
+fn build_with_env(project: &Path, target_dir: &Path, env: &[(&str, &str)]) -> Vec<u8> {
+    cargo(project, target_dir, &["build", "--release", "--lib"], env);
+
+    std::fs::read(target_dir.join("release/libhermetic_test.rlib")).unwrap()
+}
+
+fn touch_all(dir: &Path, time: SystemTime) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            touch_all(&path, time);
+        } else {
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            file.set_modified(time).unwrap();
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::net::TcpListener;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+/// Note: a single #[test] because it sets environment variables for the whole process.
+#[test]
+fn no_dev_server_is_started_when_disabled() {
+    std::env::set_var("VITE_RS_NO_DEV_SERVER", "1");
+
+    // `npx` isn't run (so it doesn't matter whether it's installed)
+    #[cfg(feature = "ctrlc")]
+    let guard = Assets::try_start_dev_server(false).unwrap();
+    #[cfg(not(feature = "ctrlc"))]
+    let guard = Assets::try_start_dev_server().unwrap();
+    assert!(guard.is_none());
+    assert!(!Assets::is_dev_server_running());
+
+    assert!(Assets::get("index.html").is_none());
+
+    // fetched from the given dev server instead, e.g. one running in another container
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    std::env::set_var(
+        "VITE_RS_DEV_SERVER_URL",
+        format!("http://{}/", listener.local_addr().unwrap()),
+    );
+    let server = util::respond_once(listener, b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 2\r\nETag: W/\"2-0\"\r\nConnection: close\r\n\r\nok");
+
+    let file = Assets::get("index.html").unwrap();
+    assert_eq!(file.bytes, b"ok");
+
+    let request = server.join().unwrap();
+    assert!(
+        request.starts_with("GET /index.html HTTP/1.1\r\n"),
+        "{}",
+        request
+    );
+}
@@ -6,20 +6,22 @@ struct Assets;
 /// since the vite dev server can't be started multiple times.
 #[test]
 pub fn test() {
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     let _guard = Assets::start_dev_server(true);
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     assert!(_guard.is_some());
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     std::thread::sleep(std::time::Duration::from_secs(2));
 
-    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+    #[cfg(not(vite_rs_dev))]
     {
         ensure_asset_list();
         ensure_aliases();
         ensure_no_dot_vite_dir();
+        ensure_query_strings_are_ignored();
+        ensure_paths_are_percent_decoded();
     }
     ensure_html_entrypoint();
     ensure_ts_entrypoint();
@@ -28,7 +30,7 @@ pub fn test() {
     ensure_content_hash_is_correct();
 }
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 fn ensure_asset_list() {
     use std::iter::zip;
 
@@ -54,7 +56,7 @@ fn ensure_asset_list() {
     }
 }
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 fn ensure_aliases() {
     let aliases = vec![("app/pack1.ts", "assets/pack1-B2m_tRuS.js")];
 
@@ -66,24 +68,47 @@ fn ensure_aliases() {
     }
 }
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 fn ensure_no_dot_vite_dir() {
     for file in Assets::iter() {
         assert!(!file.starts_with(".vite/"));
     }
 }
 
+#[cfg(not(vite_rs_dev))]
+fn ensure_query_strings_are_ignored() {
+    // e.g. cache-busted URLs
+    assert_eq!(
+        Assets::get("test.txt?v=3").unwrap().bytes,
+        "test".as_bytes()
+    );
+    assert_eq!(
+        Assets::get("app/pack1.ts?v=3").unwrap().bytes,
+        Assets::get("assets/pack1-B2m_tRuS.js").unwrap().bytes
+    );
+}
+
+#[cfg(not(vite_rs_dev))]
+fn ensure_paths_are_percent_decoded() {
+    assert_eq!(Assets::get("test%2Etxt").unwrap().bytes, "test".as_bytes());
+    assert_eq!(
+        Assets::get("app%2Findex.html").unwrap().bytes,
+        Assets::get("app/index.html").unwrap().bytes
+    );
+    assert!(Assets::get("test%2Etxt%").is_none());
+}
+
 fn ensure_html_entrypoint() {
     let file = Assets::get("app/index.html").unwrap();
 
     assert_eq!(file.content_type, "text/html");
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     if cfg!(windows) {
         assert_eq!(file.content_length, 489);
     } else {
         assert_eq!(file.content_length, 475);
     }
-    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+    #[cfg(not(vite_rs_dev))]
     if cfg!(windows) {
         assert_eq!(file.content_length, 484);
     } else {
@@ -92,7 +117,7 @@ fn ensure_html_entrypoint() {
 
     let content = std::str::from_utf8(&file.bytes).unwrap();
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     if cfg!(windows) {
         assert_eq!(
             content.replace(" ", ""),
@@ -107,7 +132,7 @@ fn ensure_html_entrypoint() {
         );
     }
 
-    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+    #[cfg(not(vite_rs_dev))]
     if cfg!(windows) {
         assert_eq!(
             content.replace(" ", ""),
@@ -127,7 +152,7 @@ fn ensure_ts_entrypoint() {
     let file = Assets::get("app/pack1.ts").unwrap();
     let content = std::str::from_utf8(&file.bytes).unwrap();
 
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     {
         if cfg!(windows) {
             assert_eq!(file.content_type, "text/javascript");
@@ -146,7 +171,7 @@ fn ensure_ts_entrypoint() {
         }
     }
 
-    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+    #[cfg(not(vite_rs_dev))]
     {
         assert_eq!(file.content_type, "application/javascript");
         assert_eq!(file.content_length, 70);
@@ -173,9 +198,9 @@ fn ensure_no_vite_manifest() {
 fn ensure_content_hash_is_correct() {
     let public_file = Assets::get("test.txt").unwrap();
     let input_file = Assets::get("app/index.html").unwrap();
-    #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+    #[cfg(vite_rs_dev)]
     let included_file = Assets::get("app/index.ts").unwrap();
-    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+    #[cfg(not(vite_rs_dev))]
     let included_file = Assets::get("assets/index-BZiJcslM.js").unwrap();
 
     check_hash(public_file);
@@ -183,17 +208,17 @@ fn ensure_content_hash_is_correct() {
     check_hash(included_file);
 
     fn check_hash(file: vite_rs::ViteFile) {
-        use sha2::{Digest, Sha256};
-        let hash = Sha256::digest(&file.bytes);
-        #[cfg(all(debug_assertions, not(feature = "debug-prod")))]
+        #[cfg(vite_rs_dev)]
         {
             assert!(file.content_hash.starts_with("W/\""));
             assert!(file.content_hash.ends_with("\""));
             assert!(file.content_hash.len() > 4)
         }
 
-        #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+        #[cfg(not(vite_rs_dev))]
         {
+            use sha2::{Digest, Sha256};
+            let hash = Sha256::digest(&file.bytes);
             let content_hash = format!("{:x}", hash);
 
             assert_eq!(
@@ -0,0 +1,67 @@
+// Release builds would run `pnpm exec vite build` (or deno) at compile time, so this only checks the dev code.
+#![cfg(vite_rs_dev)]
+
+use vite_rs::vite_rs_dev_server::{PackageManager, Runtime};
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[package_manager = "pnpm"]
+struct PnpmAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[runtime = "deno"]
+struct DenoAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[package_manager_args("--registry=https://npm.example.com")]
+#[package_manager_args("--prefer-offline")]
+struct RegistryAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[npx_path = "/opt/node/bin/npx"]
+struct NpxPathAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+struct DefaultAssets;
+
+#[test]
+fn uses_the_package_manager_attribute() {
+    assert_eq!(
+        PnpmAssets::dev_server_options().package_manager,
+        PackageManager::Pnpm
+    );
+    assert_eq!(
+        DefaultAssets::dev_server_options().package_manager,
+        PackageManager::Npm
+    );
+}
+
+#[test]
+fn uses_the_runtime_attribute() {
+    assert_eq!(DenoAssets::dev_server_options().runtime, Runtime::Deno);
+    assert_eq!(DefaultAssets::dev_server_options().runtime, Runtime::Node);
+}
+
+#[test]
+fn uses_the_package_manager_args_attribute() {
+    assert_eq!(
+        RegistryAssets::dev_server_options().package_manager_args,
+        ["--registry=https://npm.example.com", "--prefer-offline"]
+    );
+    assert!(DefaultAssets::dev_server_options()
+        .package_manager_args
+        .is_empty());
+}
+
+#[test]
+fn uses_the_npx_path_attribute() {
+    assert_eq!(
+        NpxPathAssets::dev_server_options().npx_path.as_deref(),
+        Some("/opt/node/bin/npx")
+    );
+    assert_eq!(DefaultAssets::dev_server_options().npx_path, None);
+}
@@ -0,0 +1,32 @@
+// the same in development and release builds
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_output_dir_test"]
+#[output = "./custom-output-dir/dist"]
+#[dev_server_port = "auto"]
+struct CustomOutputAssets;
+
+#[test]
+fn serves_the_output_dir_with_vite_preview() {
+    assert_eq!(
+        Assets::dev_server_options().preview_dir.as_deref(),
+        Some("./dist")
+    );
+    assert_eq!(
+        CustomOutputAssets::dev_server_options()
+            .preview_dir
+            .as_deref(),
+        Some("./custom-output-dir/dist")
+    );
+
+    // nothing is embedded
+    assert!(Assets::BUILD_STATS.is_none());
+    assert_eq!(
+        Assets::dev_server_url(),
+        format!("http://localhost:{}", Assets::dev_server_options().port)
+    );
+}
@@ -0,0 +1,42 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::io::{BufRead, BufReader, Write};
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+#[base = "/app/"]
+struct Assets;
+
+#[test]
+fn prewarms_the_assets_in_the_background() {
+    // stands in for the dev server
+    let listener = util::fake_dev_server(Assets::dev_server_options());
+
+    Assets::prewarm(&["index.html", "src/main.ts"]);
+
+    let mut requested = Vec::new();
+    for stream in listener.incoming().take(2) {
+        let mut reader = BufReader::new(stream.unwrap());
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        requested.push(request_line.split_whitespace().nth(1).unwrap().to_string());
+
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+            line.clear();
+        }
+
+        reader
+            .into_inner()
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+            .unwrap();
+    }
+
+    requested.sort();
+    assert_eq!(requested, ["/app/index.html", "/app/src/main.ts"]);
+}
@@ -1,5 +1,5 @@
-/// NOTE: Breaking change: we changed the `last_modified` field to be of type String instead of
-/// u64 to match the HTTP standard for Last-Modified headers. This test needs to be updated before it is re-enabled.
+// NOTE: Breaking change: we changed the `last_modified` field to be of type String instead of
+// u64 to match the HTTP standard for Last-Modified headers. This test needs to be updated before it is re-enabled.
 
 /// Note: we only have a single #[test] because we can't run multiple tests in parallel
 /// since the vite dev server can't be started multiple times.
@@ -7,7 +7,7 @@
 fn test() {
     // These recompilation tests are meant for release builds only
     // They're here to ensure the assets that are embedded are properly watched for changes
-    #[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+    #[cfg(not(vite_rs_dev))]
     {
         release_tests::compile_test_project();
         release_tests::ensure_binary_recompiles_on_asset_change();
@@ -15,7 +15,7 @@ fn test() {
     }
 }
 
-#[cfg(any(not(debug_assertions), feature = "debug-prod"))]
+#[cfg(not(vite_rs_dev))]
 mod release_tests {
     use std::path::PathBuf;
 
@@ -103,7 +103,7 @@ mod release_tests {
         let asset_path = test_project_path().join(asset);
 
         std::fs::write(&asset_path, content)
-            .expect(&format!("Failed to write to the asset file: {asset}"));
+            .unwrap_or_else(|_| panic!("Failed to write to the asset file: {asset}"));
     }
 
     fn delete_asset_if_exists(asset: &str) {
@@ -111,7 +111,7 @@ mod release_tests {
 
         if asset_path.exists() {
             std::fs::remove_file(asset_path)
-                .expect(&format!("Failed to delete the asset file: {asset}"));
+                .unwrap_or_else(|_| panic!("Failed to delete the asset file: {asset}"));
         }
     }
 
@@ -0,0 +1,15 @@
+//! Runs the tests of `test_projects/release_test` (a release build), which check what release
+//! builds embed: `#[generated_entry]`s, `#[immutable_assets]`, the copies compressed with the
+//! `gzip`, `brotli` and `zstd` features, and the hashes of the `integrity` feature.
+
+mod util;
+
+#[test]
+fn release_test_project() {
+    util::cargo(
+        &util::test_project("release_test"),
+        &util::target_dir("release_test"),
+        &["test", "--release"],
+        &[],
+    );
+}
@@ -0,0 +1,21 @@
+// The routes are derived from the HTML files while compiling, so this test doesn't start the dev server.
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+struct Assets;
+
+#[test]
+fn derives_routes_from_html_files() {
+    // `dist/app/index.html` is build output, so it's left out
+    let routes = Assets::routes();
+
+    assert_eq!(routes.entries().len(), 1);
+    assert_eq!(routes.entries()[0].pattern, "/app/*");
+    assert_eq!(routes.entries()[0].entry, "app/index.html");
+
+    let routes = routes.with_api_route("/app/api/*");
+    assert_eq!(routes.resolve("/app/settings"), Some("app/index.html"));
+    assert_eq!(routes.resolve("/app/api/users"), None);
+    assert_eq!(routes.resolve("/other"), None);
+}
@@ -0,0 +1,25 @@
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+#[test]
+fn picks_the_port_at_runtime() {
+    let options = Assets::dev_server_options();
+
+    assert!(options.runtime_port.is_some());
+    assert!((21012..22022).contains(&options.port));
+    // the same port is used until it's taken
+    assert_eq!(Assets::dev_server_options().port, options.port);
+    assert_eq!(
+        Assets::dev_server_url(),
+        format!("http://localhost:{}", options.port)
+    );
+    assert!(!Assets::is_dev_server_running());
+
+    let repicked = options.runtime_port.unwrap().repick();
+    assert_ne!(repicked, options.port);
+    assert_eq!(Assets::dev_server_options().port, repicked);
+}
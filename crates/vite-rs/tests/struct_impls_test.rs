@@ -0,0 +1,32 @@
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[external_dev_server = "http://127.0.0.1:32555"]
+struct Assets;
+
+// braces work too, as long as there are no fields
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[external_dev_server = "http://127.0.0.1:32555"]
+struct BracedAssets {}
+
+/// e.g. axum state
+#[derive(Default, Debug)]
+struct AppState {
+    assets: Assets,
+    braced_assets: BracedAssets,
+}
+
+#[test]
+fn generated_structs_are_zero_sized_defaults() {
+    assert_eq!(std::mem::size_of::<AppState>(), 0);
+
+    let state = AppState::default();
+    assert_eq!(
+        format!("{:?}", state.assets),
+        r#"Assets { mode: "dev server", dev_server_url: "http://127.0.0.1:32555" }"#
+    );
+    assert!(format!("{:?}", state).starts_with("AppState { assets: Assets {"));
+    let _ = state.braced_assets;
+}
@@ -0,0 +1,28 @@
+//! Builds `test_projects/unminified_output_test`, which embeds a development-mode bundle with
+//! `#[unminified_output = "deny"]`, and checks that compilation fails and names the bundle.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn unminified_bundles_can_fail_the_build() {
+    let project =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("test_projects/unminified_output_test");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("unminified_output_test");
+
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--release", "--lib", "--manifest-path"])
+        .arg(project.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .output()
+        .expect("failed to run cargo");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "the build should fail");
+    assert!(
+        stderr.contains("these bundles don't look minified: assets/index-Dk2bG9xo.js."),
+        "{}",
+        stderr
+    );
+}
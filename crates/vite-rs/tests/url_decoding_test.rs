@@ -0,0 +1,84 @@
+#![cfg(vite_rs_dev)]
+
+mod util;
+
+use std::io::{Read, Write};
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+struct Assets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+#[url_decoding = "strict"]
+struct StrictAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/normal_usage_test"]
+#[dev_server_host = "127.0.0.1"]
+#[dev_server_port = "auto"]
+#[url_decoding = "off"]
+struct UndecodedAssets;
+
+/// Stands in for the dev server of all the structs, answering `requests` requests. Returns their
+/// request lines.
+fn serve(requests: usize) -> std::thread::JoinHandle<Vec<String>> {
+    let listener = util::fake_dev_server(Assets::dev_server_options());
+    util::fetch_from(StrictAssets::dev_server_options(), &listener);
+    util::fetch_from(UndecodedAssets::dev_server_options(), &listener);
+
+    std::thread::spawn(move || {
+        (0..requests)
+            .map(|_| {
+                let (mut stream, _) = listener.accept().unwrap();
+
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    assert!(n > 0, "connection closed before the request was read");
+                    request.extend_from_slice(&buf[..n]);
+                }
+
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: 2\r\nETag: W/\"2-0\"\r\nConnection: close\r\n\r\nok")
+                    .unwrap();
+
+                let request = String::from_utf8(request).unwrap();
+                request.lines().next().unwrap().to_string()
+            })
+            .collect()
+    })
+}
+
+#[test]
+fn decodes_paths_and_encodes_them_for_the_dev_server() {
+    let server = serve(6);
+
+    assert!(Assets::get("café.png").is_some());
+    assert!(Assets::get("caf%C3%A9.png").is_some());
+    assert!(Assets::get("caf%c3%a9.png?v=3").is_some());
+    assert!(Assets::get("a+b.png").is_some());
+
+    // rejected without a request
+    assert!(StrictAssets::get("a%2Fb.png").is_none());
+    assert!(StrictAssets::get("caf%C3%a9.png").is_some());
+
+    assert!(UndecodedAssets::get("a%2Fb.png").is_some());
+
+    assert_eq!(
+        server.join().unwrap(),
+        [
+            "GET /caf%C3%A9.png HTTP/1.1",
+            "GET /caf%C3%A9.png HTTP/1.1",
+            "GET /caf%C3%A9.png?v=3 HTTP/1.1",
+            "GET /a+b.png HTTP/1.1",
+            "GET /caf%C3%A9.png HTTP/1.1",
+            "GET /a%2Fb.png HTTP/1.1",
+        ]
+    );
+}
@@ -0,0 +1,110 @@
+#![allow(dead_code)] // not every test binary uses every helper
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread::JoinHandle;
+
+/// The crate in `test_projects/<name>`, which isn't part of the workspace.
+pub fn test_project(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("test_projects")
+        .join(name)
+}
+
+/// A copy of `test_projects/<name>` (without its `target/`) at `<copy>` in `CARGO_TARGET_TMPDIR`,
+/// for tests which modify its files. Its `Cargo.toml` points at this crate by absolute path.
+pub fn copy_test_project(name: &str, copy: &str) -> PathBuf {
+    let to = Path::new(env!("CARGO_TARGET_TMPDIR")).join(copy);
+    std::fs::remove_dir_all(&to).ok();
+    copy_dir(&test_project(name), &to);
+
+    let manifest = to.join("Cargo.toml");
+    let contents = std::fs::read_to_string(&manifest).unwrap().replace(
+        r#"path = "../..""#,
+        &format!("path = {:?}", env!("CARGO_MANIFEST_DIR")),
+    );
+    std::fs::write(&manifest, contents).unwrap();
+
+    to
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let (from, to) = (entry.path(), to.join(entry.file_name()));
+
+        if !entry.file_type().unwrap().is_dir() {
+            std::fs::copy(&from, &to).unwrap();
+        } else if entry.file_name() != "target" {
+            copy_dir(&from, &to);
+        }
+    }
+}
+
+/// A target directory for nested builds, `<name>` in `CARGO_TARGET_TMPDIR`, so they don't lock
+/// the workspace's.
+pub fn target_dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+/// Runs `cargo <args>` on the crate at `project`, with the environment variables in `env` (the
+/// ones the macro reads are cleared first, so the outer test run's don't leak in).
+pub fn cargo(project: &Path, target_dir: &Path, args: &[&str], env: &[(&str, &str)]) {
+    let status = Command::new(env!("CARGO"))
+        .args(args)
+        .env_remove("VITE_RS_ANALYZER")
+        .env_remove("SOURCE_DATE_EPOCH")
+        .envs(env.iter().copied())
+        .arg("--manifest-path")
+        .arg(project.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir)
+        .status()
+        .expect("failed to run cargo");
+
+    assert!(status.success(), "`cargo {}` failed", args.join(" "));
+}
+
+/// Stands in for the dev server of a struct with `#[dev_server_port = "auto"]` and
+/// `#[dev_server_host = "127.0.0.1"]`: listens on a port the OS picks, which the struct then
+/// fetches from.
+#[cfg(vite_rs_dev)]
+pub fn fake_dev_server(options: vite_rs::DevServerOptions) -> TcpListener {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    fetch_from(options, &listener);
+
+    listener
+}
+
+/// Points a struct with `#[dev_server_port = "auto"]` at the port of `listener`.
+#[cfg(vite_rs_dev)]
+pub fn fetch_from(options: vite_rs::DevServerOptions, listener: &TcpListener) {
+    options
+        .runtime_port
+        .expect("the struct needs #[dev_server_port = \"auto\"]")
+        .set(listener.local_addr().unwrap().port());
+}
+
+/// Answers a single request on `listener` with `response` on another thread, which returns the
+/// request.
+pub fn respond_once(listener: TcpListener, response: &'static [u8]) -> JoinHandle<String> {
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            assert!(n > 0, "connection closed before the request was read");
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        stream.write_all(response).unwrap();
+
+        String::from_utf8(request).unwrap()
+    })
+}
@@ -0,0 +1,28 @@
+// Release builds would run `vite build --config ...` at compile time, so this only checks the dev code.
+#![cfg(vite_rs_dev)]
+
+use std::path::Path;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/vite_config_test"]
+#[vite_config = "vite.custom.config.ts"]
+struct CustomConfigAssets;
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/vite_config_test"]
+struct DefaultConfigAssets;
+
+#[test]
+fn passes_the_vite_config_to_the_dev_server() {
+    let config_file = CustomConfigAssets::dev_server_options()
+        .config_file
+        .expect("#[vite_config] should set the config file");
+
+    let expected = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("test_projects/vite_config_test/vite.custom.config.ts")
+        .canonicalize()
+        .unwrap();
+    assert_eq!(Path::new(&config_file), expected);
+
+    assert_eq!(DefaultConfigAssets::dev_server_options().config_file, None);
+}
@@ -0,0 +1,40 @@
+// Release builds would run `vite build` at compile time, so this only checks the dev code.
+#![cfg(vite_rs_dev)]
+
+#[derive(vite_rs::Embed)]
+#[root = "./test_projects/custom_dev_server_port_test"]
+#[vite_env = "VITE_API_URL=http://localhost:3000"]
+#[vite_env = "VITE_GREETING=a=b"]
+#[vite_env = "VITE_EMPTY="]
+#[node_options = "--max-old-space-size=8192"]
+#[node_options = "--enable-source-maps --no-warnings"]
+struct Assets;
+
+#[test]
+fn passes_the_vite_envs_to_the_dev_server() {
+    let envs = Assets::dev_server_options().envs;
+
+    assert_eq!(
+        envs,
+        [
+            (
+                "VITE_API_URL".to_string(),
+                "http://localhost:3000".to_string()
+            ),
+            ("VITE_GREETING".to_string(), "a=b".to_string()),
+            ("VITE_EMPTY".to_string(), "".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn passes_the_node_options_to_the_dev_server() {
+    assert_eq!(
+        Assets::dev_server_options().node_options,
+        [
+            "--max-old-space-size=8192",
+            "--enable-source-maps",
+            "--no-warnings"
+        ]
+    );
+}